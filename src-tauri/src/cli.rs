@@ -0,0 +1,569 @@
+// src-tauri/src/cli.rs
+//
+// 命令行入口：
+//   piterm                        正常启动界面
+//   piterm connect <server>       按 ID / 名称 / 标签查找服务器并打开终端
+//   piterm ssh://user@host:2222   打开 URL 对应的连接 (sftp:// 同样处理)
+//   piterm list [--json]          列出已保存的服务器 (无窗口)
+//   piterm exec <server> <cmd>    在服务器上执行命令并输出结果 (无窗口)
+//
+// 界面类的请求在应用已运行时由单实例插件转发给原进程，
+// 统一放入 CliState 队列并发出 cli-request 事件，前端收到后调用 take_cli_requests 取走。
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use aes_gcm::{Aes256Gcm, Key};
+use serde::Serialize;
+use sqlx::{Pool, Row, Sqlite};
+use ssh2::{CheckResult, KnownHostFileKind, Session};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::commands::ssh::core::{authenticate_session, open_handshaken_session};
+use crate::commands::ssh::{
+    compute_fingerprint, fetch_server_row, resolve_ssh_config, uses_vault_credentials,
+};
+use crate::commands::vault::internal_unlock;
+use crate::db;
+use crate::error::{AppError, AppResult};
+
+pub const USAGE: &str = "Usage:
+  piterm                         Start the application
+  piterm connect <server>        Open a terminal to a saved server (ID, name or tag)
+  piterm ssh://[user@]host[:port]
+  piterm sftp://[user@]host[:port]
+                                 Connect to a saved server matching the URL, or quick-connect
+  piterm list [--json]           List saved servers
+  piterm exec <server> <command> Run a command on a saved server and print its output
+
+Environment:
+  PITERM_VAULT_PASSWORD          Vault master password for exec (prompted when unset)";
+
+/// exec 解锁保险库时读取的环境变量
+const VAULT_PASSWORD_ENV: &str = "PITERM_VAULT_PASSWORD";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CliTarget {
+    /// 已保存的服务器 (ID / 名称 / 标签)
+    Server(String),
+    Url(SshUrl),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SshUrl {
+    pub username: Option<String>,
+    pub host: String,
+    pub port: u16,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CliCommand {
+    /// 启动界面，可附带一个连接目标
+    Gui(Option<CliTarget>),
+    List { json: bool },
+    Exec { server: String, command: String },
+    Help,
+    Version,
+}
+
+/// 推送给前端的请求
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum CliRequest {
+    #[serde(rename_all = "camelCase")]
+    Connect { server_id: String, name: String },
+    /// 没有匹配的已保存服务器时按快速连接处理
+    #[serde(rename_all = "camelCase")]
+    QuickConnect {
+        host: String,
+        port: u16,
+        username: Option<String>,
+    },
+    Error { error: AppError },
+}
+
+/// 待前端取走的请求队列
+#[derive(Default)]
+pub struct CliState(pub Mutex<Vec<CliRequest>>);
+
+// ==============================================================================
+// 参数解析
+// ==============================================================================
+
+fn is_url(arg: &str) -> bool {
+    let lower = arg.to_ascii_lowercase();
+    lower.starts_with("ssh://") || lower.starts_with("sftp://")
+}
+
+/// 解析 ssh:// / sftp:// URL
+/// 形如 ssh://user@host:port，用户名中的密码部分 (user:pass) 与 ;fingerprint= 参数会被忽略
+pub fn parse_url(raw: &str) -> Result<SshUrl, String> {
+    let (_, rest) = raw
+        .split_once("://")
+        .filter(|(scheme, _)| scheme.eq_ignore_ascii_case("ssh") || scheme.eq_ignore_ascii_case("sftp"))
+        .ok_or_else(|| format!("Unsupported URL: {}", raw))?;
+
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let (userinfo, hostport) = match authority.rsplit_once('@') {
+        Some((user, host)) => (Some(user), host),
+        None => (None, authority),
+    };
+
+    let username = userinfo
+        .map(|u| u.split([':', ';']).next().unwrap_or_default())
+        .filter(|u| !u.is_empty())
+        .map(|u| {
+            urlencoding::decode(u)
+                .map(|d| d.into_owned())
+                .map_err(|e| format!("Invalid username in URL: {}", e))
+        })
+        .transpose()?;
+
+    // [IPv6]:port 或 host:port
+    let (host, port) = if let Some(v6) = hostport.strip_prefix('[') {
+        let (host, tail) = v6
+            .split_once(']')
+            .ok_or_else(|| format!("Invalid host in URL: {}", raw))?;
+        (host, tail.strip_prefix(':'))
+    } else {
+        match hostport.rsplit_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (hostport, None),
+        }
+    };
+
+    if host.is_empty() {
+        return Err(format!("Missing host in URL: {}", raw));
+    }
+    let port = match port.filter(|p| !p.is_empty()) {
+        Some(p) => p.parse().map_err(|_| format!("Invalid port in URL: {}", raw))?,
+        None => 22,
+    };
+
+    Ok(SshUrl {
+        username,
+        host: host.to_string(),
+        port,
+    })
+}
+
+/// 解析命令行参数 (args[0] 为程序路径)
+pub fn parse_args(args: &[String]) -> Result<CliCommand, String> {
+    // macOS 从 Finder 启动时会附带 -psn_ 参数
+    let args: Vec<&str> = args
+        .iter()
+        .skip(1)
+        .map(String::as_str)
+        .filter(|a| !a.starts_with("-psn_"))
+        .collect();
+
+    let Some((&first, rest)) = args.split_first() else {
+        return Ok(CliCommand::Gui(None));
+    };
+
+    match first {
+        "-h" | "--help" | "help" => Ok(CliCommand::Help),
+        "-V" | "--version" => Ok(CliCommand::Version),
+        "connect" => match rest {
+            [server] => Ok(CliCommand::Gui(Some(CliTarget::Server(server.to_string())))),
+            _ => Err("connect expects exactly one server".to_string()),
+        },
+        "list" => match rest {
+            [] => Ok(CliCommand::List { json: false }),
+            ["--json"] => Ok(CliCommand::List { json: true }),
+            _ => Err(format!("Unexpected arguments for list: {}", rest.join(" "))),
+        },
+        "exec" => match rest {
+            [server, command @ ..] if !command.is_empty() => Ok(CliCommand::Exec {
+                server: server.to_string(),
+                command: command.join(" "),
+            }),
+            _ => Err("exec expects a server and a command".to_string()),
+        },
+        url if is_url(url) && rest.is_empty() => Ok(CliCommand::Gui(Some(CliTarget::Url(parse_url(url)?)))),
+        other => Err(format!("Unknown command: {}", other)),
+    }
+}
+
+// ==============================================================================
+// 服务器查找
+// ==============================================================================
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerSummary {
+    pub id: String,
+    pub name: String,
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub protocol: String,
+    pub tags: Vec<String>,
+}
+
+async fn load_server_summaries(pool: &Pool<Sqlite>) -> AppResult<Vec<ServerSummary>> {
+    let rows = sqlx::query("SELECT id, name, ip, port, username, protocol, tags FROM servers WHERE deleted_at IS NULL ORDER BY sort ASC")
+        .fetch_all(pool)
+        .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| ServerSummary {
+            id: row.try_get("id").unwrap_or_default(),
+            name: row.try_get("name").unwrap_or_default(),
+            host: row.try_get("ip").unwrap_or_default(),
+            port: row.try_get::<i64, _>("port").map(|p| p as u16).unwrap_or(22),
+            username: row.try_get("username").unwrap_or_default(),
+            protocol: row
+                .try_get::<Option<String>, _>("protocol")
+                .ok()
+                .flatten()
+                .unwrap_or_else(|| "ssh".to_string()),
+            tags: row
+                .try_get::<Option<String>, _>("tags")
+                .ok()
+                .flatten()
+                .and_then(|s| serde_json::from_str(&s).ok())
+                .unwrap_or_default(),
+        })
+        .collect())
+}
+
+// 多个匹配时报错并列出候选，避免连错服务器
+fn pick_one(query: &str, matches: Vec<ServerSummary>) -> AppResult<Option<ServerSummary>> {
+    if matches.len() > 1 {
+        let names: Vec<String> = matches.iter().map(|s| s.name.clone()).collect();
+        return Err(AppError::InvalidInput(format!(
+            "'{}' matches several servers: {}",
+            query,
+            names.join(", ")
+        )));
+    }
+    Ok(matches.into_iter().next())
+}
+
+/// 按 ID、名称 (忽略大小写)、标签的顺序查找服务器
+pub async fn find_server(pool: &Pool<Sqlite>, query: &str) -> AppResult<ServerSummary> {
+    let servers = load_server_summaries(pool).await?;
+
+    if let Some(pos) = servers.iter().position(|s| s.id == query) {
+        return Ok(servers.into_iter().nth(pos).unwrap());
+    }
+
+    let (by_name, rest): (Vec<_>, Vec<_>) = servers
+        .into_iter()
+        .partition(|s| s.name.eq_ignore_ascii_case(query));
+    if let Some(server) = pick_one(query, by_name)? {
+        return Ok(server);
+    }
+
+    let by_tag = rest
+        .into_iter()
+        .filter(|s| s.tags.iter().any(|t| t.eq_ignore_ascii_case(query)))
+        .collect();
+    pick_one(query, by_tag)?.ok_or_else(|| AppError::NotFound(format!("No server matches '{}'", query)))
+}
+
+/// 把命令行目标解析为前端请求
+/// URL 优先匹配主机、端口 (及用户名) 相同的已保存服务器，找不到时走快速连接
+pub async fn resolve_request(pool: &Pool<Sqlite>, target: CliTarget) -> AppResult<CliRequest> {
+    match target {
+        CliTarget::Server(query) => {
+            let server = find_server(pool, &query).await?;
+            Ok(CliRequest::Connect {
+                server_id: server.id,
+                name: server.name,
+            })
+        }
+        CliTarget::Url(url) => {
+            let saved = load_server_summaries(pool).await?.into_iter().find(|s| {
+                s.protocol == "ssh"
+                    && s.host.eq_ignore_ascii_case(&url.host)
+                    && s.port == url.port
+                    && url.username.as_ref().is_none_or(|u| *u == s.username)
+            });
+            Ok(match saved {
+                Some(server) => CliRequest::Connect {
+                    server_id: server.id,
+                    name: server.name,
+                },
+                None => CliRequest::QuickConnect {
+                    host: url.host,
+                    port: url.port,
+                    username: url.username,
+                },
+            })
+        }
+    }
+}
+
+// ==============================================================================
+// 界面模式：请求入队
+// ==============================================================================
+
+/// 解析目标并放入队列，通知前端并把主窗口置前
+pub fn dispatch_target(app: &AppHandle, target: CliTarget) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let pool = app.state::<crate::state::AppState>().db.clone();
+        let request = resolve_request(&pool, target)
+            .await
+            .unwrap_or_else(|error| CliRequest::Error { error });
+        enqueue_request(&app, request);
+    });
+}
+
+pub fn enqueue_request(app: &AppHandle, request: CliRequest) {
+    app.state::<CliState>().0.lock().unwrap().push(request);
+    let _ = app.emit("cli-request", ());
+    focus_main_window(app);
+}
+
+fn focus_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+/// 第二个实例启动时，单实例插件把它的参数转发到这里
+/// URL 参数由 deep-link 插件的 on_open_url 处理，这里跳过以免重复连接
+pub fn handle_forwarded_args(app: &AppHandle, args: Vec<String>) {
+    match parse_args(&args) {
+        Ok(CliCommand::Gui(Some(CliTarget::Server(query)))) => {
+            dispatch_target(app, CliTarget::Server(query))
+        }
+        Ok(CliCommand::Gui(_)) => focus_main_window(app),
+        // list / exec 等无窗口命令不会转发过来
+        Ok(_) => {}
+        Err(e) => enqueue_request(app, CliRequest::Error { error: AppError::InvalidInput(e) }),
+    }
+}
+
+/// deep-link 插件收到的 URL (macOS 启动与运行中，以及其他平台运行中)
+pub fn handle_urls(app: &AppHandle, urls: Vec<String>) {
+    for url in urls {
+        match parse_url(&url) {
+            Ok(target) => dispatch_target(app, CliTarget::Url(target)),
+            Err(e) => enqueue_request(app, CliRequest::Error { error: AppError::InvalidInput(e) }),
+        }
+    }
+}
+
+/// 前端启动时及收到 cli-request 事件后调用，取走全部待处理请求
+#[tauri::command]
+pub fn take_cli_requests(state: State<'_, CliState>) -> Vec<CliRequest> {
+    std::mem::take(&mut *state.0.lock().unwrap())
+}
+
+// ==============================================================================
+// 无窗口模式：list / exec
+// ==============================================================================
+
+// Windows 发布版是 GUI 子系统程序 (windows_subsystem = "windows")，启动时没有控制台，
+// 无窗口命令要接入启动它的终端，输出和提示才能显示出来
+#[cfg(windows)]
+pub(crate) fn attach_parent_console() {
+    const ATTACH_PARENT_PROCESS: u32 = u32::MAX;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn AttachConsole(process_id: u32) -> i32;
+    }
+
+    // 从资源管理器等没有控制台的父进程启动时会失败，此时保持原样
+    unsafe {
+        AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+}
+
+/// 执行无窗口命令，返回进程退出码
+/// identifier 用于定位与界面相同的数据目录
+pub fn run_headless(command: CliCommand, identifier: &str) -> i32 {
+    #[cfg(windows)]
+    attach_parent_console();
+
+    let result = tauri::async_runtime::block_on(async move {
+        match command {
+            CliCommand::Help => {
+                println!("{}", USAGE);
+                Ok(0)
+            }
+            CliCommand::Version => {
+                println!("piterm {}", env!("CARGO_PKG_VERSION"));
+                Ok(0)
+            }
+            CliCommand::List { json } => {
+                let pool = open_headless_db(identifier).await?;
+                print_servers(&pool, json).await?;
+                Ok(0)
+            }
+            CliCommand::Exec { server, command } => {
+                let pool = open_headless_db(identifier).await?;
+                exec_remote(&pool, &server, command).await
+            }
+            CliCommand::Gui(_) => Err(AppError::Internal("GUI command in headless mode".to_string())),
+        }
+    });
+
+    result.unwrap_or_else(|e| {
+        eprintln!("piterm: {}", e);
+        1
+    })
+}
+
+async fn open_headless_db(identifier: &str) -> AppResult<Pool<Sqlite>> {
+    let app_dir = dirs::data_dir()
+        .ok_or_else(|| AppError::Io("Could not determine data directory".to_string()))?
+        .join(identifier);
+    Ok(db::open_db(&app_dir).await?)
+}
+
+async fn print_servers(pool: &Pool<Sqlite>, json: bool) -> AppResult<()> {
+    let servers = load_server_summaries(pool).await?;
+
+    if json {
+        let out = serde_json::to_string_pretty(&servers).map_err(|e| AppError::Internal(e.to_string()))?;
+        println!("{}", out);
+        return Ok(());
+    }
+
+    let name_width = servers.iter().map(|s| s.name.chars().count()).max().unwrap_or(0).max(4);
+    for s in &servers {
+        let target = if s.username.is_empty() {
+            format!("{}:{}", s.host, s.port)
+        } else {
+            format!("{}@{}:{}", s.username, s.host, s.port)
+        };
+        println!(
+            "{:<name_width$}  {:<6}  {}  {}",
+            s.name,
+            s.protocol,
+            target,
+            s.tags.join(","),
+            name_width = name_width
+        );
+    }
+    Ok(())
+}
+
+// 优先读取环境变量，否则在终端中提示输入 (不回显)
+async fn unlock_vault_headless(pool: &Pool<Sqlite>) -> AppResult<Key<Aes256Gcm>> {
+    let password = match std::env::var(VAULT_PASSWORD_ENV) {
+        Ok(p) => p,
+        Err(_) => rpassword::prompt_password("Vault master password: ")?,
+    };
+    internal_unlock(pool, &password)
+        .await?
+        .ok_or_else(|| AppError::AuthFailed("Incorrect vault master password".to_string()))
+}
+
+async fn exec_remote(pool: &Pool<Sqlite>, query: &str, command: String) -> AppResult<i32> {
+    let server = find_server(pool, query).await?;
+    if server.protocol != "ssh" {
+        return Err(AppError::InvalidInput(format!(
+            "exec is only supported for SSH servers ('{}' uses {})",
+            server.name, server.protocol
+        )));
+    }
+
+    let row = fetch_server_row(pool, &server.id).await?;
+    let master_key = if uses_vault_credentials(&row) {
+        Some(unlock_vault_headless(pool).await?)
+    } else {
+        None
+    };
+    let config = resolve_ssh_config(pool, master_key.as_ref(), &row, &server.id).await?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let sess = open_handshaken_session(&config)?;
+        verify_known_host(&sess, &config.host, config.port)?;
+        authenticate_session(&sess, &config)?;
+
+        let mut channel = sess.channel_session()?;
+        channel.exec(&command)?;
+        copy_output(&sess, &mut channel)?;
+        channel.wait_close()?;
+        Ok(channel.exit_status()?)
+    })
+    .await
+    .map_err(|e| AppError::Internal(e.to_string()))?
+}
+
+// 非阻塞地轮流读取 stdout 与 stderr：
+// 顺序读取时，远端向 stderr 写满通道窗口后会停下等待，而本地还在等 stdout 结束
+fn copy_output(sess: &Session, channel: &mut ssh2::Channel) -> AppResult<()> {
+    sess.set_blocking(false);
+    let mut buf = [0u8; 8192];
+    loop {
+        let mut progressed = false;
+        for is_stderr in [false, true] {
+            let result = if is_stderr {
+                channel.stderr().read(&mut buf)
+            } else {
+                channel.read(&mut buf)
+            };
+            match result {
+                Ok(0) => {}
+                Ok(n) if is_stderr => {
+                    io::stderr().write_all(&buf[..n])?;
+                    progressed = true;
+                }
+                Ok(n) => {
+                    io::stdout().write_all(&buf[..n])?;
+                    progressed = true;
+                }
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {}
+                Err(e) => {
+                    sess.set_blocking(true);
+                    return Err(e.into());
+                }
+            }
+        }
+        // EOF 之后缓冲中可能还有数据，读空后再退出
+        if !progressed {
+            if channel.eof() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+    sess.set_blocking(true);
+    io::stdout().flush()?;
+    Ok(())
+}
+
+fn known_hosts_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|p| p.join(".ssh").join("known_hosts"))
+}
+
+// 无窗口模式无法弹出确认框，只连接 known_hosts 中已信任的主机
+fn verify_known_host(sess: &Session, host: &str, port: u16) -> AppResult<()> {
+    let (key, _) = sess
+        .host_key()
+        .ok_or_else(|| AppError::Ssh("No host key received from server".to_string()))?;
+
+    let mut known_hosts = sess.known_hosts()?;
+    if let Some(path) = known_hosts_path().filter(|p| p.exists()) {
+        let _ = known_hosts.read_file(&path, KnownHostFileKind::OpenSSH);
+    }
+
+    match known_hosts.check_port(host, port, key) {
+        CheckResult::Match => Ok(()),
+        CheckResult::Mismatch => Err(AppError::HostKeyMismatch(format!(
+            "{}:{} presented {}",
+            host,
+            port,
+            compute_fingerprint(key)
+        ))),
+        CheckResult::NotFound | CheckResult::Failure => Err(AppError::Ssh(format!(
+            "Host key for {}:{} ({}) is not trusted yet. Connect once from the app to verify it.",
+            host,
+            port,
+            compute_fingerprint(key)
+        ))),
+    }
+}
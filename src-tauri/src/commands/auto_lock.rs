@@ -0,0 +1,64 @@
+// src-tauri/src/commands/auto_lock.rs
+//
+// 保险库空闲自动锁定：前端在用户有操作时调用 touch_vault_activity，
+// 后台任务定期检查，空闲超过 AppSettings.vault.auto_lock_minutes 后清除主密钥并发出 vault-locked 事件。
+// 由后端执行，窗口隐藏到托盘 (前端计时器被节流) 时同样生效。
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::commands::settings::SettingsState;
+use crate::commands::vault::VaultState;
+
+/// 自动锁定后发出的事件 (无负载)
+pub const VAULT_LOCKED_EVENT: &str = "vault-locked";
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+/// 最近一次用户操作的时间
+pub struct VaultActivity(Mutex<Instant>);
+
+impl Default for VaultActivity {
+    fn default() -> Self {
+        Self(Mutex::new(Instant::now()))
+    }
+}
+
+impl VaultActivity {
+    pub fn touch(&self) {
+        *self.0.lock().unwrap() = Instant::now();
+    }
+
+    fn idle_for(&self) -> Duration {
+        self.0.lock().unwrap().elapsed()
+    }
+}
+
+// 空闲超时则锁定，返回是否执行了锁定
+fn lock_if_idle(app: &AppHandle) -> bool {
+    let minutes = app.state::<SettingsState>().snapshot().vault.auto_lock_minutes;
+    if minutes == 0 || app.state::<VaultActivity>().idle_for() < Duration::from_secs(u64::from(minutes) * 60) {
+        return false;
+    }
+    app.state::<VaultState>().0.lock().unwrap().take().is_some()
+}
+
+/// 启动后台检查任务
+pub fn spawn_auto_lock(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(CHECK_INTERVAL).await;
+            if lock_if_idle(&app) {
+                println!("🔒 [Vault] Locked after idle timeout");
+                let _ = app.emit(VAULT_LOCKED_EVENT, ());
+            }
+        }
+    });
+}
+
+/// 记录用户操作 (前端节流后调用)
+#[tauri::command]
+pub fn touch_vault_activity(activity: State<'_, VaultActivity>) {
+    activity.touch();
+}
@@ -34,7 +34,7 @@ pub async fn create_cloud_backup<R: Runtime>(
     
     let temp_dir = std::env::temp_dir();
     let zip_path = temp_dir.join(&filename);
-    let file = File::create(&zip_path).map_err(|e| e.to_string())?;
+    let file = File::create(&zip_path)?;
 
     let meta = BackupMetadata {
         version: "1.0.0".to_string(),
@@ -50,7 +50,7 @@ pub async fn create_cloud_backup<R: Runtime>(
 
     // 3. 调用 WebDAV Service 上传
     emit(&app, "backup.progress.uploading", 60.0);
-    let file_content = fs::read(&zip_path).map_err(|e| e.to_string())?;
+    let file_content = fs::read(&zip_path)?;
     
     let upload_result = webdav::upload_file(&app, &url, &username, &actual_password, &filename, file_content).await;
     
@@ -23,7 +23,7 @@ pub async fn export_local_backup<R: Runtime>(app: AppHandle<R>, target_path: Str
         platform: std::env::consts::OS.to_string(),
     };
 
-    let file = File::create(&target_path).map_err(|e| e.to_string())?;
+    let file = File::create(&target_path)?;
     
     // 调用 Archive Service
     archive::pack_config_dir(&app, file, meta)?;
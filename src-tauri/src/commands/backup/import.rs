@@ -1,5 +1,6 @@
 use std::fs::File;
 use tauri::{AppHandle, Runtime, Emitter};
+use crate::error::AppError;
 use crate::models::backup::{CommandResult, ProgressPayload};
 use crate::services::backup::archive;
 
@@ -10,8 +11,11 @@ fn emit<R: Runtime>(app: &AppHandle<R>, msg: &str, progress: f64) {
 #[tauri::command]
 pub async fn import_local_backup<R: Runtime>(app: AppHandle<R>, file_path: String) -> CommandResult<()> {
     emit(&app, "backup.progress.preparing", 20.0);
+
+    // 校验备份中的数据库版本 (比当前程序新的备份拒绝导入)
+    archive::validate_backup_schema(std::path::Path::new(&file_path)).await?;
     
-    let file = File::open(&file_path).map_err(|e| format!("Failed to open file: {}", e))?;
+    let file = File::open(&file_path).map_err(|e| AppError::Io(format!("Failed to open file: {}", e)))?;
     
     emit(&app, "backup.progress.extracting", 50.0);
     
@@ -1,6 +1,7 @@
 use std::fs::{self, File};
 use std::io::{Cursor, Read};
 use tauri::{AppHandle, Runtime, Emitter, State}; // 🟢 [引入 State]
+use crate::error::AppError;
 use crate::models::backup::{CommandResult, ProgressPayload, RestorePreview, BackupMetadata};
 use crate::services::backup::{archive, webdav, credentials};
 // 🟢 [引入 AppState] 用于获取并关闭数据库连接
@@ -37,7 +38,7 @@ pub async fn prepare_cloud_restore<R: Runtime>(
     let temp_dir = std::env::temp_dir();
     // 使用时间戳防止文件名冲突
     let temp_path = temp_dir.join(format!("restore_temp_{}.zip", chrono::Utc::now().timestamp()));
-    fs::write(&temp_path, &content).map_err(|e| e.to_string())?;
+    fs::write(&temp_path, &content)?;
 
     // 3. 尝试读取 zip 中的 backup_meta.json (不解压整个包)
     let mut metadata: Option<BackupMetadata> = None;
@@ -72,6 +73,9 @@ pub async fn apply_restore_file<R: Runtime>(
 ) -> CommandResult<()> {
     
     emit(&app, "backup.progress.preparing", 10.0);
+
+    // 先校验备份中的数据库版本，不通过时保持当前数据库不动
+    archive::validate_backup_schema(std::path::Path::new(&temp_file_path)).await?;
     
     // =========================================================================
     // 🟢 [关键修复] 强制关闭数据库连接池
@@ -84,7 +88,7 @@ pub async fn apply_restore_file<R: Runtime>(
     tokio::time::sleep(std::time::Duration::from_millis(500)).await;
     // =========================================================================
 
-    let file = File::open(&temp_file_path).map_err(|e| format!("Temp file missing: {}", e))?;
+    let file = File::open(&temp_file_path).map_err(|e| AppError::NotFound(format!("Temp file missing: {}", e)))?;
     
     emit(&app, "backup.progress.extracting", 50.0);
     
@@ -1,4 +1,5 @@
 use tauri::{AppHandle, Runtime};
+use crate::error::AppError;
 use crate::models::backup::CommandResult;
 use crate::services::backup::{webdav, credentials};
 
@@ -11,7 +12,7 @@ pub async fn check_webdav<R: Runtime>(
 ) -> CommandResult<String> {
     let actual_password = match password {
         Some(p) if !p.is_empty() => p,
-        _ => credentials::load_password(&app).map_err(|_| "Password required".to_string())?
+        _ => credentials::load_password(&app).map_err(|_| AppError::AuthFailed("Password required".to_string()))?
     };
 
     webdav::check_connection(&url, &username, &actual_password).await?;
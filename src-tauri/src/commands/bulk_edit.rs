@@ -0,0 +1,460 @@
+// src-tauri/src/commands/bulk_edit.rs
+//
+// 批量编辑服务器：对一组服务器 (按 id 或标签选取) 应用同一份部分修改，
+// 先整体校验，再在一个事务中逐台写入，返回每台服务器的变更明细。
+// 服务器引用了模板且模板设置了被修改的字段时，自动把该字段加入 template_overrides，
+// 否则连接时仍会套用模板的值，修改不会生效。
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Sqlite};
+use tauri::State;
+
+use crate::commands::server::map_server_row;
+use crate::commands::template::fetch_template;
+use crate::error::{AppError, AppResult};
+use crate::models::template::{ServerTemplate, TemplateField};
+use crate::models::{AuthType, ConnectionType, ServerConfig};
+use crate::state::AppState;
+
+/// 要编辑的服务器：server_ids 与带有 tag 标签的服务器取并集 (不含回收站中的服务器)
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BulkTarget {
+    pub server_ids: Vec<String>,
+    pub tag: Option<String>,
+}
+
+/// 部分修改，未设置的字段保持不变
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ServerPatch {
+    pub username: Option<String>,
+    /// 认证方式与凭据作为整体修改：password 需要 password_id，privateKey 需要 key_id
+    pub auth_type: Option<AuthType>,
+    pub password_id: Option<String>,
+    pub key_id: Option<String>,
+    /// 空字符串表示改为直连
+    pub proxy_id: Option<String>,
+    pub port: Option<u16>,
+    /// 整体替换标签，之后再应用 add_tags / remove_tags
+    pub tags: Option<Vec<String>>,
+    pub add_tags: Vec<String>,
+    pub remove_tags: Vec<String>,
+    pub connect_timeout: Option<u32>,
+    pub keep_alive_interval: Option<u32>,
+    /// 空字符串表示取消服务器单独分配的高亮规则集
+    pub highlight_set_id: Option<String>,
+}
+
+impl ServerPatch {
+    fn is_empty(&self) -> bool {
+        self.username.is_none()
+            && self.auth_type.is_none()
+            && self.proxy_id.is_none()
+            && self.port.is_none()
+            && self.tags.is_none()
+            && self.add_tags.is_empty()
+            && self.remove_tags.is_empty()
+            && self.connect_timeout.is_none()
+            && self.keep_alive_interval.is_none()
+            && self.highlight_set_id.is_none()
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkServerChange {
+    pub server_id: String,
+    pub name: String,
+    /// 为空表示该服务器已经是目标值，没有修改
+    pub changes: Vec<FieldChange>,
+}
+
+// 凭据、代理、高亮规则集在变更明细中显示名称
+#[derive(Default)]
+struct Names {
+    keys: HashMap<String, String>,
+    proxies: HashMap<String, String>,
+    proxy_types: HashMap<String, String>,
+    highlight_sets: HashMap<String, String>,
+}
+
+impl Names {
+    async fn load(pool: &Pool<Sqlite>) -> AppResult<Self> {
+        let load = |sql: &'static str| async move {
+            let rows: Vec<(String, String)> = sqlx::query_as(sql).fetch_all(pool).await?;
+            AppResult::Ok(rows.into_iter().collect::<HashMap<_, _>>())
+        };
+        Ok(Self {
+            keys: load("SELECT id, name FROM vault_keys WHERE deleted_at IS NULL").await?,
+            proxies: load("SELECT id, name FROM proxies").await?,
+            proxy_types: load("SELECT id, proxy_type FROM proxies").await?,
+            highlight_sets: load("SELECT id, name FROM highlight_rule_sets").await?,
+        })
+    }
+
+    fn name(map: &HashMap<String, String>, id: &str) -> String {
+        map.get(id).cloned().unwrap_or_else(|| id.to_string())
+    }
+
+    fn credential(&self, server: &ServerConfig) -> String {
+        match server.auth_type {
+            AuthType::Agent => "SSH Agent".to_string(),
+            AuthType::Password => match &server.password_id {
+                Some(id) => format!("Password: {}", Self::name(&self.keys, id)),
+                None => "Password".to_string(),
+            },
+            AuthType::PrivateKey => match &server.key_id {
+                Some(id) => format!("Key: {}", Self::name(&self.keys, id)),
+                None => "Key".to_string(),
+            },
+        }
+    }
+
+    // 与服务器表单一致：http / https 代理走 Http，其余按 SOCKS5 连接
+    fn proxy_connection_type(&self, id: &str) -> ConnectionType {
+        match self.proxy_types.get(id).map(String::as_str) {
+            Some("http" | "https") => ConnectionType::Http,
+            _ => ConnectionType::Socks5,
+        }
+    }
+
+    fn proxy(&self, server: &ServerConfig) -> Option<String> {
+        match server.connection_type {
+            ConnectionType::Direct => None,
+            _ => server.proxy_id.as_deref().map(|id| Self::name(&self.proxies, id)),
+        }
+    }
+}
+
+fn clean_tags(tags: &[String]) -> Vec<String> {
+    tags.iter().map(|t| t.trim().to_string()).filter(|t| !t.is_empty()).collect()
+}
+
+// 空字符串视为未设置，认证方式与凭据必须匹配，凭据必须是保险库中对应类型的条目
+async fn normalize_patch(pool: &Pool<Sqlite>, mut patch: ServerPatch) -> AppResult<ServerPatch> {
+    let clean = |v: Option<String>| v.map(|s| s.trim().to_string());
+    patch.username = clean(patch.username);
+    patch.password_id = clean(patch.password_id).filter(|s| !s.is_empty());
+    patch.key_id = clean(patch.key_id).filter(|s| !s.is_empty());
+    patch.proxy_id = clean(patch.proxy_id);
+    patch.highlight_set_id = clean(patch.highlight_set_id);
+    patch.tags = patch.tags.as_deref().map(clean_tags);
+    patch.add_tags = clean_tags(&patch.add_tags);
+    patch.remove_tags = clean_tags(&patch.remove_tags);
+
+    if patch.is_empty() {
+        return Err(AppError::InvalidInput("Nothing to change".to_string()));
+    }
+    if patch.username.as_deref() == Some("") {
+        return Err(AppError::InvalidInput("Username cannot be empty".to_string()));
+    }
+    if patch.port == Some(0) {
+        return Err(AppError::InvalidInput("Port must be between 1 and 65535".to_string()));
+    }
+    if patch.connect_timeout == Some(0) {
+        return Err(AppError::InvalidInput("Connect timeout must be at least 1 second".to_string()));
+    }
+
+    let credential = match patch.auth_type {
+        Some(AuthType::Password) => {
+            patch.key_id = None;
+            let id = patch.password_id.clone();
+            Some((id.ok_or_else(|| AppError::InvalidInput("Choose a vault password".to_string()))?, "password"))
+        }
+        Some(AuthType::PrivateKey) => {
+            patch.password_id = None;
+            let id = patch.key_id.clone();
+            Some((id.ok_or_else(|| AppError::InvalidInput("Choose a vault key".to_string()))?, "private_key"))
+        }
+        Some(AuthType::Agent) => {
+            patch.password_id = None;
+            patch.key_id = None;
+            None
+        }
+        None if patch.password_id.is_some() || patch.key_id.is_some() => {
+            return Err(AppError::InvalidInput("Choose an authentication method for the credential".to_string()));
+        }
+        None => None,
+    };
+    if let Some((id, key_type)) = credential {
+        let found: Option<(String,)> = sqlx::query_as(
+            "SELECT id FROM vault_keys WHERE id = ? AND key_type = ? AND deleted_at IS NULL",
+        )
+        .bind(&id)
+        .bind(key_type)
+        .fetch_optional(pool)
+        .await?;
+        if found.is_none() {
+            return Err(AppError::NotFound(format!("Vault entry not found: {}", id)));
+        }
+    }
+
+    if let Some(proxy_id) = patch.proxy_id.as_deref().filter(|s| !s.is_empty()) {
+        let found: Option<(String,)> = sqlx::query_as("SELECT id FROM proxies WHERE id = ?")
+            .bind(proxy_id)
+            .fetch_optional(pool)
+            .await?;
+        if found.is_none() {
+            return Err(AppError::NotFound(format!("Proxy not found: {}", proxy_id)));
+        }
+    }
+
+    if let Some(set_id) = patch.highlight_set_id.as_deref().filter(|s| !s.is_empty()) {
+        let found: Option<(String,)> = sqlx::query_as("SELECT id FROM highlight_rule_sets WHERE id = ?")
+            .bind(set_id)
+            .fetch_optional(pool)
+            .await?;
+        if found.is_none() {
+            return Err(AppError::NotFound(format!("Highlight rule set not found: {}", set_id)));
+        }
+    }
+    Ok(patch)
+}
+
+async fn load_targets(pool: &Pool<Sqlite>, target: &BulkTarget) -> AppResult<Vec<ServerConfig>> {
+    let tag = target.tag.as_deref().map(str::trim).filter(|t| !t.is_empty());
+    let rows = sqlx::query("SELECT * FROM servers WHERE deleted_at IS NULL ORDER BY sort ASC")
+        .fetch_all(pool)
+        .await?;
+    let servers: Vec<ServerConfig> = rows
+        .iter()
+        .map(map_server_row)
+        .filter(|s| target.server_ids.contains(&s.id) || tag.is_some_and(|tag| s.tags.iter().any(|t| t == tag)))
+        .collect();
+    if servers.is_empty() {
+        return Err(AppError::InvalidInput("No servers match the selection".to_string()));
+    }
+    Ok(servers)
+}
+
+// 服务器单独分配的高亮规则集
+async fn load_highlight_assignments(pool: &Pool<Sqlite>) -> AppResult<HashMap<String, String>> {
+    let rows: Vec<(String, String)> =
+        sqlx::query_as("SELECT target_id, set_id FROM highlight_assignments WHERE target_type = 'server'")
+            .fetch_all(pool)
+            .await?;
+    Ok(rows.into_iter().collect())
+}
+
+struct Diff(Vec<FieldChange>);
+
+impl Diff {
+    fn push(&mut self, field: &'static str, before: Option<String>, after: Option<String>) {
+        if before != after {
+            self.0.push(FieldChange { field, before, after });
+        }
+    }
+}
+
+// 模板设置了该字段时，服务器需要覆盖它才能使用自己的值
+fn add_override(server: &mut ServerConfig, template: Option<&ServerTemplate>, field: TemplateField) {
+    let Some(template) = template else { return };
+    let controlled = match field {
+        TemplateField::Auth => template.auth_type.is_some(),
+        TemplateField::Port => template.port.is_some(),
+        TemplateField::Proxy => template.proxy_id.is_some(),
+        TemplateField::ConnectTimeout => template.connect_timeout.is_some(),
+        TemplateField::KeepAliveInterval => template.keep_alive_interval.is_some(),
+        TemplateField::StartupCommands => template.startup_commands.is_some(),
+    };
+    if controlled && !server.template_overrides.contains(&field) {
+        server.template_overrides.push(field);
+    }
+}
+
+// 把修改应用到服务器上，返回变更明细
+fn apply_patch(
+    server: &mut ServerConfig,
+    patch: &ServerPatch,
+    template: Option<&ServerTemplate>,
+    highlight: &mut Option<String>,
+    names: &Names,
+) -> Vec<FieldChange> {
+    let mut diff = Diff(Vec::new());
+
+    if let Some(username) = &patch.username {
+        diff.push("username", Some(server.username.clone()), Some(username.clone()));
+        server.username = username.clone();
+    }
+
+    if let Some(auth_type) = &patch.auth_type {
+        let before = names.credential(server);
+        server.auth_type = auth_type.clone();
+        server.password_id = patch.password_id.clone();
+        server.key_id = patch.key_id.clone();
+        server.password_source = server.password_id.as_ref().map(|_| "vault".to_string());
+        server.key_source = server.key_id.as_ref().map(|_| "vault".to_string());
+        diff.push("credential", Some(before), Some(names.credential(server)));
+        add_override(server, template, TemplateField::Auth);
+    }
+
+    if let Some(proxy_id) = &patch.proxy_id {
+        let before = names.proxy(server);
+        if proxy_id.is_empty() {
+            server.connection_type = ConnectionType::Direct;
+            server.proxy_id = None;
+        } else {
+            server.connection_type = names.proxy_connection_type(proxy_id);
+            server.proxy_id = Some(proxy_id.clone());
+        }
+        diff.push("proxy", before, names.proxy(server));
+        add_override(server, template, TemplateField::Proxy);
+    }
+
+    if let Some(port) = patch.port {
+        diff.push("port", Some(server.port.to_string()), Some(port.to_string()));
+        server.port = port;
+        add_override(server, template, TemplateField::Port);
+    }
+
+    if patch.tags.is_some() || !patch.add_tags.is_empty() || !patch.remove_tags.is_empty() {
+        let base = patch.tags.as_ref().unwrap_or(&server.tags);
+        let mut tags: Vec<String> = Vec::new();
+        for tag in base.iter().chain(&patch.add_tags) {
+            if !tags.contains(tag) && !patch.remove_tags.contains(tag) {
+                tags.push(tag.clone());
+            }
+        }
+        diff.push("tags", Some(server.tags.join(", ")), Some(tags.join(", ")));
+        server.tags = tags;
+    }
+
+    if let Some(timeout) = patch.connect_timeout {
+        diff.push(
+            "connectTimeout",
+            server.connect_timeout.map(|v| v.to_string()),
+            Some(timeout.to_string()),
+        );
+        server.connect_timeout = Some(timeout);
+        add_override(server, template, TemplateField::ConnectTimeout);
+    }
+
+    if let Some(interval) = patch.keep_alive_interval {
+        diff.push(
+            "keepAliveInterval",
+            server.keep_alive_interval.map(|v| v.to_string()),
+            Some(interval.to_string()),
+        );
+        server.keep_alive_interval = Some(interval);
+        add_override(server, template, TemplateField::KeepAliveInterval);
+    }
+
+    if let Some(set_id) = &patch.highlight_set_id {
+        let after = Some(set_id.clone()).filter(|s| !s.is_empty());
+        let label = |id: &Option<String>| id.as_deref().map(|id| Names::name(&names.highlight_sets, id));
+        diff.push("highlightSet", label(highlight), label(&after));
+        *highlight = after;
+    }
+
+    diff.0
+}
+
+/// 批量修改服务器，返回每台服务器的变更明细
+/// dry_run 为 true 时只计算变更，不写入数据库
+#[tauri::command]
+pub async fn bulk_update_servers(
+    state: State<'_, AppState>,
+    target: BulkTarget,
+    patch: ServerPatch,
+    dry_run: Option<bool>,
+) -> AppResult<Vec<BulkServerChange>> {
+    let pool = &state.db;
+    let patch = normalize_patch(pool, patch).await?;
+    let servers = load_targets(pool, &target).await?;
+    let names = Names::load(pool).await?;
+    let mut assignments = load_highlight_assignments(pool).await?;
+
+    let mut templates: HashMap<String, Option<ServerTemplate>> = HashMap::new();
+    let mut updated = Vec::new();
+    let mut summary = Vec::new();
+    for mut server in servers {
+        let template = match &server.template_id {
+            Some(id) => {
+                if !templates.contains_key(id) {
+                    templates.insert(id.clone(), fetch_template(pool, id).await?);
+                }
+                templates[id].as_ref()
+            }
+            None => None,
+        };
+        let mut highlight = assignments.remove(&server.id);
+        let changes = apply_patch(&mut server, &patch, template, &mut highlight, &names);
+        if !changes.is_empty() {
+            updated.push((server.clone(), highlight));
+        }
+        summary.push(BulkServerChange {
+            server_id: server.id,
+            name: server.name,
+            changes,
+        });
+    }
+
+    if dry_run.unwrap_or(false) || updated.is_empty() {
+        return Ok(summary);
+    }
+
+    let now = chrono::Utc::now().timestamp_millis();
+    let mut tx = pool.begin().await?;
+    for (server, highlight) in &updated {
+        let tags_json = serde_json::to_string(&server.tags).unwrap_or("[]".to_string());
+        let overrides_json = serde_json::to_string(&server.template_overrides).unwrap_or("[]".to_string());
+        sqlx::query(
+            "UPDATE servers SET
+                username = ?, auth_type = ?, password_id = ?, password_source = ?, key_id = ?, key_source = ?,
+                connection_type = ?, proxy_id = ?, port = ?, tags = ?,
+                connect_timeout = ?, keep_alive_interval = ?, template_overrides = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(&server.username)
+        .bind(&server.auth_type)
+        .bind(&server.password_id)
+        .bind(&server.password_source)
+        .bind(&server.key_id)
+        .bind(&server.key_source)
+        .bind(&server.connection_type)
+        .bind(&server.proxy_id)
+        .bind(server.port)
+        .bind(tags_json)
+        .bind(server.connect_timeout)
+        .bind(server.keep_alive_interval)
+        .bind(overrides_json)
+        .bind(now)
+        .bind(&server.id)
+        .execute(&mut *tx)
+        .await?;
+
+        if patch.highlight_set_id.is_some() {
+            match highlight {
+                Some(set_id) => {
+                    sqlx::query(
+                        "INSERT OR REPLACE INTO highlight_assignments (target_id, target_type, set_id, created_at)
+                         VALUES (?, 'server', ?, ?)",
+                    )
+                    .bind(&server.id)
+                    .bind(set_id)
+                    .bind(now)
+                    .execute(&mut *tx)
+                    .await?;
+                }
+                None => {
+                    sqlx::query("DELETE FROM highlight_assignments WHERE target_id = ?")
+                        .bind(&server.id)
+                        .execute(&mut *tx)
+                        .await?;
+                }
+            }
+        }
+    }
+    tx.commit().await?;
+    Ok(summary)
+}
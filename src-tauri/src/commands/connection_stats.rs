@@ -0,0 +1,319 @@
+// src-tauri/src/commands/connection_stats.rs
+//
+// 连接记录与统计：每次连接已保存的服务器时写入一条 connection_events，
+// 断开时补上时长、断开原因和收发字节数。流量在内存中累计，断开时一次写库。
+// 同一个会话 (终端标签页) 再次连接视为重连，记录重连次数；用户主动断开后重新计数。
+// 快速连接、本地终端等不对应已保存服务器的会话不记录。
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Sqlite};
+use tauri::State;
+
+use crate::error::AppResult;
+use crate::state::AppState;
+
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 500;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ConnectionStatus {
+    Connecting,
+    Connected,
+    Failed,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum DisconnectReason {
+    /// 用户关闭或断开
+    User,
+    /// 远端正常关闭 (EOF)
+    Closed,
+    /// 读取出错 (网络中断等)
+    Error,
+    /// 同一会话发起了新的连接
+    Reconnect,
+    /// 应用退出时仍在连接中，下次启动时补记
+    Interrupted,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionEvent {
+    pub id: i64,
+    pub server_id: String,
+    pub session_id: String,
+    pub protocol: Option<String>,
+    pub auth_method: Option<String>,
+    pub status: ConnectionStatus,
+    pub error: Option<String>,
+    pub reconnect_count: i64,
+    pub connected_at: i64,
+    pub disconnected_at: Option<i64>,
+    pub duration_ms: Option<i64>,
+    pub disconnect_reason: Option<DisconnectReason>,
+    pub bytes_in: i64,
+    pub bytes_out: i64,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerConnectionStats {
+    pub server_id: String,
+    pub name: String,
+    pub ip: String,
+    /// 连接次数 (含失败)
+    pub attempts: i64,
+    pub failures: i64,
+    /// 失败次数 / 连接次数
+    #[sqlx(skip)]
+    pub failure_rate: f64,
+    /// 已结束会话的平均时长
+    pub avg_duration_ms: Option<f64>,
+    pub total_duration_ms: i64,
+    pub bytes_in: i64,
+    pub bytes_out: i64,
+    /// 属于重连的连接次数
+    pub reconnects: i64,
+    pub last_connected_at: Option<i64>,
+}
+
+struct ActiveSession {
+    event_id: i64,
+    bytes_in: u64,
+    bytes_out: u64,
+}
+
+#[derive(Default)]
+struct TrackerInner {
+    active: HashMap<String, ActiveSession>,
+    // 会话已经发起的连接次数
+    attempts: HashMap<String, i64>,
+}
+
+/// 进行中的会话 (按会话 ID)，读取线程与 write_ssh 在这里累计流量
+#[derive(Default)]
+pub struct ConnectionTracker(Mutex<TrackerInner>);
+
+impl ConnectionTracker {
+    fn lock(&self) -> std::sync::MutexGuard<'_, TrackerInner> {
+        match self.0.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    /// 开始连接：写入一条 connecting 记录，返回记录 ID
+    /// 会话上一次的连接还没结束时按重连结束它
+    pub async fn begin(
+        &self,
+        pool: &Pool<Sqlite>,
+        server_id: &str,
+        session_id: &str,
+        protocol: Option<&str>,
+        auth_method: Option<&str>,
+    ) -> AppResult<i64> {
+        let reconnect_count = {
+            let mut inner = self.lock();
+            let attempts = inner.attempts.entry(session_id.to_string()).or_insert(0);
+            *attempts += 1;
+            *attempts - 1
+        };
+        self.finish(pool, session_id, None, DisconnectReason::Reconnect);
+
+        let event_id = sqlx::query(
+            "INSERT INTO connection_events
+                (server_id, session_id, protocol, auth_method, status, reconnect_count, connected_at)
+             VALUES (?, ?, ?, ?, 'connecting', ?, ?)",
+        )
+        .bind(server_id)
+        .bind(session_id)
+        .bind(protocol)
+        .bind(auth_method)
+        .bind(reconnect_count)
+        .bind(chrono::Utc::now().timestamp_millis())
+        .execute(pool)
+        .await?
+        .last_insert_rowid();
+
+        self.lock().active.insert(
+            session_id.to_string(),
+            ActiveSession {
+                event_id,
+                bytes_in: 0,
+                bytes_out: 0,
+            },
+        );
+        Ok(event_id)
+    }
+
+    /// 连接结果：成功时从此刻开始计时，失败时记录错误并结束
+    pub async fn complete(&self, pool: &Pool<Sqlite>, session_id: &str, result: &AppResult<()>) -> AppResult<()> {
+        let now = chrono::Utc::now().timestamp_millis();
+        match result {
+            Ok(()) => {
+                let Some(event_id) = self.lock().active.get(session_id).map(|s| s.event_id) else {
+                    return Ok(());
+                };
+                sqlx::query(
+                    "UPDATE connection_events SET status = 'connected', connected_at = ?
+                     WHERE id = ? AND status = 'connecting'",
+                )
+                .bind(now)
+                .bind(event_id)
+                .execute(pool)
+                .await?;
+            }
+            Err(e) => {
+                let Some(session) = self.lock().active.remove(session_id) else {
+                    return Ok(());
+                };
+                sqlx::query(
+                    "UPDATE connection_events SET status = 'failed', error = ?, disconnected_at = ?
+                     WHERE id = ?",
+                )
+                .bind(e.to_string())
+                .bind(now)
+                .bind(session.event_id)
+                .execute(pool)
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn add_bytes_in(&self, session_id: &str, count: usize) {
+        if let Some(session) = self.lock().active.get_mut(session_id) {
+            session.bytes_in += count as u64;
+        }
+    }
+
+    pub fn add_bytes_out(&self, session_id: &str, count: usize) {
+        if let Some(session) = self.lock().active.get_mut(session_id) {
+            session.bytes_out += count as u64;
+        }
+    }
+
+    /// 会话结束：补上断开时间、时长、原因与流量 (只有第一次调用生效)
+    /// 传入 event_id 时只结束这条记录，避免旧连接的读取线程晚退出时结束了同一会话的新连接
+    /// 用户主动断开后，该会话之后的连接不再算作重连
+    pub fn finish(&self, pool: &Pool<Sqlite>, session_id: &str, event_id: Option<i64>, reason: DisconnectReason) {
+        let session = {
+            let mut inner = self.lock();
+            if reason == DisconnectReason::User {
+                inner.attempts.remove(session_id);
+            }
+            let matches = inner
+                .active
+                .get(session_id)
+                .is_some_and(|s| event_id.is_none_or(|id| id == s.event_id));
+            if matches {
+                inner.active.remove(session_id)
+            } else {
+                None
+            }
+        };
+        let Some(session) = session else {
+            return;
+        };
+
+        let pool = pool.clone();
+        tauri::async_runtime::spawn(async move {
+            let now = chrono::Utc::now().timestamp_millis();
+            let result = sqlx::query(
+                "UPDATE connection_events SET
+                    status = CASE status WHEN 'connecting' THEN 'connected' ELSE status END,
+                    disconnected_at = ?1, duration_ms = ?1 - connected_at, disconnect_reason = ?2,
+                    bytes_in = ?3, bytes_out = ?4
+                 WHERE id = ?5 AND disconnected_at IS NULL",
+            )
+            .bind(now)
+            .bind(reason)
+            .bind(session.bytes_in as i64)
+            .bind(session.bytes_out as i64)
+            .bind(session.event_id)
+            .execute(&pool)
+            .await;
+            if let Err(e) = result {
+                eprintln!("[Connection] Failed to record disconnect: {}", e);
+            }
+        });
+    }
+}
+
+/// 上次退出时仍未结束的记录标记为 interrupted (时长未知)
+pub async fn close_dangling_events(pool: &Pool<Sqlite>) -> AppResult<()> {
+    sqlx::query(
+        "UPDATE connection_events SET
+            status = CASE status WHEN 'connecting' THEN 'failed' ELSE status END,
+            disconnect_reason = 'interrupted'
+         WHERE disconnected_at IS NULL AND disconnect_reason IS NULL AND status <> 'failed'",
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// 各服务器的连接统计，按成功连接次数排序 (最常用的在前)
+/// since 为毫秒时间戳，只统计此后的连接；server_id 为空时统计全部服务器
+#[tauri::command]
+pub async fn get_connection_stats(
+    state: State<'_, AppState>,
+    server_id: Option<String>,
+    since: Option<i64>,
+    limit: Option<i64>,
+) -> AppResult<Vec<ServerConnectionStats>> {
+    let mut stats = sqlx::query_as::<_, ServerConnectionStats>(
+        "SELECT e.server_id, s.name, s.ip,
+                COUNT(*) AS attempts,
+                SUM(e.status = 'failed') AS failures,
+                AVG(e.duration_ms) AS avg_duration_ms,
+                IFNULL(SUM(e.duration_ms), 0) AS total_duration_ms,
+                SUM(e.bytes_in) AS bytes_in,
+                SUM(e.bytes_out) AS bytes_out,
+                SUM(e.reconnect_count > 0) AS reconnects,
+                MAX(CASE WHEN e.status <> 'failed' THEN e.connected_at END) AS last_connected_at
+         FROM connection_events e
+         JOIN servers s ON s.id = e.server_id AND s.deleted_at IS NULL
+         WHERE e.connected_at >= ?1 AND (?2 IS NULL OR e.server_id = ?2)
+         GROUP BY e.server_id
+         ORDER BY attempts - failures DESC, attempts DESC, s.name
+         LIMIT ?3",
+    )
+    .bind(since.unwrap_or(0))
+    .bind(&server_id)
+    .bind(limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT))
+    .fetch_all(&state.db)
+    .await?;
+
+    for s in &mut stats {
+        s.failure_rate = if s.attempts > 0 {
+            s.failures as f64 / s.attempts as f64
+        } else {
+            0.0
+        };
+    }
+    Ok(stats)
+}
+
+/// 服务器的会话历史，最近的在前
+#[tauri::command]
+pub async fn list_connection_events(
+    state: State<'_, AppState>,
+    server_id: String,
+    limit: Option<i64>,
+) -> AppResult<Vec<ConnectionEvent>> {
+    let events = sqlx::query_as::<_, ConnectionEvent>(
+        "SELECT * FROM connection_events WHERE server_id = ? ORDER BY connected_at DESC, id DESC LIMIT ?",
+    )
+    .bind(&server_id)
+    .bind(limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT))
+    .fetch_all(&state.db)
+    .await?;
+    Ok(events)
+}
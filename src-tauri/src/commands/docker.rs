@@ -0,0 +1,270 @@
+// src-tauri/src/commands/docker.rs
+//
+// 远程 Docker 容器管理：列表 / 启停 / 日志通过监控会话执行，
+// 进入容器则在 Shell 所在的连接上新开一个 PTY 通道，作为独立会话挂到 SshState 中。
+use std::io::Read;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, State};
+
+use crate::commands::ssh::core::{retry_eagain, spawn_shell_reader_thread, ShellOutputSink};
+use crate::commands::ssh::scrollback::ScrollbackBuffer;
+use crate::commands::ssh::shell_integration::ShellTracker;
+use crate::commands::ssh::{SshConnection, SshState};
+use crate::error::{AppError, AppResult};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContainerInfo {
+    pub id: String,
+    pub names: String,
+    pub image: String,
+    pub command: String,
+    pub state: String,
+    pub status: String,
+    pub ports: String,
+    pub created_at: String,
+}
+
+// docker ps 的 JSON 输出 (字段为大驼峰)
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct DockerPsRow {
+    #[serde(rename = "ID")]
+    id: String,
+    #[serde(default)]
+    names: String,
+    #[serde(default)]
+    image: String,
+    #[serde(default)]
+    command: String,
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    status: String,
+    #[serde(default)]
+    ports: String,
+    #[serde(default)]
+    created_at: String,
+}
+
+// 容器 ID / 名称只允许安全字符，防止命令注入
+fn validate_container_ref(container: &str) -> AppResult<()> {
+    let valid = !container.is_empty()
+        && container.len() <= 128
+        && container
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '-');
+    if valid {
+        Ok(())
+    } else {
+        Err(AppError::InvalidInput(format!("Invalid container reference: {}", container)))
+    }
+}
+
+/// 在监控会话上执行命令，返回 (stdout+stderr, exit code)
+async fn exec_on_monitor(
+    ssh_state: &State<'_, SshState>,
+    id: &str,
+    cmd: String,
+) -> AppResult<(String, i32)> {
+    let session_arc = {
+        let map = ssh_state.sessions.lock().unwrap();
+        map.get(id)
+            .map(|c| c.monitor_session.clone())
+            .ok_or_else(|| AppError::session_not_active(id))?
+    };
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let sess_guard = session_arc.lock().unwrap();
+        let sess = sess_guard
+            .as_ref()
+            .ok_or_else(|| AppError::Ssh("Monitor session unavailable".to_string()))?;
+        let mut channel = sess.channel_session()?;
+
+        channel.exec(&cmd)?;
+        let mut s = String::new();
+        channel.read_to_string(&mut s).ok();
+        channel.wait_close().ok();
+        let status = channel.exit_status().unwrap_or(-1);
+        Ok::<(String, i32), AppError>((s, status))
+    })
+    .await
+    .map_err(|e| AppError::Internal(e.to_string()))?
+}
+
+/// 列出主机上的容器 (包含已停止的)
+#[tauri::command]
+pub async fn list_docker_containers(
+    ssh_state: State<'_, SshState>,
+    id: String,
+) -> AppResult<Vec<ContainerInfo>> {
+    // '{{json .}}' 与新版的 --format json 输出一致 (每行一个对象)，但兼容旧版 Docker
+    let (output, status) = exec_on_monitor(
+        &ssh_state,
+        &id,
+        "docker ps -a --no-trunc --format '{{json .}}' 2>&1".to_string(),
+    )
+    .await?;
+
+    if status != 0 {
+        return Err(AppError::Ssh(format!("docker ps failed: {}", output.trim())));
+    }
+
+    let containers = output
+        .lines()
+        .filter(|line| line.trim_start().starts_with('{'))
+        .filter_map(|line| serde_json::from_str::<DockerPsRow>(line).ok())
+        .map(|row| ContainerInfo {
+            id: row.id,
+            names: row.names,
+            image: row.image,
+            command: row.command.trim_matches('"').to_string(),
+            state: row.state,
+            status: row.status,
+            ports: row.ports,
+            created_at: row.created_at,
+        })
+        .collect();
+
+    Ok(containers)
+}
+
+/// 启动 / 停止 / 重启容器
+/// action: "start" | "stop" | "restart"
+#[tauri::command]
+pub async fn docker_container_action(
+    ssh_state: State<'_, SshState>,
+    id: String,
+    container: String,
+    action: String,
+) -> AppResult<()> {
+    validate_container_ref(&container)?;
+    if !matches!(action.as_str(), "start" | "stop" | "restart") {
+        return Err(AppError::InvalidInput(format!("Unsupported action: {}", action)));
+    }
+
+    let (output, status) =
+        exec_on_monitor(&ssh_state, &id, format!("docker {} {} 2>&1", action, container)).await?;
+    if status != 0 {
+        return Err(AppError::Ssh(format!("docker {} failed: {}", action, output.trim())));
+    }
+    Ok(())
+}
+
+/// 获取容器最近的日志
+#[tauri::command]
+pub async fn docker_container_logs(
+    ssh_state: State<'_, SshState>,
+    id: String,
+    container: String,
+    tail: Option<u32>,
+) -> AppResult<String> {
+    validate_container_ref(&container)?;
+    let tail = tail.unwrap_or(200);
+
+    let (output, status) = exec_on_monitor(
+        &ssh_state,
+        &id,
+        format!("docker logs --tail {} --timestamps {} 2>&1", tail, container),
+    )
+    .await?;
+    if status != 0 {
+        return Err(AppError::Ssh(format!("docker logs failed: {}", output.trim())));
+    }
+    Ok(output)
+}
+
+/// 进入容器：在父会话的连接上新开 PTY 通道执行 docker exec -it
+/// 新会话使用前端传入的 session_id，终端/历史/回滚等功能与普通会话一致
+#[tauri::command]
+pub async fn open_container_shell(
+    app: AppHandle,
+    ssh_state: State<'_, SshState>,
+    id: String,
+    container: String,
+    session_id: String,
+    shell: Option<String>,
+) -> AppResult<()> {
+    validate_container_ref(&container)?;
+    if let Some(sh) = &shell {
+        // shell 只允许单个路径 (如 /bin/bash)
+        let valid = sh.chars().all(|c| c.is_ascii_alphanumeric() || "/_.-".contains(c));
+        if sh.is_empty() || !valid {
+            return Err(AppError::InvalidInput(format!("Invalid shell: {}", sh)));
+        }
+    }
+
+    let sessions = ssh_state.sessions.clone();
+
+    // 1. 取出父会话的连接与共享资源
+    let (parent_session, server_id, monitor_session, sftp_session) = {
+        let map = sessions.lock().unwrap();
+        let conn = map.get(&id).ok_or_else(|| AppError::session_not_active(&id))?;
+        let sess = conn.shell_session.clone().ok_or_else(|| {
+            AppError::InvalidInput("Container shell requires an SSH session".to_string())
+        })?;
+        (
+            sess,
+            conn.server_id.clone(),
+            conn.monitor_session.clone(),
+            conn.sftp_session.clone(),
+        )
+    };
+
+    let cmd = match shell {
+        Some(sh) => format!("docker exec -it {} {}", container, sh),
+        None => format!(
+            "docker exec -it {} sh -c 'command -v bash >/dev/null 2>&1 && exec bash || exec sh'",
+            container
+        ),
+    };
+
+    tauri::async_runtime::spawn_blocking(move || {
+        // 2. 父会话是非阻塞的，通道操作需要重试 EAGAIN
+        let mut channel = retry_eagain(|| parent_session.channel_session())
+            .map_err(|e| AppError::Ssh(format!("Channel Error: {}", e)))?;
+        retry_eagain(|| channel.request_pty("xterm", None, Some((80, 24, 0, 0))))
+            .map_err(|e| AppError::Ssh(format!("PTY Error: {}", e)))?;
+        retry_eagain(|| channel.exec(&cmd)).map_err(|e| AppError::Ssh(format!("Exec Error: {}", e)))?;
+
+        // 3. 注册为独立会话 (监控/SFTP 与父会话共享)
+        let channel_arc = Arc::new(Mutex::new(channel));
+        let shell_tracker_arc = Arc::new(Mutex::new(ShellTracker::default()));
+        let scrollback_arc = Arc::new(Mutex::new(ScrollbackBuffer::default()));
+
+        {
+            let mut map = sessions.lock().unwrap();
+            map.insert(
+                session_id.clone(),
+                SshConnection {
+                    server_id: server_id.clone(),
+                    shell_session: Some(parent_session),
+                    shell_channel: channel_arc.clone(),
+                    monitor_session,
+                    sftp_session,
+                    shell_tracker: shell_tracker_arc.clone(),
+                    scrollback: scrollback_arc.clone(),
+                },
+            );
+        }
+
+        // 4. 复用 Shell 读取线程
+        spawn_shell_reader_thread(
+            channel_arc,
+            ShellOutputSink {
+                app,
+                id: session_id,
+                server_id,
+                event_id: None,
+                tracker: shell_tracker_arc,
+                scrollback: scrollback_arc,
+            },
+        );
+
+        Ok(())
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("Async Error: {}", e)))?
+}
@@ -0,0 +1,150 @@
+// src-tauri/src/commands/expiration.rs
+//
+// 服务器到期提醒：后台任务在启动时和此后每天检查一次，找出提醒窗口内到期 (含已过期) 的服务器，
+// 发出 server-expiring 事件由前端弹出通知，并把数量显示在托盘和 Dock / 任务栏角标上。
+// 提醒窗口 (提前天数) 保存在 AppSettings.expiration 中，修改后立即重新检查。
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Local, NaiveDate};
+use serde::Serialize;
+use sqlx::{Pool, Sqlite};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tokio::sync::Notify;
+
+use crate::commands::settings::SettingsState;
+use crate::error::AppResult;
+use crate::state::AppState;
+
+/// 到期提醒事件，负载为 Vec<ExpiringServer>
+pub const EXPIRATION_EVENT: &str = "server-expiring";
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpiringServer {
+    pub id: String,
+    pub name: String,
+    pub ip: String,
+    /// 到期日 (本地日期，YYYY-MM-DD)
+    pub expire_date: String,
+    /// 距今天数，已过期为负数
+    pub days_left: i64,
+}
+
+/// 唤醒后台检查任务 (设置或服务器变化后调用)
+#[derive(Default)]
+pub struct ExpirationChecker(Arc<Notify>);
+
+impl ExpirationChecker {
+    pub fn recheck(&self) {
+        self.0.notify_one();
+    }
+}
+
+// 前端保存的是 ISO 时间 (toISOString，UTC)，换算回本地日期；也兼容纯日期
+fn parse_expire_date(raw: &str) -> Option<NaiveDate> {
+    let raw = raw.trim();
+    DateTime::parse_from_rfc3339(raw)
+        .map(|dt| dt.with_timezone(&Local).date_naive())
+        .ok()
+        .or_else(|| NaiveDate::parse_from_str(raw.get(..10)?, "%Y-%m-%d").ok())
+}
+
+/// 启用了到期提醒、且 within_days 天内到期 (含已过期) 的服务器，按到期日排序
+/// within_days 为 None 时返回全部
+pub(crate) async fn find_expiring(pool: &Pool<Sqlite>, within_days: Option<i64>) -> AppResult<Vec<ExpiringServer>> {
+    let rows: Vec<(String, String, String, String)> = sqlx::query_as(
+        "SELECT id, name, ip, expire_date FROM servers
+         WHERE enable_expiration = 1 AND IFNULL(expire_date, '') <> '' AND deleted_at IS NULL",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let today = Local::now().date_naive();
+    let mut servers: Vec<ExpiringServer> = rows
+        .into_iter()
+        .filter_map(|(id, name, ip, expire_date)| {
+            let date = parse_expire_date(&expire_date)?;
+            Some(ExpiringServer {
+                id,
+                name,
+                ip,
+                expire_date: date.format("%Y-%m-%d").to_string(),
+                days_left: (date - today).num_days(),
+            })
+        })
+        .filter(|s| within_days.is_none_or(|days| s.days_left <= days))
+        .collect();
+    servers.sort_by(|a, b| a.days_left.cmp(&b.days_left).then_with(|| a.name.cmp(&b.name)));
+    Ok(servers)
+}
+
+// 托盘提示与标题 (macOS / Linux 显示在图标旁) 以及窗口角标
+fn update_badge(app: &AppHandle, count: usize) {
+    let badge = (count > 0).then_some(count as i64);
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.set_badge_count(badge);
+    }
+    #[cfg(desktop)]
+    if let Some(tray) = app.tray_by_id(crate::MAIN_TRAY_ID) {
+        let tooltip = match count {
+            0 => "PiTerm".to_string(),
+            n => format!("PiTerm - {} server(s) expiring soon", n),
+        };
+        let _ = tray.set_tooltip(Some(tooltip));
+        let _ = tray.set_title(badge.map(|c| c.to_string()));
+    }
+}
+
+async fn run_check(app: &AppHandle) -> AppResult<()> {
+    let warn_days = app.state::<SettingsState>().snapshot().expiration.warn_days;
+    let expiring = if warn_days == 0 {
+        Vec::new()
+    } else {
+        find_expiring(&app.state::<AppState>().db, Some(warn_days as i64)).await?
+    };
+
+    update_badge(app, expiring.len());
+    if !expiring.is_empty() {
+        let _ = app.emit(EXPIRATION_EVENT, &expiring);
+    }
+    Ok(())
+}
+
+/// 启动后台检查：立即检查一次，之后每天一次，收到 recheck 时提前检查
+pub fn spawn_checker(app: AppHandle) {
+    let notify = app.state::<ExpirationChecker>().0.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            if let Err(e) = run_check(&app).await {
+                eprintln!("[Expiration] Check failed: {}", e);
+            }
+            tokio::select! {
+                _ = tokio::time::sleep(CHECK_INTERVAL) => {}
+                _ = notify.notified() => {}
+            }
+        }
+    });
+}
+
+/// 即将到期的服务器，按到期日排序
+/// within_days 为空时使用设置中的提醒窗口 (提醒关闭时返回全部启用了到期日的服务器)
+#[tauri::command]
+pub async fn list_upcoming_expirations(
+    state: State<'_, AppState>,
+    settings: State<'_, SettingsState>,
+    within_days: Option<u32>,
+) -> AppResult<Vec<ExpiringServer>> {
+    let within_days = within_days
+        .or_else(|| Some(settings.snapshot().expiration.warn_days).filter(|d| *d > 0))
+        .map(i64::from);
+    find_expiring(&state.db, within_days).await
+}
+
+/// 服务器的到期设置变化后重新检查 (更新角标，必要时再次提醒)
+#[tauri::command]
+pub fn recheck_expirations(checker: State<'_, ExpirationChecker>) {
+    checker.recheck();
+}
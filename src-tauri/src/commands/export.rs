@@ -0,0 +1,221 @@
+// src-tauri/src/commands/export.rs
+//
+// 服务器清单导出：按标签筛选后生成 ssh_config / CSV / JSON 并写入用户选择的文件。
+// 默认不含凭据；选择包含凭据时，先解密 Vault 中的密码和私钥，再用导出口令派生的密钥逐项加密。
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use aes_gcm::{Aes256Gcm, Key};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Pool, Row, Sqlite};
+use tauri::{command, State};
+
+use crate::commands::server::map_server_row;
+use crate::commands::ssh::{fetch_server_row, resolve_ssh_config, uses_vault_credentials};
+use crate::commands::vault::{derive_key, encrypt_with_salt, VaultState};
+use crate::error::{AppError, AppResult};
+use crate::models::{AuthType, ConnectionType, Proxy, ServerConfig};
+use crate::services::export::{self, ExportFormat, ExportedSecrets, ExportedServer};
+use crate::state::AppState;
+
+const MIN_PASSPHRASE_LEN: usize = 8;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportOptions {
+    pub format: ExportFormat,
+    /// 只导出带有其中任一标签的服务器，为空时导出全部
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub include_secrets: bool,
+    /// 包含凭据时必填
+    pub passphrase: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportSummary {
+    pub path: String,
+    pub count: usize,
+}
+
+// 一次导出只派生一次密钥，盐随每个加密值写出
+struct PassphraseCipher {
+    key: Key<Aes256Gcm>,
+    salt: String,
+}
+
+impl PassphraseCipher {
+    fn new(passphrase: &str) -> Self {
+        let mut salt = [0u8; 16];
+        OsRng.fill_bytes(&mut salt);
+        Self {
+            key: derive_key(passphrase, &salt),
+            salt: BASE64.encode(salt),
+        }
+    }
+
+    fn encrypt(&self, value: Option<String>) -> AppResult<Option<String>> {
+        value
+            .filter(|v| !v.is_empty())
+            .map(|v| encrypt_with_salt(&self.key, &self.salt, v.as_bytes()))
+            .transpose()
+    }
+}
+
+fn proxy_url(proxy: &Proxy) -> String {
+    let scheme = match proxy.proxy_type.as_str() {
+        "https" => "http",
+        other => other,
+    };
+    match proxy.username.as_deref().filter(|u| !u.is_empty()) {
+        Some(user) => format!("{}://{}@{}:{}", scheme, user, proxy.host, proxy.port),
+        None => format!("{}://{}:{}", scheme, proxy.host, proxy.port),
+    }
+}
+
+// 套用 fetch_server_row 解析出的生效值 (模板、文件夹默认值)，与连接时一致
+fn apply_effective(server: &mut ServerConfig, row: &SqliteRow, proxies: &HashMap<String, Proxy>) {
+    server.username = row.try_get("username").unwrap_or_default();
+    if let Ok(port) = row.try_get::<i64, _>("port") {
+        server.port = port as u16;
+    }
+    if let Ok(auth_type) = row.try_get::<AuthType, _>("auth_type") {
+        server.auth_type = auth_type;
+    }
+    server.password_id = row.try_get("password_id").ok().flatten();
+    server.key_id = row.try_get("key_id").ok().flatten();
+    server.connect_timeout = row.try_get("connect_timeout").ok().flatten();
+    server.keep_alive_interval = row.try_get("keep_alive_interval").ok().flatten();
+
+    // 模板提供的代理：服务器本身是直连时按代理类型补上连接方式
+    let proxy_id: Option<String> = row.try_get("proxy_id").ok().flatten();
+    if proxy_id.is_some() && proxy_id != server.proxy_id {
+        if server.connection_type == ConnectionType::Direct {
+            server.connection_type = match proxy_id.as_ref().and_then(|id| proxies.get(id)) {
+                Some(p) if matches!(p.proxy_type.as_str(), "http" | "https") => ConnectionType::Http,
+                _ => ConnectionType::Socks5,
+            };
+        }
+        server.proxy_id = proxy_id;
+    }
+}
+
+fn to_exported(server: &ServerConfig, proxies: &HashMap<String, Proxy>) -> ExportedServer {
+    let proxy = match server.connection_type {
+        ConnectionType::Direct => None,
+        _ => server.proxy_id.as_ref().and_then(|id| proxies.get(id)).map(proxy_url),
+    };
+    let auth_type = match server.auth_type {
+        AuthType::Password => "password",
+        AuthType::PrivateKey => "privateKey",
+        AuthType::Agent => "agent",
+    };
+
+    ExportedServer {
+        name: server.name.clone(),
+        host: server.ip.clone(),
+        port: server.port,
+        username: server.username.clone(),
+        protocol: server.protocol.clone(),
+        auth_type: auth_type.to_string(),
+        tags: server.tags.clone(),
+        proxy,
+        // 导入的服务器在备注中记录了私钥文件路径
+        identity_file: server
+            .private_key_remark
+            .clone()
+            .filter(|r| server.auth_type == AuthType::PrivateKey && !r.contains('\n')),
+        connect_timeout: server.connect_timeout,
+        keep_alive_interval: server.keep_alive_interval,
+        secrets: None,
+    }
+}
+
+async fn load_proxies(pool: &Pool<Sqlite>) -> AppResult<HashMap<String, Proxy>> {
+    let proxies = sqlx::query_as::<_, Proxy>("SELECT * FROM proxies").fetch_all(pool).await?;
+    Ok(proxies.into_iter().map(|p| (p.id.clone(), p)).collect())
+}
+
+/// 导出服务器清单到 path
+#[command]
+pub async fn export_servers(
+    state: State<'_, AppState>,
+    vault_state: State<'_, VaultState>,
+    options: ExportOptions,
+    path: String,
+) -> AppResult<ExportSummary> {
+    let pool = &state.db;
+    if path.trim().is_empty() {
+        return Err(AppError::InvalidInput("Path is required".to_string()));
+    }
+
+    let cipher = if options.include_secrets {
+        if options.format == ExportFormat::SshConfig {
+            return Err(AppError::InvalidInput(
+                "ssh_config cannot contain secrets, choose CSV or JSON".to_string(),
+            ));
+        }
+        let passphrase = options.passphrase.as_deref().unwrap_or_default();
+        if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+            return Err(AppError::InvalidInput(format!(
+                "Export passphrase must be at least {} characters",
+                MIN_PASSPHRASE_LEN
+            )));
+        }
+        Some(PassphraseCipher::new(passphrase))
+    } else {
+        None
+    };
+
+    let rows = sqlx::query("SELECT * FROM servers WHERE deleted_at IS NULL ORDER BY sort ASC, created_at ASC")
+        .fetch_all(pool)
+        .await?;
+    let proxies = load_proxies(pool).await?;
+
+    let mut servers = Vec::new();
+    let mut master_key = None;
+    for row in &rows {
+        let mut server = map_server_row(row);
+        if !options.tags.is_empty() && !server.tags.iter().any(|t| options.tags.contains(t)) {
+            continue;
+        }
+        let effective = fetch_server_row(pool, &server.id).await?;
+        apply_effective(&mut server, &effective, &proxies);
+        let mut exported = to_exported(&server, &proxies);
+
+        if let Some(cipher) = &cipher {
+            if uses_vault_credentials(&effective) && master_key.is_none() {
+                master_key = Some(vault_state.master_key()?);
+            }
+            // 没有保存凭据 (如 agent 认证) 时导出空值
+            let resolved = match resolve_ssh_config(pool, master_key.as_ref(), &effective, &server.id).await {
+                Ok(config) => Some(config),
+                Err(AppError::AuthFailed(_)) => None,
+                Err(e) => return Err(e),
+            };
+            if let Some(config) = resolved {
+                exported.secrets = Some(ExportedSecrets {
+                    password: cipher.encrypt(config.password)?,
+                    private_key: cipher.encrypt(config.private_key)?,
+                    passphrase: cipher.encrypt(config.passphrase)?,
+                });
+            }
+        }
+        servers.push(exported);
+    }
+
+    let content = export::render(options.format, &servers, cipher.is_some())?;
+    let path = PathBuf::from(path.trim());
+    fs::write(&path, content)?;
+
+    Ok(ExportSummary {
+        path: path.to_string_lossy().to_string(),
+        count: servers.len(),
+    })
+}
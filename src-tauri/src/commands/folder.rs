@@ -0,0 +1,327 @@
+// src-tauri/src/commands/folder.rs
+//
+// 服务器文件夹：树形分组、文件夹内排序与默认值继承。
+// 连接时的继承 (用户名、凭据) 由 ssh::fetch_server_row 通过 resolve_folder_defaults 完成；
+// 代理与高亮规则集的生效值通过 get_server_defaults 提供给前端。
+use std::collections::{HashMap, HashSet};
+
+use sqlx::{sqlite::SqliteRow, Pool, Row, Sqlite};
+use tauri::{command, State};
+
+use crate::error::{AppError, AppResult};
+use crate::models::folder::{FolderDefaults, ServerFolder};
+use crate::models::AuthType;
+use crate::state::AppState;
+
+fn map_folder_row(row: &SqliteRow) -> ServerFolder {
+    ServerFolder {
+        id: row.get("id"),
+        parent_id: row.try_get("parent_id").ok().flatten(),
+        name: row.get("name"),
+        sort: row.try_get("sort").unwrap_or_default(),
+        defaults: FolderDefaults {
+            username: row.try_get("default_username").ok().flatten(),
+            proxy_id: row.try_get("default_proxy_id").ok().flatten(),
+            auth_type: row.try_get::<Option<AuthType>, _>("default_auth_type").ok().flatten(),
+            password_id: row.try_get("default_password_id").ok().flatten(),
+            key_id: row.try_get("default_key_id").ok().flatten(),
+            highlight_set_id: row.try_get("default_highlight_set_id").ok().flatten(),
+        },
+        created_at: row.try_get("created_at").unwrap_or_default(),
+        updated_at: row.try_get("updated_at").unwrap_or_default(),
+    }
+}
+
+async fn load_folders(pool: &Pool<Sqlite>) -> AppResult<HashMap<String, ServerFolder>> {
+    let rows = sqlx::query("SELECT * FROM server_folders").fetch_all(pool).await?;
+    Ok(rows
+        .iter()
+        .map(map_folder_row)
+        .map(|f| (f.id.clone(), f))
+        .collect())
+}
+
+// 空字符串视为未设置
+fn normalize_defaults(mut defaults: FolderDefaults) -> FolderDefaults {
+    let clean = |v: Option<String>| v.filter(|s| !s.trim().is_empty());
+    defaults.username = clean(defaults.username);
+    defaults.proxy_id = clean(defaults.proxy_id);
+    defaults.password_id = clean(defaults.password_id);
+    defaults.key_id = clean(defaults.key_id);
+    defaults.highlight_set_id = clean(defaults.highlight_set_id);
+    defaults
+}
+
+/// 从指定文件夹向上合并默认值 (最近的文件夹优先)
+pub(crate) async fn resolve_folder_defaults(
+    pool: &Pool<Sqlite>,
+    folder_id: Option<&str>,
+) -> AppResult<FolderDefaults> {
+    let mut resolved = FolderDefaults::default();
+    let Some(folder_id) = folder_id else {
+        return Ok(resolved);
+    };
+
+    let folders = load_folders(pool).await?;
+    let mut visited = HashSet::new();
+    let mut current = folders.get(folder_id);
+    while let Some(folder) = current {
+        // 防御数据中意外出现的环
+        if !visited.insert(folder.id.as_str()) {
+            break;
+        }
+        resolved.inherit_from(&folder.defaults);
+        current = folder.parent_id.as_deref().and_then(|id| folders.get(id));
+    }
+    Ok(resolved)
+}
+
+/// 文件夹及其全部子文件夹的 ID
+pub(crate) async fn folder_subtree(pool: &Pool<Sqlite>, root_id: &str) -> AppResult<HashSet<String>> {
+    let folders = load_folders(pool).await?;
+    let mut result = HashSet::from([root_id.to_string()]);
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for folder in folders.values() {
+            let in_subtree = folder.parent_id.as_ref().is_some_and(|p| result.contains(p));
+            if in_subtree && !result.contains(&folder.id) {
+                result.insert(folder.id.clone());
+                changed = true;
+            }
+        }
+    }
+    Ok(result)
+}
+
+async fn ensure_folder_exists(pool: &Pool<Sqlite>, id: &str) -> AppResult<()> {
+    let exists: bool = sqlx::query_scalar("SELECT COUNT(*) > 0 FROM server_folders WHERE id = ?")
+        .bind(id)
+        .fetch_one(pool)
+        .await?;
+    if exists {
+        Ok(())
+    } else {
+        Err(AppError::NotFound(format!("Folder not found: {}", id)))
+    }
+}
+
+async fn fetch_folder(pool: &Pool<Sqlite>, id: &str) -> AppResult<ServerFolder> {
+    sqlx::query("SELECT * FROM server_folders WHERE id = ?")
+        .bind(id)
+        .fetch_optional(pool)
+        .await?
+        .map(|row| map_folder_row(&row))
+        .ok_or_else(|| AppError::NotFound(format!("Folder not found: {}", id)))
+}
+
+#[command]
+pub async fn list_folders(state: State<'_, AppState>) -> AppResult<Vec<ServerFolder>> {
+    let rows = sqlx::query("SELECT * FROM server_folders ORDER BY sort ASC, name ASC")
+        .fetch_all(&state.db)
+        .await?;
+    Ok(rows.iter().map(map_folder_row).collect())
+}
+
+/// 新建文件夹，排在同级文件夹的最后
+#[command]
+pub async fn create_folder(
+    state: State<'_, AppState>,
+    name: String,
+    parent_id: Option<String>,
+) -> AppResult<ServerFolder> {
+    let pool = &state.db;
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err(AppError::InvalidInput("Folder name is required".to_string()));
+    }
+    if let Some(parent) = &parent_id {
+        ensure_folder_exists(pool, parent).await?;
+    }
+
+    let max_sort: Option<i32> = sqlx::query_scalar("SELECT MAX(sort) FROM server_folders WHERE parent_id IS ?")
+        .bind(&parent_id)
+        .fetch_one(pool)
+        .await?;
+    let now = chrono::Utc::now().timestamp_millis();
+    let id = uuid::Uuid::new_v4().to_string();
+
+    sqlx::query(
+        "INSERT INTO server_folders (id, parent_id, name, sort, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?)",
+    )
+    .bind(&id)
+    .bind(&parent_id)
+    .bind(&name)
+    .bind(max_sort.unwrap_or(0) + 1)
+    .bind(now)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    fetch_folder(pool, &id).await
+}
+
+/// 修改名称与默认值 (位置通过 move_folder 调整)
+#[command]
+pub async fn update_folder(
+    state: State<'_, AppState>,
+    id: String,
+    name: String,
+    defaults: FolderDefaults,
+) -> AppResult<ServerFolder> {
+    let pool = &state.db;
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err(AppError::InvalidInput("Folder name is required".to_string()));
+    }
+    let defaults = normalize_defaults(defaults);
+
+    let result = sqlx::query(
+        "UPDATE server_folders SET
+            name = ?, default_username = ?, default_proxy_id = ?, default_auth_type = ?,
+            default_password_id = ?, default_key_id = ?, default_highlight_set_id = ?, updated_at = ?
+         WHERE id = ?",
+    )
+    .bind(&name)
+    .bind(&defaults.username)
+    .bind(&defaults.proxy_id)
+    .bind(&defaults.auth_type)
+    .bind(&defaults.password_id)
+    .bind(&defaults.key_id)
+    .bind(&defaults.highlight_set_id)
+    .bind(chrono::Utc::now().timestamp_millis())
+    .bind(&id)
+    .execute(pool)
+    .await?;
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound(format!("Folder not found: {}", id)));
+    }
+
+    fetch_folder(pool, &id).await
+}
+
+/// 移动文件夹到新的上级 (None 为根目录)，不能移动到自身或其子文件夹下
+#[command]
+pub async fn move_folder(state: State<'_, AppState>, id: String, parent_id: Option<String>) -> AppResult<()> {
+    let pool = &state.db;
+    let folders = load_folders(pool).await?;
+    if !folders.contains_key(&id) {
+        return Err(AppError::NotFound(format!("Folder not found: {}", id)));
+    }
+
+    let mut current = parent_id.as_deref();
+    while let Some(ancestor) = current {
+        if ancestor == id {
+            return Err(AppError::InvalidInput(
+                "A folder cannot be moved into itself or its subfolders".to_string(),
+            ));
+        }
+        current = folders
+            .get(ancestor)
+            .ok_or_else(|| AppError::NotFound(format!("Folder not found: {}", ancestor)))?
+            .parent_id
+            .as_deref();
+    }
+
+    let max_sort: Option<i32> = sqlx::query_scalar("SELECT MAX(sort) FROM server_folders WHERE parent_id IS ?")
+        .bind(&parent_id)
+        .fetch_one(pool)
+        .await?;
+    sqlx::query("UPDATE server_folders SET parent_id = ?, sort = ?, updated_at = ? WHERE id = ?")
+        .bind(&parent_id)
+        .bind(max_sort.unwrap_or(0) + 1)
+        .bind(chrono::Utc::now().timestamp_millis())
+        .bind(&id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// 删除文件夹，其中的服务器与子文件夹移到上一级
+#[command]
+pub async fn delete_folder(state: State<'_, AppState>, id: String) -> AppResult<()> {
+    let pool = &state.db;
+    let folder = fetch_folder(pool, &id).await?;
+
+    let mut tx = pool.begin().await?;
+    sqlx::query("UPDATE servers SET folder_id = ? WHERE folder_id = ?")
+        .bind(&folder.parent_id)
+        .bind(&id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("UPDATE server_folders SET parent_id = ? WHERE parent_id = ?")
+        .bind(&folder.parent_id)
+        .bind(&id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("DELETE FROM server_folders WHERE id = ?")
+        .bind(&id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+/// 把服务器移到文件夹 (None 为根目录)，按传入顺序排在目标文件夹末尾
+#[command]
+pub async fn move_servers_to_folder(
+    state: State<'_, AppState>,
+    server_ids: Vec<String>,
+    folder_id: Option<String>,
+) -> AppResult<()> {
+    let pool = &state.db;
+    if let Some(folder) = &folder_id {
+        ensure_folder_exists(pool, folder).await?;
+    }
+
+    let max_sort: Option<i32> = sqlx::query_scalar("SELECT MAX(sort) FROM servers WHERE folder_id IS ?")
+        .bind(&folder_id)
+        .fetch_one(pool)
+        .await?;
+    let now = chrono::Utc::now().timestamp_millis();
+
+    let mut tx = pool.begin().await?;
+    for (sort, server_id) in (max_sort.unwrap_or(0) + 1..).zip(&server_ids) {
+        sqlx::query("UPDATE servers SET folder_id = ?, sort = ?, updated_at = ? WHERE id = ?")
+            .bind(&folder_id)
+            .bind(sort)
+            .bind(now)
+            .bind(server_id)
+            .execute(&mut *tx)
+            .await?;
+    }
+    tx.commit().await?;
+    Ok(())
+}
+
+/// 文件夹内排序：server_ids 为该文件夹中服务器的新顺序
+#[command]
+pub async fn reorder_folder_servers(
+    state: State<'_, AppState>,
+    folder_id: Option<String>,
+    server_ids: Vec<String>,
+) -> AppResult<()> {
+    let mut tx = state.db.begin().await?;
+    for (sort, server_id) in (1..).zip(&server_ids) {
+        sqlx::query("UPDATE servers SET sort = ? WHERE id = ? AND folder_id IS ?")
+            .bind(sort)
+            .bind(server_id)
+            .bind(&folder_id)
+            .execute(&mut *tx)
+            .await?;
+    }
+    tx.commit().await?;
+    Ok(())
+}
+
+/// 服务器从所在文件夹链继承到的默认值
+#[command]
+pub async fn get_server_defaults(state: State<'_, AppState>, server_id: String) -> AppResult<FolderDefaults> {
+    let pool = &state.db;
+    let folder_id: Option<String> = sqlx::query_scalar("SELECT folder_id FROM servers WHERE id = ?")
+        .bind(&server_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Server not found with ID: {}", server_id)))?;
+    resolve_folder_defaults(pool, folder_id.as_deref()).await
+}
@@ -1,4 +1,5 @@
 use serde::Serialize;
+use crate::error::AppResult;
 
 // === Data Structure ===
 #[derive(Serialize, Debug, Clone)]
@@ -19,24 +20,24 @@ pub struct FileEntry {
 // All file system operations should be synchronous/blocking here.
 // Async scheduling is handled by the 'commands' layer.
 pub trait FileSystem {
-    fn read_dir(&self, path: &str) -> Result<Vec<FileEntry>, String>;
-    fn mkdir(&self, path: &str) -> Result<(), String>;
-    fn create_file(&self, path: &str) -> Result<(), String>;
-    fn rename(&self, old_path: &str, new_path: &str) -> Result<(), String>;
-    fn delete(&self, path: &str, is_dir: bool) -> Result<(), String>;
+    fn read_dir(&self, path: &str) -> AppResult<Vec<FileEntry>>;
+    fn mkdir(&self, path: &str) -> AppResult<()>;
+    fn create_file(&self, path: &str) -> AppResult<()>;
+    fn rename(&self, old_path: &str, new_path: &str) -> AppResult<()>;
+    fn delete(&self, path: &str, is_dir: bool) -> AppResult<()>;
     
     // Copy: Source Path -> Destination Path
-    fn copy(&self, from_path: &str, to_path: &str) -> Result<(), String>;
+    fn copy(&self, from_path: &str, to_path: &str) -> AppResult<()>;
     
     // Transfer
-    fn download(&self, remote_path: &str, local_path: &str) -> Result<(), String>;
-    fn upload(&self, local_path: &str, remote_path: &str) -> Result<(), String>;
+    fn download(&self, remote_path: &str, local_path: &str) -> AppResult<()>;
+    fn upload(&self, local_path: &str, remote_path: &str) -> AppResult<()>;
     
     // Permissions
-    fn chmod(&self, path: &str, mode: &str, recursive: bool) -> Result<(), String>;
+    fn chmod(&self, path: &str, mode: &str, recursive: bool) -> AppResult<()>;
     
     // Text Read/Write
-    fn read_text(&self, path: &str) -> Result<String, String>;
-    fn write_text(&self, path: &str, content: &str) -> Result<(), String>;
-    fn get_home_dir(&self) -> Result<String, String>;
+    fn read_text(&self, path: &str) -> AppResult<String>;
+    fn write_text(&self, path: &str, content: &str) -> AppResult<()>;
+    fn get_home_dir(&self) -> AppResult<String>;
 }
\ No newline at end of file
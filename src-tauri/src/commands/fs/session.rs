@@ -2,15 +2,16 @@ use crate::commands::ssh::SshState;
 use ssh2::Session;
 use std::sync::{Arc, Mutex};
 use tauri::State;
+use crate::error::{AppError, AppResult};
 
 // Get dedicated SFTP Session Arc
 // Ensures we are operating on the independent SFTP connection
 pub fn get_sftp_session_arc(
     ssh_state: &State<'_, SshState>,
     id: &str,
-) -> Result<Arc<Mutex<Option<Session>>>, String> {
-    let map = ssh_state.sessions.lock().map_err(|e| e.to_string())?;
-    let conn = map.get(id).ok_or("SSH connection not active")?;
+) -> AppResult<Arc<Mutex<Option<Session>>>> {
+    let map = ssh_state.sessions.lock().map_err(|e| AppError::Internal(e.to_string()))?;
+    let conn = map.get(id).ok_or_else(|| AppError::session_not_active(id))?;
     
     // Return sftp_session
     Ok(conn.sftp_session.clone())
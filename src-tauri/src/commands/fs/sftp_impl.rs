@@ -1,5 +1,6 @@
 use super::filesystem::{FileEntry, FileSystem}; // Ensure imports match
-use ssh2::Session;
+use crate::error::{AppError, AppResult};
+use ssh2::{Session, Sftp};
 use std::fs::File;
 use std::io::{Read, Write};
 use std::path::Path;
@@ -13,6 +14,13 @@ impl<'a> SftpFileSystem<'a> {
         Self { session }
     }
 
+    // SFTP 子系统不可用时统一归为 SftpUnavailable
+    fn open_sftp(&self) -> AppResult<Sftp> {
+        self.session
+            .sftp()
+            .map_err(|e| AppError::SftpUnavailable(e.to_string()))
+    }
+
     // === Helper: Unix permissions to string ===
     fn format_permissions(mode: u32) -> String {
         let mut s = String::with_capacity(10);
@@ -30,7 +38,7 @@ impl<'a> SftpFileSystem<'a> {
 }
 
 impl<'a> FileSystem for SftpFileSystem<'a> {
-    fn read_dir(&self, path: &str) -> Result<Vec<FileEntry>, String> {
+    fn read_dir(&self, path: &str) -> AppResult<Vec<FileEntry>> {
         // [Optimization] 1. Set handshake timeout (3s)
         self.session.set_timeout(3000);
 
@@ -46,9 +54,9 @@ impl<'a> FileSystem for SftpFileSystem<'a> {
                 eprintln!("[SFTP Init Error] Code: {:?}, Msg: {}", e.code(), e);
                 let msg = e.to_string().to_lowercase();
                 if msg.contains("wait for response") || msg.contains("timeout") {
-                    return Err("SFTP Connection Timed Out. (Server response slow)".to_string());
+                    return Err(AppError::Network("SFTP Connection Timed Out. (Server response slow)".to_string()));
                 }
-                return Err("SFTP not enabled on this server. (Please install openssh-sftp-server)".to_string());
+                return Err(AppError::SftpUnavailable("SFTP not enabled on this server. (Please install openssh-sftp-server)".to_string()));
             }
         };
 
@@ -59,7 +67,7 @@ impl<'a> FileSystem for SftpFileSystem<'a> {
         let paths_result = sftp.readdir(dir_path);
         self.session.set_timeout(0);
 
-        let paths = paths_result.map_err(|e| format!("Read Dir Error: {}", e))?;
+        let paths = paths_result.map_err(|e| AppError::Ssh(format!("Read Dir Error: {}", e)))?;
         let mut entries = Vec::new();
 
         for (path_buf, stat) in paths {
@@ -101,142 +109,142 @@ impl<'a> FileSystem for SftpFileSystem<'a> {
         Ok(entries)
     }
 
-    fn mkdir(&self, path: &str) -> Result<(), String> {
+    fn mkdir(&self, path: &str) -> AppResult<()> {
         self.session.set_timeout(5000);
-        let sftp = self.session.sftp().map_err(|e| e.to_string())?;
+        let sftp = self.open_sftp()?;
         let res = sftp.mkdir(Path::new(path), 0o755);
         self.session.set_timeout(0);
-        res.map_err(|e| e.to_string())
+        res.map_err(AppError::from)
     }
 
-    fn create_file(&self, path: &str) -> Result<(), String> {
+    fn create_file(&self, path: &str) -> AppResult<()> {
         self.session.set_timeout(5000);
-        let sftp = self.session.sftp().map_err(|e| e.to_string())?;
-        let _file = sftp.create(Path::new(path)).map_err(|e| e.to_string())?;
+        let sftp = self.open_sftp()?;
+        let _file = sftp.create(Path::new(path))?;
         self.session.set_timeout(0);
         Ok(())
     }
 
-    fn rename(&self, old_path: &str, new_path: &str) -> Result<(), String> {
+    fn rename(&self, old_path: &str, new_path: &str) -> AppResult<()> {
         self.session.set_timeout(5000);
-        let sftp = self.session.sftp().map_err(|e| e.to_string())?;
+        let sftp = self.open_sftp()?;
         let res = sftp.rename(Path::new(old_path), Path::new(new_path), None);
         self.session.set_timeout(0);
-        res.map_err(|e| e.to_string())
+        res.map_err(AppError::from)
     }
 
-    fn delete(&self, path: &str, is_dir: bool) -> Result<(), String> {
+    fn delete(&self, path: &str, is_dir: bool) -> AppResult<()> {
         self.session.set_timeout(8000);
-        let sftp = self.session.sftp().map_err(|e| e.to_string())?;
+        let sftp = self.open_sftp()?;
         let p = Path::new(path);
         let res = if is_dir { sftp.rmdir(p) } else { sftp.unlink(p) };
         self.session.set_timeout(0);
-        res.map_err(|e| e.to_string())
+        res.map_err(AppError::from)
     }
 
-    fn copy(&self, from_path: &str, to_path: &str) -> Result<(), String> {
+    fn copy(&self, from_path: &str, to_path: &str) -> AppResult<()> {
         self.session.set_timeout(10000);
-        let sftp = self.session.sftp().map_err(|e| e.to_string())?;
+        let sftp = self.open_sftp()?;
 
-        let mut src_file = sftp.open(Path::new(from_path)).map_err(|e| format!("Failed to open src: {}", e))?;
-        let mut dst_file = sftp.create(Path::new(to_path)).map_err(|e| format!("Failed to create dst: {}", e))?;
+        let mut src_file = sftp.open(Path::new(from_path)).map_err(|e| AppError::Ssh(format!("Failed to open src: {}", e)))?;
+        let mut dst_file = sftp.create(Path::new(to_path)).map_err(|e| AppError::Ssh(format!("Failed to create dst: {}", e)))?;
 
-        std::io::copy(&mut src_file, &mut dst_file).map_err(|e| format!("Copy stream failed: {}", e))?;
-        dst_file.flush().map_err(|e| format!("Flush failed: {}", e))?;
+        std::io::copy(&mut src_file, &mut dst_file).map_err(|e| AppError::Io(format!("Copy stream failed: {}", e)))?;
+        dst_file.flush().map_err(|e| AppError::Io(format!("Flush failed: {}", e)))?;
 
         self.session.set_timeout(0);
         Ok(())
     }
 
-    fn download(&self, remote_path: &str, local_path: &str) -> Result<(), String> {
+    fn download(&self, remote_path: &str, local_path: &str) -> AppResult<()> {
         self.session.set_timeout(0); // Infinite timeout
-        let sftp = self.session.sftp().map_err(|e| e.to_string())?;
+        let sftp = self.open_sftp()?;
 
-        let mut remote_file = sftp.open(Path::new(remote_path)).map_err(|e| format!("Open remote failed: {}", e))?;
-        let mut local_file = File::create(local_path).map_err(|e| format!("Create local failed: {}", e))?;
+        let mut remote_file = sftp.open(Path::new(remote_path)).map_err(|e| AppError::Ssh(format!("Open remote failed: {}", e)))?;
+        let mut local_file = File::create(local_path).map_err(|e| AppError::Io(format!("Create local failed: {}", e)))?;
 
-        std::io::copy(&mut remote_file, &mut local_file).map_err(|e| format!("Download failed: {}", e))?;
+        std::io::copy(&mut remote_file, &mut local_file).map_err(|e| AppError::Io(format!("Download failed: {}", e)))?;
         
         self.session.set_timeout(3000); // Restore default
         Ok(())
     }
 
-    fn upload(&self, local_path: &str, remote_path: &str) -> Result<(), String> {
+    fn upload(&self, local_path: &str, remote_path: &str) -> AppResult<()> {
         self.session.set_timeout(0);
-        let sftp = self.session.sftp().map_err(|e| e.to_string())?;
+        let sftp = self.open_sftp()?;
 
-        let mut local_file = File::open(local_path).map_err(|e| format!("Open local failed: {}", e))?;
-        let mut remote_file = sftp.create(Path::new(remote_path)).map_err(|e| format!("Create remote failed: {}", e))?;
+        let mut local_file = File::open(local_path).map_err(|e| AppError::Io(format!("Open local failed: {}", e)))?;
+        let mut remote_file = sftp.create(Path::new(remote_path)).map_err(|e| AppError::Ssh(format!("Create remote failed: {}", e)))?;
 
-        std::io::copy(&mut local_file, &mut remote_file).map_err(|e| format!("Upload failed: {}", e))?;
-        remote_file.flush().map_err(|e| format!("Flush failed: {}", e))?;
+        std::io::copy(&mut local_file, &mut remote_file).map_err(|e| AppError::Io(format!("Upload failed: {}", e)))?;
+        remote_file.flush().map_err(|e| AppError::Io(format!("Flush failed: {}", e)))?;
 
         self.session.set_timeout(3000);
         Ok(())
     }
 
-    fn chmod(&self, path: &str, mode: &str, recursive: bool) -> Result<(), String> {
-        let mode_num = u32::from_str_radix(mode, 8).map_err(|e| format!("Invalid octal mode: {}", e))?;
+    fn chmod(&self, path: &str, mode: &str, recursive: bool) -> AppResult<()> {
+        let mode_num = u32::from_str_radix(mode, 8).map_err(|e| AppError::InvalidInput(format!("Invalid octal mode: {}", e)))?;
 
         if recursive {
-            let mut channel = self.session.channel_session().map_err(|e| e.to_string())?;
+            let mut channel = self.session.channel_session()?;
             let safe_path = path.replace("'", "'\\''");
             let cmd = format!("chmod -R {:03o} '{}'", mode_num, safe_path);
             
-            channel.exec(&cmd).map_err(|e| e.to_string())?;
+            channel.exec(&cmd)?;
             
             let mut output = String::new();
             channel.read_to_string(&mut output).ok();
             channel.wait_close().ok();
             
             let status = channel.exit_status().unwrap_or(-1);
-            if status != 0 { return Err(format!("Recursive chmod failed (Exit: {})", status)); }
+            if status != 0 { return Err(AppError::Ssh(format!("Recursive chmod failed (Exit: {})", status))); }
         } else {
             self.session.set_timeout(5000);
-            let sftp = self.session.sftp().map_err(|e| e.to_string())?;
-            let mut stat = sftp.stat(Path::new(path)).map_err(|e| e.to_string())?;
+            let sftp = self.open_sftp()?;
+            let mut stat = sftp.stat(Path::new(path))?;
             stat.perm = Some(mode_num);
-            sftp.setstat(Path::new(path), stat).map_err(|e| e.to_string())?;
+            sftp.setstat(Path::new(path), stat)?;
             self.session.set_timeout(0);
         }
         Ok(())
     }
 
-    fn read_text(&self, path: &str) -> Result<String, String> {
+    fn read_text(&self, path: &str) -> AppResult<String> {
         self.session.set_timeout(10000);
-        let sftp = self.session.sftp().map_err(|e| e.to_string())?;
-        let mut remote_file = sftp.open(Path::new(path)).map_err(|e| e.to_string())?;
+        let sftp = self.open_sftp()?;
+        let mut remote_file = sftp.open(Path::new(path))?;
 
-        let stat = remote_file.stat().map_err(|e| e.to_string())?;
+        let stat = remote_file.stat()?;
         if stat.size.unwrap_or(0) > 5 * 1024 * 1024 {
-            return Err("File too large (>5MB)".to_string());
+            return Err(AppError::InvalidInput("File too large (>5MB)".to_string()));
         }
 
         let mut content = String::new();
-        remote_file.read_to_string(&mut content).map_err(|e| format!("Read text failed (Binary?): {}", e))?;
+        remote_file.read_to_string(&mut content).map_err(|e| AppError::InvalidInput(format!("Read text failed (Binary?): {}", e)))?;
         
         self.session.set_timeout(0);
         Ok(content)
     }
 
-    fn write_text(&self, path: &str, content: &str) -> Result<(), String> {
+    fn write_text(&self, path: &str, content: &str) -> AppResult<()> {
         self.session.set_timeout(10000);
-        let sftp = self.session.sftp().map_err(|e| e.to_string())?;
-        let mut remote_file = sftp.create(Path::new(path)).map_err(|e| e.to_string())?;
+        let sftp = self.open_sftp()?;
+        let mut remote_file = sftp.create(Path::new(path))?;
         
-        remote_file.write_all(content.as_bytes()).map_err(|e| e.to_string())?;
-        remote_file.flush().map_err(|e| e.to_string())?;
+        remote_file.write_all(content.as_bytes())?;
+        remote_file.flush()?;
         
         self.session.set_timeout(0);
         Ok(())
     }
     // 🟢 [新增] 实现获取家目录
-    fn get_home_dir(&self) -> Result<String, String> {
+    fn get_home_dir(&self) -> AppResult<String> {
         self.session.set_timeout(5000);
-        let sftp = self.session.sftp().map_err(|e| e.to_string())?;
+        let sftp = self.open_sftp()?;
         
         // "." 在 SFTP 中解析为当前工作目录 (通常是 /root 或 /home/user)
-        let path = sftp.realpath(Path::new(".")).map_err(|e| e.to_string())?;
+        let path = sftp.realpath(Path::new("."))?;
         
         self.session.set_timeout(0);
         Ok(path.to_string_lossy().to_string())
@@ -0,0 +1,347 @@
+// src-tauri/src/commands/health.rs
+//
+// 批量可达性扫描：并发探测服务器的 DNS 解析、TCP 端口与 SSH 版本串，记录 TCP 连接延迟。
+// 每台服务器只保留最近一次结果 (server_health 表)，每完成一台发出 server-scan-progress 事件。
+// 只做网络层探测、不认证，所以保险库锁定时也能扫描；从未扫描过的服务器状态视为 unknown。
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite};
+use tauri::{AppHandle, Emitter, State};
+use tokio::io::AsyncReadExt;
+use tokio::net::{lookup_host, TcpStream};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use tokio::time::timeout;
+
+use crate::commands::folder::folder_subtree;
+use crate::commands::ssh::fetch_server_row;
+use crate::error::{AppError, AppResult};
+use crate::models::Protocol;
+use crate::state::AppState;
+
+/// 扫描进度事件，负载为 ScanProgress
+pub const SCAN_PROGRESS_EVENT: &str = "server-scan-progress";
+
+const DEFAULT_CONCURRENCY: usize = 16;
+const MAX_CONCURRENCY: usize = 64;
+const DEFAULT_TIMEOUT_MS: u64 = 5000;
+// 版本串之前允许有其他行 (RFC 4253 4.2)，读到这么多字节仍没有就放弃
+const MAX_BANNER_BYTES: usize = 4096;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerHealth {
+    pub server_id: String,
+    pub status: HealthStatus,
+    /// TCP 连接耗时
+    pub latency_ms: Option<i64>,
+    pub resolved_ip: Option<String>,
+    /// SSH 版本串，如 SSH-2.0-OpenSSH_9.6
+    pub banner: Option<String>,
+    pub error: Option<String>,
+    pub checked_at: i64,
+}
+
+impl ServerHealth {
+    fn fail(mut self, error: String) -> Self {
+        self.status = HealthStatus::Down;
+        self.error = Some(error);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ScanOptions {
+    /// 指定服务器；为空时按 tag / folder_id 筛选，都为空时扫描全部
+    pub server_ids: Vec<String>,
+    pub tag: Option<String>,
+    /// 包含子文件夹中的服务器
+    pub folder_id: Option<String>,
+    pub concurrency: Option<usize>,
+    /// 每个阶段 (DNS / TCP / 版本串) 的超时
+    pub timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanProgress<'a> {
+    pub completed: usize,
+    pub total: usize,
+    pub result: &'a ServerHealth,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanSummary {
+    pub total: usize,
+    pub up: usize,
+    pub down: usize,
+    pub cancelled: bool,
+}
+
+/// 同一时间只允许一次扫描
+#[derive(Default)]
+pub struct HealthScanState {
+    running: AtomicBool,
+    cancelled: Arc<AtomicBool>,
+}
+
+struct ProbeTarget {
+    id: String,
+    host: String,
+    port: u16,
+    protocol: Protocol,
+}
+
+fn elapsed_ms(since: Instant) -> i64 {
+    since.elapsed().as_millis() as i64
+}
+
+// 读到以 SSH- 开头的完整一行为止
+async fn read_ssh_banner(stream: &mut TcpStream) -> std::io::Result<Option<String>> {
+    let mut buf = Vec::with_capacity(256);
+    let mut chunk = [0u8; 256];
+    while buf.len() < MAX_BANNER_BYTES {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        let text = String::from_utf8_lossy(&buf);
+        let banner = text
+            .split_inclusive('\n')
+            .filter(|line| line.ends_with('\n'))
+            .find(|line| line.starts_with("SSH-"));
+        if let Some(line) = banner {
+            return Ok(Some(line.trim_end().to_string()));
+        }
+    }
+    Ok(None)
+}
+
+async fn probe(target: ProbeTarget, limit: Duration) -> ServerHealth {
+    let health = ServerHealth {
+        server_id: target.id,
+        status: HealthStatus::Down,
+        latency_ms: None,
+        resolved_ip: None,
+        banner: None,
+        error: None,
+        checked_at: chrono::Utc::now().timestamp_millis(),
+    };
+
+    // 1. DNS
+    let addrs: Vec<SocketAddr> = match timeout(limit, lookup_host((target.host.as_str(), target.port))).await {
+        Ok(Ok(iter)) => iter.collect(),
+        Ok(Err(e)) => return health.fail(format!("DNS Error: {}", e)),
+        Err(_) => return health.fail("DNS Error: timed out".to_string()),
+    };
+    if addrs.is_empty() {
+        return health.fail("DNS resolution failed".to_string());
+    }
+
+    // 2. TCP (按顺序尝试每个地址)
+    let mut health = health;
+    let mut failed = Vec::new();
+    let mut connected = None;
+    for addr in &addrs {
+        let started = Instant::now();
+        match timeout(limit, TcpStream::connect(addr)).await {
+            Ok(Ok(stream)) => {
+                health.latency_ms = Some(elapsed_ms(started));
+                health.resolved_ip = Some(addr.ip().to_string());
+                connected = Some(stream);
+                break;
+            }
+            Ok(Err(e)) => failed.push(format!("{}: {}", addr, e)),
+            Err(_) => failed.push(format!("{}: timed out", addr)),
+        }
+    }
+    let Some(mut stream) = connected else {
+        health.resolved_ip = addrs.first().map(|a| a.ip().to_string());
+        return health.fail(format!("TCP Error: {}", failed.join("; ")));
+    };
+
+    // 3. SSH 版本串 (Telnet 服务不一定主动发送数据，端口可连即视为在线)
+    if target.protocol == Protocol::Telnet {
+        health.status = HealthStatus::Up;
+        return health;
+    }
+    match timeout(limit, read_ssh_banner(&mut stream)).await {
+        Ok(Ok(Some(banner))) => {
+            health.banner = Some(banner);
+            health.status = HealthStatus::Up;
+            health
+        }
+        Ok(Ok(None)) => health.fail("Port is open but did not send an SSH banner".to_string()),
+        Ok(Err(e)) => health.fail(format!("Banner Error: {}", e)),
+        Err(_) => health.fail("Port is open but no SSH banner before timeout".to_string()),
+    }
+}
+
+// 按选项筛选服务器，主机与端口取连接时的生效值 (含模板)
+async fn load_targets(pool: &Pool<Sqlite>, options: &ScanOptions) -> AppResult<Vec<ProbeTarget>> {
+    let folders = match &options.folder_id {
+        Some(id) => Some(folder_subtree(pool, id).await?),
+        None => None,
+    };
+    let rows = sqlx::query("SELECT id, tags, folder_id FROM servers WHERE deleted_at IS NULL ORDER BY sort ASC")
+        .fetch_all(pool)
+        .await?;
+
+    let mut targets = Vec::new();
+    for row in rows {
+        let id: String = row.get("id");
+        if !options.server_ids.is_empty() {
+            if !options.server_ids.contains(&id) {
+                continue;
+            }
+        } else {
+            if let Some(tag) = &options.tag {
+                let tags: Vec<String> = row
+                    .try_get::<Option<String>, _>("tags")
+                    .ok()
+                    .flatten()
+                    .and_then(|s| serde_json::from_str(&s).ok())
+                    .unwrap_or_default();
+                if !tags.contains(tag) {
+                    continue;
+                }
+            }
+            if let Some(folders) = &folders {
+                let folder_id: Option<String> = row.try_get("folder_id").ok().flatten();
+                if !folder_id.is_some_and(|f| folders.contains(&f)) {
+                    continue;
+                }
+            }
+        }
+
+        let server = fetch_server_row(pool, &id).await?;
+        targets.push(ProbeTarget {
+            id,
+            host: server.get("ip"),
+            port: server.get::<i64, _>("port") as u16,
+            protocol: server.try_get("protocol").unwrap_or(Protocol::Ssh),
+        });
+    }
+    Ok(targets)
+}
+
+async fn save_health(pool: &Pool<Sqlite>, health: &ServerHealth) -> AppResult<()> {
+    sqlx::query(
+        "INSERT OR REPLACE INTO server_health (server_id, status, latency_ms, resolved_ip, banner, error, checked_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(&health.server_id)
+    .bind(health.status)
+    .bind(health.latency_ms)
+    .bind(&health.resolved_ip)
+    .bind(&health.banner)
+    .bind(&health.error)
+    .bind(health.checked_at)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+async fn run_scan(
+    app: &AppHandle,
+    pool: &Pool<Sqlite>,
+    cancelled: &Arc<AtomicBool>,
+    options: ScanOptions,
+) -> AppResult<ScanSummary> {
+    let targets = load_targets(pool, &options).await?;
+    let total = targets.len();
+    let concurrency = options.concurrency.unwrap_or(DEFAULT_CONCURRENCY).clamp(1, MAX_CONCURRENCY);
+    let limit = Duration::from_millis(options.timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS).max(500));
+
+    let semaphore = Arc::new(Semaphore::new(concurrency));
+    let mut tasks = JoinSet::new();
+    for target in targets {
+        let semaphore = semaphore.clone();
+        let cancelled = cancelled.clone();
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await.ok()?;
+            if cancelled.load(Ordering::Relaxed) {
+                return None;
+            }
+            Some(probe(target, limit).await)
+        });
+    }
+
+    let mut summary = ScanSummary { total, up: 0, down: 0, cancelled: false };
+    while let Some(joined) = tasks.join_next().await {
+        let Ok(Some(health)) = joined else {
+            continue;
+        };
+        save_health(pool, &health).await?;
+        match health.status {
+            HealthStatus::Up => summary.up += 1,
+            HealthStatus::Down => summary.down += 1,
+        }
+        let _ = app.emit(
+            SCAN_PROGRESS_EVENT,
+            ScanProgress {
+                completed: summary.up + summary.down,
+                total,
+                result: &health,
+            },
+        );
+    }
+    summary.cancelled = cancelled.load(Ordering::Relaxed);
+    Ok(summary)
+}
+
+/// 扫描服务器可达性，完成后返回汇总 (进度通过 server-scan-progress 事件推送)
+#[tauri::command]
+pub async fn scan_servers(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    scan: State<'_, HealthScanState>,
+    options: ScanOptions,
+) -> AppResult<ScanSummary> {
+    if scan.running.swap(true, Ordering::SeqCst) {
+        return Err(AppError::InvalidInput("A scan is already running".to_string()));
+    }
+    scan.cancelled.store(false, Ordering::SeqCst);
+    let result = run_scan(&app, &state.db, &scan.cancelled, options).await;
+    scan.running.store(false, Ordering::SeqCst);
+    result
+}
+
+/// 取消正在进行的扫描 (已开始的探测会完成，排队中的跳过)
+#[tauri::command]
+pub fn cancel_server_scan(scan: State<'_, HealthScanState>) {
+    scan.cancelled.store(true, Ordering::SeqCst);
+}
+
+/// 全部服务器最近一次的扫描结果
+#[tauri::command]
+pub async fn list_server_health(state: State<'_, AppState>) -> AppResult<Vec<ServerHealth>> {
+    let rows = sqlx::query("SELECT * FROM server_health").fetch_all(&state.db).await?;
+    Ok(rows
+        .iter()
+        .map(|row| ServerHealth {
+            server_id: row.get("server_id"),
+            status: row.try_get("status").unwrap_or(HealthStatus::Down),
+            latency_ms: row.try_get("latency_ms").ok().flatten(),
+            resolved_ip: row.try_get("resolved_ip").ok().flatten(),
+            banner: row.try_get("banner").ok().flatten(),
+            error: row.try_get("error").ok().flatten(),
+            checked_at: row.try_get("checked_at").unwrap_or_default(),
+        })
+        .collect())
+}
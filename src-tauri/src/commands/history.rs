@@ -1,9 +1,10 @@
 use crate::state::AppState;
+use crate::commands::settings::SettingsState;
 use crate::models::{HistoryFilterConfig, CommandHistoryItem};
-use tauri::{State, Window};
+use tauri::State;
 use sqlx::{Row, SqlitePool, FromRow};
 use std::time::{SystemTime, UNIX_EPOCH};
-use serde::{Serialize, Deserialize};
+use serde::Serialize;
 // 辅助函数：判断是否应该记录
 fn should_record(cmd: &str, config: &HistoryFilterConfig) -> bool {
     let trimmed = cmd.trim();
@@ -73,25 +74,32 @@ fn is_nonsense(cmd: &str) -> bool {
     false
 }
 
+/// Shell Integration 采集到的附加信息 (退出码/耗时/工作目录)
+#[derive(Debug, Clone, Default)]
+pub struct CommandMeta {
+    pub exit_code: Option<i32>,
+    pub duration_ms: Option<i64>,
+    pub cwd: Option<String>,
+}
+
 /// 记录一条命令 (核心事务逻辑)
-#[tauri::command]
-pub async fn record_command_history(
-    state: State<'_, AppState>,
-    server_id: String,
-    command: String,
-    source: Option<String> // default 'user'
+/// 供前端命令与 Shell Integration 读取线程共用
+pub async fn internal_record_command(
+    pool: &SqlitePool,
+    server_id: &str,
+    command: &str,
+    source: &str,
+    meta: CommandMeta,
+    config: &HistoryFilterConfig,
 ) -> Result<(), String> {
-    let config = HistoryFilterConfig::default(); // 这里后期可以从 DB 读取配置
-    
-    if !should_record(&command, &config) {
+    if !should_record(command, config) {
         return Ok(());
     }
 
     let normalized = command.trim().to_string();
     let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
-    let source_str = source.unwrap_or_else(|| "user".to_string());
 
-    let mut tx = state.db.begin().await.map_err(|e| e.to_string())?;
+    let mut tx = pool.begin().await.map_err(|e| e.to_string())?;
 
     // 1. 插入或更新 command_history (Global Dictionary)
     // 使用 ON CONFLICT 更新时间和全局计数
@@ -106,7 +114,7 @@ pub async fn record_command_history(
         "#
     )
     .bind(&normalized)
-    .bind(&command)
+    .bind(command)
     .bind(now)
     .bind(now)
     .fetch_one(&mut *tx)
@@ -126,7 +134,7 @@ pub async fn record_command_history(
         "#
     )
     .bind(history_id)
-    .bind(&server_id)
+    .bind(server_id)
     .bind(now)
     .bind(now)
     .execute(&mut *tx)
@@ -136,14 +144,17 @@ pub async fn record_command_history(
     // 3. 插入 command_events (Audit Log)
     sqlx::query(
         r#"
-        INSERT INTO command_events (command_id, server_id, source, executed_at)
-        VALUES (?, ?, ?, ?)
+        INSERT INTO command_events (command_id, server_id, source, executed_at, exit_code, duration_ms, cwd)
+        VALUES (?, ?, ?, ?, ?, ?, ?)
         "#
     )
     .bind(history_id)
-    .bind(&server_id)
-    .bind(source_str)
+    .bind(server_id)
+    .bind(source)
     .bind(now)
+    .bind(meta.exit_code)
+    .bind(meta.duration_ms)
+    .bind(meta.cwd)
     .execute(&mut *tx)
     .await
     .map_err(|e| e.to_string())?;
@@ -153,6 +164,20 @@ pub async fn record_command_history(
     Ok(())
 }
 
+/// 记录一条命令 (前端上报)
+#[tauri::command]
+pub async fn record_command_history(
+    state: State<'_, AppState>,
+    settings: State<'_, SettingsState>,
+    server_id: String,
+    command: String,
+    source: Option<String> // default 'user'
+) -> Result<(), String> {
+    let source_str = source.unwrap_or_else(|| "user".to_string());
+    let config = settings.snapshot().history;
+    internal_record_command(&state.db, &server_id, &command, &source_str, CommandMeta::default(), &config).await
+}
+
 /// 补全搜索：根据输入前缀返回推荐列表
 /// 策略：优先匹配前缀 -> 按全局热度排序 -> 按最近使用排序
 #[tauri::command]
@@ -222,6 +247,9 @@ pub struct ServerHistoryDto {
     pub id: i64,          // 事件 ID (用于删除)
     pub command: String,  // 实际命令内容
     pub created_at: i64,  // 执行时间戳
+    pub exit_code: Option<i32>,   // Shell Integration 采集 (前端上报的记录为空)
+    pub duration_ms: Option<i64>,
+    pub cwd: Option<String>,
 }
 
 /// 获取特定服务器的命令历史 (时间倒序)
@@ -237,7 +265,10 @@ pub async fn get_command_history(
         SELECT 
             e.id as id, 
             h.display_command as command, 
-            e.executed_at as created_at
+            e.executed_at as created_at,
+            e.exit_code as exit_code,
+            e.duration_ms as duration_ms,
+            e.cwd as cwd
         FROM command_events e
         JOIN command_history h ON e.command_id = h.id
         WHERE e.server_id = ?
@@ -0,0 +1,571 @@
+// src-tauri/src/commands/import.rs
+//
+// 服务器导入：先解析外部配置生成预览 (与现有服务器逐项比对)，用户确认后再写库。
+// 引用的私钥文件在确认导入时读取并存入 Vault，同一文件只存一份；
+// 引用的代理按 (类型, 地址, 端口, 用户名) 复用 proxies 表中的记录，没有时新建。
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Sqlite};
+use tauri::{command, State};
+
+use crate::commands::server::{internal_save_server, map_server_row};
+use crate::commands::vault::{internal_add_secret, internal_get_secret, VaultState};
+use crate::error::{AppError, AppResult};
+use crate::models::{AuthType, ConnectionType, OsType, Protocol, Proxy, ServerConfig};
+use crate::services::import::{
+    csv, mobaxterm, putty, ssh_config, xshell, ImportedHost, ImportedProxy, ParsedImport, SkippedEntry,
+};
+use crate::state::AppState;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportStatus {
+    /// 新服务器
+    New,
+    /// 已存在，部分字段会被更新
+    Update,
+    /// 已存在且没有差异
+    Unchanged,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChange {
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportPreviewEntry {
+    pub host: ImportedHost,
+    pub status: ImportStatus,
+    /// 匹配到的现有服务器 (按名称，其次按地址/端口/用户名)
+    pub existing_id: Option<String>,
+    pub existing_name: Option<String>,
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportPreview {
+    /// 解析的文件路径
+    pub source: String,
+    pub entries: Vec<ImportPreviewEntry>,
+    pub warnings: Vec<String>,
+    /// 无法导入的会话及原因
+    pub skipped: Vec<SkippedEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportApplyItem {
+    pub host: ImportedHost,
+    pub existing_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportFailure {
+    pub name: String,
+    pub error: AppError,
+}
+
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportResult {
+    pub created: u32,
+    pub updated: u32,
+    pub keys_imported: u32,
+    pub proxies_created: u32,
+    pub failed: Vec<ImportFailure>,
+}
+
+fn default_ssh_config_path() -> AppResult<PathBuf> {
+    dirs::home_dir()
+        .map(|h| h.join(".ssh").join("config"))
+        .ok_or_else(|| AppError::NotFound("Home directory not found".to_string()))
+}
+
+fn proxy_label(proxy: &ImportedProxy) -> String {
+    match &proxy.username {
+        Some(user) => format!("{}://{}@{}:{}", proxy.proxy_type, user, proxy.host, proxy.port),
+        None => format!("{}://{}:{}", proxy.proxy_type, proxy.host, proxy.port),
+    }
+}
+
+fn protocol_label(protocol: &Protocol) -> String {
+    match protocol {
+        Protocol::Ssh => "ssh",
+        Protocol::Telnet => "telnet",
+    }
+    .to_string()
+}
+
+fn change(field: &str, old_value: Option<String>, new_value: Option<String>) -> Option<FieldChange> {
+    (old_value != new_value).then(|| FieldChange {
+        field: field.to_string(),
+        old_value,
+        new_value,
+    })
+}
+
+// 只比较导入会覆盖的字段 (导入值为空的字段保持原样)
+fn diff_server(existing: &ServerConfig, host: &ImportedHost, proxies: &HashMap<String, String>) -> Vec<FieldChange> {
+    let mut changes = vec![
+        change("ip", Some(existing.ip.clone()), Some(host.host.clone())),
+        change("port", Some(existing.port.to_string()), Some(host.port.to_string())),
+        change(
+            "protocol",
+            Some(protocol_label(&existing.protocol)),
+            Some(protocol_label(&host.protocol)),
+        ),
+    ];
+    if let Some(proxy) = &host.proxy {
+        let current = existing.proxy_id.as_ref().and_then(|id| proxies.get(id)).cloned();
+        changes.push(change("proxy", current, Some(proxy_label(proxy))));
+    }
+    if let Some(username) = &host.username {
+        changes.push(change("username", Some(existing.username.clone()), Some(username.clone())));
+    }
+    if let Some(timeout) = host.connect_timeout {
+        changes.push(change(
+            "connectTimeout",
+            existing.connect_timeout.map(|v| v.to_string()),
+            Some(timeout.to_string()),
+        ));
+    }
+    if let Some(interval) = host.keep_alive_interval {
+        changes.push(change(
+            "keepAliveInterval",
+            existing.keep_alive_interval.map(|v| v.to_string()),
+            Some(interval.to_string()),
+        ));
+    }
+    if let Some(identity) = &host.identity_file {
+        changes.push(change(
+            "identityFile",
+            existing.private_key_remark.clone(),
+            Some(identity.clone()),
+        ));
+    }
+    changes.into_iter().flatten().collect()
+}
+
+/// 与现有服务器比对，生成预览 (供各导入格式共用)
+pub(crate) async fn build_preview(
+    pool: &Pool<Sqlite>,
+    source: String,
+    parsed: ParsedImport,
+) -> AppResult<ImportPreview> {
+    let rows = sqlx::query("SELECT * FROM servers WHERE deleted_at IS NULL").fetch_all(pool).await?;
+    let existing: Vec<ServerConfig> = rows.iter().map(map_server_row).collect();
+    let proxies: HashMap<String, String> = sqlx::query_as::<_, Proxy>("SELECT * FROM proxies")
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(|p| {
+            let label = proxy_label(&ImportedProxy {
+                proxy_type: p.proxy_type,
+                host: p.host,
+                port: p.port,
+                username: p.username,
+                password: None,
+            });
+            (p.id, label)
+        })
+        .collect();
+
+    let entries = parsed
+        .hosts
+        .into_iter()
+        .map(|mut host| {
+            if let Some(identity) = &host.identity_file {
+                if !Path::new(identity).is_file() {
+                    host.warnings
+                        .push(format!("Identity file {} not found, it will be skipped", identity));
+                }
+            }
+
+            let matched = existing
+                .iter()
+                .find(|s| s.name.eq_ignore_ascii_case(&host.name))
+                .or_else(|| {
+                    existing.iter().find(|s| {
+                        s.ip.eq_ignore_ascii_case(&host.host)
+                            && s.port == host.port
+                            && host.username.as_deref().is_none_or(|u| u == s.username)
+                    })
+                });
+
+            match matched {
+                Some(server) => {
+                    let changes = diff_server(server, &host, &proxies);
+                    ImportPreviewEntry {
+                        status: if changes.is_empty() {
+                            ImportStatus::Unchanged
+                        } else {
+                            ImportStatus::Update
+                        },
+                        existing_id: Some(server.id.clone()),
+                        existing_name: Some(server.name.clone()),
+                        changes,
+                        host,
+                    }
+                }
+                None => ImportPreviewEntry {
+                    host,
+                    status: ImportStatus::New,
+                    existing_id: None,
+                    existing_name: None,
+                    changes: Vec::new(),
+                },
+            }
+        })
+        .collect();
+
+    Ok(ImportPreview {
+        source,
+        entries,
+        warnings: parsed.warnings,
+        skipped: parsed.skipped,
+    })
+}
+
+/// 解析 ~/.ssh/config (或指定文件) 并返回导入预览
+#[command]
+pub async fn preview_ssh_config_import(
+    state: State<'_, AppState>,
+    path: Option<String>,
+) -> AppResult<ImportPreview> {
+    let path = match path.filter(|p| !p.trim().is_empty()) {
+        Some(p) => PathBuf::from(p),
+        None => default_ssh_config_path()?,
+    };
+    let parsed = ssh_config::parse_file(&path)?;
+    build_preview(&state.db, path.to_string_lossy().to_string(), parsed).await
+}
+
+// 各文件格式的预览：路径为必填
+fn require_path(path: &str) -> AppResult<PathBuf> {
+    let path = path.trim();
+    if path.is_empty() {
+        return Err(AppError::InvalidInput("Path is required".to_string()));
+    }
+    Ok(PathBuf::from(path))
+}
+
+/// 解析 PuTTY 注册表导出 (.reg) 并返回导入预览
+#[command]
+pub async fn preview_putty_import(state: State<'_, AppState>, path: String) -> AppResult<ImportPreview> {
+    let path = require_path(&path)?;
+    let parsed = putty::parse_file(&path)?;
+    build_preview(&state.db, path.to_string_lossy().to_string(), parsed).await
+}
+
+/// 解析 Xshell 会话文件 (.xsh) 或会话目录并返回导入预览
+#[command]
+pub async fn preview_xshell_import(state: State<'_, AppState>, path: String) -> AppResult<ImportPreview> {
+    let path = require_path(&path)?;
+    let parsed = xshell::parse_path(&path)?;
+    build_preview(&state.db, path.to_string_lossy().to_string(), parsed).await
+}
+
+/// 解析 MobaXterm 会话导出 (.mxtsessions) 并返回导入预览
+#[command]
+pub async fn preview_mobaxterm_import(state: State<'_, AppState>, path: String) -> AppResult<ImportPreview> {
+    let path = require_path(&path)?;
+    let parsed = mobaxterm::parse_file(&path)?;
+    build_preview(&state.db, path.to_string_lossy().to_string(), parsed).await
+}
+
+/// 解析 CSV (Termius 导出或通用格式) 并返回导入预览
+#[command]
+pub async fn preview_csv_import(state: State<'_, AppState>, path: String) -> AppResult<ImportPreview> {
+    let path = require_path(&path)?;
+    let parsed = csv::parse_file(&path)?;
+    build_preview(&state.db, path.to_string_lossy().to_string(), parsed).await
+}
+
+fn new_server(host: &ImportedHost, sort: i32) -> ServerConfig {
+    ServerConfig {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: host.name.clone(),
+        icon: "server".to_string(),
+        provider: "Custom".to_string(),
+        sort,
+        ip: host.host.clone(),
+        port: host.port,
+        tags: Vec::new(),
+        folder_id: None,
+        template_id: None,
+        template_overrides: Vec::new(),
+        connection_type: ConnectionType::Direct,
+        proxy_id: None,
+        auth_type: AuthType::Password,
+        username: "root".to_string(),
+        password: None,
+        private_key: None,
+        passphrase: None,
+        password_id: None,
+        password_source: None,
+        key_id: None,
+        key_source: None,
+        private_key_remark: None,
+        os: OsType::Linux,
+        is_pinned: false,
+        enable_expiration: false,
+        expire_date: None,
+        created_at: 0,
+        updated_at: 0,
+        last_connected_at: None,
+        connect_timeout: None,
+        keep_alive_interval: None,
+        auto_reconnect: None,
+        max_reconnects: None,
+        scrollback_size: None,
+        protocol: host.protocol.clone(),
+        login_script: Vec::new(),
+        tmux_session: None,
+        env_vars: Vec::new(),
+        initial_cwd: None,
+        startup_commands: Vec::new(),
+        algorithm_prefs: None,
+    }
+}
+
+// 读取私钥文件并存入 Vault，同一文件在一次导入中只存一份
+async fn import_identity(
+    pool: &Pool<Sqlite>,
+    vault_state: &VaultState,
+    path: &str,
+    username: &str,
+    key_cache: &mut HashMap<String, String>,
+    result: &mut ImportResult,
+) -> AppResult<String> {
+    if let Some(id) = key_cache.get(path) {
+        return Ok(id.clone());
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(|e| AppError::NotFound(format!("Identity file {}: {}", path, e)))?;
+    if !content.contains("PRIVATE KEY") {
+        return Err(AppError::InvalidInput(format!("{} is not a private key", path)));
+    }
+
+    let master_key = vault_state.master_key()?;
+    let file_name = Path::new(path)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string());
+    let name = format!("Imported Key: {}", file_name);
+
+    // 之前导入过的同名私钥内容一致时直接复用，避免重复导入产生多份凭据
+    let candidates: Vec<String> = sqlx::query_scalar(
+        "SELECT id FROM vault_keys WHERE key_type = 'private_key' AND name = ? AND deleted_at IS NULL",
+    )
+    .bind(&name)
+    .fetch_all(pool)
+    .await?;
+    for id in candidates {
+        let stored = match internal_get_secret(pool, &master_key, &id).await {
+            Ok(stored) => stored,
+            Err(_) => continue,
+        };
+        if stored.trim() == content.trim() {
+            key_cache.insert(path.to_string(), id.clone());
+            return Ok(id);
+        }
+    }
+
+    let id = internal_add_secret(
+        pool,
+        &master_key,
+        &name,
+        "private_key",
+        &content,
+        Some(username.to_string()),
+        None,
+    )
+    .await?;
+
+    key_cache.insert(path.to_string(), id.clone());
+    result.keys_imported += 1;
+    Ok(id)
+}
+
+// 服务器已绑定同一私钥文件导入的凭据且该凭据仍在时，沿用原来的 key_id
+async fn reusable_key_id(pool: &Pool<Sqlite>, server: &ServerConfig, identity: &str) -> AppResult<Option<String>> {
+    let Some(key_id) = server.key_id.as_deref() else {
+        return Ok(None);
+    };
+    if server.private_key_remark.as_deref() != Some(identity) {
+        return Ok(None);
+    }
+    let alive: Option<String> = sqlx::query_scalar("SELECT id FROM vault_keys WHERE id = ? AND deleted_at IS NULL")
+        .bind(key_id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(alive)
+}
+
+// 查找相同的代理，没有则新建；一次导入中同一代理只查一次
+async fn resolve_proxy(
+    pool: &Pool<Sqlite>,
+    proxy: &ImportedProxy,
+    proxy_cache: &mut HashMap<String, String>,
+    result: &mut ImportResult,
+) -> AppResult<String> {
+    let label = proxy_label(proxy);
+    if let Some(id) = proxy_cache.get(&label) {
+        return Ok(id.clone());
+    }
+
+    let existing: Option<String> = sqlx::query_scalar(
+        "SELECT id FROM proxies WHERE proxy_type = ? AND host = ? AND port = ? AND IFNULL(username, '') = ?",
+    )
+    .bind(&proxy.proxy_type)
+    .bind(&proxy.host)
+    .bind(proxy.port)
+    .bind(proxy.username.as_deref().unwrap_or_default())
+    .fetch_optional(pool)
+    .await?;
+
+    let id = match existing {
+        Some(id) => id,
+        None => {
+            let id = uuid::Uuid::new_v4().to_string();
+            let now = chrono::Utc::now().timestamp_millis();
+            sqlx::query(
+                "INSERT INTO proxies (id, name, proxy_type, host, port, username, password, created_at, updated_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&id)
+            .bind(format!("{}:{}", proxy.host, proxy.port))
+            .bind(&proxy.proxy_type)
+            .bind(&proxy.host)
+            .bind(proxy.port)
+            .bind(&proxy.username)
+            .bind(&proxy.password)
+            .bind(now)
+            .bind(now)
+            .execute(pool)
+            .await?;
+            result.proxies_created += 1;
+            id
+        }
+    };
+
+    proxy_cache.insert(label, id.clone());
+    Ok(id)
+}
+
+async fn import_one(
+    pool: &Pool<Sqlite>,
+    vault_state: &VaultState,
+    item: ImportApplyItem,
+    sort: i32,
+    key_cache: &mut HashMap<String, String>,
+    proxy_cache: &mut HashMap<String, String>,
+    result: &mut ImportResult,
+) -> AppResult<()> {
+    let host = item.host;
+    let is_update = item.existing_id.is_some();
+
+    let mut server = match &item.existing_id {
+        Some(id) => {
+            let row = sqlx::query("SELECT * FROM servers WHERE id = ?")
+                .bind(id)
+                .fetch_optional(pool)
+                .await?
+                .ok_or_else(|| AppError::NotFound(format!("Server {} no longer exists", id)))?;
+            map_server_row(&row)
+        }
+        None => new_server(&host, sort),
+    };
+
+    server.ip = host.host.clone();
+    server.port = host.port;
+    server.protocol = host.protocol.clone();
+    if let Some(username) = &host.username {
+        server.username = username.clone();
+    }
+    if host.connect_timeout.is_some() {
+        server.connect_timeout = host.connect_timeout;
+    }
+    if host.keep_alive_interval.is_some() {
+        server.keep_alive_interval = host.keep_alive_interval;
+    }
+    for tag in &host.tags {
+        if !server.tags.contains(tag) {
+            server.tags.push(tag.clone());
+        }
+    }
+
+    if let Some(proxy) = &host.proxy {
+        server.proxy_id = Some(resolve_proxy(pool, proxy, proxy_cache, result).await?);
+        server.connection_type = if proxy.proxy_type == "http" {
+            ConnectionType::Http
+        } else {
+            ConnectionType::Socks5
+        };
+    }
+
+    if let Some(identity) = host.identity_file.as_deref().filter(|p| Path::new(p).is_file()) {
+        let key_id = match reusable_key_id(pool, &server, identity).await? {
+            Some(id) => id,
+            None => import_identity(pool, vault_state, identity, &server.username, key_cache, result).await?,
+        };
+        server.auth_type = AuthType::PrivateKey;
+        server.key_id = Some(key_id);
+        server.key_source = Some("vault".to_string());
+        server.private_key = None;
+        server.private_key_remark = Some(identity.to_string());
+    }
+
+    internal_save_server(pool, vault_state, server).await?;
+    if is_update {
+        result.updated += 1;
+    } else {
+        result.created += 1;
+    }
+    Ok(())
+}
+
+/// 写入预览中选中的条目，单条失败不影响其余条目
+#[command]
+pub async fn apply_server_import(
+    state: State<'_, AppState>,
+    vault_state: State<'_, VaultState>,
+    items: Vec<ImportApplyItem>,
+) -> AppResult<ImportResult> {
+    let pool = &state.db;
+    let max_sort: Option<i32> = sqlx::query_scalar("SELECT MAX(sort) FROM servers")
+        .fetch_one(pool)
+        .await?;
+
+    let mut result = ImportResult::default();
+    let mut key_cache = HashMap::new();
+    let mut proxy_cache = HashMap::new();
+    for (sort, item) in (max_sort.unwrap_or(0) + 1..).zip(items) {
+        let name = item.host.name.clone();
+        if let Err(error) = import_one(
+            pool,
+            &vault_state,
+            item,
+            sort,
+            &mut key_cache,
+            &mut proxy_cache,
+            &mut result,
+        )
+        .await {
+            result.failed.push(ImportFailure { name, error });
+        }
+    }
+
+    Ok(result)
+}
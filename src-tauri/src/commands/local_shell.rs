@@ -0,0 +1,186 @@
+// src-tauri/src/commands/local_shell.rs
+//
+// 本地终端：在 PTY 中启动用户的登录 Shell，
+// 复用 SSH 会话的 term-data-{id} / write_ssh / resize_ssh / disconnect_ssh 事件约定。
+use std::io::{Read, Write};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use portable_pty::{native_pty_system, Child, CommandBuilder, MasterPty, PtySize};
+use tauri::{AppHandle, State};
+
+use crate::commands::connection_stats::DisconnectReason;
+use crate::commands::ssh::core::ShellOutputSink;
+use crate::commands::ssh::scrollback::ScrollbackBuffer;
+use crate::commands::ssh::shell_integration::ShellTracker;
+use crate::commands::ssh::{SshConnection, SshState, TerminalBackend};
+use crate::error::{AppError, AppResult};
+
+/// 本地会话写入命令历史时使用的伪服务器 ID
+pub const LOCAL_SERVER_ID: &str = "local";
+
+/// 本地 PTY 后端
+pub struct LocalPty {
+    master: Box<dyn MasterPty + Send>,
+    writer: Box<dyn Write + Send>,
+    child: Box<dyn Child + Send + Sync>,
+}
+
+impl TerminalBackend for LocalPty {
+    fn write_input(&mut self, data: &[u8]) -> AppResult<()> {
+        self.writer.write_all(data)?;
+        Ok(self.writer.flush()?)
+    }
+
+    fn resize(&mut self, cols: u32, rows: u32) -> AppResult<()> {
+        self.master
+            .resize(PtySize {
+                rows: rows as u16,
+                cols: cols as u16,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .map_err(|e| AppError::Io(e.to_string()))
+    }
+
+    fn close(&mut self) {
+        // 结束 Shell 进程并回收，避免留下僵尸进程；读取线程随后会收到 EOF
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// 打开本地终端
+/// shell 为空时使用系统默认登录 Shell (Unix: $SHELL -l，Windows: %COMSPEC%)
+#[tauri::command]
+pub async fn open_local_shell(
+    app: AppHandle,
+    state: State<'_, SshState>,
+    id: String,
+    shell: Option<String>,
+    cwd: Option<String>,
+    rows: Option<u16>,
+    cols: Option<u16>,
+) -> AppResult<()> {
+    let sessions = state.sessions.clone();
+    let session_id = id;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        // A. 清理旧连接
+        {
+            let mut map = sessions.lock().unwrap();
+            if let Some(old) = map.remove(&session_id) {
+                if let Ok(mut c) = old.shell_channel.lock() {
+                    c.close();
+                }
+            }
+        }
+
+        // B. 创建 PTY 并启动 Shell
+        let pty_system = native_pty_system();
+        let pair = pty_system
+            .openpty(PtySize {
+                rows: rows.unwrap_or(24),
+                cols: cols.unwrap_or(80),
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .map_err(|e| AppError::Io(format!("PTY Error: {}", e)))?;
+
+        let mut cmd = match shell.as_deref().filter(|s| !s.trim().is_empty()) {
+            Some(program) => CommandBuilder::new(program),
+            None => CommandBuilder::new_default_prog(),
+        };
+        cmd.env("TERM", "xterm-256color");
+        if let Some(dir) = cwd.filter(|d| !d.trim().is_empty()) {
+            cmd.cwd(dir);
+        }
+
+        let child = pair
+            .slave
+            .spawn_command(cmd)
+            .map_err(|e| AppError::Io(format!("Shell Start Error: {}", e)))?;
+        // 子进程已持有 slave 端，这里释放掉，进程退出时读取端才能收到 EOF
+        drop(pair.slave);
+
+        let mut reader = pair
+            .master
+            .try_clone_reader()
+            .map_err(|e| AppError::Io(format!("PTY Error: {}", e)))?;
+        let writer = pair
+            .master
+            .take_writer()
+            .map_err(|e| AppError::Io(format!("PTY Error: {}", e)))?;
+
+        // C. 存入状态 (本地会话没有监控/SFTP 会话)
+        let backend: Arc<Mutex<dyn TerminalBackend>> = Arc::new(Mutex::new(LocalPty {
+            master: pair.master,
+            writer,
+            child,
+        }));
+        let backend_for_exit = backend.clone();
+        let shell_tracker_arc = Arc::new(Mutex::new(ShellTracker::default()));
+        let scrollback_arc = Arc::new(Mutex::new(ScrollbackBuffer::default()));
+
+        {
+            let mut map = sessions.lock().unwrap();
+            map.insert(
+                session_id.clone(),
+                SshConnection {
+                    server_id: Some(LOCAL_SERVER_ID.to_string()),
+                    shell_session: None,
+                    shell_channel: backend,
+                    monitor_session: Arc::new(Mutex::new(None)),
+                    sftp_session: Arc::new(Mutex::new(None)),
+                    shell_tracker: shell_tracker_arc.clone(),
+                    scrollback: scrollback_arc.clone(),
+                },
+            );
+        }
+
+        // D. 启动读取线程 (PTY 读取是阻塞的，不需要持锁轮询)
+        let sink = ShellOutputSink {
+            app,
+            id: session_id.clone(),
+            server_id: Some(LOCAL_SERVER_ID.to_string()),
+            event_id: None,
+            tracker: shell_tracker_arc,
+            scrollback: scrollback_arc,
+        };
+
+        thread::spawn(move || {
+            let mut buf = [0u8; 8192];
+            loop {
+                match reader.read(&mut buf) {
+                    Ok(0) => break,
+                    Ok(count) => sink.push(&buf[..count]),
+                    Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                    Err(e) => {
+                        eprintln!("[Local] Read Error for session {}: {}", session_id, e);
+                        break;
+                    }
+                }
+            }
+
+            println!("[Local] Shell thread exited for {}", session_id);
+            // Shell 自行退出时移除会话并回收进程 (会话已被新的 Shell 替换时不动)
+            {
+                let mut map = sessions.lock().unwrap();
+                let is_current = map
+                    .get(&session_id)
+                    .is_some_and(|conn| Arc::ptr_eq(&conn.shell_channel, &backend_for_exit));
+                if is_current {
+                    map.remove(&session_id);
+                }
+            }
+            if let Ok(mut pty) = backend_for_exit.lock() {
+                pty.close();
+            }
+            sink.exit(DisconnectReason::Closed);
+        });
+
+        Ok(())
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("Async Error: {}", e)))?
+}
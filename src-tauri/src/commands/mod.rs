@@ -8,4 +8,19 @@ pub mod proxy;
 pub mod system;
 pub mod backup;
 pub mod history;
-pub mod highlight;
\ No newline at end of file
+pub mod highlight;
+pub mod local_shell;
+pub mod telnet;
+pub mod docker;
+pub mod settings;
+pub mod import;
+pub mod export;
+pub mod folder;
+pub mod template;
+pub mod expiration;
+pub mod health;
+pub mod search;
+pub mod trash;
+pub mod bulk_edit;
+pub mod connection_stats;
+pub mod auto_lock;
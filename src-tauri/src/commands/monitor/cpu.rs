@@ -2,6 +2,7 @@ use super::MonitorCache;
 use crate::commands::ssh::SshState;
 use std::io::Read;
 use tauri::State;
+use crate::error::{AppError, AppResult};
 
 #[derive(Clone, Copy, Debug)]
 pub struct CpuTicks {
@@ -63,16 +64,16 @@ pub async fn get_ssh_cpu_info(
     ssh_state: State<'_, SshState>,
     monitor_cache: State<'_, MonitorCache>,
     id: String,
-) -> Result<RemoteCpuInfo, String> {
+) -> AppResult<RemoteCpuInfo> {
     let session_arc = {
         let map = ssh_state.sessions.lock().unwrap();
-        map.get(&id).map(|c| c.monitor_session.clone()).ok_or("SSH not active")?
+        map.get(&id).map(|c| c.monitor_session.clone()).ok_or_else(|| AppError::session_not_active(&id))?
     };
 
     let output = tauri::async_runtime::spawn_blocking(move || {
         let sess_guard = session_arc.lock().unwrap();
-        let sess = sess_guard.as_ref().ok_or("Monitor session unavailable")?;
-        let mut channel = sess.channel_session().map_err(|e| e.to_string())?;
+        let sess = sess_guard.as_ref().ok_or_else(|| AppError::Ssh("Monitor session unavailable".to_string()))?;
+        let mut channel = sess.channel_session()?;
         
         // 🟢 组合指令：模型、逻辑数、物理数、负载、CPU 统计
         let cmd = "grep 'model name' /proc/cpuinfo | head -1 | cut -d: -f2 && \
@@ -81,15 +82,15 @@ pub async fn get_ssh_cpu_info(
                    echo '---SPLIT---' && cat /proc/loadavg && \
                    echo '---SPLIT---' && cat /proc/stat | grep '^cpu'";
         
-        channel.exec(cmd).map_err(|e| e.to_string())?;
+        channel.exec(cmd)?;
         let mut s = String::new();
         channel.read_to_string(&mut s).ok();
         channel.wait_close().ok();
-        Ok::<String, String>(s)
-    }).await.map_err(|e| e.to_string())??;
+        Ok::<String, AppError>(s)
+    }).await.map_err(|e| AppError::Internal(e.to_string()))??;
 
     let parts: Vec<&str> = output.split("---SPLIT---").collect();
-    if parts.len() < 5 { return Err("Invalid data format".into()); }
+    if parts.len() < 5 { return Err(AppError::Internal("Invalid data format".to_string())); }
 
     let model = parts[0].trim().to_string();
     let logical_threads = parts[1].trim().parse().unwrap_or(1);
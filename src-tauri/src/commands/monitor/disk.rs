@@ -6,6 +6,7 @@ use std::time::Instant;
 use tauri::State;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use crate::error::{AppError, AppResult};
 
 #[derive(Clone, Copy, Debug)]
 pub struct DiskIoStats {
@@ -94,12 +95,12 @@ pub async fn get_ssh_disk_info(
     ssh_state: State<'_, SshState>,
     monitor_cache: State<'_, MonitorCache>,
     id: String,
-) -> Result<RemoteDiskInfo, String> {
+) -> AppResult<RemoteDiskInfo> {
     let session_arc = {
         let map = ssh_state.sessions.lock().unwrap();
         match map.get(&id) {
             Some(conn) => conn.monitor_session.clone(),
-            None => return Err("SSH connection not active".to_string()),
+            None => return Err(AppError::session_not_active(&id)),
         }
     };
 
@@ -107,19 +108,19 @@ pub async fn get_ssh_disk_info(
         let sess_guard = session_arc.lock().unwrap();
         let sess = match &*sess_guard {
             Some(s) => s,
-            None => return Err("Monitor session unavailable".to_string()),
+            None => return Err(AppError::Ssh("Monitor session unavailable".to_string())),
         };
-        let mut channel = sess.channel_session().map_err(|e| e.to_string())?;
+        let mut channel = sess.channel_session()?;
         let cmd = "lsblk -b -J -o NAME,SIZE,MOUNTPOINT,ROTA,RM,TYPE && echo '---SPLIT---' && df -B1 2>/dev/null && echo '---SPLIT---' && cat /proc/diskstats 2>/dev/null";
-        channel.exec(cmd).map_err(|e| e.to_string())?;
+        channel.exec(cmd)?;
         let mut s = String::new();
         channel.read_to_string(&mut s).ok();
         channel.wait_close().ok();
-        Ok::<String, String>(s)
-    }).await.map_err(|e| e.to_string())??;
+        Ok::<String, AppError>(s)
+    }).await.map_err(|e| AppError::Internal(e.to_string()))??;
 
     let parts: Vec<&str> = output.split("---SPLIT---").collect();
-    if parts.len() < 3 { return Err("Invalid disk data".into()); }
+    if parts.len() < 3 { return Err(AppError::Internal("Invalid disk data".to_string())); }
 
     let mut df_map = HashMap::new();
     for line in parts[1].lines().skip(1) {
@@ -136,7 +137,7 @@ pub async fn get_ssh_disk_info(
         dev_io_map.insert(c[2].to_string(), (c[5].parse::<u64>().unwrap_or(0) * 512, c[9].parse::<u64>().unwrap_or(0) * 512));
     }
 
-    let lsblk: LsblkOutput = serde_json::from_str(parts[0]).map_err(|e| format!("JSON Error: {}", e))?;
+    let lsblk: LsblkOutput = serde_json::from_str(parts[0]).map_err(|e| AppError::Internal(format!("JSON Error: {}", e)))?;
     let mut disks = Vec::new();
     let (mut g_total, mut g_used, mut g_read, mut g_write) = (0, 0, 0, 0);
     let now = Instant::now();
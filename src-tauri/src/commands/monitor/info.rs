@@ -1,6 +1,7 @@
 use crate::commands::ssh::SshState;
 use std::io::Read;
 use tauri::State;
+use crate::error::{AppError, AppResult};
 
 #[derive(serde::Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -16,12 +17,12 @@ pub struct RemoteOsInfo {
 pub async fn get_ssh_os_info(
     ssh_state: State<'_, SshState>,
     id: String,
-) -> Result<RemoteOsInfo, String> {
+) -> AppResult<RemoteOsInfo> {
     let session_arc = {
         let map = ssh_state.sessions.lock().unwrap();
         match map.get(&id) {
             Some(conn) => conn.monitor_session.clone(),
-            None => return Err("SSH connection not active".to_string()),
+            None => return Err(AppError::session_not_active(&id)),
         }
     };
 
@@ -30,27 +31,27 @@ pub async fn get_ssh_os_info(
         // [修复] 解包 Option
         let sess = match &*sess_guard {
             Some(s) => s,
-            None => return Err("Monitor session unavailable".to_string()),
+            None => return Err(AppError::Ssh("Monitor session unavailable".to_string())),
         };
 
         // [修复] 显式指定错误类型
-        let mut channel = sess.channel_session().map_err(|e: ssh2::Error| e.to_string())?;
+        let mut channel = sess.channel_session()?;
         
         let cmd = "cat /proc/uptime && echo '---SPLIT---' && uname -r && echo '---SPLIT---' && uname -m && echo '---SPLIT---' && (grep PRETTY_NAME /etc/os-release || uname -o) && echo '---SPLIT---' && (cat /etc/timezone 2>/dev/null || date +%Z 2>/dev/null || echo 'Unknown')";
         
-        channel.exec(cmd).map_err(|e: ssh2::Error| e.to_string())?;
+        channel.exec(cmd)?;
 
         let mut s = String::new();
-        channel.read_to_string(&mut s).map_err(|e: std::io::Error| e.to_string())?;
+        channel.read_to_string(&mut s)?;
         channel.wait_close().ok();
-        Ok::<String, String>(s)
+        Ok::<String, AppError>(s)
     })
     .await
-    .map_err(|e| e.to_string())??;
+    .map_err(|e| AppError::Internal(e.to_string()))??;
 
     let parts: Vec<&str> = output.split("---SPLIT---").collect();
     if parts.len() < 5 {
-        return Err("Invalid output".to_string());
+        return Err(AppError::Internal("Invalid output".to_string()));
     }
 
     let uptime_str = parts[0].split_whitespace().next().unwrap_or("0");
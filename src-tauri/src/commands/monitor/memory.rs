@@ -2,6 +2,7 @@
 use crate::commands::ssh::SshState;
 use std::io::Read;
 use tauri::State;
+use crate::error::{AppError, AppResult};
 
 #[derive(serde::Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -22,12 +23,12 @@ pub struct RemoteMemInfo {
 pub async fn get_ssh_mem_info(
     ssh_state: State<'_, SshState>,
     id: String,
-) -> Result<RemoteMemInfo, String> {
+) -> AppResult<RemoteMemInfo> {
     let session_arc = {
         let map = ssh_state.sessions.lock().unwrap();
         match map.get(&id) {
             Some(conn) => conn.monitor_session.clone(),
-            None => return Err("SSH connection not active".to_string()),
+            None => return Err(AppError::session_not_active(&id)),
         }
     };
 
@@ -35,19 +36,19 @@ pub async fn get_ssh_mem_info(
         let sess_guard = session_arc.lock().unwrap();
         let sess = match &*sess_guard {
             Some(s) => s,
-            None => return Err("Monitor session unavailable".to_string()),
+            None => return Err(AppError::Ssh("Monitor session unavailable".to_string())),
         };
 
-        let mut channel = sess.channel_session().map_err(|e: ssh2::Error| e.to_string())?;
-        channel.exec("cat /proc/meminfo").map_err(|e: ssh2::Error| e.to_string())?;
+        let mut channel = sess.channel_session()?;
+        channel.exec("cat /proc/meminfo")?;
 
         let mut s = String::new();
-        channel.read_to_string(&mut s).map_err(|e: std::io::Error| e.to_string())?;
+        channel.read_to_string(&mut s)?;
         channel.wait_close().ok();
-        Ok::<String, String>(s)
+        Ok::<String, AppError>(s)
     })
     .await
-    .map_err(|e| format!("{}", e))??;
+    .map_err(|e| AppError::Internal(e.to_string()))??;
 
     let (mut total, mut free, mut available, mut buffers, mut cached) = (0, 0, 0, 0, 0);
     let (mut s_total, mut s_free) = (0, 0);
@@ -5,6 +5,7 @@ use std::io::Read;
 use std::time::Instant;
 use std::collections::HashMap;
 use tauri::State;
+use crate::error::{AppError, AppResult};
 
 #[derive(Clone, Debug)]
 pub struct NetIoStats {
@@ -43,12 +44,12 @@ pub async fn get_ssh_network_info(
     ssh_state: State<'_, SshState>,
     monitor_cache: State<'_, MonitorCache>,
     id: String,
-) -> Result<RemoteNetworkInfo, String> {
+) -> AppResult<RemoteNetworkInfo> {
     let session_arc = {
         let map = ssh_state.sessions.lock().unwrap();
         match map.get(&id) {
             Some(conn) => conn.monitor_session.clone(),
-            None => return Err("SSH connection not active".to_string()),
+            None => return Err(AppError::session_not_active(&id)),
         }
     };
 
@@ -56,29 +57,29 @@ pub async fn get_ssh_network_info(
         let sess_guard = session_arc.lock().unwrap();
         let sess = match &*sess_guard {
             Some(s) => s,
-            None => return Err("Monitor session unavailable".to_string()),
+            None => return Err(AppError::Ssh("Monitor session unavailable".to_string())),
         };
 
         let mut channel = sess
             .channel_session()
-            .map_err(|e: ssh2::Error| e.to_string())?;
+            ?;
 
         // 🟢 指令组合：流量 + 地址/状态 + TCP 连接数
         let cmd = "cat /proc/net/dev && echo '---SPLIT---' && ip addr && echo '---SPLIT---' && cat /proc/net/sockstat 2>/dev/null";
 
-        channel.exec(cmd).map_err(|e: ssh2::Error| e.to_string())?;
+        channel.exec(cmd)?;
 
         let mut s = String::new();
         channel.read_to_string(&mut s).ok();
         channel.wait_close().ok();
-        Ok::<String, String>(s)
+        Ok::<String, AppError>(s)
     })
     .await
-    .map_err(|e| e.to_string())??;
+    .map_err(|e| AppError::Internal(e.to_string()))??;
 
     let parts: Vec<&str> = output.split("---SPLIT---").collect();
     if parts.len() < 3 {
-        return Err("Invalid network data format".to_string());
+        return Err(AppError::Internal("Invalid network data format".to_string()));
     }
 
     // --- Part 1: TCP 连接数解析 ---
@@ -2,6 +2,7 @@
 use crate::commands::ssh::SshState;
 use std::io::Read;
 use tauri::State;
+use crate::error::{AppError, AppResult};
 
 #[derive(serde::Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -16,32 +17,32 @@ pub struct RemoteProcessInfo {
 pub async fn get_ssh_process_list(
     ssh_state: State<'_, SshState>,
     id: String,
-) -> Result<Vec<RemoteProcessInfo>, String> {
+) -> AppResult<Vec<RemoteProcessInfo>> {
     // 获取 SSH 监控会话
     let session_arc = {
         let map = ssh_state.sessions.lock().unwrap();
         map.get(&id)
             .map(|c| c.monitor_session.clone())
-            .ok_or("SSH connection not active")?
+            .ok_or_else(|| AppError::session_not_active(&id))?
     };
 
     let output = tauri::async_runtime::spawn_blocking(move || {
         let sess_guard = session_arc.lock().unwrap();
-        let sess = sess_guard.as_ref().ok_or("Monitor session unavailable")?;
-        let mut channel = sess.channel_session().map_err(|e| e.to_string())?;
+        let sess = sess_guard.as_ref().ok_or_else(|| AppError::Ssh("Monitor session unavailable".to_string()))?;
+        let mut channel = sess.channel_session()?;
         
         // 🟢 执行指令：获取 PID, 进程名, CPU%, RSS内存(KB)
         // 按照 CPU 占用率降序排列，取前 50 个
         let cmd = "ps -eo pid,comm,%cpu,rss --sort=-%cpu | head -n 51";
         
-        channel.exec(cmd).map_err(|e| e.to_string())?;
+        channel.exec(cmd)?;
         let mut s = String::new();
         channel.read_to_string(&mut s).ok();
         channel.wait_close().ok();
-        Ok::<String, String>(s)
+        Ok::<String, AppError>(s)
     })
     .await
-    .map_err(|e| e.to_string())??;
+    .map_err(|e| AppError::Internal(e.to_string()))??;
 
     let mut processes = Vec::new();
     let mut lines = output.lines();
@@ -0,0 +1,129 @@
+// src-tauri/src/commands/search.rs
+//
+// 全局搜索 (命令面板)：在 search_index (FTS5) 中检索服务器、片段、保险库条目名称与命令历史。
+// 索引由 v6 迁移中的触发器维护，这里只负责查询。
+// trigram 分词下少于 3 个字符的词无法走全文匹配，这些词退化为 LIKE 过滤。
+use serde::{Deserialize, Serialize};
+use sqlx::Row;
+use tauri::State;
+
+use crate::error::AppResult;
+use crate::state::AppState;
+
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 200;
+// trigram 分词的最短匹配长度
+const MIN_MATCH_CHARS: usize = 3;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum SearchKind {
+    Server,
+    Snippet,
+    Key,
+    History,
+}
+
+impl SearchKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SearchKind::Server => "server",
+            SearchKind::Snippet => "snippet",
+            SearchKind::Key => "key",
+            SearchKind::History => "history",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResult {
+    pub kind: SearchKind,
+    /// 对应源表的 id (命令历史为数字 id 的字符串形式)
+    pub id: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    /// 相关度，越大越相关
+    pub score: f64,
+}
+
+// 用户输入作为 FTS5 字符串 (双引号转义)，避免被解析成查询语法
+fn quote_term(term: &str) -> String {
+    format!("\"{}\"", term.replace('"', "\"\""))
+}
+
+fn escape_like(term: &str) -> String {
+    term.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+}
+
+/// 全局搜索，结果按相关度排序；多个词之间为 AND
+/// kinds 为空时搜索全部类型
+#[tauri::command]
+pub async fn global_search(
+    state: State<'_, AppState>,
+    query: String,
+    kinds: Option<Vec<SearchKind>>,
+    limit: Option<i64>,
+) -> AppResult<Vec<SearchResult>> {
+    let terms: Vec<&str> = query.split_whitespace().collect();
+    if terms.is_empty() {
+        return Ok(Vec::new());
+    }
+    let (long, short): (Vec<&str>, Vec<&str>) =
+        terms.into_iter().partition(|t| t.chars().count() >= MIN_MATCH_CHARS);
+    let kinds = kinds.unwrap_or_default();
+
+    let mut conditions = Vec::new();
+    if !long.is_empty() {
+        conditions.push("search_index MATCH ?".to_string());
+    }
+    for _ in &short {
+        conditions.push("(title || ' ' || IFNULL(subtitle, '') || ' ' || body) LIKE ? ESCAPE '\\'".to_string());
+    }
+    if !kinds.is_empty() {
+        conditions.push(format!("kind IN ({})", vec!["?"; kinds.len()].join(", ")));
+    }
+
+    // 标题权重最高，其次是副标题 (IP、代码首行)；没有全文匹配时相关度为 0
+    let score = if long.is_empty() {
+        "0.0"
+    } else {
+        "-bm25(search_index, 0.0, 0.0, 10.0, 5.0, 1.0)"
+    };
+    let sql = format!(
+        "SELECT kind, ref_id, title, subtitle, {score} AS score FROM search_index
+         WHERE {}
+         ORDER BY score DESC,
+                  CASE kind WHEN 'server' THEN 0 WHEN 'snippet' THEN 1 WHEN 'key' THEN 2 ELSE 3 END,
+                  title
+         LIMIT ?",
+        conditions.join(" AND ")
+    );
+
+    let mut q = sqlx::query(&sql);
+    if !long.is_empty() {
+        q = q.bind(long.iter().map(|t| quote_term(t)).collect::<Vec<_>>().join(" "));
+    }
+    for term in &short {
+        q = q.bind(format!("%{}%", escape_like(term)));
+    }
+    for kind in &kinds {
+        q = q.bind(kind.as_str());
+    }
+    q = q.bind(limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT));
+
+    let rows = q.fetch_all(&state.db).await?;
+    Ok(rows
+        .iter()
+        .filter_map(|row| {
+            Some(SearchResult {
+                kind: row.try_get("kind").ok()?,
+                id: row.get("ref_id"),
+                title: row.get("title"),
+                subtitle: row.try_get("subtitle").ok().flatten(),
+                score: row.try_get("score").unwrap_or_default(),
+            })
+        })
+        .collect())
+}
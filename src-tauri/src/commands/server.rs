@@ -1,8 +1,15 @@
 use tauri::{State, command};
-use crate::models::{ServerConfig, ConnectionType, AuthType, OsType};
+use crate::models::template::TemplateField;
+use crate::models::{ServerConfig, ConnectionType, AuthType, OsType, Protocol, TelnetLoginStep, EnvVar, StartupCommand, AlgorithmPreferences};
 use crate::state::AppState;
-use crate::commands::vault::{VaultState, internal_add_secret, internal_record_usage}; // 🟢 引入 internal_record_usage
-use sqlx::Row;
+use crate::commands::vault::{VaultState, internal_add_secret, internal_get_secret, internal_record_usage, internal_update_secret}; // 🟢 引入 internal_record_usage
+use crate::error::AppResult;
+use crate::commands::trash::{move_to_trash, TrashKind};
+use crate::commands::ssh::multiplexer::validate_session_name;
+use crate::commands::ssh::validate_env_name;
+use sqlx::{sqlite::SqliteRow, Pool, Row, Sqlite, SqliteConnection};
+use aes_gcm::{Aes256Gcm, Key};
+use serde_json::Value;
 use chrono::Utc;
 
 // =========================================================
@@ -13,59 +20,202 @@ pub async fn list_servers(state: State<'_, AppState>) -> Result<Vec<ServerConfig
     let pool = &state.db;
     
     // 1. 执行查询
-    let rows = sqlx::query("SELECT * FROM servers ORDER BY sort ASC")
+    let rows = sqlx::query("SELECT * FROM servers WHERE deleted_at IS NULL ORDER BY sort ASC")
         .fetch_all(pool)
         .await
         .map_err(|e| format!("数据库查询失败: {}", e))?;
 
-    let mut servers = Vec::new();
-
     // 2. 手动映射
-    for row in rows {
-        let tags_str: String = row.try_get("tags").unwrap_or("[]".to_string());
-        let tags: Vec<String> = serde_json::from_str(&tags_str).unwrap_or_default();
+    Ok(rows.iter().map(map_server_row).collect())
+}
+
+/// servers 表的一行转为 ServerConfig (JSON 列解析失败时使用空值)
+pub(crate) fn map_server_row(row: &SqliteRow) -> ServerConfig {
+    let tags_str: String = row.try_get("tags").unwrap_or("[]".to_string());
+    let tags: Vec<String> = serde_json::from_str(&tags_str).unwrap_or_default();
+    let login_script_str: String = row.try_get("login_script").unwrap_or("[]".to_string());
+    let login_script: Vec<TelnetLoginStep> = serde_json::from_str(&login_script_str).unwrap_or_default();
+    let env_vars_str: String = row.try_get("env_vars").unwrap_or("[]".to_string());
+    let env_vars: Vec<EnvVar> = serde_json::from_str(&env_vars_str).unwrap_or_default();
+    let startup_str: String = row.try_get("startup_commands").unwrap_or("[]".to_string());
+    let startup_commands: Vec<StartupCommand> = serde_json::from_str(&startup_str).unwrap_or_default();
+    let algorithm_prefs: Option<AlgorithmPreferences> = row
+        .try_get::<Option<String>, _>("algorithm_prefs")
+        .ok()
+        .flatten()
+        .and_then(|s| serde_json::from_str(&s).ok());
+    let overrides_str: String = row.try_get("template_overrides").unwrap_or("[]".to_string());
+    let template_overrides: Vec<TemplateField> = serde_json::from_str(&overrides_str).unwrap_or_default();
+    
+    ServerConfig {
+        id: row.try_get("id").unwrap_or_default(),
+        name: row.try_get("name").unwrap_or_default(),
+        icon: row.try_get("icon").unwrap_or_default(),
+        provider: row.try_get("provider").unwrap_or_default(),
+        sort: row.try_get("sort").unwrap_or_default(),
+        ip: row.try_get("ip").unwrap_or_default(),
+        port: row.try_get("port").unwrap_or_default(),
+        tags,
+        folder_id: row.try_get("folder_id").ok().flatten(),
+        template_id: row.try_get("template_id").ok().flatten(),
+        template_overrides,
+        connection_type: row.try_get("connection_type").unwrap_or(ConnectionType::Direct),
+        proxy_id: row.try_get("proxy_id").ok(),
+        auth_type: row.try_get("auth_type").unwrap_or(AuthType::Password),
+        username: row.try_get("username").unwrap_or_default(),
         
-        servers.push(ServerConfig {
-            id: row.try_get("id").unwrap_or_default(),
-            name: row.try_get("name").unwrap_or_default(),
-            icon: row.try_get("icon").unwrap_or_default(),
-            provider: row.try_get("provider").unwrap_or_default(),
-            sort: row.try_get("sort").unwrap_or_default(),
-            ip: row.try_get("ip").unwrap_or_default(),
-            port: row.try_get("port").unwrap_or_default(),
-            tags,
-            connection_type: row.try_get("connection_type").unwrap_or(ConnectionType::Direct),
-            proxy_id: row.try_get("proxy_id").ok(),
-            auth_type: row.try_get("auth_type").unwrap_or(AuthType::Password),
-            username: row.try_get("username").unwrap_or_default(),
-            
-            password: row.try_get("password").ok(),
-            private_key: row.try_get("private_key").ok(),
-            passphrase: row.try_get("passphrase").ok(),
-            
-            password_id: row.try_get("password_id").ok(),
-            password_source: row.try_get("password_source").ok(),
-            key_id: row.try_get("key_id").ok(),
-            key_source: row.try_get("key_source").ok(),
-            private_key_remark: row.try_get("private_key_remark").ok(),
-            
-            os: row.try_get("os").unwrap_or(OsType::Linux),
-            is_pinned: row.try_get("is_pinned").unwrap_or(false),
-            enable_expiration: row.try_get("enable_expiration").unwrap_or(false),
-            expire_date: row.try_get("expire_date").ok(),
+        password: row.try_get("password").ok(),
+        private_key: row.try_get("private_key").ok(),
+        passphrase: row.try_get("passphrase").ok(),
+        
+        password_id: row.try_get("password_id").ok(),
+        password_source: row.try_get("password_source").ok(),
+        key_id: row.try_get("key_id").ok(),
+        key_source: row.try_get("key_source").ok(),
+        private_key_remark: row.try_get("private_key_remark").ok(),
+        
+        os: row.try_get("os").unwrap_or(OsType::Linux),
+        is_pinned: row.try_get("is_pinned").unwrap_or(false),
+        enable_expiration: row.try_get("enable_expiration").unwrap_or(false),
+        expire_date: row.try_get("expire_date").ok(),
+
+        created_at: row.try_get("created_at").unwrap_or(0),
+        updated_at: row.try_get("updated_at").unwrap_or(0),
+        last_connected_at: row.try_get("last_connected_at").ok(),
+
+        connect_timeout: row.try_get("connect_timeout").ok(),
+        keep_alive_interval: row.try_get("keep_alive_interval").ok(),
+        auto_reconnect: row.try_get("auto_reconnect").ok(),
+        max_reconnects: row.try_get("max_reconnects").ok(),
+        scrollback_size: row.try_get("scrollback_size").ok(),
+        protocol: row.try_get("protocol").unwrap_or(Protocol::Ssh),
+        login_script,
+        tmux_session: row.try_get("tmux_session").ok().flatten(),
+        env_vars,
+        initial_cwd: row.try_get("initial_cwd").ok().flatten(),
+        startup_commands,
+        algorithm_prefs,
+    }
+}
+
+fn has_plaintext_secrets(server: &ServerConfig) -> bool {
+    [&server.password, &server.private_key, &server.passphrase]
+        .iter()
+        .any(|v| v.as_deref().is_some_and(|s| !s.trim().is_empty()))
+}
+
+/// 把服务器上的明文凭据移入 Vault 并清空明文字段
+/// 私钥与口令存为同一个条目 ({"val", "pass"}，与密钥管理中添加的格式一致)；
+/// 私钥已在 Vault 中时，口令补到该条目上 (条目已有口令时保持不变)
+pub(crate) async fn vault_plaintext_secrets(
+    conn: &mut SqliteConnection,
+    master_key: &Key<Aes256Gcm>,
+    server: &mut ServerConfig,
+) -> AppResult<()> {
+    let passphrase = server.passphrase.take().filter(|p| !p.trim().is_empty());
 
-            created_at: row.try_get("created_at").unwrap_or(0),
-            updated_at: row.try_get("updated_at").unwrap_or(0),
-            last_connected_at: row.try_get("last_connected_at").ok(),
+    if let Some(plain_password) = server.password.take().filter(|p| !p.is_empty()) {
+        let id = internal_add_secret(
+            &mut *conn,
+            master_key,
+            &format!("Server Pass: {}", server.name),
+            "password",
+            &plain_password,
+            Some(server.username.clone()),
+            None,
+        )
+        .await?;
+        server.password_id = Some(id);
+        server.password_source = Some("vault".to_string());
+    }
 
-            connect_timeout: row.try_get("connect_timeout").ok(),
-            keep_alive_interval: row.try_get("keep_alive_interval").ok(),
-            auto_reconnect: row.try_get("auto_reconnect").ok(),
-            max_reconnects: row.try_get("max_reconnects").ok(),
-        });
+    if let Some(plain_key) = server.private_key.take().filter(|k| !k.trim().is_empty()) {
+        let content = serde_json::json!({ "val": plain_key, "pass": passphrase.unwrap_or_default() });
+        let id = internal_add_secret(
+            &mut *conn,
+            master_key,
+            &format!("Server Key: {}", server.name),
+            "private_key",
+            &content.to_string(),
+            Some(server.username.clone()),
+            None,
+        )
+        .await?;
+        server.key_id = Some(id);
+        server.key_source = Some("vault".to_string());
+    } else if let (Some(pass), Some(key_id)) = (passphrase, server.key_id.as_deref()) {
+        attach_passphrase(conn, master_key, key_id, &pass).await?;
     }
+    Ok(())
+}
 
-    Ok(servers)
+// 把口令写入 Vault 中的私钥条目 (旧格式的纯文本私钥转为 {"val", "pass"})
+async fn attach_passphrase(conn: &mut SqliteConnection, master_key: &Key<Aes256Gcm>, key_id: &str, pass: &str) -> AppResult<()> {
+    let content = internal_get_secret(&mut *conn, master_key, key_id).await?;
+    let mut entry = match serde_json::from_str::<Value>(&content) {
+        Ok(Value::Object(map)) => map,
+        _ => {
+            let mut map = serde_json::Map::new();
+            map.insert("val".to_string(), Value::String(content));
+            map
+        }
+    };
+    if entry.get("pass").and_then(Value::as_str).is_some_and(|p| !p.is_empty()) {
+        return Ok(());
+    }
+    entry.insert("pass".to_string(), Value::String(pass.to_string()));
+    internal_update_secret(conn, master_key, key_id, &Value::Object(entry).to_string()).await
+}
+
+/// 把旧版本遗留在 servers 表中的明文密码、私钥和口令移入 Vault，返回处理的服务器数量
+/// 需要主密钥，所以在解锁 (或初始化) Vault 时执行；没有明文时什么也不做
+/// Vault 中已有对应凭据时，遗留的明文不再使用，直接清除
+/// 每台服务器的 Vault 写入与清除明文在同一个事务中，失败时整体回滚，下次解锁重试不会产生重复条目
+pub(crate) async fn migrate_plaintext_secrets(pool: &Pool<Sqlite>, master_key: &Key<Aes256Gcm>) -> AppResult<usize> {
+    let rows = sqlx::query(
+        "SELECT * FROM servers
+         WHERE IFNULL(password, '') <> '' OR IFNULL(private_key, '') <> '' OR IFNULL(passphrase, '') <> ''",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut migrated = 0;
+    for row in &rows {
+        let mut server = map_server_row(row);
+        if server.password_id.is_some() {
+            server.password = None;
+        }
+        if server.key_id.is_some() {
+            server.private_key = None;
+        }
+        let mut tx = pool.begin().await?;
+        if let Err(e) = vault_plaintext_secrets(&mut tx, master_key, &mut server).await {
+            eprintln!("[Vault] Failed to migrate secrets of server {}: {}", server.id, e);
+            continue;
+        }
+        let result = sqlx::query(
+            "UPDATE servers SET password = NULL, private_key = NULL, passphrase = NULL,
+                password_id = ?, password_source = ?, key_id = ?, key_source = ?
+             WHERE id = ?",
+        )
+        .bind(&server.password_id)
+        .bind(&server.password_source)
+        .bind(&server.key_id)
+        .bind(&server.key_source)
+        .bind(&server.id)
+        .execute(&mut *tx)
+        .await;
+        if let Err(e) = result {
+            eprintln!("[Vault] Failed to clear plaintext secrets of server {}: {}", server.id, e);
+            continue;
+        }
+        if let Err(e) = tx.commit().await {
+            eprintln!("[Vault] Failed to migrate secrets of server {}: {}", server.id, e);
+            continue;
+        }
+        migrated += 1;
+    }
+    Ok(migrated)
 }
 
 // =========================================================
@@ -75,59 +225,76 @@ pub async fn list_servers(state: State<'_, AppState>) -> Result<Vec<ServerConfig
 pub async fn save_server(
     state: State<'_, AppState>,
     vault_state: State<'_, VaultState>,
-    mut server: ServerConfig
+    server: ServerConfig
 ) -> Result<(), String> {
-    let pool = &state.db;
-
-    // 1. 密码处理 (移入 Vault)
-    if let Some(plain_password) = &server.password {
-        if !plain_password.is_empty() {
-            let master_key = {
-                let guard = vault_state.0.lock().unwrap();
-                guard.as_ref().cloned().ok_or("VAULT_LOCKED")? 
-            };
+    internal_save_server(&state.db, &vault_state, server).await
+}
 
-            let secret_name = format!("Server Pass: {}", server.name);
-            
-            let new_pass_id = internal_add_secret(
-                pool, 
-                &master_key, 
-                &secret_name, 
-                "password", 
-                plain_password, 
-                Some(server.username.clone()),
-                None 
-            ).await?;
+// 保存前校验只在连接时才会用到的配置，避免存下一个每次连接都会失败的服务器
+fn validate_server(server: &ServerConfig) -> AppResult<()> {
+    if let Some(name) = server.tmux_session.as_deref().filter(|s| !s.trim().is_empty()) {
+        validate_session_name(name)?;
+    }
+    for var in &server.env_vars {
+        let name = var.name.trim();
+        // 空名称在连接时会被跳过
+        if !name.is_empty() {
+            validate_env_name(name)?;
+        }
+    }
+    Ok(())
+}
 
-            server.password_id = Some(new_pass_id);
-            server.password_source = Some("vault".to_string());
-            server.password = None;
+// 前端拿不到明文字段，编辑尚未迁移的旧服务器时沿用库中的明文，避免被覆盖成 NULL
+// 已改用 Vault 中的凭据时遗留明文作废，不再沿用
+async fn carry_over_plaintext(pool: &Pool<Sqlite>, server: &mut ServerConfig) -> AppResult<()> {
+    let stored: Option<(Option<String>, Option<String>, Option<String>)> =
+        sqlx::query_as("SELECT password, private_key, passphrase FROM servers WHERE id = ?")
+            .bind(&server.id)
+            .fetch_optional(pool)
+            .await?;
+    let Some((password, private_key, passphrase)) = stored else {
+        return Ok(());
+    };
+    let is_unset = |v: &Option<String>| v.as_deref().is_none_or(|s| s.trim().is_empty());
+    if is_unset(&server.password) && server.password_id.is_none() {
+        server.password = password;
+    }
+    if server.key_id.is_none() {
+        if is_unset(&server.private_key) {
+            server.private_key = private_key;
+        }
+        if is_unset(&server.passphrase) {
+            server.passphrase = passphrase;
         }
     }
+    Ok(())
+}
 
-    // 2. 私钥处理 (移入 Vault)
-    if let Some(plain_key) = &server.private_key {
-        if !plain_key.is_empty() {
-            let master_key = {
-                let guard = vault_state.0.lock().unwrap();
-                guard.as_ref().cloned().ok_or("VAULT_LOCKED")?
-            };
+/// 保存服务器 (明文凭据移入 Vault 后写库)，供导入等批量操作复用
+pub(crate) async fn internal_save_server(
+    pool: &Pool<Sqlite>,
+    vault_state: &VaultState,
+    mut server: ServerConfig,
+) -> Result<(), String> {
+    validate_server(&server).map_err(|e| e.to_string())?;
 
-            let secret_name = format!("Server Key: {}", server.name);
-            
-            let new_key_id = internal_add_secret(
-                pool, 
-                &master_key, 
-                &secret_name, 
-                "private_key", 
-                plain_key, 
-                Some(server.username.clone()),
-                None 
-            ).await?;
+    let incoming_plaintext = has_plaintext_secrets(&server);
+    carry_over_plaintext(pool, &mut server).await.map_err(|e| e.to_string())?;
 
-            server.key_id = Some(new_key_id);
-            server.key_source = Some("vault".to_string());
-            server.private_key = None; 
+    // 1. 明文密码、私钥、口令移入 Vault
+    // Vault 未解锁时新填写的明文不能保存；库中遗留的明文原样保留，等解锁时迁移
+    if has_plaintext_secrets(&server) {
+        let master_key = vault_state.0.lock().unwrap().as_ref().cloned();
+        match master_key {
+            Some(master_key) => {
+                let mut conn = pool.acquire().await.map_err(|e| e.to_string())?;
+                vault_plaintext_secrets(&mut conn, &master_key, &mut server)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+            None if incoming_plaintext => return Err("VAULT_LOCKED".to_string()),
+            None => {}
         }
     }
 
@@ -138,8 +305,16 @@ pub async fn save_server(
     }
     server.updated_at = now;
 
-    // 3. 存入数据库
+    // 2. 存入数据库
     let tags_json = serde_json::to_string(&server.tags).unwrap_or("[]".to_string());
+    let login_script_json = serde_json::to_string(&server.login_script).unwrap_or("[]".to_string());
+    let env_vars_json = serde_json::to_string(&server.env_vars).unwrap_or("[]".to_string());
+    let startup_json = serde_json::to_string(&server.startup_commands).unwrap_or("[]".to_string());
+    let overrides_json = serde_json::to_string(&server.template_overrides).unwrap_or("[]".to_string());
+    let algorithm_prefs_json = server
+        .algorithm_prefs
+        .as_ref()
+        .and_then(|p| serde_json::to_string(p).ok());
 
     sqlx::query(
         r#"
@@ -150,7 +325,10 @@ pub async fn save_server(
             password_id, password_source, key_id, key_source, private_key_remark,
             os, is_pinned, enable_expiration, expire_date,
             created_at, updated_at, last_connected_at,
-            connect_timeout, keep_alive_interval, auto_reconnect, max_reconnects
+            connect_timeout, keep_alive_interval, auto_reconnect, max_reconnects,
+            scrollback_size, protocol, login_script, tmux_session,
+            env_vars, initial_cwd, startup_commands, algorithm_prefs, folder_id,
+            template_id, template_overrides
         ) VALUES (
             ?, ?, ?, ?, ?, ?, ?, ?, 
             ?, ?, ?, ?, 
@@ -158,7 +336,10 @@ pub async fn save_server(
             ?, ?, ?, ?, ?,
             ?, ?, ?, ?,
             ?, ?, ?,
-            ?, ?, ?, ?
+            ?, ?, ?, ?,
+            ?, ?, ?, ?,
+            ?, ?, ?, ?, ?,
+            ?, ?
         )
         "#
     )
@@ -193,6 +374,17 @@ pub async fn save_server(
     .bind(server.keep_alive_interval)
     .bind(server.auto_reconnect)
     .bind(server.max_reconnects)
+    .bind(server.scrollback_size)
+    .bind(server.protocol)
+    .bind(login_script_json)
+    .bind(server.tmux_session.filter(|s| !s.trim().is_empty()))
+    .bind(env_vars_json)
+    .bind(server.initial_cwd.filter(|s| !s.trim().is_empty()))
+    .bind(startup_json)
+    .bind(algorithm_prefs_json)
+    .bind(server.folder_id)
+    .bind(server.template_id)
+    .bind(overrides_json)
     .execute(pool)
     .await
     .map_err(|e| format!("保存服务器失败: {}", e))?;
@@ -203,15 +395,12 @@ pub async fn save_server(
 // =========================================================
 // 删除服务器
 // =========================================================
+// 移入回收站，可达性扫描结果等附属数据在彻底清除时删除
 #[command]
 pub async fn delete_server(state: State<'_, AppState>, id: String) -> Result<(), String> {
-    sqlx::query("DELETE FROM servers WHERE id = ?")
-        .bind(id)
-        .execute(&state.db)
+    move_to_trash(&state.db, TrashKind::Server, &id)
         .await
-        .map_err(|e| format!("删除失败: {}", e))?;
-        
-    Ok(())
+        .map_err(|e| format!("删除失败: {}", e))
 }
 
 // 🟢 [修改] 更新最后连接时间，并记录凭据使用情况
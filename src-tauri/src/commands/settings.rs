@@ -0,0 +1,191 @@
+// src-tauri/src/commands/settings.rs
+//
+// 应用设置：启动时从 app_settings 表加载到内存，修改后写回数据库并广播 settings-changed 事件。
+// 后端逻辑 (窗口关闭行为、历史过滤等) 直接读取 SettingsState，无需前端每次启动重新下发。
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use sqlx::{Pool, Row, Sqlite};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::commands::expiration::ExpirationChecker;
+use crate::error::{AppError, AppResult};
+use crate::models::settings::{AppSettings, SettingsPatch};
+use crate::state::AppState;
+
+/// 设置变更事件，负载为完整的 AppSettings
+pub const SETTINGS_CHANGED_EVENT: &str = "settings-changed";
+
+/// 备份包中的设置快照文件名
+/// 恢复备份后该文件会被解压到配置目录，下次启动时导入数据库
+pub const SETTINGS_SNAPSHOT_FILE: &str = "app_settings.json";
+
+pub struct SettingsState(pub Mutex<AppSettings>);
+
+impl SettingsState {
+    pub fn new(settings: AppSettings) -> Self {
+        Self(Mutex::new(settings))
+    }
+
+    /// 当前设置的副本
+    pub fn snapshot(&self) -> AppSettings {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+/// 从数据库读取设置，缺失或损坏的分组使用默认值
+pub async fn load_settings(pool: &Pool<Sqlite>) -> AppResult<AppSettings> {
+    let rows = sqlx::query("SELECT key, value FROM app_settings")
+        .fetch_all(pool)
+        .await?;
+
+    let mut sections = Map::new();
+    for row in rows {
+        let key: String = row.get("key");
+        let value: String = row.get("value");
+        match serde_json::from_str::<Value>(&value) {
+            Ok(v) => {
+                sections.insert(key, v);
+            }
+            Err(e) => eprintln!("[Settings] Ignoring corrupted section '{}': {}", key, e),
+        }
+    }
+
+    Ok(AppSettings {
+        window: section(&mut sections, "window"),
+        terminal: section(&mut sections, "terminal"),
+        history: section(&mut sections, "history"),
+        monitor: section(&mut sections, "monitor"),
+        vault: section(&mut sections, "vault"),
+        expiration: section(&mut sections, "expiration"),
+        trash: section(&mut sections, "trash"),
+    })
+}
+
+// 单个分组结构不兼容时只回退该分组
+fn section<T: DeserializeOwned + Default>(sections: &mut Map<String, Value>, key: &str) -> T {
+    match sections.remove(key) {
+        Some(value) => serde_json::from_value(value).unwrap_or_else(|e| {
+            eprintln!("[Settings] Resetting section '{}': {}", key, e);
+            T::default()
+        }),
+        None => T::default(),
+    }
+}
+
+/// 写入全部分组 (单事务)
+pub async fn save_settings(pool: &Pool<Sqlite>, settings: &AppSettings) -> AppResult<()> {
+    let value = serde_json::to_value(settings).map_err(|e| AppError::Internal(e.to_string()))?;
+    let Value::Object(sections) = value else {
+        return Err(AppError::Internal("Settings must serialize to an object".to_string()));
+    };
+
+    let now = chrono::Utc::now().timestamp_millis();
+    let mut tx = pool.begin().await?;
+    for (key, section) in sections {
+        sqlx::query(
+            "INSERT INTO app_settings (key, value, updated_at) VALUES (?, ?, ?)
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value, updated_at = excluded.updated_at",
+        )
+        .bind(&key)
+        .bind(section.to_string())
+        .bind(now)
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+    Ok(())
+}
+
+/// 启动时加载设置
+/// 配置目录中存在恢复备份留下的快照时，先导入数据库再删除快照
+pub async fn init_settings(pool: &Pool<Sqlite>, config_dir: &Path) -> AppResult<AppSettings> {
+    let snapshot_path = config_dir.join(SETTINGS_SNAPSHOT_FILE);
+    if snapshot_path.exists() {
+        match fs::read_to_string(&snapshot_path)
+            .map_err(AppError::from)
+            .and_then(|s| serde_json::from_str::<AppSettings>(&s).map_err(|e| AppError::InvalidInput(e.to_string())))
+        {
+            Ok(restored) => save_settings(pool, &restored).await?,
+            Err(e) => eprintln!("[Settings] Failed to import restored settings: {}", e),
+        }
+        let _ = fs::remove_file(&snapshot_path);
+    }
+
+    load_settings(pool).await
+}
+
+async fn apply_settings(
+    app: &AppHandle,
+    pool: &Pool<Sqlite>,
+    state: &SettingsState,
+    settings: AppSettings,
+) -> AppResult<AppSettings> {
+    save_settings(pool, &settings).await?;
+    *state.0.lock().unwrap() = settings.clone();
+    let _ = app.emit(SETTINGS_CHANGED_EVENT, &settings);
+    Ok(settings)
+}
+
+#[tauri::command]
+pub fn get_settings(state: State<'_, SettingsState>) -> AppSettings {
+    state.snapshot()
+}
+
+/// 局部更新：只替换传入的分组，返回更新后的完整设置
+#[tauri::command]
+pub async fn update_settings(
+    app: AppHandle,
+    app_state: State<'_, AppState>,
+    state: State<'_, SettingsState>,
+    patch: SettingsPatch,
+) -> AppResult<AppSettings> {
+    let mut settings = state.snapshot();
+    if let Some(window) = patch.window {
+        settings.window = window;
+    }
+    if let Some(terminal) = patch.terminal {
+        settings.terminal = terminal;
+    }
+    if let Some(history) = patch.history {
+        settings.history = history;
+    }
+    if let Some(monitor) = patch.monitor {
+        settings.monitor = monitor;
+    }
+    if let Some(vault) = patch.vault {
+        settings.vault = vault;
+    }
+    let expiration_changed = patch
+        .expiration
+        .as_ref()
+        .is_some_and(|e| *e != settings.expiration);
+    if let Some(expiration) = patch.expiration {
+        settings.expiration = expiration;
+    }
+    if let Some(trash) = patch.trash {
+        settings.trash = trash;
+    }
+
+    let settings = apply_settings(&app, &app_state.db, &state, settings).await?;
+    // 提醒窗口变化后立即重新检查，托盘角标随之更新
+    if expiration_changed {
+        if let Some(checker) = app.try_state::<ExpirationChecker>() {
+            checker.recheck();
+        }
+    }
+    Ok(settings)
+}
+
+/// 恢复默认设置
+#[tauri::command]
+pub async fn reset_settings(
+    app: AppHandle,
+    app_state: State<'_, AppState>,
+    state: State<'_, SettingsState>,
+) -> AppResult<AppSettings> {
+    apply_settings(&app, &app_state.db, &state, AppSettings::default()).await
+}
@@ -1,11 +1,12 @@
 use tauri::State;
 use crate::state::AppState;
 use crate::models::{Snippet, SnippetDto};
+use crate::commands::trash::{move_to_trash, TrashKind};
 
 #[tauri::command]
 pub async fn get_all_snippets(state: State<'_, AppState>) -> Result<Vec<SnippetDto>, String> {
     // 1. 从数据库查询所有记录 (Snippet 结构体)
-    let rows = sqlx::query_as::<_, Snippet>("SELECT * FROM snippets ORDER BY created_at DESC")
+    let rows = sqlx::query_as::<_, Snippet>("SELECT * FROM snippets WHERE deleted_at IS NULL ORDER BY created_at DESC")
         .fetch_all(&state.db)
         .await
         .map_err(|e| e.to_string())?;
@@ -66,13 +67,10 @@ pub async fn update_snippet(state: State<'_, AppState>, snippet: SnippetDto) ->
     Ok(())
 }
 
+// 移入回收站
 #[tauri::command]
 pub async fn delete_snippet(state: State<'_, AppState>, id: String) -> Result<(), String> {
-    sqlx::query("DELETE FROM snippets WHERE id = ?")
-        .bind(id)
-        .execute(&state.db)
+    move_to_trash(&state.db, TrashKind::Snippet, &id)
         .await
-        .map_err(|e| e.to_string())?;
-
-    Ok(())
+        .map_err(|e| e.to_string())
 }
\ No newline at end of file
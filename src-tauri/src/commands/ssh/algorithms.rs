@@ -0,0 +1,202 @@
+// src-tauri/src/commands/ssh/algorithms.rs
+//
+// SSH 算法偏好：KEX / 主机密钥 / 加密 / MAC
+// 在 handshake() 之前通过 method_pref 设置，libssh2 会自动忽略本地不支持的算法。
+use serde::Serialize;
+use ssh2::{MethodType, Session};
+
+use crate::error::{AppError, AppResult};
+use crate::models::{AlgorithmPreferences, AlgorithmProfile};
+
+/// 一套完整的算法列表 (按优先级排列)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgorithmSet {
+    pub kex: Vec<&'static str>,
+    pub host_key: Vec<&'static str>,
+    pub cipher: Vec<&'static str>,
+    pub mac: Vec<&'static str>,
+}
+
+// 仅现代算法：安全基线要求的配置
+const MODERN_KEX: &[&str] = &[
+    "curve25519-sha256",
+    "curve25519-sha256@libssh.org",
+    "ecdh-sha2-nistp256",
+    "ecdh-sha2-nistp384",
+    "ecdh-sha2-nistp521",
+    "diffie-hellman-group16-sha512",
+    "diffie-hellman-group18-sha512",
+    "diffie-hellman-group-exchange-sha256",
+];
+const MODERN_HOST_KEY: &[&str] = &[
+    "ssh-ed25519",
+    "ecdsa-sha2-nistp256",
+    "ecdsa-sha2-nistp384",
+    "ecdsa-sha2-nistp521",
+    "rsa-sha2-512",
+    "rsa-sha2-256",
+];
+const MODERN_CIPHER: &[&str] = &[
+    "chacha20-poly1305@openssh.com",
+    "aes256-gcm@openssh.com",
+    "aes128-gcm@openssh.com",
+    "aes256-ctr",
+    "aes192-ctr",
+    "aes128-ctr",
+];
+const MODERN_MAC: &[&str] = &[
+    "hmac-sha2-512-etm@openssh.com",
+    "hmac-sha2-256-etm@openssh.com",
+    "hmac-sha2-512",
+    "hmac-sha2-256",
+];
+
+// 兼容：现代算法优先，额外允许 SHA1 系列与 ssh-rsa
+const COMPATIBLE_KEX: &[&str] = &["diffie-hellman-group14-sha256", "diffie-hellman-group14-sha1"];
+const COMPATIBLE_HOST_KEY: &[&str] = &["ssh-rsa"];
+const COMPATIBLE_CIPHER: &[&str] = &[];
+const COMPATIBLE_MAC: &[&str] = &["hmac-sha1"];
+
+// 遗留：老旧路由器/交换机所需的 group1 / ssh-dss / CBC
+const LEGACY_KEX: &[&str] = &["diffie-hellman-group-exchange-sha1", "diffie-hellman-group1-sha1"];
+const LEGACY_HOST_KEY: &[&str] = &["ssh-dss"];
+const LEGACY_CIPHER: &[&str] = &["aes256-cbc", "aes192-cbc", "aes128-cbc", "3des-cbc"];
+const LEGACY_MAC: &[&str] = &["hmac-sha1-96", "hmac-md5", "hmac-md5-96"];
+
+fn concat(parts: &[&[&'static str]]) -> Vec<&'static str> {
+    parts.iter().flat_map(|p| p.iter().copied()).collect()
+}
+
+impl AlgorithmSet {
+    pub fn preset(profile: AlgorithmProfile) -> Self {
+        match profile {
+            AlgorithmProfile::Modern => Self {
+                kex: MODERN_KEX.to_vec(),
+                host_key: MODERN_HOST_KEY.to_vec(),
+                cipher: MODERN_CIPHER.to_vec(),
+                mac: MODERN_MAC.to_vec(),
+            },
+            AlgorithmProfile::Compatible => Self {
+                kex: concat(&[MODERN_KEX, COMPATIBLE_KEX]),
+                host_key: concat(&[MODERN_HOST_KEY, COMPATIBLE_HOST_KEY]),
+                cipher: concat(&[MODERN_CIPHER, COMPATIBLE_CIPHER]),
+                mac: concat(&[MODERN_MAC, COMPATIBLE_MAC]),
+            },
+            AlgorithmProfile::Legacy => Self {
+                kex: concat(&[MODERN_KEX, COMPATIBLE_KEX, LEGACY_KEX]),
+                host_key: concat(&[MODERN_HOST_KEY, COMPATIBLE_HOST_KEY, LEGACY_HOST_KEY]),
+                cipher: concat(&[MODERN_CIPHER, COMPATIBLE_CIPHER, LEGACY_CIPHER]),
+                mac: concat(&[MODERN_MAC, COMPATIBLE_MAC, LEGACY_MAC]),
+            },
+        }
+    }
+}
+
+// 自定义列表优先，否则使用预设
+fn pick(custom: &[String], preset: Option<&[&'static str]>) -> Option<String> {
+    let list: Vec<&str> = custom
+        .iter()
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .collect();
+    if !list.is_empty() {
+        return Some(list.join(","));
+    }
+    preset.map(|p| p.join(","))
+}
+
+fn set_pref(
+    sess: &Session,
+    label: &str,
+    value: Option<String>,
+    method_types: &[MethodType],
+) -> AppResult<()> {
+    let Some(value) = value else {
+        return Ok(());
+    };
+    for method_type in method_types {
+        sess.method_pref(*method_type, &value)
+            .map_err(|e| {
+                AppError::InvalidInput(format!("Invalid {} preference ({}): {}", label, value, e))
+            })?;
+    }
+    Ok(())
+}
+
+/// 在 handshake() 之前应用算法偏好
+pub fn apply_algorithm_preferences(
+    sess: &Session,
+    prefs: &AlgorithmPreferences,
+) -> AppResult<()> {
+    let preset = prefs.profile.map(AlgorithmSet::preset);
+
+    set_pref(
+        sess,
+        "KEX",
+        pick(&prefs.kex, preset.as_ref().map(|p| p.kex.as_slice())),
+        &[MethodType::Kex],
+    )?;
+    set_pref(
+        sess,
+        "Host Key",
+        pick(&prefs.host_key, preset.as_ref().map(|p| p.host_key.as_slice())),
+        &[MethodType::HostKey],
+    )?;
+    set_pref(
+        sess,
+        "Cipher",
+        pick(&prefs.cipher, preset.as_ref().map(|p| p.cipher.as_slice())),
+        &[MethodType::CryptCs, MethodType::CryptSc],
+    )?;
+    set_pref(
+        sess,
+        "MAC",
+        pick(&prefs.mac, preset.as_ref().map(|p| p.mac.as_slice())),
+        &[MethodType::MacCs, MethodType::MacSc],
+    )
+}
+
+/// 握手完成后实际协商出的算法
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NegotiatedAlgorithms {
+    pub kex: Option<String>,
+    pub host_key: Option<String>,
+    pub cipher_client_to_server: Option<String>,
+    pub cipher_server_to_client: Option<String>,
+    pub mac_client_to_server: Option<String>,
+    pub mac_server_to_client: Option<String>,
+}
+
+impl NegotiatedAlgorithms {
+    pub fn from_session(sess: &Session) -> Self {
+        let get = |t: MethodType| sess.methods(t).map(|s| s.to_string());
+        Self {
+            kex: get(MethodType::Kex),
+            host_key: get(MethodType::HostKey),
+            cipher_client_to_server: get(MethodType::CryptCs),
+            cipher_server_to_client: get(MethodType::CryptSc),
+            mac_client_to_server: get(MethodType::MacCs),
+            mac_server_to_client: get(MethodType::MacSc),
+        }
+    }
+}
+
+/// 预设列表 (供服务器表单展示)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgorithmPresets {
+    pub modern: AlgorithmSet,
+    pub compatible: AlgorithmSet,
+    pub legacy: AlgorithmSet,
+}
+
+#[tauri::command]
+pub fn get_ssh_algorithm_presets() -> AlgorithmPresets {
+    AlgorithmPresets {
+        modern: AlgorithmSet::preset(AlgorithmProfile::Modern),
+        compatible: AlgorithmSet::preset(AlgorithmProfile::Compatible),
+        legacy: AlgorithmSet::preset(AlgorithmProfile::Legacy),
+    }
+}
@@ -1,4 +1,4 @@
-use std::io::Read;
+use std::io::{Read, Write};
 use std::net::{TcpStream, ToSocketAddrs};
 use std::sync::{Arc, Mutex};
 use std::thread;
@@ -7,33 +7,57 @@ use std::fs; // [必须] 引入文件操作
 use std::env; // [必须] 引入环境路径
 
 use ssh2::{Channel, Session};
-use tauri::{AppHandle, Emitter};
+use tauri::{AppHandle, Emitter, Manager};
+use crate::error::{AppError, AppResult};
 use crate::models::SshConfig;
+use crate::state::AppState;
+use crate::commands::history::{internal_record_command, CommandMeta};
+use crate::commands::settings::SettingsState;
+use crate::commands::connection_stats::{ConnectionTracker, DisconnectReason};
+use super::algorithms::apply_algorithm_preferences;
+use super::multiplexer::tmux_startup_command;
+use super::scrollback::ScrollbackBuffer;
+use super::shell_integration::{ShellTracker, TrackerUpdate};
 
 /// 建立基础 TCP 和 SSH 会话连接
 /// 这是一个通用辅助函数，被 Shell、Monitor、SFTP 三者共用
-pub fn establish_base_session(config: &SshConfig) -> Result<Session, String> {
+pub fn establish_base_session(config: &SshConfig) -> AppResult<Session> {
+    let sess = open_handshaken_session(config)?;
+    authenticate_session(&sess, config)?;
+    Ok(sess)
+}
+
+/// TCP 连接并完成 SSH 握手 (尚未认证)
+/// 需要在认证前校验主机密钥时单独调用
+pub fn open_handshaken_session(config: &SshConfig) -> AppResult<Session> {
     let addr_str = format!("{}:{}", config.host, config.port);
     let mut addrs = addr_str
         .to_socket_addrs()
-        .map_err(|e| format!("DNS Error: {}", e))?;
-    let addr = addrs.next().ok_or("DNS resolution failed")?;
+        .map_err(|e| AppError::Network(format!("DNS Error: {}", e)))?;
+    let addr = addrs
+        .next()
+        .ok_or_else(|| AppError::Network("DNS resolution failed".to_string()))?;
 
     // 1. 建立 TCP 连接 (5秒超时)
     let tcp = TcpStream::connect_timeout(&addr, Duration::from_secs(5))
-        .map_err(|e| format!("TCP Error: {}", e))?;
+        .map_err(|e| AppError::Network(format!("TCP Error: {}", e)))?;
 
     // [优化] 设置 TCP KeepAlive，防止长时间空闲断开
     let _ = tcp.set_read_timeout(Some(Duration::from_secs(60)));
     let _ = tcp.set_write_timeout(Some(Duration::from_secs(60)));
 
-    let mut sess = Session::new().map_err(|e| format!("Session Init Error: {}", e))?;
+    let mut sess = Session::new().map_err(|e| AppError::Ssh(format!("Session Init Error: {}", e)))?;
     sess.set_tcp_stream(tcp);
+    if let Some(prefs) = &config.algorithms {
+        apply_algorithm_preferences(&sess, prefs)?;
+    }
     sess.handshake()
-        .map_err(|e| format!("Handshake Error: {}", e))?;
+        .map_err(|e| AppError::Ssh(format!("Handshake Error: {}", e)))?;
+    Ok(sess)
+}
 
-    // --- 鉴权逻辑 (兼容稳健版) ---
-    
+/// 鉴权逻辑 (兼容稳健版)：先私钥后密码
+pub fn authenticate_session(sess: &Session, config: &SshConfig) -> AppResult<()> {
     // 2. 优先尝试私钥认证
     if let Some(key_content) = &config.private_key {
         if !key_content.trim().is_empty() {
@@ -44,7 +68,7 @@ pub fn establish_base_session(config: &SshConfig) -> Result<Session, String> {
             // B. 将私钥内容写入临时文件
             // 注意：fs::write 会自动创建或覆盖文件
             if let Err(e) = fs::write(&temp_key_path, key_content) {
-                return Err(format!("Failed to create temp key file: {}", e));
+                return Err(AppError::Io(format!("Failed to create temp key file: {}", e)));
             }
 
             // C. 使用 userauth_pubkey_file (兼容所有版本 ssh2)
@@ -62,7 +86,7 @@ pub fn establish_base_session(config: &SshConfig) -> Result<Session, String> {
 
             // E. 检查认证结果
             match auth_result {
-                Ok(_) => return Ok(sess),
+                Ok(_) => return Ok(()),
                 Err(e) => {
                     // 如果私钥失败，打印日志，不直接返回错误，继续尝试密码
                     println!("[SSH Auth] Key file auth failed: {}, trying password...", e);
@@ -74,29 +98,88 @@ pub fn establish_base_session(config: &SshConfig) -> Result<Session, String> {
     // 3. 尝试密码认证
     if let Some(pwd) = &config.password {
         sess.userauth_password(&config.username, pwd)
-            .map_err(|e| format!("Password Auth Error: {} (Check username/password)", e))?;
+            .map_err(|e| AppError::AuthFailed(format!("Password Auth Error: {} (Check username/password)", e)))?;
         
-        return Ok(sess);
+        return Ok(());
     }
 
     // 4. 如果都没有，报错
-    Err("Auth failed: No private key or password provided.".to_string())
+    Err(AppError::AuthFailed("No private key or password provided.".to_string()))
+}
+
+/// Shell 通道的启动选项 (来自服务器配置)
+#[derive(Debug, Clone, Default)]
+pub struct ShellOptions {
+    /// 启动后自动进入的 tmux 会话
+    pub tmux_session: Option<String>,
+    /// 通过 env 请求设置的环境变量
+    pub env: Vec<(String, String)>,
+    /// 登录后进入的目录
+    pub initial_cwd: Option<String>,
+    /// Shell 启动后依次输入的命令 (片段已展开)
+    pub startup_commands: Vec<String>,
+}
+
+impl ShellOptions {
+    // 启动后需要写入 Shell 的内容
+    // tmux 模式下目录与命令由 tmux_startup_command 在新建会话时处理，恢复已有会话时不重复执行
+    fn startup_input(&self) -> Option<String> {
+        if self.tmux_session.is_some() {
+            return None;
+        }
+        let mut lines = Vec::new();
+        if let Some(dir) = &self.initial_cwd {
+            lines.push(format!("cd {}", shell_quote(dir)));
+        }
+        lines.extend(self.startup_commands.iter().cloned());
+        if lines.is_empty() {
+            None
+        } else {
+            Some(lines.join("\n") + "\n")
+        }
+    }
+}
+
+/// 单引号转义，用于拼接远程命令行
+pub fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
 }
 
 /// 建立 Shell 通道 (Session A)
 /// 用途：终端交互，非阻塞模式
-pub fn create_shell_channel(config: &SshConfig) -> Result<(Session, Channel), String> {
+pub fn create_shell_channel(
+    config: &SshConfig,
+    options: &ShellOptions,
+) -> AppResult<(Session, Channel)> {
     let mut sess = establish_base_session(config)?;
 
     let mut channel = sess
         .channel_session()
-        .map_err(|e| format!("Channel Error: {}", e))?;
+        .map_err(|e| AppError::Ssh(format!("Channel Error: {}", e)))?;
     channel
         .request_pty("xterm", None, Some((80, 24, 0, 0)))
-        .map_err(|e| format!("PTY Error: {}", e))?;
-    channel
-        .shell()
-        .map_err(|e| format!("Shell Start Error: {}", e))?;
+        .map_err(|e| AppError::Ssh(format!("PTY Error: {}", e)))?;
+    // 环境变量需在 shell() 之前发送；服务端未放行 (AcceptEnv) 时会被拒绝，仅记录日志
+    for (name, value) in &options.env {
+        if let Err(e) = channel.setenv(name, value) {
+            println!("[SSH] setenv {} rejected by server: {}", name, e);
+        }
+    }
+
+    match &options.tmux_session {
+        Some(name) => channel
+            .exec(&tmux_startup_command(name, options.initial_cwd.as_deref(), &options.startup_commands))
+            .map_err(|e| AppError::Ssh(format!("Shell Start Error: {}", e)))?,
+        None => channel
+            .shell()
+            .map_err(|e| AppError::Ssh(format!("Shell Start Error: {}", e)))?,
+    }
+
+    if let Some(input) = options.startup_input() {
+        channel
+            .write_all(input.as_bytes())
+            .map_err(|e| AppError::Io(format!("Startup Command Error: {}", e)))?;
+    }
 
     // Shell 需要非阻塞以配合轮询读取
     sess.set_blocking(false);
@@ -104,6 +187,21 @@ pub fn create_shell_channel(config: &SshConfig) -> Result<(Session, Channel), St
     Ok((sess, channel))
 }
 
+/// 非阻塞会话上的操作会返回 EAGAIN，这里循环重试直到完成或超时
+/// 用于在 Shell 所在的 (非阻塞) 会话上打开新通道
+pub fn retry_eagain<T>(mut op: impl FnMut() -> Result<T, ssh2::Error>) -> Result<T, ssh2::Error> {
+    const EAGAIN: ssh2::ErrorCode = ssh2::ErrorCode::Session(-37);
+    let deadline = std::time::Instant::now() + Duration::from_secs(10);
+    loop {
+        match op() {
+            Err(e) if e.code() == EAGAIN && std::time::Instant::now() < deadline => {
+                thread::sleep(Duration::from_millis(10));
+            }
+            other => return other,
+        }
+    }
+}
+
 /// 尝试建立监控会话 (Session B)
 /// 用途：CPU/内存/磁盘读数，阻塞模式 (配合 spawn_blocking 使用)
 pub fn create_monitor_session(config: &SshConfig) -> Option<Session> {
@@ -140,11 +238,101 @@ pub fn create_sftp_session(config: &SshConfig) -> Option<Session> {
     }
 }
 
+/// 终端输出的统一出口
+/// 推送前端 + 写入回滚缓冲 + Shell Integration 旁路解析
+pub struct ShellOutputSink {
+    pub app: AppHandle,
+    pub id: String,
+    /// 为空 (如快速连接) 时不落库 Shell Integration 采集到的命令
+    pub server_id: Option<String>,
+    /// 本次连接的连接记录 ID，为空时不记录断开
+    pub event_id: Option<i64>,
+    pub tracker: Arc<Mutex<ShellTracker>>,
+    pub scrollback: Arc<Mutex<ScrollbackBuffer>>,
+}
+
+impl ShellOutputSink {
+    pub fn push(&self, bytes: &[u8]) {
+        if let Some(tracker) = self.app.try_state::<ConnectionTracker>() {
+            tracker.add_bytes_in(&self.id, bytes.len());
+        }
+        let data = String::from_utf8_lossy(bytes).to_string();
+
+        match self.scrollback.lock() {
+            Ok(mut b) => b.push(&data),
+            Err(poisoned) => poisoned.into_inner().push(&data),
+        }
+
+        // println!("📺 [Term Data] ID: {} | Len: {}", id, count);
+        let _ = self.app.emit(&format!("term-data-{}", self.id), data);
+
+        // Shell Integration: 旁路解析 OSC 7 / OSC 133
+        let updates = match self.tracker.lock() {
+            Ok(mut t) => t.process(bytes),
+            Err(poisoned) => poisoned.into_inner().process(bytes),
+        };
+        for update in updates {
+            self.handle_tracker_update(update);
+        }
+    }
+
+    /// 通知前端连接断开，并结束本次连接的记录
+    pub fn exit(&self, reason: DisconnectReason) {
+        if let (Some(event_id), Some(tracker), Some(app_state)) = (
+            self.event_id,
+            self.app.try_state::<ConnectionTracker>(),
+            self.app.try_state::<AppState>(),
+        ) {
+            tracker.finish(&app_state.db, &self.id, Some(event_id), reason);
+        }
+        let _ = self.app.emit(&format!("term-exit-{}", self.id), ());
+    }
+
+    /// 处理 Shell Integration 的状态变化
+    /// cwd 变化推送给前端 (SFTP 面板跟随)，命令结束则写入历史记录
+    fn handle_tracker_update(&self, update: TrackerUpdate) {
+        match update {
+            TrackerUpdate::CwdChanged(cwd) => {
+                let _ = self.app.emit(&format!("term-cwd-{}", self.id), cwd);
+            }
+            TrackerUpdate::CommandFinished(finished) => {
+                let Some(server_id) = self.server_id.clone() else {
+                    return;
+                };
+                let Some(app_state) = self.app.try_state::<AppState>() else {
+                    return;
+                };
+                let pool = app_state.db.clone();
+                let config = self
+                    .app
+                    .try_state::<SettingsState>()
+                    .map(|s| s.snapshot().history)
+                    .unwrap_or_default();
+
+                tauri::async_runtime::spawn(async move {
+                    let meta = CommandMeta {
+                        exit_code: finished.exit_code,
+                        duration_ms: Some(finished.duration_ms),
+                        cwd: finished.cwd,
+                    };
+                    if let Err(e) =
+                        internal_record_command(&pool, &server_id, &finished.command, "shell", meta, &config).await
+                    {
+                        eprintln!("[SSH] Failed to record shell history: {}", e);
+                    }
+                });
+            }
+        }
+    }
+}
+
 /// 启动读取线程
 /// 仅用于 Shell 的输出读取
-pub fn spawn_shell_reader_thread(app: AppHandle, channel: Arc<Mutex<Channel>>, id: String) {
+pub fn spawn_shell_reader_thread(channel: Arc<Mutex<Channel>>, sink: ShellOutputSink) {
     thread::spawn(move || {
+        let id = sink.id.clone();
         let mut buf = [0u8; 8192];
+        let mut reason = DisconnectReason::Closed;
         loop {
             // 获取锁进行读取
             // 使用 match 处理锁可能中毒的情况
@@ -155,9 +343,7 @@ pub fn spawn_shell_reader_thread(app: AppHandle, channel: Arc<Mutex<Channel>>, i
 
             match chan_lock.read(&mut buf) {
                 Ok(count) if count > 0 => {
-                    let data = String::from_utf8_lossy(&buf[..count]).to_string();
-                    // println!("📺 [Term Data] ID: {} | Len: {}", id, count);
-                    let _ = app.emit(&format!("term-data-{}", id), data);
+                    sink.push(&buf[..count]);
                 }
                 Ok(_) => {
                     if chan_lock.eof() {
@@ -173,6 +359,7 @@ pub fn spawn_shell_reader_thread(app: AppHandle, channel: Arc<Mutex<Channel>>, i
                         continue;
                     } else {
                         eprintln!("[SSH] Read Error for session {}: {}", id, e);
+                        reason = DisconnectReason::Error;
                         break;
                     }
                 }
@@ -183,6 +370,6 @@ pub fn spawn_shell_reader_thread(app: AppHandle, channel: Arc<Mutex<Channel>>, i
         
         println!("[SSH] Shell thread exited for {}", id);
         // [新增] 通知前端连接断开
-        let _ = app.emit(&format!("term-exit-{}", id), ());
+        sink.exit(reason);
     });
-}
\ No newline at end of file
+}
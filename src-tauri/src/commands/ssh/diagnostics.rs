@@ -0,0 +1,318 @@
+// src-tauri/src/commands/ssh/diagnostics.rs
+//
+// 连接诊断：把 test_connection 的各个阶段 (DNS / TCP / 握手 / 主机密钥 / 认证) 拆开执行，
+// 逐项记录耗时与失败原因，方便不打开终端也能定位问题。
+use std::env;
+use std::fs;
+use std::io::Read;
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use ssh2::{CheckResult, HostKeyType, KnownHostFileKind, Session};
+
+use super::algorithms::{apply_algorithm_preferences, NegotiatedAlgorithms};
+use super::compute_fingerprint;
+use crate::models::SshConfig;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsReport {
+    pub host: String,
+    pub addresses: Vec<String>,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TcpReport {
+    pub address: String,
+    pub duration_ms: u64,
+    /// 连接失败的地址及原因 (多地址时逐个尝试)
+    pub failed_addresses: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HandshakeReport {
+    /// 服务端版本串，如 SSH-2.0-OpenSSH_9.6
+    pub banner: Option<String>,
+    pub duration_ms: u64,
+    pub algorithms: NegotiatedAlgorithms,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HostKeyReport {
+    pub key_type: String,
+    pub fingerprint: String,
+    /// "verified" | "mismatch" | "unknown"
+    pub known_hosts_status: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthAttempt {
+    pub method: String,
+    pub success: bool,
+    pub error: Option<String>,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthReport {
+    /// 服务端提供的认证方式
+    pub offered_methods: Vec<String>,
+    pub attempts: Vec<AuthAttempt>,
+    /// 认证成功后远程 whoami 的结果
+    pub user: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionDiagnostics {
+    pub success: bool,
+    /// 失败的阶段: "dns" | "tcp" | "handshake" | "auth" | "exec"
+    pub failed_stage: Option<String>,
+    pub error: Option<String>,
+    pub dns: Option<DnsReport>,
+    pub tcp: Option<TcpReport>,
+    pub handshake: Option<HandshakeReport>,
+    pub host_key: Option<HostKeyReport>,
+    pub auth: Option<AuthReport>,
+    pub total_ms: u64,
+}
+
+impl ConnectionDiagnostics {
+    fn fail(mut self, stage: &str, error: String, started: Instant) -> Self {
+        self.failed_stage = Some(stage.to_string());
+        self.error = Some(error);
+        self.total_ms = elapsed_ms(started);
+        self
+    }
+}
+
+fn elapsed_ms(since: Instant) -> u64 {
+    since.elapsed().as_millis() as u64
+}
+
+fn host_key_type_name(key_type: HostKeyType) -> &'static str {
+    match key_type {
+        HostKeyType::Rsa => "ssh-rsa",
+        HostKeyType::Dss => "ssh-dss",
+        HostKeyType::Ecdsa256 => "ecdsa-sha2-nistp256",
+        HostKeyType::Ecdsa384 => "ecdsa-sha2-nistp384",
+        HostKeyType::Ecdsa521 => "ecdsa-sha2-nistp521",
+        HostKeyType::Ed25519 => "ssh-ed25519",
+        _ => "unknown",
+    }
+}
+
+fn check_known_hosts(
+    sess: &Session,
+    known_hosts_path: Option<&PathBuf>,
+    host: &str,
+    port: u16,
+    key: &[u8],
+) -> String {
+    let Ok(mut known_hosts) = sess.known_hosts() else {
+        return "unknown".to_string();
+    };
+    if let Some(path) = known_hosts_path.filter(|p| p.exists()) {
+        let _ = known_hosts.read_file(path, KnownHostFileKind::OpenSSH);
+    }
+    match known_hosts.check_port(host, port, key) {
+        CheckResult::Match => "verified",
+        CheckResult::Mismatch => "mismatch",
+        CheckResult::NotFound | CheckResult::Failure => "unknown",
+    }
+    .to_string()
+}
+
+// 私钥认证 (与 establish_base_session 相同，通过临时文件交给 libssh2)
+fn try_pubkey(sess: &Session, config: &SshConfig, key_content: &str) -> Result<(), String> {
+    let mut temp_key_path = env::temp_dir();
+    temp_key_path.push(format!("ssh_key_diag_{}.pem", uuid::Uuid::new_v4()));
+    fs::write(&temp_key_path, key_content)
+        .map_err(|e| format!("Failed to create temp key file: {}", e))?;
+
+    let pass = config.passphrase.as_deref().filter(|s| !s.is_empty());
+    let result = sess.userauth_pubkey_file(&config.username, None, &temp_key_path, pass);
+    let _ = fs::remove_file(&temp_key_path);
+    result.map_err(|e| e.to_string())
+}
+
+/// 逐阶段执行连接测试 (阻塞，需在 spawn_blocking 中调用)
+pub fn run_diagnostics(config: &SshConfig, known_hosts_path: Option<PathBuf>) -> ConnectionDiagnostics {
+    let started = Instant::now();
+    let mut report = ConnectionDiagnostics {
+        success: false,
+        failed_stage: None,
+        error: None,
+        dns: None,
+        tcp: None,
+        handshake: None,
+        host_key: None,
+        auth: None,
+        total_ms: 0,
+    };
+
+    // 1. DNS 解析 (列出全部地址)
+    let dns_started = Instant::now();
+    let addrs: Vec<SocketAddr> = match format!("{}:{}", config.host, config.port).to_socket_addrs() {
+        Ok(iter) => iter.collect(),
+        Err(e) => return report.fail("dns", format!("DNS Error: {}", e), started),
+    };
+    report.dns = Some(DnsReport {
+        host: config.host.clone(),
+        addresses: addrs.iter().map(|a| a.ip().to_string()).collect(),
+        duration_ms: elapsed_ms(dns_started),
+    });
+    if addrs.is_empty() {
+        return report.fail("dns", "DNS resolution failed".to_string(), started);
+    }
+
+    // 2. TCP 连接 (按顺序尝试每个地址)
+    let timeout = Duration::from_secs(config.connect_timeout.unwrap_or(5).max(1) as u64);
+    let mut failed_addresses = Vec::new();
+    let mut connected = None;
+    for addr in &addrs {
+        let tcp_started = Instant::now();
+        match TcpStream::connect_timeout(addr, timeout) {
+            Ok(tcp) => {
+                connected = Some((tcp, *addr, elapsed_ms(tcp_started)));
+                break;
+            }
+            Err(e) => failed_addresses.push(format!("{}: {}", addr, e)),
+        }
+    }
+    let Some((tcp, addr, tcp_ms)) = connected else {
+        let error = format!("TCP Error: {}", failed_addresses.join("; "));
+        return report.fail("tcp", error, started);
+    };
+    report.tcp = Some(TcpReport {
+        address: addr.to_string(),
+        duration_ms: tcp_ms,
+        failed_addresses,
+    });
+    let _ = tcp.set_read_timeout(Some(Duration::from_secs(60)));
+    let _ = tcp.set_write_timeout(Some(Duration::from_secs(60)));
+
+    // 3. SSH 握手
+    let handshake_started = Instant::now();
+    let mut sess = match Session::new() {
+        Ok(s) => s,
+        Err(e) => return report.fail("handshake", format!("Session Init Error: {}", e), started),
+    };
+    sess.set_tcp_stream(tcp);
+    if let Some(prefs) = &config.algorithms {
+        if let Err(e) = apply_algorithm_preferences(&sess, prefs) {
+            return report.fail("handshake", e.to_string(), started);
+        }
+    }
+    if let Err(e) = sess.handshake() {
+        return report.fail("handshake", format!("Handshake Error: {}", e), started);
+    }
+    report.handshake = Some(HandshakeReport {
+        banner: sess.banner().map(|b| b.to_string()),
+        duration_ms: elapsed_ms(handshake_started),
+        algorithms: NegotiatedAlgorithms::from_session(&sess),
+    });
+
+    // 4. 主机密钥与 known_hosts
+    if let Some((key, key_type)) = sess.host_key() {
+        report.host_key = Some(HostKeyReport {
+            key_type: host_key_type_name(key_type).to_string(),
+            fingerprint: compute_fingerprint(key),
+            known_hosts_status: check_known_hosts(
+                &sess,
+                known_hosts_path.as_ref(),
+                &config.host,
+                config.port,
+                key,
+            ),
+        });
+    }
+
+    // 5. 认证：先私钥后密码，逐个记录结果
+    let offered_methods: Vec<String> = sess
+        .auth_methods(&config.username)
+        .map(|m| m.split(',').map(|s| s.to_string()).collect())
+        .unwrap_or_default();
+    let offers = |method: &str| offered_methods.is_empty() || offered_methods.iter().any(|m| m == method);
+
+    let mut attempts = Vec::new();
+    if let Some(key_content) = config.private_key.as_deref().filter(|k| !k.trim().is_empty()) {
+        let attempt_started = Instant::now();
+        let result = if offers("publickey") {
+            try_pubkey(&sess, config, key_content)
+        } else {
+            Err("Server does not offer publickey authentication".to_string())
+        };
+        attempts.push(AuthAttempt {
+            method: "publickey".to_string(),
+            success: result.is_ok(),
+            error: result.err(),
+            duration_ms: elapsed_ms(attempt_started),
+        });
+    }
+    if !sess.authenticated() {
+        if let Some(pwd) = &config.password {
+            let attempt_started = Instant::now();
+            let result = if offers("password") {
+                sess.userauth_password(&config.username, pwd)
+                    .map_err(|e| e.to_string())
+            } else {
+                Err("Server does not offer password authentication".to_string())
+            };
+            attempts.push(AuthAttempt {
+                method: "password".to_string(),
+                success: result.is_ok(),
+                error: result.err(),
+                duration_ms: elapsed_ms(attempt_started),
+            });
+        }
+    }
+
+    let authenticated = sess.authenticated();
+    report.auth = Some(AuthReport {
+        offered_methods,
+        attempts,
+        user: None,
+    });
+    if !authenticated {
+        let error = if config.private_key.is_none() && config.password.is_none() {
+            "Auth failed: No private key or password provided.".to_string()
+        } else {
+            "Auth failed: all attempted methods were rejected".to_string()
+        };
+        return report.fail("auth", error, started);
+    }
+
+    // 6. 执行 whoami 验证会话可用
+    let whoami = (|| -> Result<String, String> {
+        let mut channel = sess.channel_session().map_err(|e| format!("通道创建失败: {}", e))?;
+        channel.exec("whoami").map_err(|e| format!("命令验证失败: {}", e))?;
+        let mut s = String::new();
+        channel
+            .read_to_string(&mut s)
+            .map_err(|e| format!("结果读取失败: {}", e))?;
+        let _ = channel.wait_close();
+        Ok(s.trim().to_string())
+    })();
+
+    match whoami {
+        Ok(user) => {
+            if let Some(auth) = report.auth.as_mut() {
+                auth.user = Some(user);
+            }
+        }
+        Err(e) => return report.fail("exec", e, started),
+    }
+
+    report.success = true;
+    report.total_ms = elapsed_ms(started);
+    report
+}
@@ -1,11 +1,19 @@
 use std::sync::{Arc, Mutex};
 use tauri::{AppHandle, State, Manager, Emitter};
+use sqlx::sqlite::SqliteRow;
 use sqlx::Row;
+use aes_gcm::{Aes256Gcm, Key};
 use serde_json::Value;
+use crate::error::{AppError, AppResult};
 use crate::models::TestConnectionPayload;
 use crate::models::SshConfig;
+use crate::models::{AlgorithmPreferences, EnvVar, StartupCommand};
 use crate::state::AppState;
+use crate::commands::folder::resolve_folder_defaults;
+use crate::commands::template::resolve_server_template;
 use crate::commands::vault::{VaultState, internal_get_secret};
+use crate::commands::telnet::internal_connect_telnet;
+use crate::commands::connection_stats::{ConnectionTracker, DisconnectReason};
 
 // 🟢 [新增] 引入依赖
 use ssh2::{CheckResult, KnownHostFileKind};
@@ -16,13 +24,27 @@ use std::io::Write;
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
 
 // 导出子模块
+pub mod algorithms;
 pub mod core;
+pub mod diagnostics;
+pub mod multiplexer;
+pub mod scrollback;
+pub mod shell_integration;
 pub mod state;
 
-pub use state::{SshConnection, SshState};
+pub use algorithms::get_ssh_algorithm_presets;
+pub use multiplexer::{attach_remote_mux_session, list_remote_mux_sessions};
+pub use scrollback::{get_scrollback, list_active_sessions, search_scrollback};
+pub use shell_integration::{get_remote_cwd, inject_shell_integration};
+pub use state::{SshConnection, SshState, TerminalBackend};
 use core::{
     create_monitor_session, create_sftp_session, create_shell_channel, spawn_shell_reader_thread,
+    ShellOptions, ShellOutputSink,
 };
+use diagnostics::{run_diagnostics, ConnectionDiagnostics};
+use multiplexer::validate_session_name;
+use scrollback::{ScrollbackBuffer, DEFAULT_SCROLLBACK_SIZE};
+use shell_integration::ShellTracker;
 
 // ==============================================================================
 // 🟢 [新增] 主机密钥验证相关结构体
@@ -49,7 +71,7 @@ fn get_known_hosts_path(app: &AppHandle) -> Option<PathBuf> {
 }
 
 // 辅助函数：计算指纹 (SHA256 Base64)
-fn compute_fingerprint(host_key: &[u8]) -> String {
+pub(crate) fn compute_fingerprint(host_key: &[u8]) -> String {
     use sha2::{Sha256, Digest};
     let mut hasher = Sha256::new();
     hasher.update(host_key);
@@ -72,7 +94,7 @@ pub async fn check_host_key(
     _id: String, // 预留服务器 ID 参数
     host: String,
     port: u16
-) -> Result<HostKeyCheckResult, String> {
+) -> AppResult<HostKeyCheckResult> {
     // 立即向前端发送开始日志
     emit_ssh_log(&app, &format!("Checking host identity for {}:{}...", host, port));
 
@@ -83,22 +105,24 @@ pub async fn check_host_key(
             .map_err(|e| {
                 let err = format!("Network unreachable: {}", e);
                 emit_ssh_log(&app, &err);
-                err
+                AppError::Network(err)
             })?;
         
         // 2. 发起 SSH 握手 (仅交换密钥，不进行用户认证)
         emit_ssh_log(&app, "Initiating SSH protocol handshake...");
-        let mut sess = ssh2::Session::new().map_err(|e| e.to_string())?;
+        let mut sess = ssh2::Session::new()?;
         sess.set_tcp_stream(tcp);
         sess.handshake().map_err(|e| {
             let err = format!("SSH handshake failed: {}", e);
             emit_ssh_log(&app, &err);
-            err
+            AppError::Ssh(err)
         })?;
 
         // 3. 获取远程主机密钥
         emit_ssh_log(&app, "Retrieving remote host key...");
-        let (host_key, key_type_enum) = sess.host_key().ok_or("No host key received from server")?;
+        let (host_key, key_type_enum) = sess
+            .host_key()
+            .ok_or_else(|| AppError::Ssh("No host key received from server".to_string()))?;
         
         // 映射密钥类型字符串
         let key_type = match key_type_enum {
@@ -116,7 +140,7 @@ pub async fn check_host_key(
 
         // 4. 读取本地 known_hosts 文件进行安全比对
         emit_ssh_log(&app, "Comparing with local known_hosts file...");
-        let mut known_hosts = sess.known_hosts().map_err(|e| e.to_string())?;
+        let mut known_hosts = sess.known_hosts()?;
         let known_hosts_path = get_known_hosts_path(&app);
 
         if let Some(path) = &known_hosts_path {
@@ -156,7 +180,7 @@ pub async fn check_host_key(
                 None
             }
         })
-    }).await.map_err(|e| format!("Task aborted: {}", e))?
+    }).await.map_err(|e| AppError::Internal(format!("Task aborted: {}", e)))?
 }
 // ==============================================================================
 // 🟢 [新增] 命令：信任主机密钥 (手动文件写入版)
@@ -168,7 +192,7 @@ pub async fn trust_host_key(
     id: String,          
     fingerprint: String, 
     _key_type: String     // 未使用
-) -> Result<(), String> {
+) -> AppResult<()> {
     
     let db_pool = &app_state.db;
 
@@ -176,9 +200,8 @@ pub async fn trust_host_key(
     let row = sqlx::query("SELECT ip, port FROM servers WHERE id = ?")
         .bind(&id)
         .fetch_optional(db_pool)
-        .await
-        .map_err(|e| e.to_string())?
-        .ok_or("Server not found")?;
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Server not found: {}", id)))?;
     
     let host: String = row.get("ip");
     let port: u16 = row.get::<i64, _>("port") as u16;
@@ -186,21 +209,24 @@ pub async fn trust_host_key(
     tauri::async_runtime::spawn_blocking(move || {
         // 2. 重新连接获取密钥 (为了获取原始密钥数据)
         let tcp = TcpStream::connect(format!("{}:{}", host, port))
-            .map_err(|e| format!("Re-connection failed: {}", e))?;
+            .map_err(|e| AppError::Network(format!("Re-connection failed: {}", e)))?;
         
-        let mut sess = ssh2::Session::new().map_err(|e| e.to_string())?;
+        let mut sess = ssh2::Session::new()?;
         sess.set_tcp_stream(tcp);
-        sess.handshake().map_err(|e| format!("Handshake failed: {}", e))?;
+        sess.handshake()
+            .map_err(|e| AppError::Ssh(format!("Handshake failed: {}", e)))?;
 
-        let (host_key, key_type_enum) = sess.host_key().ok_or("No host key found")?;
+        let (host_key, key_type_enum) = sess
+            .host_key()
+            .ok_or_else(|| AppError::Ssh("No host key found".to_string()))?;
         
         // 二次校验指纹，防止竞争条件下的欺诈
         let current_fingerprint = compute_fingerprint(host_key);
         if current_fingerprint != fingerprint {
-            return Err(format!(
-                "Security Warning: Key changed during verification! Expected {}, got {}", 
+            return Err(AppError::HostKeyMismatch(format!(
+                "Key changed during verification! Expected {}, got {}",
                 fingerprint, current_fingerprint
-            ));
+            )));
         }
 
         // 3. 构造 OpenSSH known_hosts 格式字符串: "host key_type key_base64"
@@ -211,7 +237,7 @@ pub async fn trust_host_key(
             ssh2::HostKeyType::Ecdsa384 => "ecdsa-sha2-nistp384",
             ssh2::HostKeyType::Ecdsa521 => "ecdsa-sha2-nistp521",
             ssh2::HostKeyType::Ed25519 => "ssh-ed25519", 
-            _ => return Err("Unsupported key type to save".to_string()),
+            _ => return Err(AppError::InvalidInput("Unsupported key type to save".to_string())),
         };
 
         let key_base64 = BASE64.encode(host_key);
@@ -227,12 +253,12 @@ pub async fn trust_host_key(
 
         // 4. 追加写入 known_hosts 文件
         let known_hosts_path = get_known_hosts_path(&app)
-            .ok_or("Could not determine home directory")?;
+            .ok_or_else(|| AppError::Io("Could not determine home directory".to_string()))?;
         
         // 确保 .ssh 目录存在
         if let Some(parent) = known_hosts_path.parent() {
             if !parent.exists() {
-                std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create .ssh dir: {}", e))?;
+                std::fs::create_dir_all(parent).map_err(|e| AppError::Io(format!("Failed to create .ssh dir: {}", e)))?;
             }
         }
 
@@ -241,45 +267,184 @@ pub async fn trust_host_key(
             .create(true)
             .append(true)
             .open(&known_hosts_path)
-            .map_err(|e| format!("Failed to open known_hosts: {}", e))?;
+            .map_err(|e| AppError::Io(format!("Failed to open known_hosts: {}", e)))?;
 
         file.write_all(line.as_bytes())
-            .map_err(|e| format!("Failed to write to known_hosts: {}", e))?;
+            .map_err(|e| AppError::Io(format!("Failed to write to known_hosts: {}", e)))?;
 
         Ok(())
-    }).await.map_err(|e| format!("Runtime Error: {}", e))?
+    }).await.map_err(|e| AppError::Internal(format!("Runtime Error: {}", e)))?
 }
 
 
 // ==============================================================================
-// 🚀 核心连接命令 (保持原有逻辑)
+// 启动选项解析 (环境变量 / 启动命令)
 // ==============================================================================
-#[tauri::command]
-pub async fn connect_ssh(
-    app: AppHandle,
-    state: State<'_, SshState>,
-    app_state: State<'_, AppState>,      
-    vault_state: State<'_, VaultState>,  
-    server_id: String,                   
-    session_id: String,                  
-) -> Result<(), String> {
 
-    let sessions = state.sessions.clone();
-    let db_pool = &app_state.db;
+/// 环境变量名只允许 [A-Za-z_][A-Za-z0-9_]*
+pub(crate) fn validate_env_name(name: &str) -> AppResult<()> {
+    let valid = name.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(AppError::InvalidInput(format!("Invalid environment variable name: {}", name)))
+    }
+}
 
-    // 1. --- 从数据库查询服务器基础信息 ---
-    let row = sqlx::query(
-        "SELECT id, ip, port, username, auth_type, password_id, key_id, passphrase, private_key, password, 
-                connect_timeout, keep_alive_interval, auto_reconnect, max_reconnects 
-         FROM servers WHERE id = ?"
+fn parse_env_vars(raw: Option<String>) -> AppResult<Vec<(String, String)>> {
+    let vars: Vec<EnvVar> = raw
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default();
+
+    let mut env = Vec::with_capacity(vars.len());
+    for var in vars {
+        let name = var.name.trim();
+        if name.is_empty() {
+            continue;
+        }
+        validate_env_name(name)?;
+        env.push((name.to_string(), var.value));
+    }
+    Ok(env)
+}
+
+// 展开启动命令中引用的片段 (找不到的片段直接跳过)
+async fn resolve_startup_commands(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    raw: Option<String>,
+) -> AppResult<Vec<String>> {
+    let steps: Vec<StartupCommand> = raw
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default();
+
+    let mut commands = Vec::with_capacity(steps.len());
+    for step in steps {
+        match step {
+            StartupCommand::Command { command } => {
+                if !command.trim().is_empty() {
+                    commands.push(command);
+                }
+            }
+            StartupCommand::Snippet { snippet_id } => {
+                let code: Option<String> = sqlx::query_scalar("SELECT code FROM snippets WHERE id = ? AND deleted_at IS NULL")
+                    .bind(&snippet_id)
+                    .fetch_optional(pool)
+                    .await?;
+                match code {
+                    Some(code) => commands.push(code.trim_end().to_string()),
+                    None => eprintln!("[SSH] Startup snippet not found: {}", snippet_id),
+                }
+            }
+        }
+    }
+    Ok(commands)
+}
+
+// connect_ssh 与命令行 exec 共用的服务器查询
+// 引用模板时套用模板上未被服务器覆盖的字段 (认证、端口、代理、超时、启动命令)；
+// 用户名为空、或既没有模板凭据也没有自身凭据时使用所在文件夹链上的默认值
+pub(crate) async fn fetch_server_row(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    server_id: &str,
+) -> AppResult<SqliteRow> {
+    let (folder_id, template_id, template_overrides): (Option<String>, Option<String>, Option<String>) =
+        sqlx::query_as("SELECT folder_id, template_id, template_overrides FROM servers WHERE id = ?")
+            .bind(server_id)
+            .fetch_optional(pool)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Server not found with ID: {}", server_id)))?;
+    let defaults = resolve_folder_defaults(pool, folder_id.as_deref()).await?;
+    let template = resolve_server_template(pool, template_id.as_deref(), template_overrides.as_deref())
+        .await?
+        .unwrap_or_default();
+
+    // 模板设置了认证时整体替换服务器的认证，否则按文件夹默认值补全
+    let force_auth = template.auth_type.is_some();
+    let (auth_type, password_id, key_id) = if force_auth {
+        (template.auth_type, template.password_id, template.key_id)
+    } else {
+        (defaults.auth_type, defaults.password_id, defaults.key_id)
+    };
+    let startup_json = template
+        .startup_commands
+        .as_ref()
+        .and_then(|c| serde_json::to_string(c).ok());
+
+    sqlx::query(
+        "WITH s AS (
+            SELECT *, (password_id IS NULL AND key_id IS NULL AND IFNULL(password, '') = ''
+                       AND IFNULL(private_key, '') = '' AND auth_type <> 'agent') AS inherit_auth
+            FROM servers WHERE id = ?
+         )
+         SELECT id, ip,
+                IFNULL(?, port) AS port,
+                IFNULL(?, proxy_id) AS proxy_id,
+                CASE WHEN TRIM(IFNULL(username, '')) = '' THEN IFNULL(?, username) ELSE username END AS username,
+                CASE WHEN ? OR (inherit_auth AND ? IS NOT NULL) THEN ? ELSE auth_type END AS auth_type,
+                CASE WHEN ? OR (inherit_auth AND ? IS NOT NULL) THEN ? ELSE password_id END AS password_id,
+                CASE WHEN ? OR (inherit_auth AND ? IS NOT NULL) THEN ? ELSE key_id END AS key_id,
+                IFNULL(password, '') <> '' AS has_plain_password,
+                IFNULL(private_key, '') <> '' AS has_plain_key,
+                IFNULL(?, connect_timeout) AS connect_timeout,
+                IFNULL(?, keep_alive_interval) AS keep_alive_interval,
+                auto_reconnect, max_reconnects, scrollback_size, protocol, tmux_session,
+                env_vars, initial_cwd,
+                IFNULL(?, startup_commands) AS startup_commands,
+                algorithm_prefs, login_script
+         FROM s"
     )
-    .bind(&server_id) 
-    .fetch_optional(db_pool)
-    .await
-    .map_err(|e| format!("DB Query Error: {}", e))?
-    .ok_or(format!("Server not found with ID: {}", server_id))?;
+    .bind(server_id)
+    .bind(template.port)
+    .bind(&template.proxy_id)
+    .bind(&defaults.username)
+    .bind(force_auth)
+    .bind(&auth_type)
+    .bind(&auth_type)
+    .bind(force_auth)
+    .bind(&auth_type)
+    .bind(&password_id)
+    .bind(force_auth)
+    .bind(&auth_type)
+    .bind(&key_id)
+    .bind(template.connect_timeout)
+    .bind(template.keep_alive_interval)
+    .bind(startup_json)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Server not found with ID: {}", server_id)))
+}
 
-    // 2. --- 解析并解密凭证 ---
+/// 凭证是否保存在保险库中 (需要先解锁才能连接)
+pub(crate) fn uses_vault_credentials(row: &SqliteRow) -> bool {
+    let auth_type: String = row.try_get("auth_type").unwrap_or_default();
+    let column = match auth_type.as_str() {
+        "password" => "password_id",
+        "key" | "privateKey" => "key_id",
+        _ => return false,
+    };
+    row.try_get::<Option<String>, _>(column).ok().flatten().is_some()
+}
+
+// 旧版本明文保存在 servers 表中、尚未迁入 Vault 的凭据 (解锁保险库时迁移)
+// fetch_server_row 查出的是 has_plain_* 标记，SELECT * 的行直接看原始列
+fn has_plaintext_secret(row: &SqliteRow, flag: &str, column: &str) -> bool {
+    row.try_get::<bool, _>(flag).unwrap_or_else(|_| {
+        row.try_get::<Option<String>, _>(column)
+            .ok()
+            .flatten()
+            .is_some_and(|v| !v.trim().is_empty())
+    })
+}
+
+/// 解析并解密凭证，组装 SshConfig
+/// master_key 仅在凭证保存在保险库中时需要
+pub(crate) async fn resolve_ssh_config(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    master_key: Option<&Key<Aes256Gcm>>,
+    row: &SqliteRow,
+    server_id: &str,
+) -> AppResult<SshConfig> {
     let host: String = row.get("ip");
     let port: u16 = row.get::<i64, _>("port") as u16;
     let username: String = row.get("username");
@@ -289,21 +454,21 @@ pub async fn connect_ssh(
     let keep_alive_interval: Option<u32> = row.try_get("keep_alive_interval").ok();
     let auto_reconnect: Option<bool> = row.try_get("auto_reconnect").ok();
     let max_reconnects: Option<u32> = row.try_get("max_reconnects").ok();
+    let algorithms: Option<AlgorithmPreferences> = row
+        .try_get::<Option<String>, _>("algorithm_prefs")
+        .ok()
+        .flatten()
+        .and_then(|s| serde_json::from_str(&s).ok());
 
     let mut final_password: Option<String> = None;
     let mut final_private_key: Option<String> = None;
-    let mut final_passphrase: Option<String> = row.get("passphrase");
-
-    // 获取 Master Key
-    let master_key = {
-        let guard = vault_state.0.lock().unwrap();
-        guard.as_ref().cloned().ok_or("VAULT_LOCKED: Please unlock the vault first.")?
-    };
+    // 口令与私钥一起保存在 Vault 条目中
+    let mut final_passphrase: Option<String> = None;
 
     if auth_type == "password" {
         let pwd_id: Option<String> = row.get("password_id");
         if let Some(pid) = pwd_id {
-            let decrypted = internal_get_secret(db_pool, &master_key, &pid).await?;
+            let decrypted = internal_get_secret(pool, master_key.ok_or(AppError::VaultLocked)?, &pid).await?;
             if let Ok(parsed) = serde_json::from_str::<Value>(&decrypted) {
                 if let Some(val) = parsed.get("val").and_then(|v| v.as_str()) {
                     final_password = Some(val.to_string());
@@ -313,15 +478,15 @@ pub async fn connect_ssh(
             } else {
                 final_password = Some(decrypted);
             }
-        } else {
-            final_password = row.get("password"); 
+        } else if has_plaintext_secret(row, "has_plain_password", "password") {
+            return Err(AppError::VaultLocked);
         }
     } 
     // 兼容 "key" 和 "privateKey"
     else if auth_type == "key" || auth_type == "privateKey" {
         let k_id: Option<String> = row.get("key_id");
         if let Some(kid) = k_id {
-            let decrypted = internal_get_secret(db_pool, &master_key, &kid).await?;
+            let decrypted = internal_get_secret(pool, master_key.ok_or(AppError::VaultLocked)?, &kid).await?;
             
             let mut raw_key = String::new();
             
@@ -381,15 +546,8 @@ pub async fn connect_ssh(
             }
 
             final_private_key = Some(key_clean);
-
-        } else {
-            if let Some(pk) = row.get::<Option<String>, _>("private_key") {
-                let mut key_clean = pk.trim().to_string();
-                if !key_clean.is_empty() && !key_clean.ends_with('\n') {
-                    key_clean.push('\n');
-                }
-                final_private_key = Some(key_clean);
-            }
+        } else if has_plaintext_secret(row, "has_plain_key", "private_key") {
+            return Err(AppError::VaultLocked);
         }
     }
 
@@ -400,12 +558,14 @@ pub async fn connect_ssh(
     }
 
     if final_password.is_none() && final_private_key.is_none() {
-        return Err(format!("Auth Failed: No password or private key resolved from database. (Type: {})", auth_type));
+        return Err(AppError::AuthFailed(format!(
+            "No password or private key resolved from database. (Type: {})",
+            auth_type
+        )));
     }
 
-    // 3. --- 组装 SshConfig 对象 ---
-    let config = SshConfig {
-        id: server_id.clone(),
+    Ok(SshConfig {
+        id: server_id.to_string(),
         host,
         port,
         username,
@@ -418,8 +578,102 @@ pub async fn connect_ssh(
         keep_alive_interval,
         auto_reconnect,
         max_reconnects,
+        algorithms,
+    })
+}
+
+// ==============================================================================
+// 🚀 核心连接命令 (保持原有逻辑)
+// ==============================================================================
+#[tauri::command]
+pub async fn connect_ssh(
+    app: AppHandle,
+    state: State<'_, SshState>,
+    app_state: State<'_, AppState>,      
+    vault_state: State<'_, VaultState>,  
+    tracker: State<'_, ConnectionTracker>,
+    server_id: String,                   
+    session_id: String,                  
+) -> AppResult<()> {
+    let db_pool = &app_state.db;
+
+    // 1. --- 从数据库查询服务器基础信息 ---
+    let row = fetch_server_row(db_pool, &server_id).await?;
+
+    // 连接记录 (写库失败不影响连接)
+    let protocol: String = row.try_get("protocol").unwrap_or_else(|_| "ssh".to_string());
+    let auth_method: Option<String> = row.try_get("auth_type").ok();
+    let event_id = match tracker
+        .begin(db_pool, &server_id, &session_id, Some(&protocol), auth_method.as_deref())
+        .await
+    {
+        Ok(id) => Some(id),
+        Err(e) => {
+            eprintln!("[Connection] Failed to record connect: {}", e);
+            None
+        }
     };
 
+    let result = open_server_session(app, &state, db_pool, &vault_state, row, session_id.clone(), event_id).await;
+    if let Err(e) = tracker.complete(db_pool, &session_id, &result).await {
+        eprintln!("[Connection] Failed to record connect result: {}", e);
+    }
+    result
+}
+
+// 建立 Shell 连接并启动读取线程
+async fn open_server_session(
+    app: AppHandle,
+    state: &SshState,
+    db_pool: &sqlx::Pool<sqlx::Sqlite>,
+    vault_state: &VaultState,
+    row: SqliteRow,
+    session_id: String,
+    event_id: Option<i64>,
+) -> AppResult<()> {
+    let sessions = state.sessions.clone();
+    let server_id: String = row.get("id");
+
+    // Telnet 服务器走独立的传输层，但复用同一个会话表和终端事件
+    let protocol: String = row.try_get("protocol").unwrap_or_else(|_| "ssh".to_string());
+    if protocol == "telnet" {
+        let master_key = vault_state.0.lock().unwrap().as_ref().cloned();
+        return internal_connect_telnet(app, state, db_pool, master_key, row, session_id, event_id).await;
+    }
+
+    let scrollback_size = row
+        .try_get::<Option<i64>, _>("scrollback_size")
+        .ok()
+        .flatten()
+        .map(|v| v as usize)
+        .unwrap_or(DEFAULT_SCROLLBACK_SIZE);
+    let tmux_session: Option<String> = row
+        .try_get::<Option<String>, _>("tmux_session")
+        .ok()
+        .flatten()
+        .filter(|s| !s.trim().is_empty());
+    if let Some(name) = &tmux_session {
+        validate_session_name(name)?;
+    }
+    let shell_options = ShellOptions {
+        tmux_session,
+        env: parse_env_vars(row.try_get("env_vars").ok().flatten())?,
+        initial_cwd: row
+            .try_get::<Option<String>, _>("initial_cwd")
+            .ok()
+            .flatten()
+            .filter(|s| !s.trim().is_empty()),
+        startup_commands: resolve_startup_commands(
+            db_pool,
+            row.try_get("startup_commands").ok().flatten(),
+        )
+        .await?,
+    };
+
+    // 2. --- 解析并解密凭证 ---
+    let master_key = vault_state.master_key()?;
+    let config = resolve_ssh_config(db_pool, Some(&master_key), &row, &server_id).await?;
+
     let config_monitor = config.clone();
     let config_sftp = config.clone();
 
@@ -435,8 +689,7 @@ pub async fn connect_ssh(
         }
 
         // B. 建立连接
-        let (_shell_sess, shell_channel) =
-            create_shell_channel(&config).map_err(|e| format!("Shell Connection Failed: {}", e))?;
+        let (shell_sess, shell_channel) = create_shell_channel(&config, &shell_options)?;
 
         let monitor_sess = create_monitor_session(&config_monitor);
         let sftp_sess = create_sftp_session(&config_sftp);
@@ -445,26 +698,42 @@ pub async fn connect_ssh(
         let shell_channel_arc = Arc::new(Mutex::new(shell_channel));
         let monitor_session_arc = Arc::new(Mutex::new(monitor_sess));
         let sftp_session_arc = Arc::new(Mutex::new(sftp_sess));
+        let shell_tracker_arc = Arc::new(Mutex::new(ShellTracker::default()));
+        let scrollback_arc = Arc::new(Mutex::new(ScrollbackBuffer::new(scrollback_size)));
 
         {
             let mut map = sessions.lock().unwrap();
             map.insert(
                 session_id.clone(),
                 SshConnection {
+                    server_id: Some(server_id.clone()),
+                    shell_session: Some(shell_sess),
                     shell_channel: shell_channel_arc.clone(),
                     monitor_session: monitor_session_arc,
                     sftp_session: sftp_session_arc,
+                    shell_tracker: shell_tracker_arc.clone(),
+                    scrollback: scrollback_arc.clone(),
                 },
             );
         }
 
         // D. 启动读取线程
-        spawn_shell_reader_thread(app, shell_channel_arc, session_id.clone());
+        spawn_shell_reader_thread(
+            shell_channel_arc,
+            ShellOutputSink {
+                app,
+                id: session_id.clone(),
+                server_id: Some(server_id),
+                event_id,
+                tracker: shell_tracker_arc,
+                scrollback: scrollback_arc,
+            },
+        );
 
         Ok(())
     })
     .await
-    .map_err(|e| format!("Async Error: {}", e))?
+    .map_err(|e| AppError::Internal(format!("Async Error: {}", e)))?
 }
 
 // ==============================================================================
@@ -472,24 +741,34 @@ pub async fn connect_ssh(
 // ==============================================================================
 
 #[tauri::command]
-pub fn disconnect_ssh(state: State<'_, SshState>, id: String) -> Result<(), String> {
+pub fn disconnect_ssh(
+    state: State<'_, SshState>,
+    app_state: State<'_, AppState>,
+    tracker: State<'_, ConnectionTracker>,
+    id: String,
+) -> AppResult<()> {
+    tracker.finish(&app_state.db, &id, None, DisconnectReason::User);
     let mut map = state.sessions.lock().unwrap();
     if let Some(conn) = map.remove(&id) {
         if let Ok(mut c) = conn.shell_channel.lock() {
-            let _ = c.close();
+            c.close();
         }
     }
     Ok(())
 }
 
 #[tauri::command]
-pub fn write_ssh(state: State<'_, SshState>, id: String, data: String) -> Result<(), String> {
+pub fn write_ssh(
+    state: State<'_, SshState>,
+    tracker: State<'_, ConnectionTracker>,
+    id: String,
+    data: String,
+) -> AppResult<()> {
+    tracker.add_bytes_out(&id, data.len());
     let map = state.sessions.lock().unwrap();
     if let Some(conn) = map.get(&id) {
         if let Ok(mut c) = conn.shell_channel.lock() {
-            use std::io::Write;
-            c.write_all(data.as_bytes()).map_err(|e| e.to_string())?;
-            c.flush().map_err(|e| e.to_string())?;
+            c.write_input(data.as_bytes())?;
         }
     }
     Ok(())
@@ -501,11 +780,11 @@ pub fn resize_ssh(
     id: String,
     rows: u32,
     cols: u32,
-) -> Result<(), String> {
+) -> AppResult<()> {
     let map = state.sessions.lock().unwrap();
     if let Some(conn) = map.get(&id) {
         if let Ok(mut c) = conn.shell_channel.lock() {
-            let _ = c.request_pty_size(cols, rows, None, None);
+            let _ = c.resize(cols, rows);
         }
     }
     Ok(())
@@ -513,10 +792,11 @@ pub fn resize_ssh(
 
 #[tauri::command]
 pub async fn test_connection(
+    app: AppHandle,
     app_state: State<'_, AppState>,
     vault_state: State<'_, VaultState>,
     payload: TestConnectionPayload
-) -> Result<String, String> {
+) -> AppResult<ConnectionDiagnostics> {
     let db_pool = &app_state.db;
 
     // 1. 解析密码/密钥 (核心逻辑：判断 Source)
@@ -525,17 +805,12 @@ pub async fn test_connection(
     let mut final_passphrase: Option<String> = payload.passphrase.clone();
 
     let mut master_key = None;
-    
+
     let needs_decryption = (payload.auth_type == "password" && payload.password_source.as_deref() == Some("store")) ||
                            ((payload.auth_type == "key" || payload.auth_type == "privateKey") && payload.key_source.as_deref() == Some("store"));
 
     if needs_decryption {
-        let guard = vault_state.0.lock().unwrap();
-        if let Some(key) = guard.as_ref() {
-            master_key = Some(key.clone());
-        } else {
-            return Err("VAULT_LOCKED: Please unlock the vault to use saved credentials.".to_string());
-        }
+        master_key = Some(vault_state.master_key()?);
     }
 
     // --- 处理密码 ---
@@ -608,29 +883,14 @@ pub async fn test_connection(
         keep_alive_interval: None,
         auto_reconnect: None,
         max_reconnects: None,
+        algorithms: payload.algorithm_prefs,
     };
 
-    tauri::async_runtime::spawn_blocking(move || {
-        use crate::commands::ssh::core::establish_base_session;
-        use std::io::Read;
-
-        let sess = establish_base_session(&config)
-            .map_err(|e| format!("连接建立失败: {}", e))?;
-
-        let mut channel = sess.channel_session()
-            .map_err(|e| format!("通道创建失败: {}", e))?;
-        
-        channel.exec("whoami")
-            .map_err(|e| format!("命令验证失败: {}", e))?;
-
-        let mut s = String::new();
-        channel.read_to_string(&mut s)
-            .map_err(|e| format!("结果读取失败: {}", e))?;
-
-        Ok(format!("连接成功! 用户: {}", s.trim()))
-    })
-    .await
-    .map_err(|e| format!("Runtime Error: {}", e))?
+    // 各阶段的结果 (包括失败原因) 都放在诊断报告里返回
+    let known_hosts_path = get_known_hosts_path(&app);
+    tauri::async_runtime::spawn_blocking(move || run_diagnostics(&config, known_hosts_path))
+        .await
+        .map_err(|e| AppError::Internal(format!("Runtime Error: {}", e)))
 }
 
 #[tauri::command]
@@ -644,7 +904,7 @@ pub async fn quick_connect(
     password: Option<String>,
     private_key: Option<String>,
     passphrase: Option<String>
-) -> Result<(), String> {
+) -> AppResult<()> {
     
     // 获取会话锁的引用，准备后续存入
     let sessions = state.sessions.clone();
@@ -680,6 +940,7 @@ pub async fn quick_connect(
         keep_alive_interval: Some(15),   // 默认 15秒保活
         auto_reconnect: Some(false),     // 快速连接不自动重连
         max_reconnects: Some(0),
+        algorithms: None,
     };
 
     // 克隆配置用于监控和SFTP会话
@@ -699,8 +960,7 @@ pub async fn quick_connect(
 
         // B. 建立 Shell 通道
         // 复用 core 模块中的底层函数
-        let (_shell_sess, shell_channel) =
-            create_shell_channel(&config).map_err(|e| format!("Shell Connection Failed: {}", e))?;
+        let (shell_sess, shell_channel) = create_shell_channel(&config, &ShellOptions::default())?;
 
         // C. 建立辅助会话 (监控和文件传输)
         let monitor_sess = create_monitor_session(&config_monitor);
@@ -710,24 +970,41 @@ pub async fn quick_connect(
         let shell_channel_arc = Arc::new(Mutex::new(shell_channel));
         let monitor_session_arc = Arc::new(Mutex::new(monitor_sess));
         let sftp_session_arc = Arc::new(Mutex::new(sftp_sess));
+        let shell_tracker_arc = Arc::new(Mutex::new(ShellTracker::default()));
+        let scrollback_arc = Arc::new(Mutex::new(ScrollbackBuffer::default()));
 
         {
             let mut map = sessions.lock().unwrap();
             map.insert(
                 session_id.clone(),
                 SshConnection {
+                    server_id: None,
+                    shell_session: Some(shell_sess),
                     shell_channel: shell_channel_arc.clone(),
                     monitor_session: monitor_session_arc,
                     sftp_session: sftp_session_arc,
+                    shell_tracker: shell_tracker_arc.clone(),
+                    scrollback: scrollback_arc.clone(),
                 },
             );
         }
 
         // E. 启动读取线程 (监听 SSH 输出并发回前端)
-        spawn_shell_reader_thread(app, shell_channel_arc, session_id);
+        // 快速连接没有服务器记录，不落库命令历史
+        spawn_shell_reader_thread(
+            shell_channel_arc,
+            ShellOutputSink {
+                app,
+                id: session_id,
+                server_id: None,
+                event_id: None,
+                tracker: shell_tracker_arc,
+                scrollback: scrollback_arc,
+            },
+        );
 
         Ok(())
     })
     .await
-    .map_err(|e| format!("Async Error: {}", e))?
+    .map_err(|e| AppError::Internal(format!("Async Error: {}", e)))?
 }
\ No newline at end of file
@@ -0,0 +1,190 @@
+// src-tauri/src/commands/ssh/multiplexer.rs
+//
+// 远程终端复用器 (tmux / screen)：
+// 通过监控会话列出主机上已有的会话，并让 Shell 通道接入其中一个。
+// 服务器配置了 tmux_session 时，connect_ssh 会直接在该 tmux 会话中启动 Shell，
+// 断线后重连 (再次 connect_ssh) 即可回到原来的会话。
+use std::io::Read;
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use super::core::shell_quote;
+use crate::error::{AppError, AppResult};
+use super::SshState;
+
+// 两段输出之间的分隔标记
+const SECTION_MARKER: &str = "__PITERM_SCREEN__";
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Multiplexer {
+    Tmux,
+    Screen,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteMuxSession {
+    pub kind: Multiplexer,
+    /// tmux 为会话名；screen 为 pid.name
+    pub name: String,
+    pub windows: Option<u32>,
+    pub attached: bool,
+    /// 创建时间 (Unix 秒)，screen 不提供
+    pub created_at: Option<i64>,
+}
+
+/// 会话名只允许安全字符，拼进命令行时不需要再转义
+pub fn validate_session_name(name: &str) -> AppResult<()> {
+    let valid = !name.is_empty()
+        && name.len() <= 64
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '-');
+    if valid {
+        Ok(())
+    } else {
+        Err(AppError::InvalidInput(format!("Invalid session name: {}", name)))
+    }
+}
+
+/// 登录时进入 tmux 会话的启动命令 (存在则接入，不存在则创建)
+/// 主机上没有 tmux 时退回普通登录 Shell，避免连接直接失败
+/// cwd 与启动命令只在新建会话时生效：命令通过 send-keys 输入新会话的 Shell，恢复已有会话时不会重复执行
+pub fn tmux_startup_command(name: &str, cwd: Option<&str>, commands: &[String]) -> String {
+    let cwd_arg = cwd
+        .map(|dir| format!(" -c {}", shell_quote(dir)))
+        .unwrap_or_default();
+    let keys: String = commands
+        .iter()
+        .map(|cmd| format!(" \\; send-keys -l {} \\; send-keys Enter", shell_quote(cmd)))
+        .collect();
+    format!(
+        "command -v tmux >/dev/null 2>&1 || exec \"${{SHELL:-/bin/sh}}\" -l; \
+         tmux has-session -t ={name} 2>/dev/null && exec tmux attach-session -t ={name}; \
+         exec tmux new-session -s {name}{cwd_arg}{keys}",
+    )
+}
+
+// 在 Shell 中输入的接入命令 (不使用 exec，detach 后回到原来的 Shell)
+// 已在 tmux 中 (如服务器配置了自动 tmux 会话) 时嵌套 attach 会被拒绝，改为切换会话
+fn attach_command(kind: Multiplexer, name: &str) -> String {
+    match kind {
+        Multiplexer::Tmux => format!(
+            "if [ -n \"$TMUX\" ]; then tmux switch-client -t {name}; else tmux attach-session -t {name}; fi\r"
+        ),
+        // -x 允许多个终端同时接入，-R 在会话不存在时创建
+        Multiplexer::Screen => format!("screen -x -R {}\r", name),
+    }
+}
+
+fn parse_tmux_sessions(output: &str) -> Vec<RemoteMuxSession> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split('\t');
+            let name = parts.next()?.trim();
+            if name.is_empty() {
+                return None;
+            }
+            let windows = parts.next().and_then(|v| v.trim().parse().ok());
+            let attached = parts
+                .next()
+                .and_then(|v| v.trim().parse::<u32>().ok())
+                .unwrap_or(0)
+                > 0;
+            let created_at = parts.next().and_then(|v| v.trim().parse().ok());
+            Some(RemoteMuxSession {
+                kind: Multiplexer::Tmux,
+                name: name.to_string(),
+                windows,
+                attached,
+                created_at,
+            })
+        })
+        .collect()
+}
+
+// screen -ls 的输出形如:
+//     12345.work	(01/02/2024 10:00:00 AM)	(Detached)
+fn parse_screen_sessions(output: &str) -> Vec<RemoteMuxSession> {
+    output
+        .lines()
+        .filter(|line| line.starts_with('\t') || line.starts_with(' '))
+        .filter_map(|line| {
+            let mut parts = line.split('\t').map(str::trim).filter(|p| !p.is_empty());
+            let name = parts.next()?;
+            // 会话标识必须是 pid.name 形式
+            let (pid, _) = name.split_once('.')?;
+            if pid.is_empty() || !pid.chars().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            let attached = parts.any(|p| p.eq_ignore_ascii_case("(Attached)"));
+            Some(RemoteMuxSession {
+                kind: Multiplexer::Screen,
+                name: name.to_string(),
+                windows: None,
+                attached,
+                created_at: None,
+            })
+        })
+        .collect()
+}
+
+/// 列出主机上的 tmux / screen 会话 (未安装的复用器直接忽略)
+#[tauri::command]
+pub async fn list_remote_mux_sessions(
+    state: State<'_, SshState>,
+    id: String,
+) -> AppResult<Vec<RemoteMuxSession>> {
+    let session_arc = {
+        let map = state.sessions.lock().unwrap();
+        map.get(&id)
+            .map(|c| c.monitor_session.clone())
+            .ok_or_else(|| AppError::session_not_active(&id))?
+    };
+
+    let cmd = format!(
+        "tmux list-sessions -F '#{{session_name}}\t#{{session_windows}}\t#{{session_attached}}\t#{{session_created}}' 2>/dev/null; echo {}; screen -ls 2>/dev/null",
+        SECTION_MARKER
+    );
+
+    let output = tauri::async_runtime::spawn_blocking(move || {
+        let sess_guard = session_arc.lock().unwrap();
+        let sess = sess_guard
+            .as_ref()
+            .ok_or_else(|| AppError::Ssh("Monitor session unavailable".to_string()))?;
+        let mut channel = sess.channel_session()?;
+
+        channel.exec(&cmd)?;
+        let mut s = String::new();
+        channel.read_to_string(&mut s).ok();
+        channel.wait_close().ok();
+        Ok::<String, AppError>(s)
+    })
+    .await
+    .map_err(|e| AppError::Internal(e.to_string()))??;
+
+    let (tmux_out, screen_out) = output.split_once(SECTION_MARKER).unwrap_or((&output, ""));
+
+    let mut sessions = parse_tmux_sessions(tmux_out);
+    sessions.extend(parse_screen_sessions(screen_out));
+    Ok(sessions)
+}
+
+/// 让当前 Shell 接入指定的 tmux / screen 会话
+#[tauri::command]
+pub fn attach_remote_mux_session(
+    state: State<'_, SshState>,
+    id: String,
+    kind: Multiplexer,
+    name: String,
+) -> AppResult<()> {
+    validate_session_name(&name)?;
+
+    let map = state.sessions.lock().unwrap();
+    let conn = map.get(&id).ok_or_else(|| AppError::session_not_active(&id))?;
+    let mut c = conn.shell_channel.lock().map_err(|e| AppError::Internal(e.to_string()))?;
+    c.write_input(attach_command(kind, &name).as_bytes())
+}
@@ -0,0 +1,188 @@
+// src-tauri/src/commands/ssh/scrollback.rs
+//
+// 服务端回滚缓冲：保存每个会话最近的终端输出，
+// WebView 刷新或重新打开标签页后可通过 get_scrollback 重新灌入 xterm.js。
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use regex::RegexBuilder;
+use serde::Serialize;
+use tauri::State;
+
+use super::SshState;
+use crate::error::{AppError, AppResult};
+
+/// 默认缓冲大小 1 MiB
+pub const DEFAULT_SCROLLBACK_SIZE: usize = 1024 * 1024;
+const MIN_SCROLLBACK_SIZE: usize = 64 * 1024;
+const MAX_SCROLLBACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// 有界环形缓冲
+/// 以读取线程推送给前端的数据块为单位存储，超出容量时从头部整块淘汰
+pub struct ScrollbackBuffer {
+    chunks: VecDeque<String>,
+    len: usize,
+    capacity: usize,
+    // 已被淘汰的字节数，用于计算绝对偏移
+    start_offset: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScrollbackSnapshot {
+    pub data: String,
+    /// data 第一个字节在整个会话输出中的绝对偏移
+    pub start_offset: u64,
+    pub end_offset: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScrollbackMatch {
+    /// 匹配在整个会话输出中的绝对字节偏移
+    pub offset: u64,
+    pub length: usize,
+    /// 相对缓冲起点的行号 (从 0 开始)
+    pub line: usize,
+    pub line_text: String,
+}
+
+impl ScrollbackBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            chunks: VecDeque::new(),
+            len: 0,
+            capacity: capacity.clamp(MIN_SCROLLBACK_SIZE, MAX_SCROLLBACK_SIZE),
+            start_offset: 0,
+        }
+    }
+
+    pub fn push(&mut self, data: &str) {
+        if data.is_empty() {
+            return;
+        }
+        self.len += data.len();
+        self.chunks.push_back(data.to_string());
+
+        while self.len > self.capacity && self.chunks.len() > 1 {
+            if let Some(front) = self.chunks.pop_front() {
+                self.len -= front.len();
+                self.start_offset += front.len() as u64;
+            }
+        }
+    }
+
+    fn contents(&self) -> String {
+        let mut s = String::with_capacity(self.len);
+        for chunk in &self.chunks {
+            s.push_str(chunk);
+        }
+        s
+    }
+
+    pub fn snapshot(&self) -> ScrollbackSnapshot {
+        ScrollbackSnapshot {
+            data: self.contents(),
+            start_offset: self.start_offset,
+            end_offset: self.start_offset + self.len as u64,
+        }
+    }
+
+    pub fn search(
+        &self,
+        query: &str,
+        is_regex: bool,
+        case_sensitive: bool,
+        limit: usize,
+    ) -> AppResult<Vec<ScrollbackMatch>> {
+        if query.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let pattern = if is_regex { query.to_string() } else { regex::escape(query) };
+        let re = RegexBuilder::new(&pattern)
+            .case_insensitive(!case_sensitive)
+            .build()
+            .map_err(|e| AppError::InvalidInput(format!("Invalid pattern: {}", e)))?;
+
+        let text = self.contents();
+
+        // 行起始位置表，用于把匹配偏移换算成行号
+        let mut line_starts = vec![0usize];
+        line_starts.extend(text.match_indices('\n').map(|(i, _)| i + 1));
+
+        let mut matches = Vec::new();
+        for m in re.find_iter(&text) {
+            if m.as_str().is_empty() {
+                continue;
+            }
+            let line = line_starts.partition_point(|&start| start <= m.start()) - 1;
+            let line_start = line_starts[line];
+            let line_end = text[line_start..]
+                .find('\n')
+                .map(|i| line_start + i)
+                .unwrap_or(text.len());
+
+            matches.push(ScrollbackMatch {
+                offset: self.start_offset + m.start() as u64,
+                length: m.len(),
+                line,
+                line_text: text[line_start..line_end].trim_end_matches('\r').to_string(),
+            });
+
+            if matches.len() >= limit {
+                break;
+            }
+        }
+
+        Ok(matches)
+    }
+}
+
+impl Default for ScrollbackBuffer {
+    fn default() -> Self {
+        Self::new(DEFAULT_SCROLLBACK_SIZE)
+    }
+}
+
+// ==============================================================================
+// Tauri Commands
+// ==============================================================================
+
+/// 列出后端仍存活的会话 ID (WebView 刷新后用于重新挂载标签页)
+#[tauri::command]
+pub fn list_active_sessions(state: State<'_, SshState>) -> AppResult<Vec<String>> {
+    let map = state.sessions.lock().unwrap();
+    Ok(map.keys().cloned().collect())
+}
+
+// 取出会话的回滚缓冲后立即释放会话表的锁，
+// 复制或搜索大缓冲时不阻塞其他会话的读写
+fn session_scrollback(state: &SshState, id: &str) -> AppResult<Arc<Mutex<ScrollbackBuffer>>> {
+    let map = state.sessions.lock().unwrap();
+    let conn = map.get(id).ok_or_else(|| AppError::session_not_active(id))?;
+    Ok(conn.scrollback.clone())
+}
+
+/// 获取会话的回滚缓冲内容 (重新挂载时调用)
+#[tauri::command]
+pub fn get_scrollback(state: State<'_, SshState>, id: String) -> AppResult<ScrollbackSnapshot> {
+    let scrollback = session_scrollback(&state, &id)?;
+    let buffer = scrollback.lock().map_err(|e| AppError::Internal(e.to_string()))?;
+    Ok(buffer.snapshot())
+}
+
+/// 在回滚缓冲中搜索 (纯文本或正则)，返回匹配的绝对偏移
+#[tauri::command]
+pub fn search_scrollback(
+    state: State<'_, SshState>,
+    id: String,
+    query: String,
+    is_regex: bool,
+    case_sensitive: bool,
+    limit: Option<usize>,
+) -> AppResult<Vec<ScrollbackMatch>> {
+    let scrollback = session_scrollback(&state, &id)?;
+    let buffer = scrollback.lock().map_err(|e| AppError::Internal(e.to_string()))?;
+    buffer.search(&query, is_regex, case_sensitive, limit.unwrap_or(500))
+}
@@ -0,0 +1,251 @@
+// src-tauri/src/commands/ssh/shell_integration.rs
+//
+// Shell Integration：解析终端输出中的 OSC 7 (工作目录) 与 OSC 133 (命令边界) 序列。
+// 序列本身原样透传给前端 (xterm.js 会忽略不认识的 OSC)，这里只做旁路解析。
+use std::time::Instant;
+
+use tauri::State;
+
+use super::SshState;
+use crate::error::{AppError, AppResult};
+
+// OSC 负载上限，防止未终止的序列无限增长
+const MAX_OSC_LEN: usize = 4096;
+
+/// 注入到远程 Shell 的钩子脚本 (bash >= 4.4 / zsh)
+/// 以空格开头，配合 HISTCONTROL=ignorespace 避免污染远程历史
+pub const SHELL_INTEGRATION_SCRIPT: &str = concat!(
+    " if [ -z \"$__PITERM_SI\" ]; then __PITERM_SI=1;",
+    " if [ -n \"$ZSH_VERSION\" ]; then",
+    " __piterm_precmd() { local ec=$?; printf '\\033]133;D;%s\\007\\033]7;file://%s%s\\007\\033]133;A\\007' \"$ec\" \"$HOST\" \"$PWD\"; };",
+    " __piterm_preexec() { printf '\\033]133;E;%s\\007\\033]133;C\\007' \"$1\"; };",
+    " autoload -Uz add-zsh-hook; add-zsh-hook precmd __piterm_precmd; add-zsh-hook preexec __piterm_preexec;",
+    " elif [ -n \"$BASH_VERSION\" ]; then",
+    " __piterm_prompt() { local ec=$?; printf '\\033]133;D;%s\\007\\033]7;file://%s%s\\007\\033]133;A\\007' \"$ec\" \"$HOSTNAME\" \"$PWD\"; };",
+    " __piterm_preexec() { printf '\\033]133;E;%s\\007\\033]133;C\\007' \"$(HISTTIMEFORMAT= history 1 | sed 's/^ *[0-9]* *//')\"; };",
+    " PROMPT_COMMAND=\"__piterm_prompt${PROMPT_COMMAND:+;$PROMPT_COMMAND}\"; PS0='$(__piterm_preexec)';",
+    " fi; fi\n",
+);
+
+/// 解析出的 Shell 事件
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShellEvent {
+    /// OSC 7: 当前工作目录
+    Cwd(String),
+    /// OSC 133;A: 提示符开始
+    PromptStart,
+    /// OSC 133;B: 用户开始输入命令
+    CommandStart,
+    /// OSC 133;E;<cmd>: 即将执行的命令行
+    CommandLine(String),
+    /// OSC 133;C: 命令开始执行 (输出开始)
+    CommandExecuted,
+    /// OSC 133;D[;exit]: 命令结束
+    CommandFinished(Option<i32>),
+}
+
+#[derive(Default, Clone, Copy, PartialEq)]
+enum ParseState {
+    #[default]
+    Ground,
+    Escape,
+    Osc,
+    OscEscape,
+}
+
+/// 流式 OSC 解析器
+/// 读取线程拿到的数据块可能在任意位置被截断，所以状态需要跨块保留
+#[derive(Default)]
+pub struct OscParser {
+    state: ParseState,
+    buf: Vec<u8>,
+}
+
+impl OscParser {
+    pub fn feed(&mut self, data: &[u8]) -> Vec<ShellEvent> {
+        let mut events = Vec::new();
+
+        for &b in data {
+            match self.state {
+                ParseState::Ground => {
+                    if b == 0x1b {
+                        self.state = ParseState::Escape;
+                    }
+                }
+                ParseState::Escape => {
+                    if b == b']' {
+                        self.buf.clear();
+                        self.state = ParseState::Osc;
+                    } else if b == 0x1b {
+                        self.state = ParseState::Escape;
+                    } else {
+                        self.state = ParseState::Ground;
+                    }
+                }
+                ParseState::Osc => match b {
+                    // BEL 结束
+                    0x07 => {
+                        if let Some(ev) = Self::parse_payload(&self.buf) {
+                            events.push(ev);
+                        }
+                        self.buf.clear();
+                        self.state = ParseState::Ground;
+                    }
+                    // 可能是 ST (ESC \)
+                    0x1b => self.state = ParseState::OscEscape,
+                    _ => {
+                        if self.buf.len() < MAX_OSC_LEN {
+                            self.buf.push(b);
+                        } else {
+                            // 超长序列直接丢弃
+                            self.buf.clear();
+                            self.state = ParseState::Ground;
+                        }
+                    }
+                },
+                ParseState::OscEscape => {
+                    if b == b'\\' {
+                        if let Some(ev) = Self::parse_payload(&self.buf) {
+                            events.push(ev);
+                        }
+                        self.buf.clear();
+                        self.state = ParseState::Ground;
+                    } else if b == b']' {
+                        // 未终止的 OSC 后紧跟新的 OSC
+                        self.buf.clear();
+                        self.state = ParseState::Osc;
+                    } else {
+                        self.buf.clear();
+                        self.state = ParseState::Ground;
+                    }
+                }
+            }
+        }
+
+        events
+    }
+
+    fn parse_payload(payload: &[u8]) -> Option<ShellEvent> {
+        let text = String::from_utf8_lossy(payload);
+
+        if let Some(url) = text.strip_prefix("7;") {
+            return parse_cwd_url(url).map(ShellEvent::Cwd);
+        }
+
+        let rest = text.strip_prefix("133;")?;
+        let mut parts = rest.splitn(2, ';');
+        let kind = parts.next()?;
+        let arg = parts.next();
+
+        match kind {
+            "A" => Some(ShellEvent::PromptStart),
+            "B" => Some(ShellEvent::CommandStart),
+            "C" => Some(ShellEvent::CommandExecuted),
+            "D" => Some(ShellEvent::CommandFinished(
+                arg.and_then(|a| a.split(';').next()).and_then(|a| a.trim().parse().ok()),
+            )),
+            "E" => arg.map(|cmd| ShellEvent::CommandLine(cmd.to_string())),
+            _ => None,
+        }
+    }
+}
+
+// file://host/path -> /path (路径部分可能经过 URL 编码)
+fn parse_cwd_url(url: &str) -> Option<String> {
+    let without_scheme = url.strip_prefix("file://")?;
+    let path_start = without_scheme.find('/')?;
+    let path = &without_scheme[path_start..];
+    let decoded = urlencoding::decode(path)
+        .map(|p| p.into_owned())
+        .unwrap_or_else(|_| path.to_string());
+    Some(decoded)
+}
+
+/// 一条执行完成的命令
+#[derive(Debug, Clone)]
+pub struct FinishedCommand {
+    pub command: String,
+    pub exit_code: Option<i32>,
+    pub duration_ms: i64,
+    pub cwd: Option<String>,
+}
+
+/// 解析结果中读取线程需要关心的变化
+#[derive(Debug, Clone)]
+pub enum TrackerUpdate {
+    CwdChanged(String),
+    CommandFinished(FinishedCommand),
+}
+
+/// 每个会话一份的 Shell 状态
+#[derive(Default)]
+pub struct ShellTracker {
+    parser: OscParser,
+    pub cwd: Option<String>,
+    pending_command: Option<String>,
+    started_at: Option<Instant>,
+}
+
+impl ShellTracker {
+    pub fn process(&mut self, data: &[u8]) -> Vec<TrackerUpdate> {
+        let mut updates = Vec::new();
+
+        for event in self.parser.feed(data) {
+            match event {
+                ShellEvent::Cwd(path) => {
+                    if self.cwd.as_deref() != Some(path.as_str()) {
+                        self.cwd = Some(path.clone());
+                        updates.push(TrackerUpdate::CwdChanged(path));
+                    }
+                }
+                ShellEvent::PromptStart | ShellEvent::CommandStart => {}
+                ShellEvent::CommandLine(cmd) => {
+                    self.pending_command = Some(cmd);
+                    self.started_at = Some(Instant::now());
+                }
+                ShellEvent::CommandExecuted => {
+                    self.started_at = Some(Instant::now());
+                }
+                ShellEvent::CommandFinished(exit_code) => {
+                    // 首个提示符也会发 D，此时没有待完成的命令
+                    if let (Some(command), Some(started)) =
+                        (self.pending_command.take(), self.started_at.take())
+                    {
+                        updates.push(TrackerUpdate::CommandFinished(FinishedCommand {
+                            command,
+                            exit_code,
+                            duration_ms: started.elapsed().as_millis() as i64,
+                            cwd: self.cwd.clone(),
+                        }));
+                    }
+                }
+            }
+        }
+
+        updates
+    }
+}
+
+// ==============================================================================
+// Tauri Commands
+// ==============================================================================
+
+/// 获取会话当前的远程工作目录 (未注入钩子或尚未收到 OSC 7 时为 None)
+#[tauri::command]
+pub fn get_remote_cwd(state: State<'_, SshState>, id: String) -> AppResult<Option<String>> {
+    let map = state.sessions.lock().unwrap();
+    let conn = map.get(&id).ok_or_else(|| AppError::session_not_active(&id))?;
+    let tracker = conn.shell_tracker.lock().map_err(|e| AppError::Internal(e.to_string()))?;
+    Ok(tracker.cwd.clone())
+}
+
+/// 向 Shell 通道写入集成钩子 (bash/zsh)
+#[tauri::command]
+pub fn inject_shell_integration(state: State<'_, SshState>, id: String) -> AppResult<()> {
+    let map = state.sessions.lock().unwrap();
+    let conn = map.get(&id).ok_or_else(|| AppError::session_not_active(&id))?;
+    if let Ok(mut c) = conn.shell_channel.lock() {
+        c.write_input(SHELL_INTEGRATION_SCRIPT.as_bytes())?;
+    }
+    Ok(())
+}
@@ -1,17 +1,59 @@
 use ssh2::{Channel, Session};
 use std::collections::HashMap;
+use std::io::Write;
 use std::sync::{Arc, Mutex};
 
+use super::scrollback::ScrollbackBuffer;
+use crate::error::AppResult;
+use super::shell_integration::ShellTracker;
+
+/// 终端后端抽象
+/// SSH 通道、本地 PTY 等都实现它，前端统一走 write_ssh / resize_ssh / disconnect_ssh
+pub trait TerminalBackend: Send {
+    fn write_input(&mut self, data: &[u8]) -> AppResult<()>;
+    fn resize(&mut self, cols: u32, rows: u32) -> AppResult<()>;
+    fn close(&mut self);
+}
+
+impl TerminalBackend for Channel {
+    fn write_input(&mut self, data: &[u8]) -> AppResult<()> {
+        self.write_all(data)?;
+        Ok(self.flush()?)
+    }
+
+    fn resize(&mut self, cols: u32, rows: u32) -> AppResult<()> {
+        Ok(self.request_pty_size(cols, rows, None, None)?)
+    }
+
+    fn close(&mut self) {
+        let _ = Channel::close(self);
+    }
+}
+
 /// 管理 SSH 连接状态
 pub struct SshConnection {
+    /// 对应的服务器 ID (快速连接为空，本地终端为 "local")
+    pub server_id: Option<String>,
+
+    /// Shell 所在的 SSH 会话 (非阻塞)，用于在同一连接上开新的 PTY 通道
+    /// 本地终端 / Telnet 为空
+    pub shell_session: Option<Session>,
+
     /// Shell 专用 (非阻塞，有读取线程在跑)
-    pub shell_channel: Arc<Mutex<Channel>>,
+    /// 本地终端等非 SSH 会话也复用这个字段
+    pub shell_channel: Arc<Mutex<dyn TerminalBackend>>,
 
     /// Monitor 专用 (阻塞，空闲状态，随时可用)
     /// Option 用于容错，允许监控连接建立失败
     pub monitor_session: Arc<Mutex<Option<Session>>>,
     // 2. [新增] SFTP 专用 Session (文件管理)
     pub sftp_session: Arc<Mutex<Option<Session>>>,
+
+    /// Shell Integration 状态 (cwd / 正在执行的命令)，由读取线程更新
+    pub shell_tracker: Arc<Mutex<ShellTracker>>,
+
+    /// 终端输出回滚缓冲 (重新挂载 / 搜索)
+    pub scrollback: Arc<Mutex<ScrollbackBuffer>>,
 }
 
 #[derive(Default)]
@@ -0,0 +1,264 @@
+// src-tauri/src/commands/telnet/mod.rs
+//
+// Telnet 会话：与 SSH 共用 SshState 会话表和 term-data-{id} 等事件，
+// 前端终端无需区分协议。由 connect_ssh 根据服务器的 protocol 字段分发进来。
+use std::io::{Read, Write};
+use std::net::{Shutdown, TcpStream, ToSocketAddrs};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use aes_gcm::{Aes256Gcm, Key};
+use serde_json::Value;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Pool, Row, Sqlite};
+use tauri::AppHandle;
+
+use crate::commands::connection_stats::DisconnectReason;
+use crate::commands::ssh::core::ShellOutputSink;
+use crate::commands::ssh::scrollback::{ScrollbackBuffer, DEFAULT_SCROLLBACK_SIZE};
+use crate::commands::ssh::shell_integration::ShellTracker;
+use crate::commands::ssh::{SshConnection, SshState, TerminalBackend};
+use crate::commands::vault::internal_get_secret;
+use crate::error::{AppError, AppResult};
+use crate::models::TelnetLoginStep;
+
+pub mod protocol;
+
+use protocol::{LoginScriptRunner, TelnetCodec};
+
+/// Telnet 后端
+pub struct TelnetBackend {
+    writer: Arc<Mutex<TcpStream>>,
+    codec: Arc<Mutex<TelnetCodec>>,
+}
+
+impl TerminalBackend for TelnetBackend {
+    fn write_input(&mut self, data: &[u8]) -> AppResult<()> {
+        let encoded = TelnetCodec::encode_input(data);
+        let mut w = self.writer.lock().map_err(|e| AppError::Internal(e.to_string()))?;
+        w.write_all(&encoded)?;
+        Ok(w.flush()?)
+    }
+
+    fn resize(&mut self, cols: u32, rows: u32) -> AppResult<()> {
+        let naws = self
+            .codec
+            .lock()
+            .map_err(|e| AppError::Internal(e.to_string()))?
+            .resize(cols as u16, rows as u16);
+        if let Some(bytes) = naws {
+            let mut w = self.writer.lock().map_err(|e| AppError::Internal(e.to_string()))?;
+            w.write_all(&bytes)?;
+        }
+        Ok(())
+    }
+
+    fn close(&mut self) {
+        if let Ok(w) = self.writer.lock() {
+            let _ = w.shutdown(Shutdown::Both);
+        }
+    }
+}
+
+// 未配置登录脚本时的默认流程
+fn default_login_script(username: &str, password: Option<&str>) -> Vec<(String, String)> {
+    let mut steps = Vec::new();
+    if !username.is_empty() {
+        steps.push(("login:".to_string(), username.to_string()));
+    }
+    if let Some(pwd) = password {
+        steps.push(("password:".to_string(), pwd.to_string()));
+    }
+    steps
+}
+
+fn render_login_script(
+    script: &[TelnetLoginStep],
+    username: &str,
+    password: Option<&str>,
+) -> Vec<(String, String)> {
+    script
+        .iter()
+        .map(|step| {
+            let send = step
+                .send
+                .replace("{username}", username)
+                .replace("{password}", password.unwrap_or(""));
+            (step.expect.clone(), send)
+        })
+        .collect()
+}
+
+/// 建立 Telnet 会话 (connect_ssh 在 protocol = telnet 时调用)
+/// row 来自 ssh::fetch_server_row，已套用模板与文件夹默认值
+pub async fn internal_connect_telnet(
+    app: AppHandle,
+    ssh_state: &SshState,
+    pool: &Pool<Sqlite>,
+    master_key: Option<Key<Aes256Gcm>>,
+    row: SqliteRow,
+    session_id: String,
+    event_id: Option<i64>,
+) -> AppResult<()> {
+    let sessions = ssh_state.sessions.clone();
+
+    // 1. --- 服务器信息 ---
+    let server_id: String = row.get("id");
+    let host: String = row.get("ip");
+    let port: u16 = row.try_get::<i64, _>("port").map(|p| p as u16).unwrap_or(23);
+    let username: String = row.try_get("username").unwrap_or_default();
+    let connect_timeout: u64 = row
+        .try_get::<Option<i64>, _>("connect_timeout")
+        .ok()
+        .flatten()
+        .map(|v| v as u64)
+        .unwrap_or(10);
+    let scrollback_size = row
+        .try_get::<Option<i64>, _>("scrollback_size")
+        .ok()
+        .flatten()
+        .map(|v| v as usize)
+        .unwrap_or(DEFAULT_SCROLLBACK_SIZE);
+    let script: Vec<TelnetLoginStep> = row
+        .try_get::<Option<String>, _>("login_script")
+        .ok()
+        .flatten()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default();
+
+    // 2. --- 解析密码 (可选) ---
+    let password_id: Option<String> = row.try_get("password_id").ok().flatten();
+    let password: Option<String> = match password_id {
+        Some(pid) => {
+            let mk = master_key.ok_or(AppError::VaultLocked)?;
+            let decrypted = internal_get_secret(pool, &mk, &pid).await?;
+            match serde_json::from_str::<Value>(&decrypted) {
+                Ok(parsed) => match parsed.get("val").and_then(|v| v.as_str()) {
+                    Some(val) => Some(val.to_string()),
+                    None => Some(decrypted),
+                },
+                Err(_) => Some(decrypted),
+            }
+        }
+        // 旧版本遗留的明文密码要等解锁 Vault 迁移后才能使用
+        None if row.try_get::<bool, _>("has_plain_password").unwrap_or(false) => {
+            return Err(AppError::VaultLocked);
+        }
+        None => None,
+    };
+
+    let steps = if script.is_empty() {
+        default_login_script(&username, password.as_deref())
+    } else {
+        render_login_script(&script, &username, password.as_deref())
+    };
+
+    // 3. --- 建立连接 ---
+    tauri::async_runtime::spawn_blocking(move || {
+        // A. 清理旧连接
+        {
+            let mut map = sessions.lock().unwrap();
+            if let Some(old) = map.remove(&session_id) {
+                if let Ok(mut c) = old.shell_channel.lock() {
+                    c.close();
+                }
+            }
+        }
+
+        // B. TCP 连接
+        let addr = format!("{}:{}", host, port)
+            .to_socket_addrs()
+            .map_err(|e| AppError::Network(format!("DNS Error: {}", e)))?
+            .next()
+            .ok_or_else(|| AppError::Network("DNS resolution failed".to_string()))?;
+        let stream = TcpStream::connect_timeout(&addr, Duration::from_secs(connect_timeout))
+            .map_err(|e| AppError::Network(format!("TCP Error: {}", e)))?;
+        let _ = stream.set_nodelay(true);
+
+        let mut reader = stream.try_clone()?;
+        let writer = Arc::new(Mutex::new(stream));
+        let codec = Arc::new(Mutex::new(TelnetCodec::new("XTERM", 80, 24)));
+
+        // C. 存入状态 (Telnet 没有监控/SFTP 会话)
+        let backend: Arc<Mutex<dyn TerminalBackend>> = Arc::new(Mutex::new(TelnetBackend {
+            writer: writer.clone(),
+            codec: codec.clone(),
+        }));
+        let shell_tracker_arc = Arc::new(Mutex::new(ShellTracker::default()));
+        let scrollback_arc = Arc::new(Mutex::new(ScrollbackBuffer::new(scrollback_size)));
+
+        {
+            let mut map = sessions.lock().unwrap();
+            map.insert(
+                session_id.clone(),
+                SshConnection {
+                    server_id: Some(server_id.clone()),
+                    shell_session: None,
+                    shell_channel: backend,
+                    monitor_session: Arc::new(Mutex::new(None)),
+                    sftp_session: Arc::new(Mutex::new(None)),
+                    shell_tracker: shell_tracker_arc.clone(),
+                    scrollback: scrollback_arc.clone(),
+                },
+            );
+        }
+
+        // D. 读取线程：解码 -> 回写协商应答 -> 执行登录脚本 -> 推送前端
+        let sink = ShellOutputSink {
+            app,
+            id: session_id.clone(),
+            server_id: Some(server_id),
+            event_id,
+            tracker: shell_tracker_arc,
+            scrollback: scrollback_arc,
+        };
+        let mut login = LoginScriptRunner::new(steps);
+
+        thread::spawn(move || {
+            let mut buf = [0u8; 8192];
+            let mut reason = DisconnectReason::Closed;
+            loop {
+                let count = match reader.read(&mut buf) {
+                    Ok(0) => break,
+                    Ok(n) => n,
+                    Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                    Err(e) => {
+                        eprintln!("[Telnet] Read Error for session {}: {}", session_id, e);
+                        reason = DisconnectReason::Error;
+                        break;
+                    }
+                };
+
+                let decoded = match codec.lock() {
+                    Ok(mut c) => c.decode(&buf[..count]),
+                    Err(poisoned) => poisoned.into_inner().decode(&buf[..count]),
+                };
+
+                let mut outgoing = decoded.reply;
+                if !login.is_done() {
+                    for line in login.feed(&decoded.data) {
+                        outgoing.extend(TelnetCodec::encode_input(line.as_bytes()));
+                        outgoing.extend_from_slice(b"\r\n");
+                    }
+                }
+                if !outgoing.is_empty() {
+                    if let Ok(mut w) = writer.lock() {
+                        let _ = w.write_all(&outgoing);
+                    }
+                }
+
+                if !decoded.data.is_empty() {
+                    sink.push(&decoded.data);
+                }
+            }
+
+            println!("[Telnet] Session thread exited for {}", session_id);
+            sink.exit(reason);
+        });
+
+        Ok(())
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("Async Error: {}", e)))?
+}
@@ -0,0 +1,392 @@
+// src-tauri/src/commands/telnet/protocol.rs
+//
+// Telnet 协议编解码 (RFC 854 / 1073 / 1091)
+// 纯状态机，不做任何 I/O：输入网络字节，输出终端数据与需要回给服务器的协商应答。
+
+// 命令字节
+pub const IAC: u8 = 255;
+pub const DONT: u8 = 254;
+pub const DO: u8 = 253;
+pub const WONT: u8 = 252;
+pub const WILL: u8 = 251;
+pub const SB: u8 = 250;
+pub const SE: u8 = 240;
+
+// 选项
+pub const OPT_ECHO: u8 = 1;
+pub const OPT_SGA: u8 = 3;
+pub const OPT_TTYPE: u8 = 24;
+pub const OPT_NAWS: u8 = 31;
+
+// TTYPE 子协商
+const TTYPE_IS: u8 = 0;
+const TTYPE_SEND: u8 = 1;
+
+// 子协商负载上限
+const MAX_SB_LEN: usize = 1024;
+
+#[derive(Default, Clone, Copy, PartialEq)]
+enum ParseState {
+    #[default]
+    Data,
+    Iac,
+    Negotiate(u8),
+    Sub,
+    SubIac,
+}
+
+/// 一次解码的结果
+#[derive(Default)]
+pub struct Decoded {
+    /// 交给终端显示的数据
+    pub data: Vec<u8>,
+    /// 需要写回服务器的协商应答
+    pub reply: Vec<u8>,
+}
+
+pub struct TelnetCodec {
+    state: ParseState,
+    sb_buf: Vec<u8>,
+    terminal_type: String,
+    cols: u16,
+    rows: u16,
+    // 本端已启用的选项 (WILL 已被对方 DO 确认)
+    local: [bool; 256],
+    // 对端已启用的选项 (对方 WILL 已被我们 DO 确认)
+    remote: [bool; 256],
+}
+
+impl TelnetCodec {
+    pub fn new(terminal_type: &str, cols: u16, rows: u16) -> Self {
+        Self {
+            state: ParseState::Data,
+            sb_buf: Vec::new(),
+            terminal_type: terminal_type.to_string(),
+            cols,
+            rows,
+            local: [false; 256],
+            remote: [false; 256],
+        }
+    }
+
+    pub fn decode(&mut self, input: &[u8]) -> Decoded {
+        let mut out = Decoded::default();
+
+        for &b in input {
+            match self.state {
+                ParseState::Data => {
+                    if b == IAC {
+                        self.state = ParseState::Iac;
+                    } else {
+                        out.data.push(b);
+                    }
+                }
+                ParseState::Iac => match b {
+                    IAC => {
+                        // IAC IAC 表示字面量 0xFF
+                        out.data.push(IAC);
+                        self.state = ParseState::Data;
+                    }
+                    DO | DONT | WILL | WONT => self.state = ParseState::Negotiate(b),
+                    SB => {
+                        self.sb_buf.clear();
+                        self.state = ParseState::Sub;
+                    }
+                    // NOP / GA / DM 等其余命令直接忽略
+                    _ => self.state = ParseState::Data,
+                },
+                ParseState::Negotiate(cmd) => {
+                    self.negotiate(cmd, b, &mut out.reply);
+                    self.state = ParseState::Data;
+                }
+                ParseState::Sub => {
+                    if b == IAC {
+                        self.state = ParseState::SubIac;
+                    } else if self.sb_buf.len() < MAX_SB_LEN {
+                        self.sb_buf.push(b);
+                    }
+                }
+                ParseState::SubIac => match b {
+                    SE => {
+                        self.subnegotiate(&mut out.reply);
+                        self.state = ParseState::Data;
+                    }
+                    IAC => {
+                        if self.sb_buf.len() < MAX_SB_LEN {
+                            self.sb_buf.push(IAC);
+                        }
+                        self.state = ParseState::Sub;
+                    }
+                    _ => self.state = ParseState::Data,
+                },
+            }
+        }
+
+        out
+    }
+
+    fn negotiate(&mut self, cmd: u8, opt: u8, reply: &mut Vec<u8>) {
+        let idx = opt as usize;
+        match cmd {
+            // 对方请求我们启用某选项
+            DO => {
+                let supported = matches!(opt, OPT_TTYPE | OPT_NAWS | OPT_SGA);
+                if supported {
+                    if !self.local[idx] {
+                        self.local[idx] = true;
+                        reply.extend_from_slice(&[IAC, WILL, opt]);
+                    }
+                    // 窗口大小在确认后立即上报
+                    if opt == OPT_NAWS {
+                        reply.extend_from_slice(&self.naws_bytes());
+                    }
+                } else {
+                    reply.extend_from_slice(&[IAC, WONT, opt]);
+                }
+            }
+            DONT if self.local[idx] => {
+                self.local[idx] = false;
+                reply.extend_from_slice(&[IAC, WONT, opt]);
+            }
+            // 对方声明将启用某选项
+            WILL => {
+                let accepted = matches!(opt, OPT_ECHO | OPT_SGA);
+                if accepted {
+                    if !self.remote[idx] {
+                        self.remote[idx] = true;
+                        reply.extend_from_slice(&[IAC, DO, opt]);
+                    }
+                } else {
+                    reply.extend_from_slice(&[IAC, DONT, opt]);
+                }
+            }
+            WONT if self.remote[idx] => {
+                self.remote[idx] = false;
+                reply.extend_from_slice(&[IAC, DONT, opt]);
+            }
+            _ => {}
+        }
+    }
+
+    fn subnegotiate(&mut self, reply: &mut Vec<u8>) {
+        // TTYPE SEND -> TTYPE IS <type>
+        if self.sb_buf.first() == Some(&OPT_TTYPE) && self.sb_buf.get(1) == Some(&TTYPE_SEND) {
+            reply.extend_from_slice(&[IAC, SB, OPT_TTYPE, TTYPE_IS]);
+            reply.extend_from_slice(self.terminal_type.as_bytes());
+            reply.extend_from_slice(&[IAC, SE]);
+        }
+        self.sb_buf.clear();
+    }
+
+    /// 窗口变化：更新尺寸，若 NAWS 已协商成功则返回需要发送的子协商
+    pub fn resize(&mut self, cols: u16, rows: u16) -> Option<Vec<u8>> {
+        self.cols = cols;
+        self.rows = rows;
+        if self.local[OPT_NAWS as usize] {
+            Some(self.naws_bytes())
+        } else {
+            None
+        }
+    }
+
+    fn naws_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![IAC, SB, OPT_NAWS];
+        for v in [self.cols, self.rows] {
+            for b in v.to_be_bytes() {
+                // 负载中的 0xFF 需要转义
+                bytes.push(b);
+                if b == IAC {
+                    bytes.push(IAC);
+                }
+            }
+        }
+        bytes.extend_from_slice(&[IAC, SE]);
+        bytes
+    }
+
+    /// 用户输入编码：转义 0xFF，回车按 NVT 约定发送 CR LF
+    pub fn encode_input(data: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(data.len() + 4);
+        let mut iter = data.iter().peekable();
+        while let Some(&b) = iter.next() {
+            match b {
+                IAC => out.extend_from_slice(&[IAC, IAC]),
+                b'\r' => {
+                    out.extend_from_slice(b"\r\n");
+                    if iter.peek() == Some(&&b'\n') {
+                        iter.next();
+                    }
+                }
+                _ => out.push(b),
+            }
+        }
+        out
+    }
+}
+
+// ==============================================================================
+// 登录脚本 (expect / send)
+// ==============================================================================
+
+/// 登录脚本执行器
+/// 在最近的输出中等待 expect 出现 (不区分大小写)，命中后返回要发送的内容并推进到下一步
+pub struct LoginScriptRunner {
+    steps: Vec<(String, String)>,
+    current: usize,
+    window: String,
+}
+
+// 只保留最近的输出用于匹配提示符
+const LOGIN_WINDOW_LEN: usize = 2048;
+
+impl LoginScriptRunner {
+    /// steps: (expect, send)，send 已替换好占位符
+    pub fn new(steps: Vec<(String, String)>) -> Self {
+        Self {
+            steps,
+            current: 0,
+            window: String::new(),
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.current >= self.steps.len()
+    }
+
+    pub fn feed(&mut self, data: &[u8]) -> Vec<String> {
+        let mut to_send = Vec::new();
+        if self.is_done() {
+            return to_send;
+        }
+
+        self.window.push_str(&String::from_utf8_lossy(data).to_lowercase());
+        if self.window.len() > LOGIN_WINDOW_LEN {
+            let mut cut = self.window.len() - LOGIN_WINDOW_LEN;
+            while !self.window.is_char_boundary(cut) {
+                cut += 1;
+            }
+            self.window.drain(..cut);
+        }
+
+        while let Some((expect, send)) = self.steps.get(self.current) {
+            let needle = expect.to_lowercase();
+            match self.window.find(&needle) {
+                Some(pos) => {
+                    to_send.push(send.clone());
+                    self.window.drain(..pos + needle.len());
+                    self.current += 1;
+                }
+                None => break,
+            }
+        }
+
+        to_send
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn do_ttype_replies_will_then_answers_send() {
+        let mut codec = TelnetCodec::new("XTERM", 80, 24);
+
+        let out = codec.decode(&[IAC, DO, OPT_TTYPE]);
+        assert!(out.data.is_empty());
+        assert_eq!(out.reply, vec![IAC, WILL, OPT_TTYPE]);
+
+        // 重复的 DO 不再应答，避免协商循环
+        assert!(codec.decode(&[IAC, DO, OPT_TTYPE]).reply.is_empty());
+
+        let out = codec.decode(&[IAC, SB, OPT_TTYPE, TTYPE_SEND, IAC, SE]);
+        let mut expected = vec![IAC, SB, OPT_TTYPE, TTYPE_IS];
+        expected.extend_from_slice(b"XTERM");
+        expected.extend_from_slice(&[IAC, SE]);
+        assert_eq!(out.reply, expected);
+    }
+
+    #[test]
+    fn will_accepts_echo_and_refuses_unknown() {
+        let mut codec = TelnetCodec::new("XTERM", 80, 24);
+        assert_eq!(codec.decode(&[IAC, WILL, OPT_ECHO]).reply, vec![IAC, DO, OPT_ECHO]);
+        assert_eq!(codec.decode(&[IAC, WILL, 42]).reply, vec![IAC, DONT, 42]);
+        assert_eq!(codec.decode(&[IAC, DO, 42]).reply, vec![IAC, WONT, 42]);
+        assert_eq!(codec.decode(&[IAC, WONT, OPT_ECHO]).reply, vec![IAC, DONT, OPT_ECHO]);
+    }
+
+    #[test]
+    fn naws_reported_after_do_and_on_resize() {
+        let mut codec = TelnetCodec::new("XTERM", 80, 24);
+        // 协商前窗口变化不发送
+        assert_eq!(codec.resize(100, 30), None);
+
+        let out = codec.decode(&[IAC, DO, OPT_NAWS]);
+        assert_eq!(
+            out.reply,
+            vec![IAC, WILL, OPT_NAWS, IAC, SB, OPT_NAWS, 0, 100, 0, 30, IAC, SE]
+        );
+
+        assert_eq!(
+            codec.resize(132, 43),
+            Some(vec![IAC, SB, OPT_NAWS, 0, 132, 0, 43, IAC, SE])
+        );
+        // 负载中的 0xFF 需要转义
+        assert_eq!(
+            codec.resize(255, 24),
+            Some(vec![IAC, SB, OPT_NAWS, 0, IAC, IAC, 0, 24, IAC, SE])
+        );
+    }
+
+    #[test]
+    fn escaped_iac_split_across_reads() {
+        let mut codec = TelnetCodec::new("XTERM", 80, 24);
+        let first = codec.decode(&[b'a', IAC]);
+        assert_eq!(first.data, b"a");
+        let second = codec.decode(&[IAC, b'b']);
+        assert_eq!(second.data, vec![IAC, b'b']);
+        assert!(first.reply.is_empty() && second.reply.is_empty());
+    }
+
+    #[test]
+    fn negotiation_split_across_reads() {
+        let mut codec = TelnetCodec::new("XTERM", 80, 24);
+        assert!(codec.decode(&[b'x', IAC, DO]).reply.is_empty());
+        let out = codec.decode(&[OPT_SGA, b'y']);
+        assert_eq!(out.reply, vec![IAC, WILL, OPT_SGA]);
+        assert_eq!(out.data, b"y");
+    }
+
+    #[test]
+    fn encode_input_escapes_iac_and_sends_crlf() {
+        assert_eq!(TelnetCodec::encode_input(&[b'a', IAC]), vec![b'a', IAC, IAC]);
+        assert_eq!(TelnetCodec::encode_input(b"ls\r"), b"ls\r\n");
+        assert_eq!(TelnetCodec::encode_input(b"ls\r\n"), b"ls\r\n");
+    }
+
+    #[test]
+    fn login_script_matches_prompts_split_across_chunks() {
+        let mut runner = LoginScriptRunner::new(vec![
+            ("login:".to_string(), "admin\r".to_string()),
+            ("password:".to_string(), "secret\r".to_string()),
+        ]);
+
+        assert!(runner.feed(b"Welcome\r\nLog").is_empty());
+        assert_eq!(runner.feed(b"in: "), vec!["admin\r".to_string()]);
+        assert!(runner.feed(b"admin\r\nPass").is_empty());
+        assert!(!runner.is_done());
+        assert_eq!(runner.feed(b"WORD: "), vec!["secret\r".to_string()]);
+        assert!(runner.is_done());
+        assert!(runner.feed(b"login: ").is_empty());
+    }
+
+    #[test]
+    fn login_script_runs_several_steps_from_one_chunk() {
+        let mut runner = LoginScriptRunner::new(vec![
+            ("user".to_string(), "a".to_string()),
+            ("pass".to_string(), "b".to_string()),
+        ]);
+        assert_eq!(runner.feed(b"user? pass?"), vec!["a".to_string(), "b".to_string()]);
+        assert!(runner.is_done());
+    }
+}
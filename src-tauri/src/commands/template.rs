@@ -0,0 +1,215 @@
+// src-tauri/src/commands/template.rs
+//
+// 服务器模板：增删改查，以及连接时按服务器的覆盖字段解析出要套用的模板值。
+// 模板的值不写入 servers 表，而是在 ssh::fetch_server_row 中合并，
+// 所以在模板上更换密钥后，所有引用它的服务器下次连接即使用新密钥。
+use sqlx::{sqlite::SqliteRow, Pool, Row, Sqlite};
+use tauri::{command, State};
+
+use crate::error::{AppError, AppResult};
+use crate::models::template::{ServerTemplate, TemplateField};
+use crate::models::{AuthType, StartupCommand};
+use crate::state::AppState;
+
+fn map_template_row(row: &SqliteRow) -> ServerTemplate {
+    let startup_commands: Option<Vec<StartupCommand>> = row
+        .try_get::<Option<String>, _>("startup_commands")
+        .ok()
+        .flatten()
+        .and_then(|s| serde_json::from_str(&s).ok());
+
+    ServerTemplate {
+        id: row.get("id"),
+        name: row.get("name"),
+        description: row.try_get("description").ok().flatten(),
+        auth_type: row.try_get::<Option<AuthType>, _>("auth_type").ok().flatten(),
+        password_id: row.try_get("password_id").ok().flatten(),
+        key_id: row.try_get("key_id").ok().flatten(),
+        port: row.try_get("port").ok().flatten(),
+        proxy_id: row.try_get("proxy_id").ok().flatten(),
+        connect_timeout: row.try_get("connect_timeout").ok().flatten(),
+        keep_alive_interval: row.try_get("keep_alive_interval").ok().flatten(),
+        startup_commands,
+        created_at: row.try_get("created_at").unwrap_or_default(),
+        updated_at: row.try_get("updated_at").unwrap_or_default(),
+    }
+}
+
+pub(crate) async fn fetch_template(pool: &Pool<Sqlite>, id: &str) -> AppResult<Option<ServerTemplate>> {
+    Ok(sqlx::query("SELECT * FROM server_templates WHERE id = ?")
+        .bind(id)
+        .fetch_optional(pool)
+        .await?
+        .map(|row| map_template_row(&row)))
+}
+
+/// 服务器连接时要套用的模板值 (已去掉服务器覆盖的字段)
+/// 没有引用模板、或模板已不存在时返回 None
+pub(crate) async fn resolve_server_template(
+    pool: &Pool<Sqlite>,
+    template_id: Option<&str>,
+    overrides_json: Option<&str>,
+) -> AppResult<Option<ServerTemplate>> {
+    let Some(template_id) = template_id else {
+        return Ok(None);
+    };
+    let overrides: Vec<TemplateField> = overrides_json
+        .and_then(|s| serde_json::from_str(s).ok())
+        .unwrap_or_default();
+    Ok(fetch_template(pool, template_id)
+        .await?
+        .map(|t| t.without_overrides(&overrides)))
+}
+
+// 空字符串视为未设置，认证方式与凭据必须匹配
+fn normalize_template(mut template: ServerTemplate) -> AppResult<ServerTemplate> {
+    let clean = |v: Option<String>| v.filter(|s| !s.trim().is_empty());
+    template.name = template.name.trim().to_string();
+    template.description = clean(template.description);
+    template.password_id = clean(template.password_id);
+    template.key_id = clean(template.key_id);
+    template.proxy_id = clean(template.proxy_id);
+
+    if template.name.is_empty() {
+        return Err(AppError::InvalidInput("Template name is required".to_string()));
+    }
+    if template.port == Some(0) {
+        return Err(AppError::InvalidInput("Port must be between 1 and 65535".to_string()));
+    }
+
+    match template.auth_type {
+        Some(AuthType::Password) => {
+            template.key_id = None;
+            if template.password_id.is_none() {
+                return Err(AppError::InvalidInput("Choose a vault password for the template".to_string()));
+            }
+        }
+        Some(AuthType::PrivateKey) => {
+            template.password_id = None;
+            if template.key_id.is_none() {
+                return Err(AppError::InvalidInput("Choose a vault key for the template".to_string()));
+            }
+        }
+        Some(AuthType::Agent) | None => {
+            template.password_id = None;
+            template.key_id = None;
+        }
+    }
+    Ok(template)
+}
+
+#[command]
+pub async fn list_templates(state: State<'_, AppState>) -> AppResult<Vec<ServerTemplate>> {
+    let rows = sqlx::query("SELECT * FROM server_templates ORDER BY name COLLATE NOCASE ASC")
+        .fetch_all(&state.db)
+        .await?;
+    Ok(rows.iter().map(map_template_row).collect())
+}
+
+/// 新建或更新模板 (id 为空时新建)
+#[command]
+pub async fn save_template(state: State<'_, AppState>, template: ServerTemplate) -> AppResult<ServerTemplate> {
+    let pool = &state.db;
+    let mut template = normalize_template(template)?;
+    let now = chrono::Utc::now().timestamp_millis();
+    if template.id.trim().is_empty() {
+        template.id = uuid::Uuid::new_v4().to_string();
+        template.created_at = now;
+    } else if let Some(existing) = fetch_template(pool, &template.id).await? {
+        template.created_at = existing.created_at;
+    } else {
+        return Err(AppError::NotFound(format!("Template not found: {}", template.id)));
+    }
+    template.updated_at = now;
+
+    let startup_json = template
+        .startup_commands
+        .as_ref()
+        .and_then(|c| serde_json::to_string(c).ok());
+
+    sqlx::query(
+        "INSERT OR REPLACE INTO server_templates (
+            id, name, description, auth_type, password_id, key_id, port, proxy_id,
+            connect_timeout, keep_alive_interval, startup_commands, created_at, updated_at
+        ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(&template.id)
+    .bind(&template.name)
+    .bind(&template.description)
+    .bind(&template.auth_type)
+    .bind(&template.password_id)
+    .bind(&template.key_id)
+    .bind(template.port)
+    .bind(&template.proxy_id)
+    .bind(template.connect_timeout)
+    .bind(template.keep_alive_interval)
+    .bind(startup_json)
+    .bind(template.created_at)
+    .bind(template.updated_at)
+    .execute(pool)
+    .await?;
+
+    Ok(template)
+}
+
+/// 删除模板：引用它的服务器先把当前生效的模板值写回自身，再解除引用，
+/// 保证删除后连接行为不变
+#[command]
+pub async fn delete_template(state: State<'_, AppState>, id: String) -> AppResult<()> {
+    let pool = &state.db;
+    let template = fetch_template(pool, &id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Template not found: {}", id)))?;
+
+    let servers: Vec<(String, Option<String>)> =
+        sqlx::query_as("SELECT id, template_overrides FROM servers WHERE template_id = ?")
+            .bind(&id)
+            .fetch_all(pool)
+            .await?;
+
+    let now = chrono::Utc::now().timestamp_millis();
+    let mut tx = pool.begin().await?;
+    for (server_id, overrides_json) in servers {
+        let overrides: Vec<TemplateField> = overrides_json
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        let applied = template.clone().without_overrides(&overrides);
+        let startup_json = applied
+            .startup_commands
+            .as_ref()
+            .and_then(|c| serde_json::to_string(c).ok());
+
+        sqlx::query(
+            "UPDATE servers SET
+                auth_type = COALESCE(?1, auth_type),
+                password_id = CASE WHEN ?1 IS NULL THEN password_id ELSE ?2 END,
+                key_id = CASE WHEN ?1 IS NULL THEN key_id ELSE ?3 END,
+                port = COALESCE(?4, port),
+                proxy_id = COALESCE(?5, proxy_id),
+                connection_type = CASE WHEN ?5 IS NULL THEN connection_type ELSE 'proxy' END,
+                connect_timeout = COALESCE(?6, connect_timeout),
+                keep_alive_interval = COALESCE(?7, keep_alive_interval),
+                startup_commands = COALESCE(?8, startup_commands),
+                template_id = NULL, template_overrides = NULL, updated_at = ?9
+             WHERE id = ?10",
+        )
+        .bind(&applied.auth_type)
+        .bind(&applied.password_id)
+        .bind(&applied.key_id)
+        .bind(applied.port)
+        .bind(&applied.proxy_id)
+        .bind(applied.connect_timeout)
+        .bind(applied.keep_alive_interval)
+        .bind(startup_json)
+        .bind(now)
+        .bind(&server_id)
+        .execute(&mut *tx)
+        .await?;
+    }
+    sqlx::query("DELETE FROM server_templates WHERE id = ?")
+        .bind(&id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+    Ok(())
+}
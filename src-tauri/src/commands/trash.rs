@@ -0,0 +1,175 @@
+// src-tauri/src/commands/trash.rs
+//
+// 回收站：服务器、代码片段、保险库条目删除时只记录 deleted_at (软删除)，可以恢复；
+// 超过保留天数 (AppSettings.trash) 的条目由后台任务每天彻底清除，也可以手动清除。
+// 密钥的使用记录 (key_usages)、服务器的扫描结果 (server_health) 与连接记录 (connection_events) 在彻底清除时才删除。
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Sqlite};
+use tauri::{AppHandle, Manager, State};
+
+use crate::commands::settings::SettingsState;
+use crate::error::{AppError, AppResult};
+use crate::state::AppState;
+
+const PURGE_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum TrashKind {
+    Server,
+    Snippet,
+    Key,
+}
+
+impl TrashKind {
+    fn table(&self) -> &'static str {
+        match self {
+            TrashKind::Server => "servers",
+            TrashKind::Snippet => "snippets",
+            TrashKind::Key => "vault_keys",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashItem {
+    pub kind: TrashKind,
+    pub id: String,
+    pub name: String,
+    /// 服务器为 IP，片段为语言，保险库条目为类型
+    pub detail: Option<String>,
+    pub deleted_at: i64,
+}
+
+/// 软删除：记录删除时间 (已在回收站中的条目保持原删除时间)
+pub(crate) async fn move_to_trash(pool: &Pool<Sqlite>, kind: TrashKind, id: &str) -> AppResult<()> {
+    let sql = format!(
+        "UPDATE {} SET deleted_at = ? WHERE id = ? AND deleted_at IS NULL",
+        kind.table()
+    );
+    sqlx::query(&sql)
+        .bind(chrono::Utc::now().timestamp_millis())
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+// 彻底删除一条记录及其附属数据
+async fn purge_item(pool: &Pool<Sqlite>, kind: TrashKind, id: &str) -> AppResult<()> {
+    let mut tx = pool.begin().await?;
+    match kind {
+        TrashKind::Server => {
+            sqlx::query("DELETE FROM server_health WHERE server_id = ?")
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+            sqlx::query("DELETE FROM key_usages WHERE server_id = ?")
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+            sqlx::query("DELETE FROM connection_events WHERE server_id = ?")
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        TrashKind::Key => {
+            sqlx::query("DELETE FROM key_usages WHERE key_id = ?")
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        TrashKind::Snippet => {}
+    }
+    let sql = format!("DELETE FROM {} WHERE id = ? AND deleted_at IS NOT NULL", kind.table());
+    sqlx::query(&sql).bind(id).execute(&mut *tx).await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+async fn fetch_trash(pool: &Pool<Sqlite>) -> AppResult<Vec<TrashItem>> {
+    let items = sqlx::query_as::<_, TrashItem>(
+        "SELECT 'server' AS kind, id, name, ip AS detail, deleted_at FROM servers WHERE deleted_at IS NOT NULL
+         UNION ALL
+         SELECT 'snippet', id, title, language, deleted_at FROM snippets WHERE deleted_at IS NOT NULL
+         UNION ALL
+         SELECT 'key', id, name, key_type, deleted_at FROM vault_keys WHERE deleted_at IS NOT NULL
+         ORDER BY deleted_at DESC",
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(items)
+}
+
+/// 清除删除时间早于 retention_days 天前的条目，返回清除数量
+pub(crate) async fn purge_expired(pool: &Pool<Sqlite>, retention_days: u32) -> AppResult<usize> {
+    if retention_days == 0 {
+        return Ok(0);
+    }
+    let cutoff = chrono::Utc::now().timestamp_millis() - retention_days as i64 * DAY_MS;
+    let expired: Vec<TrashItem> = fetch_trash(pool)
+        .await?
+        .into_iter()
+        .filter(|item| item.deleted_at < cutoff)
+        .collect();
+    for item in &expired {
+        purge_item(pool, item.kind, &item.id).await?;
+    }
+    Ok(expired.len())
+}
+
+/// 启动后台清理：立即执行一次，之后每天一次
+pub fn spawn_purger(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let retention_days = app.state::<SettingsState>().snapshot().trash.retention_days;
+            match purge_expired(&app.state::<AppState>().db, retention_days).await {
+                Ok(0) => {}
+                Ok(n) => println!("[Trash] Purged {} expired item(s)", n),
+                Err(e) => eprintln!("[Trash] Purge failed: {}", e),
+            }
+            tokio::time::sleep(PURGE_INTERVAL).await;
+        }
+    });
+}
+
+/// 回收站中的全部条目，最近删除的在前
+#[tauri::command]
+pub async fn list_trash(state: State<'_, AppState>) -> AppResult<Vec<TrashItem>> {
+    fetch_trash(&state.db).await
+}
+
+/// 从回收站恢复
+#[tauri::command]
+pub async fn restore_from_trash(state: State<'_, AppState>, kind: TrashKind, id: String) -> AppResult<()> {
+    let sql = format!(
+        "UPDATE {} SET deleted_at = NULL WHERE id = ? AND deleted_at IS NOT NULL",
+        kind.table()
+    );
+    let result = sqlx::query(&sql).bind(&id).execute(&state.db).await?;
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound(format!("{} is not in the trash", id)));
+    }
+    Ok(())
+}
+
+/// 彻底删除回收站中的一个条目
+#[tauri::command]
+pub async fn purge_from_trash(state: State<'_, AppState>, kind: TrashKind, id: String) -> AppResult<()> {
+    purge_item(&state.db, kind, &id).await
+}
+
+/// 清空回收站，返回清除数量
+#[tauri::command]
+pub async fn empty_trash(state: State<'_, AppState>) -> AppResult<usize> {
+    let items = fetch_trash(&state.db).await?;
+    for item in &items {
+        purge_item(&state.db, item.kind, &item.id).await?;
+    }
+    Ok(items.len())
+}
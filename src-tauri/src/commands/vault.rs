@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::sync::Mutex;
 use tauri::{State, command};
 use aes_gcm::{
@@ -12,6 +13,11 @@ use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
 use serde::{Deserialize, Serialize};
 use sqlx::{Pool, Sqlite, Row, FromRow}; // 🟢 确保引入 FromRow
 use crate::state::AppState;
+use crate::error::{AppError, AppResult};
+use crate::commands::trash::{move_to_trash, TrashKind};
+use crate::commands::auto_lock::VaultActivity;
+use crate::commands::folder::folder_subtree;
+use crate::commands::ssh::fetch_server_row;
 use chrono::Utc; 
 
 // --- 常量 ---
@@ -21,6 +27,13 @@ const AUTH_CHECK_TEXT: &[u8] = b"VALID_PASSWORD_CHECK";
 
 pub struct VaultState(pub Mutex<Option<Key<Aes256Gcm>>>);
 
+impl VaultState {
+    /// 取出主密钥，未解锁时返回 VaultLocked
+    pub fn master_key(&self) -> AppResult<Key<Aes256Gcm>> {
+        self.0.lock().unwrap().as_ref().cloned().ok_or(AppError::VaultLocked)
+    }
+}
+
 #[derive(Serialize)]
 pub struct VaultStatus {
     pub is_initialized: bool,
@@ -122,39 +135,44 @@ fn get_key_fingerprint(key: &Key<Aes256Gcm>) -> String {
 // 核心工具函数 (加密/解密)
 // =========================================================
 
-fn derive_key(password: &str, salt: &[u8]) -> Key<Aes256Gcm> {
+pub(crate) fn derive_key(password: &str, salt: &[u8]) -> Key<Aes256Gcm> {
     let mut key = [0u8; 32]; 
     let _ = pbkdf2::<Hmac<Sha256>>(password.as_bytes(), salt, 100_000, &mut key);
     *Key::<Aes256Gcm>::from_slice(&key)
 }
 
-fn encrypt_data(key: &Key<Aes256Gcm>, plaintext: &[u8]) -> Result<String, String> {
+fn encrypt_data(key: &Key<Aes256Gcm>, plaintext: &[u8]) -> AppResult<String> {
+    encrypt_with_salt(key, "", plaintext)
+}
+
+/// 加密并在结果中记录派生密钥所用的盐 (导出文件等脱离数据库的场景)
+pub(crate) fn encrypt_with_salt(key: &Key<Aes256Gcm>, salt: &str, plaintext: &[u8]) -> AppResult<String> {
     let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
     let cipher = Aes256Gcm::new(key);
     let ciphertext = cipher.encrypt(&nonce, plaintext)
-        .map_err(|e| format!("Encryption failed: {}", e))?;
+        .map_err(|e| AppError::Crypto(format!("Encryption failed: {}", e)))?;
 
     let json = serde_json::to_string(&EncryptedData {
         iv: BASE64.encode(nonce),
         data: BASE64.encode(ciphertext),
-        salt: "".to_string(), 
-    }).map_err(|e| e.to_string())?;
+        salt: salt.to_string(),
+    }).map_err(|e| AppError::Crypto(e.to_string()))?;
     
     Ok(json)
 }
 
-fn decrypt_data(key: &Key<Aes256Gcm>, json_str: &str) -> Result<Vec<u8>, String> {
+fn decrypt_data(key: &Key<Aes256Gcm>, json_str: &str) -> AppResult<Vec<u8>> {
     let enc_data: EncryptedData = serde_json::from_str(json_str)
-        .map_err(|e| format!("Invalid encrypted format: {}", e))?;
+        .map_err(|e| AppError::Crypto(format!("Invalid encrypted format: {}", e)))?;
 
-    let nonce_bytes = BASE64.decode(&enc_data.iv).map_err(|_| "Invalid IV".to_string())?;
-    let ciphertext_bytes = BASE64.decode(&enc_data.data).map_err(|_| "Invalid Ciphertext".to_string())?;
+    let nonce_bytes = BASE64.decode(&enc_data.iv).map_err(|_| AppError::Crypto("Invalid IV".to_string()))?;
+    let ciphertext_bytes = BASE64.decode(&enc_data.data).map_err(|_| AppError::Crypto("Invalid Ciphertext".to_string()))?;
     
     let nonce = Nonce::from_slice(&nonce_bytes);
     let cipher = Aes256Gcm::new(key);
 
     cipher.decrypt(nonce, ciphertext_bytes.as_ref())
-        .map_err(|_| "Decryption failed".to_string())
+        .map_err(|_| AppError::Crypto("Decryption failed".to_string()))
 }
 
 // =========================================================
@@ -166,7 +184,7 @@ pub async fn internal_record_usage(
     pool: &Pool<Sqlite>,
     key_id: &str,
     server_id: &str
-) -> Result<(), String> {
+) -> AppResult<()> {
     let now = Utc::now().timestamp_millis();
     
     // 使用 INSERT OR REPLACE 确保更新最后使用时间
@@ -178,20 +196,23 @@ pub async fn internal_record_usage(
     .bind(now)
     .execute(pool)
     .await
-    .map_err(|e| format!("Failed to record key usage: {}", e))?;
+    .map_err(|e| AppError::Db(format!("Failed to record key usage: {}", e)))?;
 
     Ok(())
 }
 
-pub async fn internal_add_secret(
-    pool: &Pool<Sqlite>,
+pub async fn internal_add_secret<'e, E>(
+    executor: E,
     master_key: &Key<Aes256Gcm>,
     name: &str,
     key_type: &str,
     content: &str,
     username: Option<String>,
     algorithm: Option<String>,
-) -> Result<String, String> {
+) -> AppResult<String>
+where
+    E: sqlx::Executor<'e, Database = Sqlite>,
+{
     println!("🔐 [Internal Add] Encrypting with Key Fingerprint: {}", get_key_fingerprint(master_key));
 
     let encrypted_json = encrypt_data(master_key, content.as_bytes())?;
@@ -211,47 +232,67 @@ pub async fn internal_add_secret(
     .bind(algorithm)
     .bind(now)
     .bind(now)
-    .execute(pool)
+    .execute(executor)
     .await
-    .map_err(|e| format!("Database error: {}", e))?;
+    .map_err(|e| AppError::Db(format!("Database error: {}", e)))?;
 
     println!("✅ [Internal Add] Secret saved with ID: {}", new_id);
     Ok(new_id)
 }
 
-pub async fn internal_get_secret(
-    pool: &Pool<Sqlite>,
+pub async fn internal_get_secret<'e, E>(
+    executor: E,
     master_key: &Key<Aes256Gcm>,
     id: &str,
-) -> Result<String, String> {
+) -> AppResult<String>
+where
+    E: sqlx::Executor<'e, Database = Sqlite>,
+{
     let row = sqlx::query("SELECT encrypted_content FROM vault_keys WHERE id = ?")
         .bind(id)
-        .fetch_optional(pool)
-        .await
-        .map_err(|e| e.to_string())?;
+        .fetch_optional(executor)
+        .await?;
 
     match row {
         Some(r) => {
             let enc_json: String = r.get(0);
             let plaintext = decrypt_data(master_key, &enc_json)?;
-            String::from_utf8(plaintext).map_err(|_| "Invalid UTF-8 content".to_string())
+            String::from_utf8(plaintext).map_err(|_| AppError::Crypto("Invalid UTF-8 content".to_string()))
         }
-        None => Err("Secret not found".to_string()),
+        None => Err(AppError::NotFound(format!("Secret not found: {}", id))),
     }
 }
 
+/// 重新加密并覆盖已有凭据的内容
+pub(crate) async fn internal_update_secret<'e, E>(
+    executor: E,
+    master_key: &Key<Aes256Gcm>,
+    id: &str,
+    content: &str,
+) -> AppResult<()>
+where
+    E: sqlx::Executor<'e, Database = Sqlite>,
+{
+    let encrypted_json = encrypt_data(master_key, content.as_bytes())?;
+    let result = sqlx::query("UPDATE vault_keys SET encrypted_content = ?, updated_at = ? WHERE id = ?")
+        .bind(&encrypted_json)
+        .bind(Utc::now().timestamp_millis())
+        .bind(id)
+        .execute(executor)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound(format!("Secret not found: {}", id)));
+    }
+    Ok(())
+}
+
 // =========================================================
 // Tauri Commands
 // =========================================================
 
-#[command]
-pub async fn check_key_associations(
-    state: State<'_, AppState>,
-    id: String
-) -> Result<KeyUsageStats, String> {
-    let pool = &state.db;
-
-    let associations = sqlx::query_as::<_, KeyAssociation>(
+// 引用该凭据的服务器 (直接引用或通过模板引用，不含回收站中的服务器)
+async fn fetch_key_associations(pool: &Pool<Sqlite>, id: &str) -> AppResult<Vec<KeyAssociation>> {
+    let mut associations = sqlx::query_as::<_, KeyAssociation>(
         r#"
         SELECT 
             s.id as server_id, 
@@ -259,16 +300,71 @@ pub async fn check_key_associations(
             ku.last_used_at
         FROM servers s
         LEFT JOIN key_usages ku ON s.id = ku.server_id AND ku.key_id = ?
-        WHERE s.password_id = ? OR s.key_id = ?
+        WHERE s.deleted_at IS NULL
+          AND (s.password_id = ? OR s.key_id = ?
+               OR s.template_id IN (SELECT id FROM server_templates WHERE password_id = ? OR key_id = ?))
         ORDER BY ku.last_used_at DESC
         "#
     )
-    .bind(&id)
-    .bind(&id)
-    .bind(&id)
+    .bind(id)
+    .bind(id)
+    .bind(id)
+    .bind(id)
+    .bind(id)
     .fetch_all(pool)
-    .await
-    .map_err(|e| e.to_string())?;
+    .await?;
+
+    // 文件夹默认凭据：展开到子文件夹中实际继承该凭据的服务器
+    let folder_ids: Vec<String> = sqlx::query_scalar(
+        "SELECT id FROM server_folders WHERE default_password_id = ? OR default_key_id = ?",
+    )
+    .bind(id)
+    .bind(id)
+    .fetch_all(pool)
+    .await?;
+    let mut folders = HashSet::new();
+    for folder_id in &folder_ids {
+        folders.extend(folder_subtree(pool, folder_id).await?);
+    }
+    if folders.is_empty() {
+        return Ok(associations);
+    }
+
+    let members: Vec<(String, String, Option<i64>, Option<String>)> = sqlx::query_as(
+        r#"
+        SELECT s.id, s.name, ku.last_used_at, s.folder_id
+        FROM servers s
+        LEFT JOIN key_usages ku ON s.id = ku.server_id AND ku.key_id = ?
+        WHERE s.deleted_at IS NULL AND s.folder_id IS NOT NULL
+        "#
+    )
+    .bind(id)
+    .fetch_all(pool)
+    .await?;
+    let known: HashSet<String> = associations.iter().map(|a| a.server_id.clone()).collect();
+    for (server_id, server_name, last_used_at, folder_id) in members {
+        let in_folder = folder_id.is_some_and(|f| folders.contains(&f));
+        if !in_folder || known.contains(&server_id) {
+            continue;
+        }
+        // 与连接时相同的继承规则：服务器自身或模板的凭据优先，离得最近的文件夹优先
+        let row = fetch_server_row(pool, &server_id).await?;
+        let password_id: Option<String> = row.try_get("password_id").ok().flatten();
+        let key_id: Option<String> = row.try_get("key_id").ok().flatten();
+        if password_id.as_deref() == Some(id) || key_id.as_deref() == Some(id) {
+            associations.push(KeyAssociation { server_id, server_name, last_used_at });
+        }
+    }
+    associations.sort_by_key(|a| std::cmp::Reverse(a.last_used_at));
+    Ok(associations)
+}
+
+#[command]
+pub async fn check_key_associations(
+    state: State<'_, AppState>,
+    id: String
+) -> AppResult<KeyUsageStats> {
+    let associations = fetch_key_associations(&state.db, &id).await?;
 
     Ok(KeyUsageStats {
         key_id: id,
@@ -281,13 +377,12 @@ pub async fn check_key_associations(
 pub async fn get_vault_status(
     state: State<'_, AppState>,
     vault_state: State<'_, VaultState>
-) -> Result<VaultStatus, String> {
+) -> AppResult<VaultStatus> {
     let pool = &state.db;
     
     let initialized = sqlx::query("SELECT 1 FROM vault_config WHERE key = 'vault_salt'")
         .fetch_optional(pool)
-        .await
-        .map_err(|e| e.to_string())?
+        .await?
         .is_some();
 
     let has_key = vault_state.0.lock().unwrap().is_some();
@@ -302,18 +397,18 @@ pub async fn get_vault_status(
 pub async fn init_vault(
     state: State<'_, AppState>,
     vault_state: State<'_, VaultState>,
+    activity: State<'_, VaultActivity>,
     password: String
-) -> Result<(), String> {
+) -> AppResult<()> {
     let pool = &state.db;
 
     let exists = sqlx::query("SELECT 1 FROM vault_config WHERE key = 'vault_salt'")
         .fetch_optional(pool)
-        .await
-        .map_err(|e| e.to_string())?
+        .await?
         .is_some();
 
     if exists {
-        return Err("Vault is already initialized".to_string());
+        return Err(AppError::InvalidInput("Vault is already initialized".to_string()));
     }
 
     let mut salt_bytes = [0u8; 16];
@@ -323,13 +418,15 @@ pub async fn init_vault(
     let key = derive_key(&password, &salt_bytes);
     let auth_check_json = encrypt_data(&key, AUTH_CHECK_TEXT)?;
 
-    let mut tx = pool.begin().await.map_err(|e| e.to_string())?;
+    let mut tx = pool.begin().await?;
     sqlx::query("INSERT INTO vault_config (key, value) VALUES (?, ?)")
-        .bind("vault_salt").bind(&salt_str).execute(&mut *tx).await.map_err(|e| e.to_string())?;
+        .bind("vault_salt").bind(&salt_str).execute(&mut *tx).await?;
     sqlx::query("INSERT INTO vault_config (key, value) VALUES (?, ?)")
-        .bind("auth_check").bind(&auth_check_json).execute(&mut *tx).await.map_err(|e| e.to_string())?;
-    tx.commit().await.map_err(|e| e.to_string())?;
+        .bind("auth_check").bind(&auth_check_json).execute(&mut *tx).await?;
+    tx.commit().await?;
 
+    migrate_server_secrets(pool, &key).await;
+    activity.touch();
     *vault_state.0.lock().unwrap() = Some(key);
     Ok(())
 }
@@ -338,31 +435,51 @@ pub async fn init_vault(
 pub async fn unlock_vault(
     state: State<'_, AppState>,
     vault_state: State<'_, VaultState>,
+    activity: State<'_, VaultActivity>,
     password: String
-) -> Result<bool, String> {
-    let pool = &state.db;
+) -> AppResult<bool> {
+    match internal_unlock(&state.db, &password).await? {
+        Some(key) => {
+            migrate_server_secrets(&state.db, &key).await;
+            activity.touch();
+            *vault_state.0.lock().unwrap() = Some(key);
+            Ok(true)
+        },
+        None => Ok(false)
+    }
+}
+
+// 旧版本遗留在 servers 表中的明文凭据需要主密钥才能加密，解锁时顺带迁移
+// 迁移失败不影响解锁，下次解锁时会再次尝试
+async fn migrate_server_secrets(pool: &Pool<Sqlite>, key: &Key<Aes256Gcm>) {
+    match crate::commands::server::migrate_plaintext_secrets(pool, key).await {
+        Ok(0) => {}
+        Ok(n) => println!("🔐 [Vault] Moved plaintext credentials of {} server(s) into the vault", n),
+        Err(e) => eprintln!("[Vault] Failed to migrate plaintext server credentials: {}", e),
+    }
+}
 
+/// 校验主密码并派生 Master Key，密码错误时返回 None
+/// 供 unlock_vault 与命令行 (无窗口) 模式共用
+pub async fn internal_unlock(pool: &Pool<Sqlite>, password: &str) -> AppResult<Option<Key<Aes256Gcm>>> {
     let salt_row = sqlx::query("SELECT value FROM vault_config WHERE key = 'vault_salt'")
-        .fetch_optional(pool).await.map_err(|e| e.to_string())?;
+        .fetch_optional(pool).await?;
     let auth_row = sqlx::query("SELECT value FROM vault_config WHERE key = 'auth_check'")
-        .fetch_optional(pool).await.map_err(|e| e.to_string())?;
+        .fetch_optional(pool).await?;
 
-    if salt_row.is_none() || auth_row.is_none() {
-        return Err("Vault not initialized".to_string());
-    }
+    let (Some(salt_row), Some(auth_row)) = (salt_row, auth_row) else {
+        return Err(AppError::VaultNotInitialized);
+    };
 
-    let salt_str: String = salt_row.unwrap().get(0);
-    let auth_check_str: String = auth_row.unwrap().get(0);
+    let salt_str: String = salt_row.get(0);
+    let auth_check_str: String = auth_row.get(0);
+
+    let salt_bytes = BASE64.decode(salt_str).map_err(|_| AppError::Crypto("Invalid Salt".to_string()))?;
+    let key = derive_key(password, &salt_bytes);
 
-    let salt_bytes = BASE64.decode(salt_str).map_err(|_| "Invalid Salt".to_string())?;
-    let key = derive_key(&password, &salt_bytes);
-    
     match decrypt_data(&key, &auth_check_str) {
-        Ok(decrypted) if decrypted == AUTH_CHECK_TEXT => {
-            *vault_state.0.lock().unwrap() = Some(key);
-            Ok(true)
-        },
-        _ => Ok(false) 
+        Ok(decrypted) if decrypted == AUTH_CHECK_TEXT => Ok(Some(key)),
+        _ => Ok(None)
     }
 }
 
@@ -380,11 +497,8 @@ pub async fn add_key(
     content: String,
     username: Option<String>,
     algorithm: Option<String> 
-) -> Result<KeyEntry, String> {
-    let master_key = {
-        let guard = vault_state.0.lock().unwrap();
-        guard.as_ref().cloned().ok_or("VAULT_LOCKED")?
-    };
+) -> AppResult<KeyEntry> {
+    let master_key = vault_state.master_key()?;
 
     let new_id = internal_add_secret(
         &state.db, 
@@ -412,33 +526,34 @@ pub async fn add_key(
     })
 }
 
+/// 移入回收站；仍被服务器引用时需要 force
 #[command]
 pub async fn delete_key(
     state: State<'_, AppState>, 
-    id: String
-) -> Result<(), String> {
+    id: String,
+    force: Option<bool>
+) -> AppResult<()> {
     let pool = &state.db;
 
-    // 1. 删除使用记录
-    sqlx::query("DELETE FROM key_usages WHERE key_id = ?")
-        .bind(&id)
-        .execute(pool)
-        .await
-        .map_err(|e| format!("Failed to delete key usage records: {}", e))?;
-
-    // 2. 删除密钥本体
-    sqlx::query("DELETE FROM vault_keys WHERE id = ?")
-        .bind(id)
-        .execute(pool)
-        .await
-        .map_err(|e| format!("Failed to delete key: {}", e))?;
+    // 1. 仍被服务器引用时拒绝删除，避免这些服务器无法连接
+    //    前端展示过关联服务器并经用户确认后传 force
+    let associations = fetch_key_associations(pool, &id).await?;
+    if !associations.is_empty() && !force.unwrap_or(false) {
+        let names: Vec<&str> = associations.iter().map(|a| a.server_name.as_str()).collect();
+        return Err(AppError::InvalidInput(format!(
+            "Key is still used by {} server(s): {}",
+            names.len(),
+            names.join(", ")
+        )));
+    }
 
-    Ok(())
+    // 2. 移入回收站 (使用记录保留到彻底清除时)
+    move_to_trash(pool, TrashKind::Key, &id).await
 }
 
 // 🟢 [核心修改] 关联查询使用记录
 #[command]
-pub async fn get_all_keys(state: State<'_, AppState>) -> Result<Vec<KeyEntry>, String> {
+pub async fn get_all_keys(state: State<'_, AppState>) -> AppResult<Vec<KeyEntry>> {
     let pool = &state.db;
 
     // 使用子查询获取每个密钥的“最新一条”使用记录
@@ -460,12 +575,12 @@ pub async fn get_all_keys(state: State<'_, AppState>) -> Result<Vec<KeyEntry>, S
             )
         ) ku_latest ON vk.id = ku_latest.key_id
         LEFT JOIN servers s ON ku_latest.server_id = s.id
+        WHERE vk.deleted_at IS NULL
         ORDER BY vk.created_at DESC
         "#
     )
     .fetch_all(pool)
-    .await
-    .map_err(|e| e.to_string())?;
+    .await?;
 
     // 将 Row 转换为嵌套的 KeyEntry 结构
     let keys = rows.into_iter().map(|row| {
@@ -501,11 +616,8 @@ pub async fn get_decrypted_content(
     state: State<'_, AppState>,
     vault_state: State<'_, VaultState>,
     id: String 
-) -> Result<String, String> {
+) -> AppResult<String> {
     let pool = &state.db;
-    let master_key = {
-        let guard = vault_state.0.lock().unwrap();
-        guard.as_ref().cloned().ok_or("VAULT_LOCKED")?
-    };
+    let master_key = vault_state.master_key()?;
     internal_get_secret(pool, &master_key, &id).await
 }
\ No newline at end of file
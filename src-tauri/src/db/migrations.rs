@@ -0,0 +1,722 @@
+// src-tauri/src/db/migrations.rs
+//
+// 版本化的数据库迁移
+// 每个迁移有递增的版本号，已执行的版本记录在 schema_version 表中；
+// 启动时在同一个事务里执行全部未执行的迁移，执行前用 VACUUM INTO 备份数据库。
+// 新增表或列时在 MIGRATIONS 末尾追加新的迁移，不要修改已发布的迁移。
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use sqlx::{Pool, Sqlite, SqliteConnection};
+
+pub enum Step {
+    Sql(&'static str),
+    /// 补列，列已存在时跳过
+    AddColumn {
+        table: &'static str,
+        column: &'static str,
+        definition: &'static str,
+    },
+}
+
+pub struct Migration {
+    pub version: i64,
+    pub description: &'static str,
+    pub steps: &'static [Step],
+}
+
+// 版本 1：引入版本表之前的全部表结构 (均为 IF NOT EXISTS，对旧库执行也安全)
+const V1_BASELINE: &[Step] = &[
+    // Vault 表 (Config & Keys)
+    Step::Sql(
+        "CREATE TABLE IF NOT EXISTS vault_config (
+            key TEXT PRIMARY KEY,
+            value TEXT NOT NULL
+        );",
+    ),
+    Step::Sql(
+        "CREATE TABLE IF NOT EXISTS vault_keys (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            key_type TEXT NOT NULL,
+            username TEXT,
+            encrypted_content TEXT NOT NULL,
+            salt TEXT NOT NULL,
+            algorithm TEXT,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL
+        );",
+    ),
+    // Server 表 (tags 存为 JSON 字符串)
+    Step::Sql(
+        "CREATE TABLE IF NOT EXISTS servers (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            icon TEXT,
+            provider TEXT,
+            sort INTEGER,
+            ip TEXT NOT NULL,
+            port INTEGER,
+            tags TEXT,
+            connection_type TEXT,
+            proxy_id TEXT,
+            auth_type TEXT,
+            username TEXT,
+            password TEXT,
+            private_key TEXT,
+            passphrase TEXT,
+            password_id TEXT,
+            password_source TEXT,
+            key_id TEXT,
+            key_source TEXT,
+            private_key_remark TEXT,
+            os TEXT,
+            is_pinned BOOLEAN,
+            enable_expiration BOOLEAN,
+            expire_date TEXT,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL,
+            last_connected_at INTEGER,
+            connect_timeout INTEGER DEFAULT 10,
+            keep_alive_interval INTEGER DEFAULT 60,
+            auto_reconnect BOOLEAN DEFAULT 0,
+            max_reconnects INTEGER DEFAULT 3,
+            scrollback_size INTEGER,
+            protocol TEXT DEFAULT 'ssh',
+            login_script TEXT,
+            tmux_session TEXT,
+            env_vars TEXT,
+            initial_cwd TEXT,
+            startup_commands TEXT,
+            algorithm_prefs TEXT
+        );",
+    ),
+    // 引入版本表之前的旧库靠 ALTER 补列，这里保证补齐
+    Step::AddColumn { table: "servers", column: "scrollback_size", definition: "INTEGER" },
+    Step::AddColumn { table: "servers", column: "protocol", definition: "TEXT DEFAULT 'ssh'" },
+    Step::AddColumn { table: "servers", column: "login_script", definition: "TEXT" },
+    Step::AddColumn { table: "servers", column: "tmux_session", definition: "TEXT" },
+    Step::AddColumn { table: "servers", column: "env_vars", definition: "TEXT" },
+    Step::AddColumn { table: "servers", column: "initial_cwd", definition: "TEXT" },
+    Step::AddColumn { table: "servers", column: "startup_commands", definition: "TEXT" },
+    Step::AddColumn { table: "servers", column: "algorithm_prefs", definition: "TEXT" },
+    // Snippets 表
+    Step::Sql(
+        "CREATE TABLE IF NOT EXISTS snippets (
+            id TEXT PRIMARY KEY,
+            title TEXT NOT NULL,
+            code TEXT NOT NULL,
+            language TEXT NOT NULL,
+            tags TEXT NOT NULL,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL
+        );",
+    ),
+    // Proxies 表
+    Step::Sql(
+        "CREATE TABLE IF NOT EXISTS proxies (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            proxy_type TEXT NOT NULL,
+            host TEXT NOT NULL,
+            port INTEGER NOT NULL,
+            username TEXT,
+            password TEXT,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL
+        );",
+    ),
+    // 密钥使用记录：同一个密钥在同一个服务器只保留一条最新记录
+    Step::Sql(
+        "CREATE TABLE IF NOT EXISTS key_usages (
+            key_id TEXT NOT NULL,
+            server_id TEXT NOT NULL,
+            last_used_at INTEGER NOT NULL,
+            PRIMARY KEY (key_id, server_id),
+            FOREIGN KEY(key_id) REFERENCES vault_keys(id) ON DELETE CASCADE,
+            FOREIGN KEY(server_id) REFERENCES servers(id) ON DELETE CASCADE
+        );",
+    ),
+    // 命令历史：全局字典表
+    Step::Sql(
+        "CREATE TABLE IF NOT EXISTS command_history (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            normalized_command TEXT NOT NULL UNIQUE,
+            display_command TEXT NOT NULL,
+            created_at INTEGER NOT NULL,
+            last_used_at INTEGER NOT NULL,
+            global_exec_count INTEGER DEFAULT 1
+        );",
+    ),
+    Step::Sql("CREATE INDEX IF NOT EXISTS idx_history_command ON command_history(normalized_command);"),
+    Step::Sql("CREATE INDEX IF NOT EXISTS idx_history_count ON command_history(global_exec_count DESC);"),
+    // 命令历史：服务器统计表
+    Step::Sql(
+        "CREATE TABLE IF NOT EXISTS command_usage (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            command_id INTEGER NOT NULL,
+            server_id TEXT NOT NULL,
+            created_at INTEGER NOT NULL,
+            last_used_at INTEGER NOT NULL,
+            exec_count INTEGER DEFAULT 1,
+            FOREIGN KEY(command_id) REFERENCES command_history(id) ON DELETE CASCADE,
+            UNIQUE(command_id, server_id)
+        );",
+    ),
+    Step::Sql("CREATE INDEX IF NOT EXISTS idx_usage_server_rank ON command_usage(server_id, exec_count DESC);"),
+    // 命令历史：流水表
+    Step::Sql(
+        "CREATE TABLE IF NOT EXISTS command_events (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            command_id INTEGER NOT NULL,
+            server_id TEXT NOT NULL,
+            source TEXT DEFAULT 'user',
+            executed_at INTEGER NOT NULL,
+            exit_code INTEGER,
+            duration_ms INTEGER,
+            cwd TEXT,
+            FOREIGN KEY(command_id) REFERENCES command_history(id) ON DELETE CASCADE
+        );",
+    ),
+    Step::AddColumn { table: "command_events", column: "exit_code", definition: "INTEGER" },
+    Step::AddColumn { table: "command_events", column: "duration_ms", definition: "INTEGER" },
+    Step::AddColumn { table: "command_events", column: "cwd", definition: "TEXT" },
+    Step::Sql("CREATE INDEX IF NOT EXISTS idx_events_timeline ON command_events(server_id, executed_at DESC);"),
+    // 高亮：规则集合 (Profile)
+    Step::Sql(
+        "CREATE TABLE IF NOT EXISTS highlight_rule_sets (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            description TEXT,
+            is_default BOOLEAN DEFAULT 0,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL
+        );",
+    ),
+    // 高亮：样式定义 (Style)
+    Step::Sql(
+        "CREATE TABLE IF NOT EXISTS highlight_styles (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            foreground TEXT,
+            background TEXT,
+            is_bold BOOLEAN DEFAULT 0,
+            is_italic BOOLEAN DEFAULT 0,
+            is_underline BOOLEAN DEFAULT 0,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL
+        );",
+    ),
+    // 高亮：规则本体 (Rule)
+    Step::Sql(
+        "CREATE TABLE IF NOT EXISTS highlight_rules (
+            id TEXT PRIMARY KEY,
+            set_id TEXT NOT NULL,
+            style_id TEXT NOT NULL,
+            pattern TEXT NOT NULL,
+            description TEXT,
+            is_regex BOOLEAN DEFAULT 0,
+            is_case_sensitive BOOLEAN DEFAULT 0,
+            is_enabled BOOLEAN DEFAULT 1,
+            priority INTEGER DEFAULT 0,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL,
+            FOREIGN KEY(set_id) REFERENCES highlight_rule_sets(id) ON DELETE CASCADE,
+            FOREIGN KEY(style_id) REFERENCES highlight_styles(id)
+        );",
+    ),
+    Step::Sql("CREATE INDEX IF NOT EXISTS idx_highlight_rules_set_id ON highlight_rules(set_id);"),
+    // 高亮：规则集绑定 (global / proxy)
+    Step::Sql(
+        "CREATE TABLE IF NOT EXISTS highlight_assignments (
+            target_id TEXT PRIMARY KEY, -- 'global' 或者 proxy 的 UUID
+            target_type TEXT NOT NULL,  -- 'global' 或 'proxy'
+            set_id TEXT NOT NULL,       -- 绑定的规则集 ID
+            created_at INTEGER NOT NULL,
+            FOREIGN KEY(set_id) REFERENCES highlight_rule_sets(id) ON DELETE CASCADE
+        );",
+    ),];
+
+// 版本 2：应用设置 (每个分组一行 JSON)
+const V2_APP_SETTINGS: &[Step] = &[Step::Sql(
+    "CREATE TABLE IF NOT EXISTS app_settings (
+        key TEXT PRIMARY KEY,
+        value TEXT NOT NULL,
+        updated_at INTEGER NOT NULL
+    );",
+)];
+
+// 版本 3：服务器文件夹 (可嵌套)，default_* 列为成员服务器继承的默认值
+const V3_SERVER_FOLDERS: &[Step] = &[
+    Step::Sql(
+        "CREATE TABLE IF NOT EXISTS server_folders (
+            id TEXT PRIMARY KEY,
+            parent_id TEXT,
+            name TEXT NOT NULL,
+            sort INTEGER NOT NULL DEFAULT 0,
+            default_username TEXT,
+            default_proxy_id TEXT,
+            default_auth_type TEXT,
+            default_password_id TEXT,
+            default_key_id TEXT,
+            default_highlight_set_id TEXT,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL
+        );",
+    ),
+    Step::Sql("CREATE INDEX IF NOT EXISTS idx_server_folders_parent ON server_folders(parent_id);"),
+    Step::AddColumn {
+        table: "servers",
+        column: "folder_id",
+        definition: "TEXT",
+    },
+    Step::Sql("CREATE INDEX IF NOT EXISTS idx_servers_folder ON servers(folder_id);"),
+];
+
+// 版本 4：服务器模板，servers.template_overrides 为服务器自行覆盖的字段 (JSON 数组)
+const V4_SERVER_TEMPLATES: &[Step] = &[
+    Step::Sql(
+        "CREATE TABLE IF NOT EXISTS server_templates (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            description TEXT,
+            auth_type TEXT,
+            password_id TEXT,
+            key_id TEXT,
+            port INTEGER,
+            proxy_id TEXT,
+            connect_timeout INTEGER,
+            keep_alive_interval INTEGER,
+            startup_commands TEXT,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL
+        );",
+    ),
+    Step::AddColumn {
+        table: "servers",
+        column: "template_id",
+        definition: "TEXT",
+    },
+    Step::AddColumn {
+        table: "servers",
+        column: "template_overrides",
+        definition: "TEXT",
+    },
+    Step::Sql("CREATE INDEX IF NOT EXISTS idx_servers_template ON servers(template_id);"),
+];
+
+// 版本 5：服务器可达性扫描的最近一次结果 (独立成表，避免保存服务器时被 INSERT OR REPLACE 覆盖)
+const V5_SERVER_HEALTH: &[Step] = &[Step::Sql(
+    "CREATE TABLE IF NOT EXISTS server_health (
+        server_id TEXT PRIMARY KEY,
+        status TEXT NOT NULL,
+        latency_ms INTEGER,
+        resolved_ip TEXT,
+        banner TEXT,
+        error TEXT,
+        checked_at INTEGER NOT NULL
+    );",
+)];
+
+// 版本 6：全局搜索索引 (FTS5，trigram 分词以支持 IP 片段与中文子串)
+// 由触发器与源表保持同步，导入、恢复备份等路径无需单独维护；保险库只索引名称，不含内容
+const V6_SEARCH_INDEX: &[Step] = &[
+    Step::Sql(
+        "CREATE VIRTUAL TABLE IF NOT EXISTS search_index USING fts5(
+            kind UNINDEXED,
+            ref_id UNINDEXED,
+            title,
+            subtitle,
+            body,
+            tokenize = 'trigram'
+        );",
+    ),
+    // 服务器：名称 / IP / 标签 (临时的快速连接不索引)
+    Step::Sql(
+        "CREATE TRIGGER IF NOT EXISTS search_servers_insert AFTER INSERT ON servers BEGIN
+            DELETE FROM search_index WHERE kind = 'server' AND ref_id = NEW.id;
+            INSERT INTO search_index (kind, ref_id, title, subtitle, body)
+            SELECT 'server', NEW.id, NEW.name, NEW.ip,
+                   CASE WHEN json_valid(NEW.tags) THEN IFNULL((SELECT group_concat(value, ' ') FROM json_each(NEW.tags)), '') ELSE IFNULL(NEW.tags, '') END
+                   || ' ' || IFNULL(NEW.provider, '')
+            WHERE IFNULL(NEW.provider, '') <> 'QuickConnect';
+        END;",
+    ),
+    Step::Sql(
+        "CREATE TRIGGER IF NOT EXISTS search_servers_update AFTER UPDATE OF name, ip, tags, provider ON servers BEGIN
+            DELETE FROM search_index WHERE kind = 'server' AND ref_id = OLD.id;
+            INSERT INTO search_index (kind, ref_id, title, subtitle, body)
+            SELECT 'server', NEW.id, NEW.name, NEW.ip,
+                   CASE WHEN json_valid(NEW.tags) THEN IFNULL((SELECT group_concat(value, ' ') FROM json_each(NEW.tags)), '') ELSE IFNULL(NEW.tags, '') END
+                   || ' ' || IFNULL(NEW.provider, '')
+            WHERE IFNULL(NEW.provider, '') <> 'QuickConnect';
+        END;",
+    ),
+    Step::Sql(
+        "CREATE TRIGGER IF NOT EXISTS search_servers_delete AFTER DELETE ON servers BEGIN
+            DELETE FROM search_index WHERE kind = 'server' AND ref_id = OLD.id;
+        END;",
+    ),
+    // 片段：标题 / 代码 (副标题为代码首行)
+    Step::Sql(
+        "CREATE TRIGGER IF NOT EXISTS search_snippets_insert AFTER INSERT ON snippets BEGIN
+            DELETE FROM search_index WHERE kind = 'snippet' AND ref_id = NEW.id;
+            INSERT INTO search_index (kind, ref_id, title, subtitle, body)
+            VALUES ('snippet', NEW.id, NEW.title,
+                    substr(NEW.code, 1, instr(NEW.code || char(10), char(10)) - 1),
+                    NEW.code || ' ' || NEW.language || ' ' || NEW.tags);
+        END;",
+    ),
+    Step::Sql(
+        "CREATE TRIGGER IF NOT EXISTS search_snippets_update AFTER UPDATE ON snippets BEGIN
+            DELETE FROM search_index WHERE kind = 'snippet' AND ref_id = OLD.id;
+            INSERT INTO search_index (kind, ref_id, title, subtitle, body)
+            VALUES ('snippet', NEW.id, NEW.title,
+                    substr(NEW.code, 1, instr(NEW.code || char(10), char(10)) - 1),
+                    NEW.code || ' ' || NEW.language || ' ' || NEW.tags);
+        END;",
+    ),
+    Step::Sql(
+        "CREATE TRIGGER IF NOT EXISTS search_snippets_delete AFTER DELETE ON snippets BEGIN
+            DELETE FROM search_index WHERE kind = 'snippet' AND ref_id = OLD.id;
+        END;",
+    ),
+    // 保险库：只有名称与类型
+    Step::Sql(
+        "CREATE TRIGGER IF NOT EXISTS search_keys_insert AFTER INSERT ON vault_keys BEGIN
+            DELETE FROM search_index WHERE kind = 'key' AND ref_id = NEW.id;
+            INSERT INTO search_index (kind, ref_id, title, subtitle, body)
+            VALUES ('key', NEW.id, NEW.name, NEW.key_type, '');
+        END;",
+    ),
+    Step::Sql(
+        "CREATE TRIGGER IF NOT EXISTS search_keys_update AFTER UPDATE OF name, key_type ON vault_keys BEGIN
+            DELETE FROM search_index WHERE kind = 'key' AND ref_id = OLD.id;
+            INSERT INTO search_index (kind, ref_id, title, subtitle, body)
+            VALUES ('key', NEW.id, NEW.name, NEW.key_type, '');
+        END;",
+    ),
+    Step::Sql(
+        "CREATE TRIGGER IF NOT EXISTS search_keys_delete AFTER DELETE ON vault_keys BEGIN
+            DELETE FROM search_index WHERE kind = 'key' AND ref_id = OLD.id;
+        END;",
+    ),
+    // 命令历史 (记录命令时只更新计数，不重建索引)
+    Step::Sql(
+        "CREATE TRIGGER IF NOT EXISTS search_history_insert AFTER INSERT ON command_history BEGIN
+            INSERT INTO search_index (kind, ref_id, title, subtitle, body)
+            VALUES ('history', CAST(NEW.id AS TEXT), NEW.display_command, NULL, '');
+        END;",
+    ),
+    Step::Sql(
+        "CREATE TRIGGER IF NOT EXISTS search_history_update AFTER UPDATE OF display_command ON command_history BEGIN
+            DELETE FROM search_index WHERE kind = 'history' AND ref_id = CAST(OLD.id AS TEXT);
+            INSERT INTO search_index (kind, ref_id, title, subtitle, body)
+            VALUES ('history', CAST(NEW.id AS TEXT), NEW.display_command, NULL, '');
+        END;",
+    ),
+    Step::Sql(
+        "CREATE TRIGGER IF NOT EXISTS search_history_delete AFTER DELETE ON command_history BEGIN
+            DELETE FROM search_index WHERE kind = 'history' AND ref_id = CAST(OLD.id AS TEXT);
+        END;",
+    ),
+    // 索引已有数据
+    Step::Sql("DELETE FROM search_index;"),
+    Step::Sql(
+        "INSERT INTO search_index (kind, ref_id, title, subtitle, body)
+         SELECT 'server', id, name, ip,
+                CASE WHEN json_valid(tags) THEN IFNULL((SELECT group_concat(value, ' ') FROM json_each(tags)), '') ELSE IFNULL(tags, '') END
+                || ' ' || IFNULL(provider, '')
+         FROM servers WHERE IFNULL(provider, '') <> 'QuickConnect';",
+    ),
+    Step::Sql(
+        "INSERT INTO search_index (kind, ref_id, title, subtitle, body)
+         SELECT 'snippet', id, title, substr(code, 1, instr(code || char(10), char(10)) - 1),
+                code || ' ' || language || ' ' || tags
+         FROM snippets;",
+    ),
+    Step::Sql(
+        "INSERT INTO search_index (kind, ref_id, title, subtitle, body)
+         SELECT 'key', id, name, key_type, '' FROM vault_keys;",
+    ),
+    Step::Sql(
+        "INSERT INTO search_index (kind, ref_id, title, subtitle, body)
+         SELECT 'history', CAST(id AS TEXT), display_command, NULL, '' FROM command_history;",
+    ),
+];
+
+// 版本 7：回收站 (服务器、片段、保险库条目软删除)
+// 删除时只记录 deleted_at，超过保留天数后彻底清除；软删除的条目同时移出搜索索引
+const V7_TRASH: &[Step] = &[
+    Step::AddColumn {
+        table: "servers",
+        column: "deleted_at",
+        definition: "INTEGER",
+    },
+    Step::AddColumn {
+        table: "snippets",
+        column: "deleted_at",
+        definition: "INTEGER",
+    },
+    Step::AddColumn {
+        table: "vault_keys",
+        column: "deleted_at",
+        definition: "INTEGER",
+    },
+    Step::Sql("DROP TRIGGER IF EXISTS search_servers_update;"),
+    Step::Sql(
+        "CREATE TRIGGER IF NOT EXISTS search_servers_update AFTER UPDATE OF name, ip, tags, provider, deleted_at ON servers BEGIN
+            DELETE FROM search_index WHERE kind = 'server' AND ref_id = OLD.id;
+            INSERT INTO search_index (kind, ref_id, title, subtitle, body)
+            SELECT 'server', NEW.id, NEW.name, NEW.ip,
+                   CASE WHEN json_valid(NEW.tags) THEN IFNULL((SELECT group_concat(value, ' ') FROM json_each(NEW.tags)), '') ELSE IFNULL(NEW.tags, '') END
+                   || ' ' || IFNULL(NEW.provider, '')
+            WHERE IFNULL(NEW.provider, '') <> 'QuickConnect' AND NEW.deleted_at IS NULL;
+        END;",
+    ),
+    Step::Sql("DROP TRIGGER IF EXISTS search_snippets_update;"),
+    Step::Sql(
+        "CREATE TRIGGER IF NOT EXISTS search_snippets_update AFTER UPDATE ON snippets BEGIN
+            DELETE FROM search_index WHERE kind = 'snippet' AND ref_id = OLD.id;
+            INSERT INTO search_index (kind, ref_id, title, subtitle, body)
+            SELECT 'snippet', NEW.id, NEW.title,
+                   substr(NEW.code, 1, instr(NEW.code || char(10), char(10)) - 1),
+                   NEW.code || ' ' || NEW.language || ' ' || NEW.tags
+            WHERE NEW.deleted_at IS NULL;
+        END;",
+    ),
+    Step::Sql("DROP TRIGGER IF EXISTS search_keys_update;"),
+    Step::Sql(
+        "CREATE TRIGGER IF NOT EXISTS search_keys_update AFTER UPDATE OF name, key_type, deleted_at ON vault_keys BEGIN
+            DELETE FROM search_index WHERE kind = 'key' AND ref_id = OLD.id;
+            INSERT INTO search_index (kind, ref_id, title, subtitle, body)
+            SELECT 'key', NEW.id, NEW.name, NEW.key_type, ''
+            WHERE NEW.deleted_at IS NULL;
+        END;",
+    ),
+];
+
+// 版本 8：连接记录 (每次连接一行，断开时补上时长、原因与流量)
+const V8_CONNECTION_EVENTS: &[Step] = &[
+    Step::Sql(
+        "CREATE TABLE IF NOT EXISTS connection_events (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            server_id TEXT NOT NULL,
+            session_id TEXT NOT NULL,
+            protocol TEXT,
+            auth_method TEXT,
+            status TEXT NOT NULL,       -- 'connecting' / 'connected' / 'failed'
+            error TEXT,
+            reconnect_count INTEGER NOT NULL DEFAULT 0,
+            connected_at INTEGER NOT NULL,
+            disconnected_at INTEGER,
+            duration_ms INTEGER,
+            disconnect_reason TEXT,
+            bytes_in INTEGER NOT NULL DEFAULT 0,
+            bytes_out INTEGER NOT NULL DEFAULT 0
+        );",
+    ),
+    Step::Sql(
+        "CREATE INDEX IF NOT EXISTS idx_connection_events_server ON connection_events(server_id, connected_at);",
+    ),
+];
+
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Baseline schema",
+        steps: V1_BASELINE,
+    },
+    Migration {
+        version: 2,
+        description: "App settings",
+        steps: V2_APP_SETTINGS,
+    },
+    Migration {
+        version: 3,
+        description: "Server folders",
+        steps: V3_SERVER_FOLDERS,
+    },
+    Migration {
+        version: 4,
+        description: "Server templates",
+        steps: V4_SERVER_TEMPLATES,
+    },
+    Migration {
+        version: 5,
+        description: "Server health",
+        steps: V5_SERVER_HEALTH,
+    },
+    Migration {
+        version: 6,
+        description: "Search index",
+        steps: V6_SEARCH_INDEX,
+    },
+    Migration {
+        version: 7,
+        description: "Trash",
+        steps: V7_TRASH,
+    },
+    Migration {
+        version: 8,
+        description: "Connection events",
+        steps: V8_CONNECTION_EVENTS,
+    },
+];
+
+// 保留最近几份迁移前备份
+const MAX_MIGRATION_BACKUPS: usize = 5;
+
+/// 当前程序支持的最新版本
+pub fn latest_version() -> i64 {
+    MIGRATIONS.last().map(|m| m.version).unwrap_or(0)
+}
+
+/// 读取数据库的版本号 (没有版本表的旧库返回 0)
+pub async fn current_version(pool: &Pool<Sqlite>) -> Result<i64, String> {
+    let has_table: bool = sqlx::query_scalar(
+        "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'table' AND name = 'schema_version'",
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    if !has_table {
+        return Ok(0);
+    }
+
+    let version: Option<i64> = sqlx::query_scalar("SELECT MAX(version) FROM schema_version")
+        .fetch_one(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(version.unwrap_or(0))
+}
+
+async fn apply_step(conn: &mut SqliteConnection, step: &Step) -> Result<(), String> {
+    match step {
+        Step::Sql(sql) => {
+            sqlx::query(sql)
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+        Step::AddColumn { table, column, definition } => {
+            let exists: bool =
+                sqlx::query_scalar("SELECT COUNT(*) > 0 FROM pragma_table_info(?) WHERE name = ?")
+                    .bind(table)
+                    .bind(column)
+                    .fetch_one(&mut *conn)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            if !exists {
+                let sql = format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition);
+                sqlx::query(&sql)
+                    .execute(&mut *conn)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// 迁移前备份：VACUUM INTO 生成一致的副本 (包含 WAL 中尚未合并的数据)
+async fn backup_before_migration(
+    pool: &Pool<Sqlite>,
+    app_dir: &Path,
+    from_version: i64,
+) -> Result<PathBuf, String> {
+    let backup_dir = app_dir.join("db_backups");
+    fs::create_dir_all(&backup_dir).map_err(|e| e.to_string())?;
+
+    let backup_path = backup_dir.join(format!(
+        "ishelldb-v{}-{}.sqlite",
+        from_version,
+        chrono::Local::now().format("%Y%m%d%H%M%S")
+    ));
+    sqlx::query("VACUUM INTO ?")
+        .bind(backup_path.to_string_lossy().to_string())
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Pre-migration backup failed: {}", e))?;
+
+    // 只保留最近几份
+    let mut backups: Vec<PathBuf> = fs::read_dir(&backup_dir)
+        .map_err(|e| e.to_string())?
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|ext| ext == "sqlite"))
+        .collect();
+    backups.sort_by_key(|p| p.metadata().and_then(|m| m.modified()).ok());
+    let excess = backups.len().saturating_sub(MAX_MIGRATION_BACKUPS);
+    for old in backups.into_iter().take(excess) {
+        let _ = fs::remove_file(old);
+    }
+
+    Ok(backup_path)
+}
+
+/// 执行全部未执行的迁移
+/// 数据库版本比程序新时拒绝打开，避免旧程序写坏新结构
+pub async fn run_migrations(pool: &Pool<Sqlite>, app_dir: &Path) -> Result<(), String> {
+    let current = current_version(pool).await?;
+    let latest = latest_version();
+
+    if current > latest {
+        return Err(format!(
+            "Database schema version {} is newer than this build supports ({}), please update PiTerm",
+            current, latest
+        ));
+    }
+
+    let pending: Vec<&Migration> = MIGRATIONS.iter().filter(|m| m.version > current).collect();
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    // 全新的空库无需备份
+    let has_tables: bool = sqlx::query_scalar(
+        "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'",
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    if has_tables {
+        let path = backup_before_migration(pool, app_dir, current).await?;
+        println!("[DB] Pre-migration backup: {}", path.display());
+    }
+
+    // 任一步失败整体回滚，数据库保持在迁移前的版本
+    let mut tx = pool.begin().await.map_err(|e| e.to_string())?;
+
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS schema_version (
+            version INTEGER PRIMARY KEY,
+            description TEXT NOT NULL,
+            applied_at INTEGER NOT NULL
+        );",
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    for migration in pending {
+        for step in migration.steps {
+            apply_step(&mut tx, step).await.map_err(|e| {
+                format!(
+                    "Migration {} ({}) failed: {}",
+                    migration.version, migration.description, e
+                )
+            })?;
+        }
+        sqlx::query("INSERT INTO schema_version (version, description, applied_at) VALUES (?, ?, ?)")
+            .bind(migration.version)
+            .bind(migration.description)
+            .bind(chrono::Utc::now().timestamp_millis())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+        println!("[DB] Applied migration {}: {}", migration.version, migration.description);
+    }
+
+    tx.commit().await.map_err(|e| e.to_string())?;
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use sqlx::{sqlite::SqlitePoolOptions, Pool, Sqlite};
+use std::fs;
+use std::path::Path;
+use tauri::AppHandle;
+use tauri::Manager; // 用于访问 path
+
+pub mod migrations;
+
+/// 数据库文件名 (备份/恢复也依赖该名称)
+pub const DB_FILE_NAME: &str = "ishelldb.sqlite";
+
+// 初始化数据库连接池
+pub async fn init_db(app: &AppHandle) -> Result<Pool<Sqlite>, String> {
+    let app_dir = app.path().app_data_dir().expect("failed to get app data dir");
+    open_db(&app_dir).await
+}
+
+// 打开 (必要时创建) 应用数据目录下的数据库并执行迁移
+// 命令行模式没有 AppHandle，直接传入数据目录
+pub async fn open_db(app_dir: &Path) -> Result<Pool<Sqlite>, String> {
+    if !app_dir.exists() {
+        fs::create_dir_all(app_dir).map_err(|e| e.to_string())?;
+    }
+    
+    // 统一使用一个数据库文件，例如 database.sqlite
+    let db_path = app_dir.join(DB_FILE_NAME);
+    
+    // sqlx 需要文件存在才能连接
+    if !db_path.exists() {
+        fs::File::create(&db_path).map_err(|e| e.to_string())?;
+    }
+
+    let db_url = format!("sqlite://{}", db_path.to_str().unwrap());
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(5)
+        .connect(&db_url)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        // 🟢 [新增] 开启 WAL 模式，大幅提升并发写入性能，防止 UI 卡死
+    sqlx::query("PRAGMA journal_mode=WAL;")
+        .execute(&pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // --- 执行版本化迁移 ---
+    migrations::run_migrations(&pool, app_dir).await?;
+
+    Ok(pool)
+}
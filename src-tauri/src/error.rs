@@ -0,0 +1,184 @@
+// src-tauri/src/error.rs
+//
+// 统一的命令错误类型
+// 序列化为 { code, message, details }：前端按 code 判断与本地化，
+// message 为英文概要，details 为底层的原始错误信息 (可能为空)。
+use std::fmt;
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+
+#[derive(Debug, Clone)]
+pub enum AppError {
+    /// 保险库未解锁
+    VaultLocked,
+    /// 保险库尚未初始化
+    VaultNotInitialized,
+    /// SSH / WebDAV 等认证失败
+    AuthFailed(String),
+    /// 主机密钥与 known_hosts 记录不一致
+    HostKeyMismatch(String),
+    /// DNS / TCP / 超时等网络问题
+    Network(String),
+    /// SSH 协议层错误 (握手、通道等)
+    Ssh(String),
+    /// 会话不存在或已断开
+    SessionNotActive(String),
+    /// 服务器未启用 SFTP 或 SFTP 会话不可用
+    SftpUnavailable(String),
+    /// 记录不存在 (服务器、密钥、备份文件等)
+    NotFound(String),
+    /// 参数不合法
+    InvalidInput(String),
+    Db(String),
+    Io(String),
+    /// 加解密失败
+    Crypto(String),
+    /// 其余未归类的错误
+    Internal(String),
+}
+
+pub type AppResult<T> = Result<T, AppError>;
+
+impl AppError {
+    /// 稳定的错误码，前端据此判断
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::VaultLocked => "VAULT_LOCKED",
+            AppError::VaultNotInitialized => "VAULT_NOT_INITIALIZED",
+            AppError::AuthFailed(_) => "AUTH_FAILED",
+            AppError::HostKeyMismatch(_) => "HOST_KEY_MISMATCH",
+            AppError::Network(_) => "NETWORK",
+            AppError::Ssh(_) => "SSH",
+            AppError::SessionNotActive(_) => "SESSION_NOT_ACTIVE",
+            AppError::SftpUnavailable(_) => "SFTP_UNAVAILABLE",
+            AppError::NotFound(_) => "NOT_FOUND",
+            AppError::InvalidInput(_) => "INVALID_INPUT",
+            AppError::Db(_) => "DB",
+            AppError::Io(_) => "IO",
+            AppError::Crypto(_) => "CRYPTO",
+            AppError::Internal(_) => "INTERNAL",
+        }
+    }
+
+    pub fn message(&self) -> &'static str {
+        match self {
+            AppError::VaultLocked => "Vault is locked",
+            AppError::VaultNotInitialized => "Vault is not initialized",
+            AppError::AuthFailed(_) => "Authentication failed",
+            AppError::HostKeyMismatch(_) => "Host key mismatch",
+            AppError::Network(_) => "Network error",
+            AppError::Ssh(_) => "SSH error",
+            AppError::SessionNotActive(_) => "Session not active",
+            AppError::SftpUnavailable(_) => "SFTP unavailable",
+            AppError::NotFound(_) => "Not found",
+            AppError::InvalidInput(_) => "Invalid input",
+            AppError::Db(_) => "Database error",
+            AppError::Io(_) => "I/O error",
+            AppError::Crypto(_) => "Encryption error",
+            AppError::Internal(_) => "Internal error",
+        }
+    }
+
+    pub fn details(&self) -> Option<&str> {
+        match self {
+            AppError::VaultLocked | AppError::VaultNotInitialized => None,
+            AppError::AuthFailed(d)
+            | AppError::HostKeyMismatch(d)
+            | AppError::Network(d)
+            | AppError::Ssh(d)
+            | AppError::SessionNotActive(d)
+            | AppError::SftpUnavailable(d)
+            | AppError::NotFound(d)
+            | AppError::InvalidInput(d)
+            | AppError::Db(d)
+            | AppError::Io(d)
+            | AppError::Crypto(d)
+            | AppError::Internal(d) => Some(d.as_str()).filter(|d| !d.is_empty()),
+        }
+    }
+
+    /// 会话表中找不到对应 ID
+    pub fn session_not_active(id: &str) -> Self {
+        AppError::SessionNotActive(id.to_string())
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.details() {
+            Some(details) => write!(f, "{}: {}", self.message(), details),
+            None => write!(f, "{}", self.message()),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl Serialize for AppError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("AppError", 3)?;
+        s.serialize_field("code", self.code())?;
+        s.serialize_field("message", self.message())?;
+        s.serialize_field("details", &self.details())?;
+        s.end()
+    }
+}
+
+// 兼容尚未迁移的 Result<_, String> 辅助函数
+// 旧代码以 "VAULT_LOCKED" 前缀表示保险库未解锁，这里还原为对应的错误
+impl From<String> for AppError {
+    fn from(s: String) -> Self {
+        if s.starts_with("VAULT_LOCKED") {
+            AppError::VaultLocked
+        } else {
+            AppError::Internal(s)
+        }
+    }
+}
+
+impl From<&str> for AppError {
+    fn from(s: &str) -> Self {
+        AppError::from(s.to_string())
+    }
+}
+
+// 反向转换供仍返回 String 的命令使用，保留错误码前缀
+impl From<AppError> for String {
+    fn from(e: AppError) -> Self {
+        format!("{}: {}", e.code(), e)
+    }
+}
+
+impl From<sqlx::Error> for AppError {
+    fn from(e: sqlx::Error) -> Self {
+        match e {
+            sqlx::Error::RowNotFound => AppError::NotFound(e.to_string()),
+            _ => AppError::Db(e.to_string()),
+        }
+    }
+}
+
+impl From<std::io::Error> for AppError {
+    fn from(e: std::io::Error) -> Self {
+        AppError::Io(e.to_string())
+    }
+}
+
+impl From<ssh2::Error> for AppError {
+    fn from(e: ssh2::Error) -> Self {
+        AppError::Ssh(e.to_string())
+    }
+}
+
+impl From<reqwest::Error> for AppError {
+    fn from(e: reqwest::Error) -> Self {
+        AppError::Network(e.to_string())
+    }
+}
+
+impl From<tauri::Error> for AppError {
+    fn from(e: tauri::Error) -> Self {
+        AppError::Internal(e.to_string())
+    }
+}
@@ -1,19 +1,44 @@
+pub mod cli;
 pub mod commands;
 pub mod models;
 pub mod db;
 pub mod state;
 pub mod services;
 pub mod utils;
+pub mod error;
 // 🟢 [修改 1] 更新引用列表，确保包含所有需要的类型
 use tauri::{
     menu::{Menu, MenuEvent, MenuItem},
     tray::{TrayIcon, TrayIconBuilder, TrayIconEvent},
     AppHandle, Manager, WindowEvent,
 };
+
+/// 主托盘图标 ID (到期提醒通过它更新提示与角标)
+pub const MAIN_TRAY_ID: &str = "main";
 use std::sync::Mutex;
 use crate::state::AppState;
+use crate::models::settings::CloseBehavior;
+use cli::{CliCommand, CliRequest, CliState};
 use commands::monitor::MonitorCache;
+use commands::settings::{get_settings, reset_settings, update_settings, SettingsState};
+use commands::expiration::{list_upcoming_expirations, recheck_expirations, ExpirationChecker};
+use commands::auto_lock::{touch_vault_activity, VaultActivity};
+use commands::bulk_edit::bulk_update_servers;
+use commands::export::export_servers;
+use commands::search::global_search;
+use commands::trash::{empty_trash, list_trash, purge_from_trash, restore_from_trash};
+use commands::health::{cancel_server_scan, list_server_health, scan_servers, HealthScanState};
+use commands::connection_stats::{get_connection_stats, list_connection_events, ConnectionTracker};
+use commands::folder::{
+    create_folder, delete_folder, get_server_defaults, list_folders, move_folder, move_servers_to_folder,
+    reorder_folder_servers, update_folder,
+};
+use commands::import::{
+    apply_server_import, preview_csv_import, preview_mobaxterm_import, preview_putty_import,
+    preview_ssh_config_import, preview_xshell_import,
+};
 use commands::ssh::SshState;
+use commands::template::{delete_template, list_templates, save_template};
 use commands::vault::VaultState;
 use tauri_plugin_autostart::MacosLauncher;
 
@@ -29,6 +54,10 @@ use commands::backup::{
 // ================================
 use commands::ssh::*;
 use commands::history::*;
+use commands::local_shell::open_local_shell;
+use commands::docker::{
+    list_docker_containers, docker_container_action, docker_container_logs, open_container_shell,
+};
 // ================================
 // 引入 Vault (密钥管理) 命令
 // ================================
@@ -62,31 +91,36 @@ use commands::highlight::{
     assign_highlight_set,
     unassign_highlight_set,
 };
-// ==============================================================================
-// 🟢 [修改 2] 定义窗口配置状态
-// ==============================================================================
-pub struct WindowConfigState {
-    pub minimize_to_tray: Mutex<bool>,
-    pub close_behavior: Mutex<String>, // "quit" 或 "minimize"
-}
-
-// ==============================================================================
-// 🟢 [修改 3] 实现更新配置的命令
-// ==============================================================================
-#[tauri::command]
-fn update_app_config(
-    state: tauri::State<'_, WindowConfigState>,
-    minimize_to_tray: bool,
-    close_behavior: String,
-) {
-    *state.minimize_to_tray.lock().unwrap() = minimize_to_tray;
-    *state.close_behavior.lock().unwrap() = close_behavior;
-}
-
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
+    let context = tauri::generate_context!();
+
+    // 命令行参数：list / exec 等无窗口命令直接执行后退出
+    let args: Vec<String> = std::env::args().collect();
+    let initial_target = match cli::parse_args(&args) {
+        Ok(CliCommand::Gui(target)) => target,
+        Ok(command) => std::process::exit(cli::run_headless(command, &context.config().identifier)),
+        Err(e) => {
+            #[cfg(windows)]
+            cli::attach_parent_console();
+            eprintln!("piterm: {}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    let mut builder = tauri::Builder::default();
+
+    // 单实例必须最先注册：再次启动时把参数转发给已运行的实例
+    #[cfg(desktop)]
+    {
+        builder = builder.plugin(tauri_plugin_single_instance::init(|app, argv, _cwd| {
+            cli::handle_forwarded_args(app, argv);
+        }));
+    }
+
+    builder
         // 插件注册
+        .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
@@ -97,19 +131,58 @@ pub fn run() {
         .manage(SshState::default())
         .manage(MonitorCache::new())
         .manage(VaultState(Mutex::new(None)))
-        
-        // 初始化窗口配置状态
-        .manage(WindowConfigState {
-            minimize_to_tray: Mutex::new(true),
-            close_behavior: Mutex::new("quit".to_string()), 
-        })
+        .manage(VaultActivity::default())
+        .manage(CliState::default())
+        .manage(ExpirationChecker::default())
+        .manage(HealthScanState::default())
+        .manage(ConnectionTracker::default())
 
         .setup(|app| {
              let handle = app.handle().clone();
              let pool = tauri::async_runtime::block_on(async move {
                  db::init_db(&handle).await.expect("数据库初始化失败")
              });
-             app.manage(AppState { db: pool });
+             app.manage(AppState { db: pool.clone() });
+
+             // 上次退出时未结束的连接记录
+             if let Err(e) = tauri::async_runtime::block_on(commands::connection_stats::close_dangling_events(&pool)) {
+                 eprintln!("[Connection] Failed to close dangling events: {}", e);
+             }
+
+             // 持久化设置 (窗口行为、历史过滤等)，加载失败时使用默认值
+             let config_dir = app.path().app_config_dir()?;
+             let settings = tauri::async_runtime::block_on(commands::settings::init_settings(&pool, &config_dir))
+                 .unwrap_or_else(|e| {
+                     eprintln!("[Settings] Failed to load settings: {}", e);
+                     Default::default()
+                 });
+             app.manage(SettingsState::new(settings));
+
+             // ============================================================
+             // 命令行 / URL 启动参数
+             // ============================================================
+             if let Some(target) = initial_target {
+                 let request = tauri::async_runtime::block_on(cli::resolve_request(&pool, target))
+                     .unwrap_or_else(|error| CliRequest::Error { error });
+                 cli::enqueue_request(app.handle(), request);
+             }
+
+             #[cfg(desktop)]
+             {
+                 use tauri_plugin_deep_link::DeepLinkExt;
+
+                 // Linux / Windows 开发与便携版需要运行时注册 ssh:// sftp:// 协议
+                 #[cfg(any(windows, target_os = "linux"))]
+                 if let Err(e) = app.deep_link().register_all() {
+                     eprintln!("[CLI] Failed to register URL schemes: {}", e);
+                 }
+
+                 let handle = app.handle().clone();
+                 app.deep_link().on_open_url(move |event| {
+                     let urls = event.urls().iter().map(|u| u.to_string()).collect();
+                     cli::handle_urls(&handle, urls);
+                 });
+             }
 
              // ============================================================
              // 🟢 [新增] 系统托盘配置 (带类型注解)
@@ -124,7 +197,7 @@ pub fn run() {
                  let menu = Menu::with_items(handle, &[&show_i, &quit_i])?;
 
                  // 2. 创建托盘图标
-                 let _tray = TrayIconBuilder::new()
+                 let _tray = TrayIconBuilder::with_id(MAIN_TRAY_ID)
                      .icon(app.default_window_icon().unwrap().clone()) 
                      .menu(&menu)
                      // 🟢 添加了类型注解: |app: &AppHandle, event: MenuEvent|
@@ -160,6 +233,15 @@ pub fn run() {
              }
              // ============================================================
 
+             // 服务器到期检查 (托盘创建之后，才能更新托盘角标)
+             commands::expiration::spawn_checker(app.handle().clone());
+
+             // 回收站过期清理
+             commands::trash::spawn_purger(app.handle().clone());
+
+             // 保险库空闲自动锁定
+             commands::auto_lock::spawn_auto_lock(app.handle().clone());
+
              Ok(())
         })
 
@@ -167,11 +249,13 @@ pub fn run() {
         .on_window_event(|window, event| {
             if let WindowEvent::CloseRequested { api, .. } = event {
                 // 1. 获取当前状态
-                let state = window.state::<WindowConfigState>();
-                let behavior = state.close_behavior.lock().unwrap().clone();
+                let Some(state) = window.try_state::<SettingsState>() else {
+                    return;
+                };
+                let behavior = state.0.lock().unwrap().window.close_behavior;
                 
                 // 2. 如果设置为 "minimize"，则阻止关闭并隐藏窗口
-                if behavior == "minimize" {
+                if behavior == CloseBehavior::Minimize {
                     api.prevent_close(); // 阻止默认退出行为
                     window.hide().unwrap(); // 隐藏窗口 (配合托盘使用)
                 }
@@ -185,15 +269,71 @@ pub fn run() {
             save_server,
             delete_server,
             update_last_connected,
+            // 服务器导入
+            preview_ssh_config_import,
+            preview_putty_import,
+            preview_xshell_import,
+            preview_mobaxterm_import,
+            preview_csv_import,
+            apply_server_import,
+            // 服务器导出
+            export_servers,
+            // 服务器文件夹
+            list_folders,
+            create_folder,
+            update_folder,
+            move_folder,
+            delete_folder,
+            move_servers_to_folder,
+            reorder_folder_servers,
+            get_server_defaults,
+            // 服务器到期提醒
+            list_upcoming_expirations,
+            recheck_expirations,
+            // 批量编辑服务器
+            bulk_update_servers,
+            // 回收站
+            list_trash,
+            restore_from_trash,
+            purge_from_trash,
+            empty_trash,
+            // 全局搜索
+            global_search,
+            // 服务器可达性扫描
+            scan_servers,
+            cancel_server_scan,
+            list_server_health,
+            // 连接统计与会话历史
+            get_connection_stats,
+            list_connection_events,
+            // 服务器模板
+            list_templates,
+            save_template,
+            delete_template,
             // SSH
             connect_ssh,
             write_ssh,
             resize_ssh,
             disconnect_ssh,
             test_connection,
+            get_ssh_algorithm_presets,
             check_host_key,
             trust_host_key,
             quick_connect,
+            get_remote_cwd,
+            inject_shell_integration,
+            list_active_sessions,
+            get_scrollback,
+            search_scrollback,
+            list_remote_mux_sessions,
+            attach_remote_mux_session,
+            // 本地终端
+            open_local_shell,
+            // Docker 容器
+            list_docker_containers,
+            docker_container_action,
+            docker_container_logs,
+            open_container_shell,
             // 监控命令
             get_ssh_cpu_info,
             get_ssh_mem_info,
@@ -225,6 +365,7 @@ pub fn run() {
             get_all_keys,
             get_vault_status,
             check_key_associations,
+            touch_vault_activity,
             
             // Snippet 命令
             get_all_snippets,
@@ -256,8 +397,12 @@ pub fn run() {
             get_command_history,
             delete_command_history,
 
-            // 注册更新配置命令
-            update_app_config,
+            // 应用设置
+            get_settings,
+            update_settings,
+            reset_settings,
+            // 命令行请求
+            cli::take_cli_requests,
 
             // 高亮规则相关命令
                 get_highlight_sets,
@@ -276,6 +421,6 @@ pub fn run() {
                 assign_highlight_set,
                 unassign_highlight_set,
         ])
-        .run(tauri::generate_context!())
+        .run(context)
         .expect("error while running tauri application");
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-pub type CommandResult<T> = Result<T, String>;
+pub type CommandResult<T> = crate::error::AppResult<T>;
 
 // [新增] 恢复预览信息 (下载后返回给前端)
 #[derive(Debug, Serialize, Deserialize)] // 🟢 建议加上 Deserialize，万一以后要传回后端
@@ -0,0 +1,56 @@
+// src-tauri/src/models/folder.rs
+//
+// 服务器文件夹：通过 parent_id 组成树，文件夹上的默认值由成员服务器 (含子文件夹中的服务器) 继承，
+// 离服务器最近的文件夹上设置的值优先。
+use serde::{Deserialize, Serialize};
+
+use super::AuthType;
+
+/// 文件夹级默认值，None 表示不设置 (继续向上继承)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FolderDefaults {
+    pub username: Option<String>,
+    pub proxy_id: Option<String>,
+    /// 与 password_id / key_id 一起作为成员服务器的凭据
+    pub auth_type: Option<AuthType>,
+    pub password_id: Option<String>,
+    pub key_id: Option<String>,
+    pub highlight_set_id: Option<String>,
+}
+
+impl FolderDefaults {
+    /// 用上级文件夹的值补全未设置的字段
+    pub fn inherit_from(&mut self, parent: &FolderDefaults) {
+        fn fill<T: Clone>(value: &mut Option<T>, parent: &Option<T>) {
+            if value.is_none() {
+                value.clone_from(parent);
+            }
+        }
+        fill(&mut self.username, &parent.username);
+        fill(&mut self.proxy_id, &parent.proxy_id);
+        fill(&mut self.highlight_set_id, &parent.highlight_set_id);
+        // 凭据作为整体继承，避免认证方式与凭据来自不同层级
+        if self.auth_type.is_none() {
+            self.auth_type.clone_from(&parent.auth_type);
+            self.password_id.clone_from(&parent.password_id);
+            self.key_id.clone_from(&parent.key_id);
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerFolder {
+    pub id: String,
+    pub parent_id: Option<String>,
+    pub name: String,
+    #[serde(default)]
+    pub sort: i32,
+    #[serde(default)]
+    pub defaults: FolderDefaults,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
+}
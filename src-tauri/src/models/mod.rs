@@ -1,5 +1,8 @@
 pub mod backup;
+pub mod folder;
 pub mod highlight;
+pub mod settings;
+pub mod template;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
@@ -35,6 +38,67 @@ pub enum ConnectionType {
     Socks5, 
 }
 
+// 终端协议：默认 SSH，老旧网络设备使用 Telnet
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum Protocol {
+    #[default]
+    Ssh,
+    Telnet,
+}
+
+// Telnet 登录脚本的一步：等待 expect 出现后发送 send
+// send 支持 {username} / {password} 占位符
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TelnetLoginStep {
+    pub expect: String,
+    pub send: String,
+}
+
+// SSH 环境变量 (通过 env 请求发送，服务端需在 AcceptEnv 中放行)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvVar {
+    pub name: String,
+    pub value: String,
+}
+
+// Shell 启动后依次执行的命令：直接给出命令，或引用片段库中的 snippet
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum StartupCommand {
+    #[serde(rename_all = "camelCase")]
+    Command { command: String },
+    #[serde(rename_all = "camelCase")]
+    Snippet { snippet_id: String },
+}
+
+// SSH 算法预设
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AlgorithmProfile {
+    Modern,
+    Compatible,
+    Legacy,
+}
+
+// SSH 算法偏好：各类列表为空时使用 profile 预设，二者都为空时使用 libssh2 默认值
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgorithmPreferences {
+    pub profile: Option<AlgorithmProfile>,
+    #[serde(default)]
+    pub kex: Vec<String>,
+    #[serde(default)]
+    pub host_key: Vec<String>,
+    #[serde(default)]
+    pub cipher: Vec<String>,
+    #[serde(default)]
+    pub mac: Vec<String>,
+}
+
 // =========================================================
 // ServerConfig 主配置结构体 (用于 CRUD)
 // =========================================================
@@ -63,6 +127,18 @@ pub struct ServerConfig {
     #[serde(default)]
     pub tags: Vec<String>,
 
+    // 所属文件夹，为空时位于根目录
+    #[serde(default)]
+    pub folder_id: Option<String>,
+
+    // 引用的服务器模板，template_overrides 中的字段不使用模板的值
+    #[serde(default)]
+    pub template_id: Option<String>,
+
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub template_overrides: Vec<template::TemplateField>,
+
     #[serde(default = "default_connection_type")]
     pub connection_type: ConnectionType,
 
@@ -74,8 +150,12 @@ pub struct ServerConfig {
     #[serde(default = "default_username")]
     pub username: String,
 
+    // 明文凭据只在保存时由前端传入，随即移入 Vault，不会返回给前端
+    #[serde(default, skip_serializing)]
     pub password: Option<String>,
+    #[serde(default, skip_serializing)]
     pub private_key: Option<String>,
+    #[serde(default, skip_serializing)]
     pub passphrase: Option<String>,
 
     pub password_id: Option<String>,
@@ -109,6 +189,35 @@ pub struct ServerConfig {
     pub keep_alive_interval: Option<u32>,
     pub auto_reconnect: Option<bool>,
     pub max_reconnects: Option<u32>,
+
+    // 终端回滚缓冲大小 (字节)，为空时使用默认值
+    pub scrollback_size: Option<u32>,
+
+    #[serde(default = "default_protocol")]
+    pub protocol: Protocol,
+
+    // Telnet 登录脚本，为空时按 login:/Password: 自动填充
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub login_script: Vec<TelnetLoginStep>,
+
+    // 连接时自动进入 (或恢复) 该名称的 tmux 会话，断线重连后回到原来的工作现场
+    pub tmux_session: Option<String>,
+
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub env_vars: Vec<EnvVar>,
+
+    // 登录后自动进入的目录
+    pub initial_cwd: Option<String>,
+
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub startup_commands: Vec<StartupCommand>,
+
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub algorithm_prefs: Option<AlgorithmPreferences>,
 }
 
 // 默认值函数
@@ -119,6 +228,7 @@ fn default_connection_type() -> ConnectionType { ConnectionType::Direct }
 fn default_auth_type() -> AuthType { AuthType::Password }
 fn default_username() -> String { "root".to_string() }
 fn default_os() -> OsType { OsType::Linux }
+fn default_protocol() -> Protocol { Protocol::Ssh }
 
 // =========================================================
 // SshConfig (SSH 连接配置核心)
@@ -144,6 +254,9 @@ pub struct SshConfig {
     pub keep_alive_interval: Option<u32>,
     pub auto_reconnect: Option<bool>,
     pub max_reconnects: Option<u32>,
+
+    #[serde(default)]
+    pub algorithms: Option<AlgorithmPreferences>,
 }
 
 // =========================================================
@@ -218,6 +331,8 @@ pub struct TestConnectionPayload {
     // 高级设置
     pub connect_timeout: Option<u32>,
     pub proxy_id: Option<String>,
+    #[serde(default)]
+    pub algorithm_prefs: Option<AlgorithmPreferences>,
 }
 
 // =========================================================
@@ -242,8 +357,9 @@ pub struct CommandUsageItem {
     pub last_used_at: i64,
 }
 
-// 敏感词过滤配置 (暂时硬编码结构，后续可存入数据库)
-#[derive(Debug, Clone)]
+// 敏感词过滤配置 (作为设置项持久化，见 models::settings)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct HistoryFilterConfig {
     pub ignore_short: bool,
     pub min_length: usize,
@@ -0,0 +1,140 @@
+// src-tauri/src/models/settings.rs
+//
+// 应用设置 (持久化在 app_settings 表中，每个分组一行 JSON)
+// 所有字段带 serde(default)：旧版本写入的数据缺少新字段时自动使用默认值。
+use serde::{Deserialize, Serialize};
+
+use super::HistoryFilterConfig;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CloseBehavior {
+    #[default]
+    Quit,
+    Minimize,
+}
+
+// 窗口行为
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WindowSettings {
+    pub minimize_to_tray: bool,
+    pub close_behavior: CloseBehavior,
+}
+
+impl Default for WindowSettings {
+    fn default() -> Self {
+        Self {
+            minimize_to_tray: true,
+            close_behavior: CloseBehavior::Quit,
+        }
+    }
+}
+
+// 新建终端的默认选项 (与前端 terminal.* 设置项的默认值一致)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TerminalDefaults {
+    pub font_family: String,
+    pub font_size: u32,
+    pub line_height: f64,
+    pub scrollback: u32,
+    pub cursor_style: String,
+    pub cursor_blink: bool,
+}
+
+impl Default for TerminalDefaults {
+    fn default() -> Self {
+        Self {
+            font_family: "Menlo, Monaco, \"Courier New\", monospace".to_string(),
+            font_size: 14,
+            line_height: 1.0,
+            scrollback: 1000,
+            cursor_style: "block".to_string(),
+            cursor_blink: true,
+        }
+    }
+}
+
+// 监控面板的刷新间隔 (毫秒)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MonitorSettings {
+    /// 终端侧边栏的 CPU / 内存 / 磁盘 / 网络
+    pub metrics_interval_ms: u64,
+    /// 进程列表
+    pub process_interval_ms: u64,
+    /// 独立监控窗口
+    pub dashboard_interval_ms: u64,
+}
+
+impl Default for MonitorSettings {
+    fn default() -> Self {
+        Self {
+            metrics_interval_ms: 3000,
+            process_interval_ms: 5000,
+            dashboard_interval_ms: 10000,
+        }
+    }
+}
+
+// 保险库自动锁定
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct VaultSettings {
+    /// 空闲多少分钟后锁定，0 表示不自动锁定
+    pub auto_lock_minutes: u32,
+}
+
+// 服务器到期提醒
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ExpirationSettings {
+    /// 提前多少天提醒，0 表示关闭提醒
+    pub warn_days: u32,
+}
+
+impl Default for ExpirationSettings {
+    fn default() -> Self {
+        Self { warn_days: 7 }
+    }
+}
+
+// 回收站
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TrashSettings {
+    /// 删除的条目保留多少天，0 表示不自动清除
+    pub retention_days: u32,
+}
+
+impl Default for TrashSettings {
+    fn default() -> Self {
+        Self { retention_days: 30 }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AppSettings {
+    pub window: WindowSettings,
+    pub terminal: TerminalDefaults,
+    pub history: HistoryFilterConfig,
+    pub monitor: MonitorSettings,
+    pub vault: VaultSettings,
+    pub expiration: ExpirationSettings,
+    pub trash: TrashSettings,
+}
+
+// 局部更新：只替换传入的分组
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsPatch {
+    pub window: Option<WindowSettings>,
+    pub terminal: Option<TerminalDefaults>,
+    pub history: Option<HistoryFilterConfig>,
+    pub monitor: Option<MonitorSettings>,
+    pub vault: Option<VaultSettings>,
+    pub expiration: Option<ExpirationSettings>,
+    pub trash: Option<TrashSettings>,
+}
@@ -0,0 +1,65 @@
+// src-tauri/src/models/template.rs
+//
+// 服务器模板：集中定义认证方式、凭据、端口、代理、超时与启动命令。
+// 服务器通过 template_id 引用模板，template_overrides 中列出的字段使用服务器自己的值，
+// 其余字段在连接时取模板上的值 (模板未设置的字段仍使用服务器自己的值)。
+use serde::{Deserialize, Serialize};
+
+use super::{AuthType, StartupCommand};
+
+/// 可被服务器单独覆盖的模板字段
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum TemplateField {
+    /// 认证方式与凭据 (auth_type / password_id / key_id 作为整体)
+    Auth,
+    Port,
+    Proxy,
+    ConnectTimeout,
+    KeepAliveInterval,
+    StartupCommands,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerTemplate {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+
+    // 以下字段为 None 时表示模板不设置该项
+    pub auth_type: Option<AuthType>,
+    pub password_id: Option<String>,
+    pub key_id: Option<String>,
+    pub port: Option<u16>,
+    pub proxy_id: Option<String>,
+    pub connect_timeout: Option<u32>,
+    pub keep_alive_interval: Option<u32>,
+    pub startup_commands: Option<Vec<StartupCommand>>,
+
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+impl ServerTemplate {
+    /// 去掉服务器覆盖的字段，剩下的就是连接时要套用的值
+    pub fn without_overrides(mut self, overrides: &[TemplateField]) -> Self {
+        for field in overrides {
+            match field {
+                TemplateField::Auth => {
+                    self.auth_type = None;
+                    self.password_id = None;
+                    self.key_id = None;
+                }
+                TemplateField::Port => self.port = None,
+                TemplateField::Proxy => self.proxy_id = None,
+                TemplateField::ConnectTimeout => self.connect_timeout = None,
+                TemplateField::KeepAliveInterval => self.keep_alive_interval = None,
+                TemplateField::StartupCommands => self.startup_commands = None,
+            }
+        }
+        self
+    }
+}
@@ -5,7 +5,12 @@ use tauri::{AppHandle, Runtime, Manager};
 use walkdir::WalkDir;
 use zip::write::FileOptions;
 // 🛑 [移除] ProgressPayload (不再需要)
+use crate::commands::settings::{SettingsState, SETTINGS_SNAPSHOT_FILE};
+use crate::db::{migrations, DB_FILE_NAME};
+use crate::error::AppError;
 use crate::models::backup::{BackupMetadata, CommandResult}; 
+use sqlx::sqlite::SqlitePoolOptions;
+use std::path::Path;
 
 // 🛑 [删除] 整个 emit_progress 函数
 // fn emit_progress<R: Runtime>(app: &AppHandle<R>, msg: &str, progress: f64) { ... }
@@ -17,7 +22,7 @@ pub fn pack_config_dir<R: Runtime, W>(
 ) -> CommandResult<()> 
 where W: Write + Seek 
 {
-    let config_dir = app.path().app_config_dir().map_err(|e| e.to_string())?;
+    let config_dir = app.path().app_config_dir().map_err(|e| AppError::Io(e.to_string()))?;
     
     let mut zip = zip::ZipWriter::new(writer);
     let options = FileOptions::default()
@@ -26,16 +31,24 @@ where W: Write + Seek
 
     // 1. 写入元数据
     if let Ok(meta_json) = serde_json::to_string_pretty(&metadata) {
-        zip.start_file("backup_meta.json", options).map_err(|e| e.to_string())?;
-        zip.write_all(meta_json.as_bytes()).map_err(|e| e.to_string())?;
+        zip.start_file("backup_meta.json", options).map_err(|e| AppError::Io(e.to_string()))?;
+        zip.write_all(meta_json.as_bytes()).map_err(|e| AppError::Io(e.to_string()))?;
     }
 
-    // 2. 遍历并打包
+    // 2. 设置快照：数据库可能不在配置目录下 (Linux)，单独写一份保证设置随备份走
+    if let Some(settings) = app.try_state::<SettingsState>() {
+        if let Ok(settings_json) = serde_json::to_string_pretty(&settings.snapshot()) {
+            zip.start_file(SETTINGS_SNAPSHOT_FILE, options).map_err(|e| AppError::Io(e.to_string()))?;
+            zip.write_all(settings_json.as_bytes()).map_err(|e| AppError::Io(e.to_string()))?;
+        }
+    }
+
+    // 3. 遍历并打包
     let walk_dir = WalkDir::new(&config_dir);
     for entry in walk_dir.into_iter().filter_map(|e| e.ok()) {
         let path = entry.path();
         let name = path.strip_prefix(&config_dir)
-            .map_err(|e| e.to_string())?
+            .map_err(|e| AppError::Io(e.to_string()))?
             .to_str()
             .ok_or("Invalid path encoding")?;
         
@@ -43,18 +56,22 @@ where W: Write + Seek
         if name.contains(".webdav_secret") || name.contains(".credentials") {
             continue;
         }
+        // 快照已在上面写入
+        if name == SETTINGS_SNAPSHOT_FILE {
+            continue;
+        }
 
         if path.is_file() {
-            zip.start_file(name, options).map_err(|e| e.to_string())?;
-            let mut f = File::open(path).map_err(|e| e.to_string())?;
+            zip.start_file(name, options).map_err(|e| AppError::Io(e.to_string()))?;
+            let mut f = File::open(path).map_err(|e| AppError::Io(e.to_string()))?;
             let mut buffer = Vec::new();
-            f.read_to_end(&mut buffer).map_err(|e| e.to_string())?;
-            zip.write_all(&buffer).map_err(|e| e.to_string())?;
+            f.read_to_end(&mut buffer).map_err(|e| AppError::Io(e.to_string()))?;
+            zip.write_all(&buffer).map_err(|e| AppError::Io(e.to_string()))?;
         } else if !name.is_empty() {
-            zip.add_directory(name, options).map_err(|e| e.to_string())?;
+            zip.add_directory(name, options).map_err(|e| AppError::Io(e.to_string()))?;
         }
     }
-    zip.finish().map_err(|e| e.to_string())?;
+    zip.finish().map_err(|e| AppError::Io(e.to_string()))?;
     Ok(())
 }
 
@@ -64,11 +81,11 @@ pub fn unpack_zip_to_config<R: Runtime, Reader>(
 ) -> CommandResult<()> 
 where Reader: Read + Seek 
 {
-    let config_dir = app.path().app_config_dir().map_err(|e| e.to_string())?;
-    let mut archive = zip::ZipArchive::new(reader).map_err(|e| format!("Invalid zip: {}", e))?;
+    let config_dir = app.path().app_config_dir().map_err(|e| AppError::Io(e.to_string()))?;
+    let mut archive = zip::ZipArchive::new(reader).map_err(|e| AppError::InvalidInput(format!("Invalid zip: {}", e)))?;
 
     for i in 0..archive.len() {
-        let mut file = archive.by_index(i).map_err(|e| e.to_string())?;
+        let mut file = archive.by_index(i).map_err(|e| AppError::Io(e.to_string()))?;
         
         if let Some(name) = file.enclosed_name() {
             // 🔒 安全过滤
@@ -77,15 +94,75 @@ where Reader: Read + Seek
             let outpath = config_dir.join(name);
 
             if file.name().ends_with('/') {
-                fs::create_dir_all(&outpath).map_err(|e| e.to_string())?;
+                fs::create_dir_all(&outpath).map_err(|e| AppError::Io(e.to_string()))?;
             } else {
                 if let Some(p) = outpath.parent() {
-                    if !p.exists() { fs::create_dir_all(p).map_err(|e| e.to_string())?; }
+                    if !p.exists() { fs::create_dir_all(p).map_err(|e| AppError::Io(e.to_string()))?; }
                 }
-                let mut outfile = File::create(&outpath).map_err(|e| e.to_string())?;
-                std::io::copy(&mut file, &mut outfile).map_err(|e| e.to_string())?;
+                let mut outfile = File::create(&outpath).map_err(|e| AppError::Io(e.to_string()))?;
+                std::io::copy(&mut file, &mut outfile).map_err(|e| AppError::Io(e.to_string()))?;
             }
         }
     }
     Ok(())
-}
\ No newline at end of file
+}
+
+/// 恢复前校验备份中的数据库
+/// 解压到临时目录打开，检查完整性与 schema 版本：
+/// 比当前程序新的版本拒绝恢复 (旧程序无法使用新结构)，旧版本在下次启动时自动迁移。
+/// 备份中不含数据库时直接通过。
+pub async fn validate_backup_schema(zip_path: &Path) -> CommandResult<()> {
+    let file = File::open(zip_path).map_err(|e| AppError::NotFound(format!("Backup file missing: {}", e)))?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| AppError::InvalidInput(format!("Invalid zip: {}", e)))?;
+    if archive.by_name(DB_FILE_NAME).is_err() {
+        return Ok(());
+    }
+
+    let check_dir = std::env::temp_dir().join(format!("piterm_restore_check_{}", uuid::Uuid::new_v4()));
+    fs::create_dir_all(&check_dir)?;
+
+    // WAL 中可能还有未合并的数据，一并解压
+    let wal_name = format!("{}-wal", DB_FILE_NAME);
+    for name in [DB_FILE_NAME, wal_name.as_str()] {
+        if let Ok(mut entry) = archive.by_name(name) {
+            let mut out = File::create(check_dir.join(name))?;
+            std::io::copy(&mut entry, &mut out)?;
+        }
+    }
+
+    let result = check_extracted_db(&check_dir.join(DB_FILE_NAME)).await;
+    let _ = fs::remove_dir_all(&check_dir);
+    result
+}
+
+async fn check_extracted_db(db_path: &Path) -> CommandResult<()> {
+    let db_url = format!("sqlite://{}", db_path.to_string_lossy());
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect(&db_url)
+        .await
+        .map_err(|e| AppError::InvalidInput(format!("Backup database cannot be opened: {}", e)))?;
+
+    let check = async {
+        let integrity: String = sqlx::query_scalar("PRAGMA quick_check;")
+            .fetch_one(&pool)
+            .await?;
+        if integrity != "ok" {
+            return Err(AppError::InvalidInput(format!("Backup database is corrupted: {}", integrity)));
+        }
+
+        let version = migrations::current_version(&pool).await?;
+        let latest = migrations::latest_version();
+        if version > latest {
+            return Err(AppError::InvalidInput(format!(
+                "Backup schema version {} is newer than this build supports ({}), please update PiTerm first",
+                version, latest
+            )));
+        }
+        Ok(())
+    }
+    .await;
+
+    pool.close().await;
+    check
+}
@@ -1,8 +1,19 @@
 use tauri::{AppHandle, Runtime, Emitter};
 use reqwest::Client;
 use regex::Regex;
+use crate::error::AppError;
 use crate::models::backup::{CommandResult, CloudBackupFile, ProgressPayload};
 
+// 按 HTTP 状态码归类 WebDAV 错误
+fn status_error(context: &str, status: reqwest::StatusCode) -> AppError {
+    let details = format!("{}: {}", context, status);
+    match status.as_u16() {
+        401 | 403 => AppError::AuthFailed(details),
+        404 => AppError::NotFound(details),
+        _ => AppError::Network(details),
+    }
+}
+
 fn emit_progress<R: Runtime>(app: &AppHandle<R>, msg: &str, progress: f64) {
     let _ = app.emit("backup_progress", ProgressPayload {
         message: msg.to_string(),
@@ -17,12 +28,12 @@ pub async fn check_connection(url: &str, username: &str, password: &str) -> Comm
         .header("Depth", "0")
         .send()
         .await
-        .map_err(|e| format!("Request failed: {}", e))?;
+        .map_err(|e| AppError::Network(format!("Request failed: {}", e)))?;
 
     if res.status().is_success() || res.status().as_u16() == 207 {
         Ok(())
     } else {
-        Err(format!("Server returned status: {}", res.status()))
+        Err(status_error("Server returned status", res.status()))
     }
 }
 
@@ -44,12 +55,12 @@ pub async fn upload_file<R: Runtime>(
         .body(file_content)
         .send()
         .await
-        .map_err(|e| format!("Upload failed: {}", e))?;
+        .map_err(|e| AppError::Network(format!("Upload failed: {}", e)))?;
 
     if res.status().is_success() || res.status().as_u16() == 201 || res.status().as_u16() == 204 {
         Ok(())
     } else {
-        Err(format!("WebDAV upload failed: {}", res.status()))
+        Err(status_error("WebDAV upload failed", res.status()))
     }
 }
 
@@ -67,10 +78,10 @@ pub async fn download_file<R: Runtime>(
         .basic_auth(username, Some(password))
         .send()
         .await
-        .map_err(|e| format!("Download failed: {}", e))?;
+        .map_err(|e| AppError::Network(format!("Download failed: {}", e)))?;
 
     if !res.status().is_success() {
-        return Err(format!("Server error: {}", res.status()));
+        return Err(status_error("Server error", res.status()));
     }
 
     // 精确进度下载
@@ -80,7 +91,7 @@ pub async fn download_file<R: Runtime>(
     
     emit_progress(app, "backup.progress.downloading", 20.0);
 
-    while let Some(chunk) = res.chunk().await.map_err(|e| format!("Chunk error: {}", e))? {
+    while let Some(chunk) = res.chunk().await.map_err(|e| AppError::Network(format!("Chunk error: {}", e)))? {
         content.extend_from_slice(&chunk);
         downloaded += chunk.len() as u64;
         
@@ -101,14 +112,13 @@ pub async fn list_files(url: &str, username: &str, password: &str) -> CommandRes
         .basic_auth(username, Some(password))
         .header("Depth", "1")
         .send()
-        .await
-        .map_err(|e| e.to_string())?;
+        .await?;
 
     if !res.status().is_success() && res.status().as_u16() != 207 {
-         return Err(format!("Failed to list files: {}", res.status()));
+         return Err(status_error("Failed to list files", res.status()));
     }
 
-    let body = res.text().await.map_err(|e| e.to_string())?;
+    let body = res.text().await?;
     let mut files = Vec::new();
 
     // Regex parsing (same as before)
@@ -147,11 +157,11 @@ pub async fn delete_file(url: &str, username: &str, password: &str, filename: &s
         .basic_auth(username, Some(password))
         .send()
         .await
-        .map_err(|e| format!("Delete failed: {}", e))?;
+        .map_err(|e| AppError::Network(format!("Delete failed: {}", e)))?;
 
     if res.status().is_success() || res.status().as_u16() == 204 {
         Ok(())
     } else {
-        Err(format!("Status: {}", res.status()))
+        Err(status_error("Status", res.status()))
     }
 }
\ No newline at end of file
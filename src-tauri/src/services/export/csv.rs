@@ -0,0 +1,73 @@
+// src-tauri/src/services/export/csv.rs
+//
+// 生成 CSV 清单，列名与 services::import::csv 可识别的列一致，便于再次导入。
+use super::ExportedServer;
+use crate::models::Protocol;
+
+const COLUMNS: &[&str] = &[
+    "name",
+    "host",
+    "port",
+    "username",
+    "protocol",
+    "tags",
+    "proxy",
+    "identity_file",
+    "connect_timeout",
+    "keep_alive_interval",
+];
+
+// 仅在加密导出时追加
+const SECRET_COLUMNS: &[&str] = &["password", "private_key", "passphrase"];
+
+pub fn render(servers: &[ExportedServer], encrypted: bool) -> String {
+    let mut header: Vec<&str> = COLUMNS.to_vec();
+    if encrypted {
+        header.extend_from_slice(SECRET_COLUMNS);
+    }
+
+    let mut out = String::new();
+    push_record(&mut out, header.iter().map(|h| h.to_string()));
+
+    for server in servers {
+        let protocol = match server.protocol {
+            Protocol::Ssh => "ssh",
+            Protocol::Telnet => "telnet",
+        };
+        let mut record = vec![
+            server.name.clone(),
+            server.host.clone(),
+            server.port.to_string(),
+            server.username.clone(),
+            protocol.to_string(),
+            server.tags.join(";"),
+            server.proxy.clone().unwrap_or_default(),
+            server.identity_file.clone().unwrap_or_default(),
+            server.connect_timeout.map(|v| v.to_string()).unwrap_or_default(),
+            server.keep_alive_interval.map(|v| v.to_string()).unwrap_or_default(),
+        ];
+        if encrypted {
+            let secrets = server.secrets.clone().unwrap_or_default();
+            record.push(secrets.password.unwrap_or_default());
+            record.push(secrets.private_key.unwrap_or_default());
+            record.push(secrets.passphrase.unwrap_or_default());
+        }
+        push_record(&mut out, record.into_iter());
+    }
+    out
+}
+
+fn push_record(out: &mut String, fields: impl Iterator<Item = String>) {
+    let line: Vec<String> = fields.map(|f| escape(&f)).collect();
+    out.push_str(&line.join(","));
+    out.push_str("\r\n");
+}
+
+// RFC 4180：含逗号、引号或换行的字段用双引号包裹
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
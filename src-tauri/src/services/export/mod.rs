@@ -0,0 +1,92 @@
+// src-tauri/src/services/export/mod.rs
+//
+// 导出服务器清单：commands::export 负责读库、过滤和解密凭据，
+// 这里只把 ExportedServer 渲染为目标格式 (ssh_config / CSV / JSON)。
+use serde::{Deserialize, Serialize};
+
+use crate::error::{AppError, AppResult};
+use crate::models::Protocol;
+
+pub mod csv;
+pub mod ssh_config;
+
+/// 密钥派生参数 (与 Vault 一致)，写入 JSON 导出供解密方参考
+pub const KDF_NAME: &str = "PBKDF2-HMAC-SHA256";
+pub const KDF_ITERATIONS: u32 = 100_000;
+pub const CIPHER_NAME: &str = "AES-256-GCM";
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ExportFormat {
+    SshConfig,
+    Csv,
+    Json,
+}
+
+/// 加密后的凭据，每个值是 {iv, data, salt} JSON (口令派生密钥加密)
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedSecrets {
+    pub password: Option<String>,
+    pub private_key: Option<String>,
+    pub passphrase: Option<String>,
+}
+
+/// 导出的一台服务器 (不含内部 id 与 Vault 引用)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedServer {
+    pub name: String,
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub protocol: Protocol,
+    pub auth_type: String,
+    pub tags: Vec<String>,
+    /// 代理 URL (type://[user@]host:port)，不含代理密码
+    pub proxy: Option<String>,
+    /// 私钥来源文件路径 (导入时记录)
+    pub identity_file: Option<String>,
+    pub connect_timeout: Option<u32>,
+    pub keep_alive_interval: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<ExportedSecrets>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EncryptionInfo {
+    cipher: &'static str,
+    kdf: &'static str,
+    iterations: u32,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonExport<'a> {
+    version: u32,
+    exported_at: i64,
+    encryption: Option<EncryptionInfo>,
+    servers: &'a [ExportedServer],
+}
+
+/// 渲染为指定格式
+pub fn render(format: ExportFormat, servers: &[ExportedServer], encrypted: bool) -> AppResult<String> {
+    match format {
+        ExportFormat::SshConfig => Ok(ssh_config::render(servers)),
+        ExportFormat::Csv => Ok(csv::render(servers, encrypted)),
+        ExportFormat::Json => {
+            let export = JsonExport {
+                version: 1,
+                exported_at: chrono::Utc::now().timestamp_millis(),
+                encryption: encrypted.then_some(EncryptionInfo {
+                    cipher: CIPHER_NAME,
+                    kdf: KDF_NAME,
+                    iterations: KDF_ITERATIONS,
+                }),
+                servers,
+            };
+            serde_json::to_string_pretty(&export).map_err(|e| AppError::Internal(e.to_string()))
+        }
+    }
+}
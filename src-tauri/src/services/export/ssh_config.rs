@@ -0,0 +1,76 @@
+// src-tauri/src/services/export/ssh_config.rs
+//
+// 生成 OpenSSH 客户端配置。ssh_config 无法携带凭据，只输出连接参数；
+// Telnet 服务器以注释形式列出，代理通过 nc 的 ProxyCommand 实现。
+use std::fmt::Write;
+
+use super::ExportedServer;
+use crate::models::Protocol;
+
+pub fn render(servers: &[ExportedServer]) -> String {
+    let mut out = format!(
+        "# Generated by PiTerm on {}\n",
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
+    );
+
+    for server in servers {
+        out.push('\n');
+        if !server.tags.is_empty() {
+            let _ = writeln!(out, "# tags: {}", server.tags.join(", "));
+        }
+        if server.protocol == Protocol::Telnet {
+            let _ = writeln!(out, "# {} (telnet {}:{}) skipped", server.name, server.host, server.port);
+            continue;
+        }
+
+        let _ = writeln!(out, "Host {}", host_alias(&server.name));
+        let _ = writeln!(out, "    HostName {}", server.host);
+        if server.port != 22 {
+            let _ = writeln!(out, "    Port {}", server.port);
+        }
+        let _ = writeln!(out, "    User {}", server.username);
+        if let Some(identity) = &server.identity_file {
+            let _ = writeln!(out, "    IdentityFile {}", quote(identity));
+        }
+        if let Some(timeout) = server.connect_timeout {
+            let _ = writeln!(out, "    ConnectTimeout {}", timeout);
+        }
+        if let Some(interval) = server.keep_alive_interval {
+            let _ = writeln!(out, "    ServerAliveInterval {}", interval);
+        }
+        if let Some(command) = server.proxy.as_deref().and_then(proxy_command) {
+            let _ = writeln!(out, "    ProxyCommand {}", command);
+        }
+    }
+    out
+}
+
+// Host 别名不能包含空白和通配符
+fn host_alias(name: &str) -> String {
+    let alias: String = name
+        .trim()
+        .chars()
+        .map(|c| if c.is_whitespace() || matches!(c, '*' | '?' | '!' | '#') { '-' } else { c })
+        .collect();
+    if alias.is_empty() { "unnamed".to_string() } else { alias }
+}
+
+fn quote(value: &str) -> String {
+    if value.contains(char::is_whitespace) {
+        format!("\"{}\"", value)
+    } else {
+        value.to_string()
+    }
+}
+
+// type://[user@]host:port -> nc -X <版本> -x host:port %h %p
+fn proxy_command(url: &str) -> Option<String> {
+    let (scheme, rest) = url.split_once("://")?;
+    let address = rest.rsplit_once('@').map_or(rest, |(_, a)| a);
+    let version = match scheme {
+        "http" | "https" => "connect",
+        "socks4" => "4",
+        _ => "5",
+    };
+    Some(format!("nc -X {} -x {} %h %p", version, address))
+}
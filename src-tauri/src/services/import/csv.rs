@@ -0,0 +1,227 @@
+// src-tauri/src/services/import/csv.rs
+//
+// CSV 导入 (Termius 导出及通用表格)：第一行为表头，列名不区分大小写并忽略符号，
+// 例如 "Hostname/IP" 与 "hostname_ip" 视为同一列。未识别的列会在预览中提示。
+use std::path::Path;
+
+use super::{read_text, ImportedHost, ImportedProxy, ParsedImport, SkippedEntry};
+use crate::error::{AppError, AppResult};
+use crate::models::Protocol;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Column {
+    Name,
+    Host,
+    Port,
+    Username,
+    Group,
+    Tags,
+    Key,
+    Protocol,
+    Proxy,
+    Password,
+}
+
+// 规范化后的列名 -> 字段
+const COLUMN_ALIASES: &[(&str, Column)] = &[
+    ("name", Column::Name),
+    ("label", Column::Name),
+    ("alias", Column::Name),
+    ("title", Column::Name),
+    ("host", Column::Host),
+    ("hostname", Column::Host),
+    ("hostnameip", Column::Host),
+    ("address", Column::Host),
+    ("ip", Column::Host),
+    ("port", Column::Port),
+    ("username", Column::Username),
+    ("user", Column::Username),
+    ("login", Column::Username),
+    ("group", Column::Group),
+    ("groups", Column::Group),
+    ("folder", Column::Group),
+    ("tags", Column::Tags),
+    ("tag", Column::Tags),
+    ("key", Column::Key),
+    ("sshkey", Column::Key),
+    ("identityfile", Column::Key),
+    ("privatekey", Column::Key),
+    ("keyfile", Column::Key),
+    ("protocol", Column::Protocol),
+    ("proxy", Column::Proxy),
+    ("password", Column::Password),
+];
+
+/// 解析 CSV 文件
+pub fn parse_file(path: &Path) -> AppResult<ParsedImport> {
+    let content = read_text(path)?;
+    let mut records = parse_records(&content).into_iter();
+    let header = records
+        .next()
+        .ok_or_else(|| AppError::InvalidInput(format!("{} is empty", path.display())))?;
+
+    let mut parsed = ParsedImport::default();
+    let mut ignored = Vec::new();
+    let columns: Vec<Option<Column>> = header
+        .iter()
+        .map(|h| {
+            let normalized: String = h.chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_lowercase();
+            let column = COLUMN_ALIASES.iter().find(|(alias, _)| *alias == normalized).map(|(_, c)| *c);
+            if column.is_none() && !h.trim().is_empty() {
+                ignored.push(h.trim().to_string());
+            }
+            column
+        })
+        .collect();
+
+    if !columns.contains(&Some(Column::Host)) {
+        return Err(AppError::InvalidInput("CSV has no host column".to_string()));
+    }
+    if !ignored.is_empty() {
+        parsed.warnings.push(format!("Ignored columns: {}", ignored.join(", ")));
+    }
+    if columns.contains(&Some(Column::Password)) {
+        parsed
+            .warnings
+            .push("Passwords are not imported from CSV, add them in the vault after import".to_string());
+    }
+
+    for (index, record) in records.enumerate() {
+        if record.iter().all(|v| v.trim().is_empty()) {
+            continue;
+        }
+        let get = |column: Column| {
+            columns
+                .iter()
+                .position(|c| *c == Some(column))
+                .and_then(|i| record.get(i))
+                .map(|v| v.trim())
+                .filter(|v| !v.is_empty())
+        };
+
+        // 表头占第 1 行
+        let line_label = format!("Row {}", index + 2);
+        let Some(host) = get(Column::Host) else {
+            parsed.skipped.push(SkippedEntry::new(line_label, "No host name"));
+            continue;
+        };
+        let name = get(Column::Name).unwrap_or(host).to_string();
+
+        let protocol = match get(Column::Protocol).map(str::to_lowercase).as_deref() {
+            None | Some("ssh") => Protocol::Ssh,
+            Some("telnet") => Protocol::Telnet,
+            Some(other) => {
+                parsed
+                    .skipped
+                    .push(SkippedEntry::new(name, format!("Protocol '{}' is not supported", other)));
+                continue;
+            }
+        };
+
+        let mut warnings = Vec::new();
+        let default_port = if protocol == Protocol::Telnet { 23 } else { 22 };
+        let port = match get(Column::Port) {
+            Some(p) => p.parse::<u16>().unwrap_or_else(|_| {
+                warnings.push(format!("Invalid Port '{}', using {}", p, default_port));
+                default_port
+            }),
+            None => default_port,
+        };
+
+        let proxy = get(Column::Proxy).and_then(|url| {
+            let proxy = parse_proxy_url(url);
+            if proxy.is_none() {
+                warnings.push(format!("Proxy '{}' was not recognized", url));
+            }
+            proxy
+        });
+
+        let mut tags: Vec<String> = get(Column::Group).map(str::to_string).into_iter().collect();
+        for tag in get(Column::Tags).unwrap_or_default().split([',', ';']) {
+            let tag = tag.trim();
+            if !tag.is_empty() && !tags.iter().any(|t| t == tag) {
+                tags.push(tag.to_string());
+            }
+        }
+
+        parsed.hosts.push(ImportedHost {
+            name,
+            host: host.to_string(),
+            port,
+            username: get(Column::Username).map(str::to_string),
+            identity_file: get(Column::Key).map(str::to_string),
+            connect_timeout: None,
+            keep_alive_interval: None,
+            protocol,
+            proxy,
+            tags,
+            warnings,
+        });
+    }
+    Ok(parsed)
+}
+
+// RFC 4180：逗号分隔，双引号包裹的字段可含逗号、换行，"" 表示一个引号
+fn parse_records(content: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (in_quotes, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            (true, '"') => in_quotes = false,
+            (true, _) => field.push(c),
+            (false, '"') => in_quotes = true,
+            (false, ',') => record.push(std::mem::take(&mut field)),
+            (false, '\r') => {}
+            (false, '\n') => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            (false, _) => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records
+}
+
+// type://[user[:password]@]host:port
+fn parse_proxy_url(url: &str) -> Option<ImportedProxy> {
+    let (scheme, rest) = url.split_once("://")?;
+    let proxy_type = match scheme.to_lowercase().as_str() {
+        "http" | "https" => "http",
+        "socks4" | "socks4a" => "socks4",
+        "socks" | "socks5" | "socks5h" => "socks5",
+        _ => return None,
+    };
+    let rest = rest.trim_end_matches('/');
+    let (credentials, address) = match rest.rsplit_once('@') {
+        Some((c, a)) => (Some(c), a),
+        None => (None, rest),
+    };
+    let (host, port) = address.rsplit_once(':')?;
+    let (username, password) = match credentials.map(|c| c.split_once(':').unwrap_or((c, ""))) {
+        Some((u, p)) => (
+            Some(u.to_string()).filter(|u| !u.is_empty()),
+            Some(p.to_string()).filter(|p| !p.is_empty()),
+        ),
+        None => (None, None),
+    };
+
+    Some(ImportedProxy {
+        proxy_type: proxy_type.to_string(),
+        host: host.to_string(),
+        port: port.parse().ok()?,
+        username,
+        password,
+    })
+}
@@ -0,0 +1,140 @@
+// src-tauri/src/services/import/mobaxterm.rs
+//
+// MobaXterm 会话导入：解析导出的 .mxtsessions 文件。
+// 每个 [Bookmarks*] 节是一个文件夹 (SubRep 为文件夹路径)，其余每行是一个会话：
+//   名称=#图标#类型%主机%端口%用户名%...#字体设置#...
+// 类型与主机之间的字段以 % 分隔，下面的下标依据 MobaXterm 导出的 SSH 会话格式。
+use std::path::Path;
+
+use super::{parse_ini, read_text, ImportedHost, ImportedProxy, ParsedImport, SkippedEntry};
+use crate::error::{AppError, AppResult};
+use crate::models::Protocol;
+
+const TYPE_SSH: &str = "0";
+const TYPE_TELNET: &str = "1";
+
+const FIELD_HOST: usize = 1;
+const FIELD_PORT: usize = 2;
+const FIELD_USER: usize = 3;
+const FIELD_GATEWAY_HOST: usize = 8;
+const FIELD_PRIVATE_KEY: usize = 14;
+const FIELD_PROXY_TYPE: usize = 19;
+const FIELD_PROXY_HOST: usize = 20;
+const FIELD_PROXY_PORT: usize = 21;
+const FIELD_PROXY_USER: usize = 22;
+
+/// 解析 MobaXterm 会话导出文件
+pub fn parse_file(path: &Path) -> AppResult<ParsedImport> {
+    let sections = parse_ini(&read_text(path)?);
+    let bookmarks: Vec<_> = sections
+        .iter()
+        .filter(|(name, _)| name.to_lowercase().starts_with("bookmarks"))
+        .collect();
+    if bookmarks.is_empty() {
+        return Err(AppError::InvalidInput(format!(
+            "{} is not a MobaXterm sessions file",
+            path.display()
+        )));
+    }
+
+    let mut parsed = ParsedImport::default();
+    for (_, entries) in bookmarks {
+        let folder = entries
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case("SubRep"))
+            .map(|(_, v)| v.replace('\\', "/"))
+            .filter(|f| !f.is_empty());
+
+        for (name, value) in entries {
+            if name.eq_ignore_ascii_case("SubRep") || name.eq_ignore_ascii_case("ImgNum") {
+                continue;
+            }
+            match parse_session(name, value, folder.as_deref()) {
+                Ok(host) => parsed.hosts.push(host),
+                Err(reason) => parsed.skipped.push(SkippedEntry::new(name.as_str(), reason)),
+            }
+        }
+    }
+    Ok(parsed)
+}
+
+fn parse_session(name: &str, value: &str, folder: Option<&str>) -> Result<ImportedHost, String> {
+    // #图标#会话参数#...
+    let data = value.split('#').nth(2).ok_or("Unrecognized session format")?;
+    let fields: Vec<&str> = data.split('%').collect();
+    let field = |i: usize| fields.get(i).map(|v| v.trim()).filter(|v| !v.is_empty());
+
+    let protocol = match fields[0] {
+        TYPE_SSH => Protocol::Ssh,
+        TYPE_TELNET => Protocol::Telnet,
+        other => return Err(format!("Session type {} is not supported", other)),
+    };
+    let host = field(FIELD_HOST).ok_or("No host name")?.to_string();
+
+    let mut warnings = Vec::new();
+    let default_port = if protocol == Protocol::Telnet { 23 } else { 22 };
+    let port = match field(FIELD_PORT) {
+        Some(p) => p.parse::<u16>().unwrap_or_else(|_| {
+            warnings.push(format!("Invalid Port '{}', using {}", p, default_port));
+            default_port
+        }),
+        None => default_port,
+    };
+
+    let mut identity_file = None;
+    let mut proxy = None;
+    if protocol == Protocol::Ssh {
+        if let Some(gateway) = field(FIELD_GATEWAY_HOST) {
+            warnings.push(format!(
+                "SSH gateway {} is not supported, the server will be connected directly",
+                gateway
+            ));
+        }
+
+        // _ProfileDir_ 是 MobaXterm 对用户目录的占位符；PuTTY 格式的私钥 libssh2 读不了，只给出提示
+        identity_file = field(FIELD_PRIVATE_KEY)
+            .map(|key| match dirs::home_dir() {
+                Some(home) => key.replace("_ProfileDir_", &home.to_string_lossy()),
+                None => key.to_string(),
+            })
+            .and_then(|key| {
+                if key.to_lowercase().ends_with(".ppk") {
+                    warnings.push(format!(
+                        "PuTTY key {} must be converted to OpenSSH format and imported manually",
+                        key
+                    ));
+                    None
+                } else {
+                    Some(key)
+                }
+            });
+
+        let proxy_type = match field(FIELD_PROXY_TYPE) {
+            Some("1") => Some("http"),
+            Some("2") => Some("socks4"),
+            Some("3") => Some("socks5"),
+            _ => None,
+        };
+        proxy = proxy_type.zip(field(FIELD_PROXY_HOST)).map(|(proxy_type, proxy_host)| ImportedProxy {
+            proxy_type: proxy_type.to_string(),
+            host: proxy_host.to_string(),
+            port: field(FIELD_PROXY_PORT).and_then(|p| p.parse().ok()).unwrap_or(1080),
+            username: field(FIELD_PROXY_USER).map(str::to_string),
+            password: None,
+        });
+    }
+
+    Ok(ImportedHost {
+        name: name.to_string(),
+        host,
+        port,
+        username: field(FIELD_USER).map(str::to_string),
+        identity_file,
+        connect_timeout: None,
+        keep_alive_interval: None,
+        protocol,
+        proxy,
+        tags: folder.map(str::to_string).into_iter().collect(),
+        warnings,
+    })
+}
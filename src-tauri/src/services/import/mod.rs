@@ -0,0 +1,159 @@
+// src-tauri/src/services/import/mod.rs
+//
+// 从外部配置导入服务器：各格式的解析器只负责产出 ImportedHost，
+// 与现有服务器比对、写库由 commands::import 统一处理。
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{AppError, AppResult};
+use crate::models::Protocol;
+
+pub mod csv;
+pub mod mobaxterm;
+pub mod putty;
+pub mod ssh_config;
+pub mod xshell;
+
+/// 会话引用的代理 (确认导入时在 proxies 表中查找或创建)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportedProxy {
+    /// http / socks4 / socks5，与 proxies.proxy_type 取值一致
+    pub proxy_type: String,
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+/// 从外部配置解析出的一台主机 (尚未写库)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportedHost {
+    pub name: String,
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    /// 私钥文件路径，确认导入时读取内容存入 Vault
+    pub identity_file: Option<String>,
+    pub connect_timeout: Option<u32>,
+    pub keep_alive_interval: Option<u32>,
+    #[serde(default)]
+    pub protocol: Protocol,
+    #[serde(default)]
+    pub proxy: Option<ImportedProxy>,
+    /// 文件夹与标签 (文件夹路径整体作为一个标签)
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// 无法映射到 ServerConfig 的选项 (如 ProxyJump)
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct ParsedImport {
+    pub hosts: Vec<ImportedHost>,
+    /// 整个文件级别的提示 (不支持的指令、无法读取的 Include 等)
+    pub warnings: Vec<String>,
+    /// 无法导入的会话 (RDP、串口等不支持的协议)
+    pub skipped: Vec<SkippedEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkippedEntry {
+    pub name: String,
+    pub reason: String,
+}
+
+impl SkippedEntry {
+    pub fn new(name: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            reason: reason.into(),
+        }
+    }
+}
+
+/// 读取文本文件，识别 UTF-16 (注册表导出、Xshell 会话常用) 与 UTF-8 BOM
+pub fn read_text(path: &Path) -> AppResult<String> {
+    let bytes = fs::read(path).map_err(|e| AppError::NotFound(format!("{}: {}", path.display(), e)))?;
+
+    let decode_utf16 = |data: &[u8], le: bool| {
+        let units: Vec<u16> = data
+            .chunks_exact(2)
+            .map(|c| if le { u16::from_le_bytes([c[0], c[1]]) } else { u16::from_be_bytes([c[0], c[1]]) })
+            .collect();
+        String::from_utf16_lossy(&units)
+    };
+
+    let text = match bytes.as_slice() {
+        [0xFF, 0xFE, rest @ ..] => decode_utf16(rest, true),
+        [0xFE, 0xFF, rest @ ..] => decode_utf16(rest, false),
+        [0xEF, 0xBB, 0xBF, rest @ ..] => String::from_utf8_lossy(rest).to_string(),
+        // 无 BOM 的 UTF-16LE：ASCII 字符的高字节为 0
+        [_, 0, ..] => decode_utf16(&bytes, true),
+        _ => String::from_utf8_lossy(&bytes).to_string(),
+    };
+    Ok(text)
+}
+
+/// INI 分节：(节名, [(键, 值)])，保持文件中的顺序
+pub type IniSection = (String, Vec<(String, String)>);
+
+/// 解析 INI 格式 (Xshell、MobaXterm)，节名外的内容归入空节名
+pub fn parse_ini(content: &str) -> Vec<IniSection> {
+    let mut sections: Vec<IniSection> = vec![(String::new(), Vec::new())];
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(';') {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            sections.push((name.to_string(), Vec::new()));
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            if let Some((_, entries)) = sections.last_mut() {
+                entries.push((key.trim().to_string(), value.trim().to_string()));
+            }
+        }
+    }
+    sections
+}
+
+/// 在 INI 节中按键名 (忽略大小写) 取值
+pub fn ini_get<'a>(entries: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    entries
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(key))
+        .map(|(_, v)| v.as_str())
+        .filter(|v| !v.is_empty())
+}
+
+/// 简单通配符匹配：* 匹配任意长度，? 匹配单个字符
+pub fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let t: Vec<char> = text.chars().collect();
+    let (mut pi, mut ti) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+
+    while ti < t.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == t[ti]) {
+            pi += 1;
+            ti += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            star = Some((pi, ti));
+            pi += 1;
+        } else if let Some((star_pi, star_ti)) = star {
+            pi = star_pi + 1;
+            ti = star_ti + 1;
+            star = Some((star_pi, star_ti + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|c| *c == '*')
+}
@@ -0,0 +1,200 @@
+// src-tauri/src/services/import/putty.rs
+//
+// PuTTY 会话导入：解析 regedit 导出的 .reg 文件
+// (HKEY_CURRENT_USER\Software\SimonTatham\PuTTY\Sessions)，每个子键是一个会话，子键名经过 URL 编码。
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::{read_text, ImportedHost, ImportedProxy, ParsedImport, SkippedEntry};
+use crate::error::{AppError, AppResult};
+use crate::models::Protocol;
+
+const SESSIONS_KEY: &str = "\\software\\simontatham\\putty\\sessions\\";
+
+enum RegValue {
+    Str(String),
+    Dword(u32),
+}
+
+/// 解析 PuTTY 注册表导出文件
+pub fn parse_file(path: &Path) -> AppResult<ParsedImport> {
+    let content = read_text(path)?;
+    if !content.trim_start().starts_with("Windows Registry Editor") && !content.trim_start().starts_with("REGEDIT4") {
+        return Err(AppError::InvalidInput(format!("{} is not a registry export", path.display())));
+    }
+
+    let mut sessions: Vec<(String, HashMap<String, RegValue>)> = Vec::new();
+    let mut in_session = false;
+    for line in content.lines() {
+        let line = line.trim();
+        if let Some(key) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            let lower = key.to_lowercase();
+            in_session = match lower.find(SESSIONS_KEY) {
+                // 只取 Sessions 的直接子键
+                Some(pos) if !key[pos + SESSIONS_KEY.len()..].contains('\\') => {
+                    let raw = &key[pos + SESSIONS_KEY.len()..];
+                    let name = urlencoding::decode(raw)
+                        .map(|n| n.to_string())
+                        .unwrap_or_else(|_| raw.to_string());
+                    sessions.push((name, HashMap::new()));
+                    true
+                }
+                _ => false,
+            };
+            continue;
+        }
+        if !in_session {
+            continue;
+        }
+        if let (Some((name, value)), Some((_, values))) = (parse_value(line), sessions.last_mut()) {
+            values.insert(name.to_lowercase(), value);
+        }
+    }
+
+    let mut parsed = ParsedImport::default();
+    if sessions.is_empty() {
+        parsed.warnings.push("No PuTTY sessions found in the registry export".to_string());
+    }
+    for (name, values) in sessions {
+        // 默认设置只是新会话的模板
+        if name == "Default Settings" {
+            continue;
+        }
+        match session_to_host(&name, &values) {
+            Ok(host) => parsed.hosts.push(host),
+            Err(reason) => parsed.skipped.push(SkippedEntry::new(name, reason)),
+        }
+    }
+    Ok(parsed)
+}
+
+// "Name"="value" / "Name"=dword:00000016，其余类型 (hex 等) 忽略
+fn parse_value(line: &str) -> Option<(String, RegValue)> {
+    let rest = line.strip_prefix('"')?;
+    let end = rest.find("\"=")?;
+    let name = rest[..end].to_string();
+    let raw = &rest[end + 2..];
+
+    if let Some(hex) = raw.strip_prefix("dword:") {
+        return u32::from_str_radix(hex.trim(), 16).ok().map(|v| (name, RegValue::Dword(v)));
+    }
+    let quoted = raw.strip_prefix('"')?.strip_suffix('"')?;
+    let mut value = String::new();
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(next) = chars.next() {
+                value.push(next);
+            }
+        } else {
+            value.push(c);
+        }
+    }
+    Some((name, RegValue::Str(value)))
+}
+
+fn session_to_host(name: &str, values: &HashMap<String, RegValue>) -> Result<ImportedHost, String> {
+    let get_str = |key: &str| match values.get(key) {
+        Some(RegValue::Str(s)) if !s.is_empty() => Some(s.clone()),
+        _ => None,
+    };
+    let get_dword = |key: &str| match values.get(key) {
+        Some(RegValue::Dword(v)) => Some(*v),
+        _ => None,
+    };
+
+    let protocol = match get_str("protocol").as_deref() {
+        None | Some("ssh") => Protocol::Ssh,
+        Some("telnet") => Protocol::Telnet,
+        Some(other) => return Err(format!("Protocol '{}' is not supported", other)),
+    };
+
+    let Some(host_name) = get_str("hostname") else {
+        return Err("No host name".to_string());
+    };
+    // HostName 可以写成 user@host
+    let (mut username, host) = match host_name.rsplit_once('@') {
+        Some((user, host)) => (Some(user.to_string()), host.to_string()),
+        None => (None, host_name),
+    };
+    if let Some(user) = get_str("username") {
+        username = Some(user);
+    }
+
+    let mut warnings = Vec::new();
+    let default_port = if protocol == Protocol::Telnet { 23 } else { 22 };
+    let port = get_dword("portnumber")
+        .and_then(|p| u16::try_from(p).ok())
+        .filter(|p| *p > 0)
+        .unwrap_or(default_port);
+
+    // libssh2 无法读取 PuTTY 格式的私钥，需要先用 PuTTYgen 转换为 OpenSSH 格式
+    let identity_file = get_str("publickeyfile").and_then(|key| {
+        if key.to_lowercase().ends_with(".ppk") {
+            warnings.push(format!(
+                "PuTTY key {} must be converted to OpenSSH format and imported manually",
+                key
+            ));
+            None
+        } else {
+            Some(key)
+        }
+    });
+
+    // 新版本为秒，老版本 PingInterval 为分钟
+    let keep_alive_interval = get_dword("pingintervalsecs")
+        .or_else(|| get_dword("pinginterval").map(|m| m * 60))
+        .filter(|n| *n > 0);
+
+    let proxy_type = match get_dword("proxymethod").unwrap_or(0) {
+        0 => None,
+        1 => Some("socks4"),
+        2 => Some("socks5"),
+        3 => Some("http"),
+        other => {
+            let kind = match other {
+                4 => "Telnet",
+                5 => "local command",
+                _ => "SSH",
+            };
+            warnings.push(format!(
+                "{} proxy is not supported, the server will be connected directly",
+                kind
+            ));
+            None
+        }
+    };
+    let proxy = proxy_type.and_then(|proxy_type| {
+        let host = get_str("proxyhost")?;
+        Some(ImportedProxy {
+            proxy_type: proxy_type.to_string(),
+            host,
+            port: get_dword("proxyport")
+                .and_then(|p| u16::try_from(p).ok())
+                .unwrap_or(if proxy_type == "http" { 8080 } else { 1080 }),
+            username: get_str("proxyusername"),
+            password: get_str("proxypassword"),
+        })
+    });
+
+    if get_str("portforwardings").is_some() {
+        warnings.push("Port forwardings were not imported".to_string());
+    }
+    if get_str("remotecommand").is_some() {
+        warnings.push("Remote command was not imported".to_string());
+    }
+
+    Ok(ImportedHost {
+        name: name.to_string(),
+        host,
+        port,
+        username,
+        identity_file,
+        connect_timeout: None,
+        keep_alive_interval,
+        protocol,
+        proxy,
+        tags: Vec::new(),
+        warnings,
+    })
+}
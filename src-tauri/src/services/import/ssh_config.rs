@@ -0,0 +1,399 @@
+// src-tauri/src/services/import/ssh_config.rs
+//
+// OpenSSH 客户端配置 (~/.ssh/config) 解析
+// 按 ssh 的规则计算每个具体主机的生效配置：依次扫描所有匹配的 Host 块，同一选项第一次出现的值生效。
+// 含通配符的 Host 块只作为默认值，不会单独导入；Match 块不支持，直接跳过。
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{wildcard_match, ImportedHost, ParsedImport};
+use crate::error::{AppError, AppResult};
+use crate::models::Protocol;
+
+const MAX_INCLUDE_DEPTH: usize = 16;
+
+struct Block {
+    patterns: Vec<String>,
+    /// Match 块：其中的选项全部忽略
+    skip: bool,
+    /// (小写关键字, 原始参数)
+    options: Vec<(String, String)>,
+}
+
+struct Parser {
+    ssh_dir: PathBuf,
+    blocks: Vec<Block>,
+    warnings: Vec<String>,
+    visited: HashSet<PathBuf>,
+    match_warned: bool,
+}
+
+/// 解析 ssh 配置文件
+pub fn parse_file(path: &Path) -> AppResult<ParsedImport> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| AppError::NotFound(format!("{}: {}", path.display(), e)))?;
+
+    let ssh_dir = dirs::home_dir()
+        .map(|h| h.join(".ssh"))
+        .or_else(|| path.parent().map(Path::to_path_buf))
+        .unwrap_or_default();
+
+    let mut parser = Parser {
+        ssh_dir,
+        // 第一个 Host 之前的选项对所有主机生效
+        blocks: vec![Block {
+            patterns: vec!["*".to_string()],
+            skip: false,
+            options: Vec::new(),
+        }],
+        warnings: Vec::new(),
+        visited: HashSet::new(),
+        match_warned: false,
+    };
+    if let Ok(canonical) = path.canonicalize() {
+        parser.visited.insert(canonical);
+    }
+    parser.parse_content(&content, 0);
+
+    let hosts = parser
+        .concrete_aliases()
+        .into_iter()
+        .map(|alias| parser.resolve_host(&alias))
+        .collect();
+
+    Ok(ParsedImport {
+        hosts,
+        warnings: parser.warnings,
+        skipped: Vec::new(),
+    })
+}
+
+impl Parser {
+    fn parse_content(&mut self, content: &str, depth: usize) {
+        for line in content.lines() {
+            let Some((keyword, args)) = split_line(line) else {
+                continue;
+            };
+
+            match keyword.as_str() {
+                "host" => self.blocks.push(Block {
+                    patterns: args,
+                    skip: false,
+                    options: Vec::new(),
+                }),
+                "match" => {
+                    if !self.match_warned {
+                        self.warnings
+                            .push("Match blocks are not supported and were skipped".to_string());
+                        self.match_warned = true;
+                    }
+                    self.blocks.push(Block {
+                        patterns: Vec::new(),
+                        skip: true,
+                        options: Vec::new(),
+                    });
+                }
+                "include" => self.include(&args, depth),
+                _ => {
+                    if let Some(block) = self.blocks.last_mut().filter(|b| !b.skip) {
+                        block.options.push((keyword, args.join(" ")));
+                    }
+                }
+            }
+        }
+    }
+
+    // Include 的内容按原位展开；被包含文件中的 Host 不影响包含点之后的上下文
+    fn include(&mut self, args: &[String], depth: usize) {
+        if depth >= MAX_INCLUDE_DEPTH {
+            self.warnings
+                .push("Include nesting is too deep, remaining includes were skipped".to_string());
+            return;
+        }
+        let (outer_patterns, outer_skip) = match self.blocks.last() {
+            Some(b) => (b.patterns.clone(), b.skip),
+            None => (vec!["*".to_string()], false),
+        };
+        if outer_skip {
+            return;
+        }
+
+        let block_count = self.blocks.len();
+        for arg in args {
+            let expanded = expand_tilde(arg);
+            let path = if expanded.is_absolute() {
+                expanded
+            } else {
+                self.ssh_dir.join(expanded)
+            };
+
+            for file in expand_glob(&path) {
+                let Ok(canonical) = file.canonicalize() else {
+                    continue;
+                };
+                if !self.visited.insert(canonical) {
+                    continue;
+                }
+                match fs::read_to_string(&file) {
+                    Ok(content) => self.parse_content(&content, depth + 1),
+                    Err(e) => self
+                        .warnings
+                        .push(format!("Failed to read include {}: {}", file.display(), e)),
+                }
+            }
+        }
+
+        if self.blocks.len() != block_count {
+            self.blocks.push(Block {
+                patterns: outer_patterns,
+                skip: false,
+                options: Vec::new(),
+            });
+        }
+    }
+
+    /// 所有 Host 行中不含通配符和否定的别名，按出现顺序去重
+    fn concrete_aliases(&self) -> Vec<String> {
+        let mut seen = HashSet::new();
+        let mut aliases = Vec::new();
+        for block in self.blocks.iter().filter(|b| !b.skip) {
+            for pattern in &block.patterns {
+                if pattern.contains(['*', '?', '!']) {
+                    continue;
+                }
+                if seen.insert(pattern.to_lowercase()) {
+                    aliases.push(pattern.clone());
+                }
+            }
+        }
+        aliases
+    }
+
+    fn block_matches(block: &Block, alias: &str) -> bool {
+        let alias = alias.to_lowercase();
+        let mut matched = false;
+        for pattern in &block.patterns {
+            let pattern = pattern.to_lowercase();
+            if let Some(negated) = pattern.strip_prefix('!') {
+                if wildcard_match(negated, &alias) {
+                    return false;
+                }
+            } else if wildcard_match(&pattern, &alias) {
+                matched = true;
+            }
+        }
+        matched
+    }
+
+    // 计算生效配置：同一选项以第一次出现的值为准
+    fn resolve_host(&self, alias: &str) -> ImportedHost {
+        let mut options: HashMap<&str, &str> = HashMap::new();
+        for block in self.blocks.iter().filter(|b| !b.skip && Self::block_matches(b, alias)) {
+            for (key, value) in &block.options {
+                options.entry(key.as_str()).or_insert(value.as_str());
+            }
+        }
+
+        let mut warnings = Vec::new();
+        let host = options
+            .get("hostname")
+            .map(|h| h.replace("%h", alias).replace("%%", "%"))
+            .unwrap_or_else(|| alias.to_string());
+
+        let port = match options.get("port") {
+            Some(p) => p.parse::<u16>().unwrap_or_else(|_| {
+                warnings.push(format!("Invalid Port '{}', using 22", p));
+                22
+            }),
+            None => 22,
+        };
+
+        // 未指定 User 时 ssh 使用本地用户名
+        let username = options
+            .get("user")
+            .map(|u| u.to_string())
+            .or_else(local_username);
+
+        let identity_file = options
+            .get("identityfile")
+            .filter(|f| !f.eq_ignore_ascii_case("none"))
+            .map(|f| {
+                let expanded = expand_tokens(f, alias, &host, username.as_deref());
+                let path = expand_tilde(&expanded);
+                let path = if path.is_relative() {
+                    dirs::home_dir().map(|h| h.join(&path)).unwrap_or(path)
+                } else {
+                    path
+                };
+                path.to_string_lossy().to_string()
+            });
+
+        let parse_secs = |key: &str, label: &str, warnings: &mut Vec<String>| -> Option<u32> {
+            options.get(key).and_then(|v| match v.parse::<u32>() {
+                Ok(n) => Some(n),
+                Err(_) => {
+                    warnings.push(format!("Invalid {} '{}' was ignored", label, v));
+                    None
+                }
+            })
+        };
+        let keep_alive_interval = parse_secs("serveraliveinterval", "ServerAliveInterval", &mut warnings)
+            .filter(|n| *n > 0);
+        let connect_timeout = parse_secs("connecttimeout", "ConnectTimeout", &mut warnings);
+
+        if let Some(jump) = options.get("proxyjump").filter(|v| !v.eq_ignore_ascii_case("none")) {
+            warnings.push(format!(
+                "ProxyJump {} is not supported, the server will be connected directly",
+                jump
+            ));
+        }
+        if let Some(command) = options.get("proxycommand").filter(|v| !v.eq_ignore_ascii_case("none")) {
+            warnings.push(format!(
+                "ProxyCommand '{}' is not supported, the server will be connected directly",
+                command
+            ));
+        }
+
+        ImportedHost {
+            name: alias.to_string(),
+            host,
+            port,
+            username,
+            identity_file,
+            connect_timeout,
+            keep_alive_interval,
+            protocol: Protocol::Ssh,
+            proxy: None,
+            tags: Vec::new(),
+            warnings,
+        }
+    }
+}
+
+// 拆分一行为 (小写关键字, 参数)，关键字与参数之间可以是空白或 '='
+fn split_line(line: &str) -> Option<(String, Vec<String>)> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let split_at = line
+        .find(|c: char| c.is_whitespace() || c == '=')
+        .unwrap_or(line.len());
+    let keyword = line[..split_at].to_lowercase();
+    let rest = line[split_at..].trim_start();
+    let rest = rest.strip_prefix('=').unwrap_or(rest).trim();
+
+    Some((keyword, tokenize(rest)))
+}
+
+// 按空白分割参数，支持单双引号
+fn tokenize(s: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut has_token = false;
+
+    for c in s.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                has_token = true;
+            }
+            None if c.is_whitespace() => {
+                if has_token {
+                    args.push(std::mem::take(&mut current));
+                    has_token = false;
+                }
+            }
+            None => {
+                current.push(c);
+                has_token = true;
+            }
+        }
+    }
+    if has_token {
+        args.push(current);
+    }
+    args
+}
+
+fn local_username() -> Option<String> {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .ok()
+        .filter(|u| !u.is_empty())
+}
+
+fn expand_tilde(path: &str) -> PathBuf {
+    if path == "~" {
+        return dirs::home_dir().unwrap_or_else(|| PathBuf::from(path));
+    }
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+// IdentityFile 中常用的 % 占位符
+fn expand_tokens(value: &str, alias: &str, host: &str, user: Option<&str>) -> String {
+    let home = dirs::home_dir()
+        .map(|h| h.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let local_user = local_username().unwrap_or_default();
+
+    let mut out = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('%') => out.push('%'),
+            Some('d') => out.push_str(&home),
+            Some('h') => out.push_str(host),
+            Some('n') => out.push_str(alias),
+            Some('r') => out.push_str(user.unwrap_or_default()),
+            Some('u') => out.push_str(&local_user),
+            Some(other) => {
+                out.push('%');
+                out.push(other);
+            }
+            None => out.push('%'),
+        }
+    }
+    out
+}
+
+// Include 支持文件名部分的通配符，结果按文件名排序 (与 ssh 一致)
+fn expand_glob(path: &Path) -> Vec<PathBuf> {
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    if !file_name.contains(['*', '?']) {
+        return if path.is_file() { vec![path.to_path_buf()] } else { Vec::new() };
+    }
+
+    let Some(dir) = path.parent() else {
+        return Vec::new();
+    };
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.is_file())
+        .filter(|p| {
+            p.file_name()
+                .map(|n| wildcard_match(&file_name, &n.to_string_lossy()))
+                .unwrap_or(false)
+        })
+        .collect();
+    files.sort();
+    files
+}
@@ -0,0 +1,133 @@
+// src-tauri/src/services/import/xshell.rs
+//
+// Xshell 会话导入：每个 .xsh 文件是一个 INI 格式的会话。
+// 可以选择单个文件，也可以选择 Sessions 目录，此时子目录路径作为文件夹标签。
+// Xshell 的密码、用户密钥和代理配置保存在各自的存储中，会话文件只有引用名，因此只能给出提示。
+use std::path::Path;
+
+use walkdir::WalkDir;
+
+use super::{ini_get, parse_ini, read_text, ImportedHost, ParsedImport, SkippedEntry};
+use crate::error::AppResult;
+use crate::models::Protocol;
+
+const SESSION_EXT: &str = "xsh";
+
+/// 解析 .xsh 文件或包含 .xsh 文件的目录
+pub fn parse_path(path: &Path) -> AppResult<ParsedImport> {
+    let mut parsed = ParsedImport::default();
+
+    if !path.is_dir() {
+        let name = session_name(path);
+        parse_session(path, &name, None, &mut parsed)?;
+        return Ok(parsed);
+    }
+
+    let mut files: Vec<_> = WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .filter(|p| p.extension().is_some_and(|ext| ext.eq_ignore_ascii_case(SESSION_EXT)))
+        .collect();
+    files.sort();
+
+    if files.is_empty() {
+        parsed.warnings.push(format!("No .xsh session files found in {}", path.display()));
+    }
+    for file in files {
+        let name = session_name(&file);
+        // 相对 Sessions 目录的子目录路径
+        let folder = file
+            .parent()
+            .and_then(|p| p.strip_prefix(path).ok())
+            .map(|p| p.to_string_lossy().replace('\\', "/"))
+            .filter(|f| !f.is_empty());
+        if let Err(e) = parse_session(&file, &name, folder, &mut parsed) {
+            parsed.skipped.push(SkippedEntry::new(name, e.to_string()));
+        }
+    }
+    Ok(parsed)
+}
+
+fn session_name(path: &Path) -> String {
+    path.file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+fn parse_session(path: &Path, name: &str, folder: Option<String>, parsed: &mut ParsedImport) -> AppResult<()> {
+    let sections = parse_ini(&read_text(path)?);
+    let section = |name: &str| {
+        sections
+            .iter()
+            .find(|(s, _)| s.eq_ignore_ascii_case(name))
+            .map(|(_, entries)| entries.as_slice())
+            .unwrap_or_default()
+    };
+    let connection = section("CONNECTION");
+    let auth = section("CONNECTION:AUTHENTICATION");
+    let proxy = section("CONNECTION:PROXY");
+
+    let protocol = match ini_get(connection, "Protocol").map(str::to_uppercase).as_deref() {
+        None | Some("SSH") => Protocol::Ssh,
+        Some("TELNET") => Protocol::Telnet,
+        Some(other) => {
+            parsed
+                .skipped
+                .push(SkippedEntry::new(name, format!("Protocol '{}' is not supported", other)));
+            return Ok(());
+        }
+    };
+
+    let Some(host) = ini_get(connection, "Host") else {
+        parsed.skipped.push(SkippedEntry::new(name, "No host name"));
+        return Ok(());
+    };
+
+    let mut warnings = Vec::new();
+    let default_port = if protocol == Protocol::Telnet { 23 } else { 22 };
+    let port = match ini_get(connection, "Port") {
+        Some(p) => p.parse::<u16>().unwrap_or_else(|_| {
+            warnings.push(format!("Invalid Port '{}', using {}", p, default_port));
+            default_port
+        }),
+        None => default_port,
+    };
+
+    let keep_alive_interval = (ini_get(connection, "SendKeepAlive") == Some("1"))
+        .then(|| ini_get(connection, "SendKeepAliveInterval").and_then(|v| v.parse::<u32>().ok()))
+        .flatten()
+        .filter(|n| *n > 0);
+
+    if ini_get(auth, "Password").is_some() {
+        warnings.push("Saved password is encrypted by Xshell and was not imported".to_string());
+    }
+    if let Some(key) = ini_get(auth, "UserKey") {
+        warnings.push(format!(
+            "User key '{}' must be exported from Xshell and imported manually",
+            key
+        ));
+    }
+    if let Some(profile) = ini_get(proxy, "Proxy") {
+        warnings.push(format!(
+            "Proxy profile '{}' is stored outside the session file and was not imported",
+            profile
+        ));
+    }
+
+    parsed.hosts.push(ImportedHost {
+        name: name.to_string(),
+        host: host.to_string(),
+        port,
+        username: ini_get(auth, "UserName").map(str::to_string),
+        identity_file: None,
+        connect_timeout: None,
+        keep_alive_interval,
+        protocol,
+        proxy: None,
+        tags: folder.into_iter().collect(),
+        warnings,
+    });
+    Ok(())
+}
@@ -1,2 +1,4 @@
 pub mod backup;
-pub mod highlight;
\ No newline at end of file
+pub mod export;
+pub mod highlight;
+pub mod import;
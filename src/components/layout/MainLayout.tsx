@@ -9,11 +9,20 @@ import { useSettingsStore } from "@/features/settings/application/useSettingsSto
 
 // 🟢 [新增] 引入我们刚才创建的 Hook
 import { useLocalImage } from "@/hooks/useLocalImage"; 
+import { useCliRequests } from "@/hooks/useCliRequests";
+import { useExpirationAlerts } from "@/features/server/expiration";
+import { CommandPalette } from "@/features/search";
 
 export const MainLayout = () => {
   const location = useLocation();
   const settings = useSettingsStore(s => s.settings);
 
+  // 命令行 / ssh:// 链接发来的连接请求
+  useCliRequests();
+
+  // 服务器到期提醒
+  useExpirationAlerts();
+
   // 1. 解析当前主题 (用于判断显示 Light 还是 Dark 壁纸)
   const appTheme = settings['appearance.appTheme'];
   const [systemTheme, setSystemTheme] = useState<'light'|'dark'>('dark');
@@ -114,6 +123,9 @@ export const MainLayout = () => {
         />
       </div>
 
+      {/* 命令面板 (Ctrl/Cmd + K) */}
+      <CommandPalette />
+
       {/* --- 前景布局 (保持不变) --- */}
       <div className="relative z-10 flex w-full h-full">
         <Sidebar />
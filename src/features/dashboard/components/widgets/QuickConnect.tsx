@@ -5,8 +5,7 @@ import { Zap, ArrowRight, Monitor, Command } from "lucide-react";
 import { toast } from "sonner";
 import { useTerminalStore } from "@/store/useTerminalStore";
 import { useServerStore } from "@/features/server/application/useServerStore";
-import { Server } from "@/features/server/domain/types";
-import { v4 as uuidv4 } from 'uuid';
+import { createQuickServer } from "@/features/server/domain/quickServer";
 import { cn } from "@/lib/utils"; // 🟢 引入 cn 工具函数以匹配 DashboardHeader 风格
 
 export const QuickConnect = () => {
@@ -35,30 +34,8 @@ export const QuickConnect = () => {
 
     toast.info(t('dashboard.quickConnect.connecting', 'Connecting to {{host}}...', { host }));
 
-    const quickId = `quick-${uuidv4()}`;
-
     // 2. 构建 Server 对象
-    const quickServer: Server = {
-        id: quickId,
-        name: `${username}@${host}:${port}`,
-        ip: host,
-        port: port,
-        username: username,
-        authType: 'password', 
-        connectionType: 'direct',
-        os: 'linux', 
-        icon: 'zap', 
-        provider: 'QuickConnect', 
-        tags: ['Quick'],
-        sort: 0,
-        isPinned: false,
-        enableExpiration: false,
-        createdAt: Date.now(),
-        updatedAt: Date.now(),
-        passwordSource: 'manual',
-        keySource: 'manual',
-        password: "" 
-    };
+    const quickServer = createQuickServer(host, port, username);
 
     try {
         // 3. 存入 Store 并跳转
@@ -9,6 +9,7 @@ import { FileEntry } from '@/features/fs/types';
 import { ConfirmDialog } from '@/components/common/ConfirmDialog';
 import { getLanguage } from './config';
 import { cn } from '@/lib/utils';
+import { getErrorMessage } from '@/utils/error';
 
 // [修改] 定义 Props
 interface FileEditorProps {
@@ -76,7 +77,7 @@ export const FileEditor = ({ sessionId, file, isStandalone = false, onRefresh }:
             if (onRefresh) onRefresh();
         } catch (err: any) {
             console.error(err);
-            toast.error(t('editor.error.save', 'Failed to save: ') + err.toString());
+            toast.error(t('editor.error.save', 'Failed to save: ') + getErrorMessage(err));
         } finally {
             setIsSaving(false);
         }
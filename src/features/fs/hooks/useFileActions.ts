@@ -11,6 +11,7 @@ import { open, save } from '@tauri-apps/plugin-dialog';
 import { isEditable } from '../editor/config';
 // [新增] 引入 Tauri 窗口 API
 import { WebviewWindow } from '@tauri-apps/api/webviewWindow';
+import { getErrorMessage } from '@/utils/error';
 
 const pathUtils = {
     join: (parent: string, name: string) => {
@@ -217,7 +218,7 @@ export const useFileActions = (sessionId: string) => {
             showToast(t('fs.msg.downloadSuccess', 'Download successful'));
         } catch (error: any) {
             console.error("Download failed:", error);
-            updateStatus(transferId, 'error', error.toString());
+            updateStatus(transferId, 'error', getErrorMessage(error));
             showToast(t('fs.msg.downloadFailed', 'Download failed'), 'error');
         } finally {
             setIsSubmitting(false);
@@ -277,7 +278,7 @@ export const useFileActions = (sessionId: string) => {
             }
         } catch (err: any) {
             console.error("Paste error:", err);
-            showToast(err.toString(), 'error');
+            showToast(getErrorMessage(err), 'error');
         } finally {
             setIsSubmitting(false);
         }
@@ -420,7 +421,7 @@ export const useFileActions = (sessionId: string) => {
 
         } catch (error: any) {
             console.error("FS Action Error:", error);
-            showToast(error.toString(), 'error');
+            showToast(getErrorMessage(error), 'error');
         } finally {
             setIsSubmitting(false);
         }
@@ -4,6 +4,9 @@ import { AlertTriangle, Loader2, Trash2, Link2 } from 'lucide-react';
 import { useKeyStore } from '@/store/useKeyStore';
 import { KeyUsageStats } from '../types';
 import { ConfirmDialog } from '@/components/common/ConfirmDialog'; // 🟢 引入公共组件
+import { toast } from 'sonner';
+import { showUndoToast } from '@/features/trash';
+import { getErrorMessage } from '@/utils/error';
 
 interface Props {
     keyId: string | null; // 传入 ID 即表示打开弹窗
@@ -12,7 +15,7 @@ interface Props {
 
 export const DeleteKeyModal = ({ keyId, onClose }: Props) => {
     const { t } = useTranslation();
-    const { checkAssociations, deleteKey } = useKeyStore();
+    const { checkAssociations, deleteKey, loadKeys } = useKeyStore();
     
     // 状态管理
     const [isChecking, setIsChecking] = useState(false); // 正在检查关联
@@ -40,11 +43,13 @@ export const DeleteKeyModal = ({ keyId, onClose }: Props) => {
         
         setIsDeleting(true);
         try {
-            await deleteKey(keyId);
+            // 关联警告已展示给用户，确认即强制删除
+            await deleteKey(keyId, true);
+            showUndoToast(t, t('trash.keyDeleted', 'Credential moved to trash'), [{ kind: 'key', id: keyId }], loadKeys);
             onClose(); // 删除成功后关闭
         } catch (error) {
             console.error("Delete failed", error);
-            // 这里可以加一个 toast.error 提示
+            toast.error(getErrorMessage(error));
         } finally {
             setIsDeleting(false);
         }
@@ -103,7 +108,7 @@ export const DeleteKeyModal = ({ keyId, onClose }: Props) => {
             open={!!keyId}
             onOpenChange={(open) => !open && onClose()}
             title={t('keys.delete.title', 'Delete Credential')}
-            description={t('keys.delete.confirm', 'Are you sure you want to delete this credential? This action cannot be undone.')}
+            description={t('keys.delete.confirm', 'Move this credential to the trash? You can restore it from Settings > Trash.')}
             variant="destructive"
             confirmText={t('common.delete', 'Delete')}
             cancelText={t('common.cancel', 'Cancel')}
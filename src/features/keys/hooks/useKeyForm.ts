@@ -3,6 +3,8 @@ import { open } from '@tauri-apps/plugin-dialog';
 import { readTextFile } from '@tauri-apps/plugin-fs';
 import { useKeyStore } from '@/store/useKeyStore';
 import { KeyType } from '../types';
+import { toast } from 'sonner';
+import { getErrorMessage } from '@/utils/error';
 
 export const useKeyForm = () => {
     const { 
@@ -131,7 +133,9 @@ export const useKeyForm = () => {
             }
             closeModal();
         } catch (e) {
+            // 编辑时会先删除旧条目，仍被服务器引用时后端拒绝
             console.error(e);
+            toast.error(getErrorMessage(e));
         } finally {
             setIsLoading(false);
         }
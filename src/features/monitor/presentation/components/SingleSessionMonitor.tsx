@@ -9,6 +9,7 @@ import { clsx } from "clsx";
 
 import { useMonitorStore, RemoteCpuInfo, RemoteMemInfo, RemoteDiskInfo, RemoteOsInfo, RemoteNetworkInfo } from "@/store/useMonitorStore";
 import { MonitorDescriptor, MonitorSyncPayload } from "@/features/terminal/components/monitor/types";
+import { useMonitorSettings } from "@/store/useAppSettingsStore";
 
 import { InfoCard } from "@/features/terminal/components/monitor/card/InfoCard";
 import { CpuCard } from "@/features/terminal/components/monitor/card/CpuCard";
@@ -28,6 +29,7 @@ export const SingleSessionMonitor = ({ sessionId, isDashboard = false }: Props)
 
   const { sessions, setSessionData, updateHistory } = useMonitorStore();
   const sessionData = sessions[sessionId];
+  const { dashboardIntervalMs } = useMonitorSettings();
 
   const CARD_DESCRIPTORS = useMemo<MonitorDescriptor[]>(() => [
     { id: 'os',   Component: InfoCard,    icon: <Server className="w-5 h-5" />,   color: "green" },
@@ -74,13 +76,13 @@ export const SingleSessionMonitor = ({ sessionId, isDashboard = false }: Props)
       } catch (err) {}
     };
 
-    const interval = setInterval(fetchData, 10000); 
+    const interval = setInterval(fetchData, dashboardIntervalMs); 
 
     return () => {
         unlistenPromise.then(fn => fn());
         clearInterval(interval);
     };
-  }, [sessionId, setSessionData, updateHistory]);
+  }, [sessionId, setSessionData, updateHistory, dashboardIntervalMs]);
 
   if (!sessionData) {
       return (
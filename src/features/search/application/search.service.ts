@@ -0,0 +1,8 @@
+import { invoke } from '@tauri-apps/api/core';
+import { SearchKind, SearchResult } from '../domain/types';
+
+export const SearchService = {
+  // kinds 为空时搜索全部类型
+  search: (query: string, kinds?: SearchKind[], limit?: number) =>
+    invoke<SearchResult[]>('global_search', { query, kinds: kinds ?? null, limit: limit ?? null }),
+};
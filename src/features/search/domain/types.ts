@@ -0,0 +1,16 @@
+// 全局搜索 (对应 src-tauri/src/commands/search.rs)
+
+export type SearchKind = 'server' | 'snippet' | 'key' | 'history';
+
+export interface SearchResult {
+  kind: SearchKind;
+  // 源表 id (命令历史为数字 id 的字符串形式)
+  id: string;
+  title: string;
+  subtitle?: string | null;
+  // 相关度，越大越相关
+  score: number;
+}
+
+// 命令面板中分组的显示顺序
+export const SEARCH_KINDS: SearchKind[] = ['server', 'snippet', 'key', 'history'];
@@ -0,0 +1,5 @@
+// src/features/search/index.ts
+
+export { SearchService } from './application/search.service';
+export { CommandPalette } from './presentation/CommandPalette';
+export * from './domain/types';
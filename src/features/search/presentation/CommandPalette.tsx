@@ -0,0 +1,146 @@
+import { useEffect, useState } from "react";
+import { useNavigate } from "react-router-dom";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { Server, Code2, KeyRound, History } from "lucide-react";
+
+import { Dialog, DialogContent, DialogTitle } from "@/components/ui/dialog";
+import { Command, CommandEmpty, CommandGroup, CommandInput, CommandItem, CommandList } from "@/components/ui/command";
+import { useTerminalStore } from "@/store/useTerminalStore";
+import { useServerStore } from "@/features/server/application/useServerStore";
+import { SearchService } from "../application/search.service";
+import { SEARCH_KINDS, SearchKind, SearchResult } from "../domain/types";
+
+const KIND_ICONS: Record<SearchKind, typeof Server> = {
+  server: Server,
+  snippet: Code2,
+  key: KeyRound,
+  history: History,
+};
+
+const KIND_LABELS: Record<SearchKind, [string, string]> = {
+  server: ["palette.kinds.server", "Servers"],
+  snippet: ["palette.kinds.snippet", "Snippets"],
+  key: ["palette.kinds.key", "Vault"],
+  history: ["palette.kinds.history", "Command History"],
+};
+
+// 输入停顿后再查询
+const SEARCH_DEBOUNCE_MS = 150;
+
+/**
+ * 命令面板 (Ctrl/Cmd + K)：全局搜索服务器、片段、保险库条目与命令历史
+ */
+export const CommandPalette = () => {
+  const { t } = useTranslation();
+  const navigate = useNavigate();
+  const [open, setOpen] = useState(false);
+  const [query, setQuery] = useState("");
+  const [results, setResults] = useState<SearchResult[]>([]);
+
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if ((e.metaKey || e.ctrlKey) && e.key.toLowerCase() === "k") {
+        e.preventDefault();
+        setOpen((prev) => !prev);
+      }
+    };
+    window.addEventListener("keydown", handleKeyDown);
+    return () => window.removeEventListener("keydown", handleKeyDown);
+  }, []);
+
+  useEffect(() => {
+    if (!open || !query.trim()) {
+      setResults([]);
+      return;
+    }
+    let cancelled = false;
+    const timer = setTimeout(() => {
+      SearchService.search(query)
+        .then((rows) => !cancelled && setResults(rows))
+        .catch(console.error);
+    }, SEARCH_DEBOUNCE_MS);
+    return () => {
+      cancelled = true;
+      clearTimeout(timer);
+    };
+  }, [open, query]);
+
+  const handleOpenChange = (next: boolean) => {
+    setOpen(next);
+    if (!next) setQuery("");
+  };
+
+  const handleSelect = (result: SearchResult) => {
+    handleOpenChange(false);
+    switch (result.kind) {
+      case "server": {
+        const server = useServerStore.getState().servers.find((s) => s.id === result.id);
+        useTerminalStore.getState().createTab({
+          id: result.id,
+          title: server?.name ?? result.title,
+          type: "ssh",
+          serverIp: server?.ip,
+        });
+        navigate("/terminal");
+        break;
+      }
+      case "snippet":
+        navigate("/snippets");
+        break;
+      case "key":
+        navigate("/keys");
+        break;
+      case "history":
+        navigator.clipboard.writeText(result.title);
+        toast.success(t("palette.copied", "Command copied to clipboard"));
+        break;
+    }
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={handleOpenChange}>
+      <DialogContent className="overflow-hidden p-0 max-w-xl">
+        <DialogTitle className="sr-only">{t("palette.title", "Search")}</DialogTitle>
+        <Command
+          shouldFilter={false}
+          className="[&_[cmdk-group-heading]]:px-2 [&_[cmdk-group-heading]]:font-medium [&_[cmdk-group-heading]]:text-muted-foreground [&_[cmdk-group]]:px-2 [&_[cmdk-input]]:h-12 [&_[cmdk-item]]:px-2 [&_[cmdk-item]]:py-2"
+        >
+          <CommandInput
+            value={query}
+            onValueChange={setQuery}
+            placeholder={t("palette.placeholder", "Search servers, snippets, vault and history...")}
+          />
+          <CommandList className="max-h-[400px]">
+            {query.trim() && <CommandEmpty>{t("palette.empty", "No results found.")}</CommandEmpty>}
+            {SEARCH_KINDS.map((kind) => {
+              const items = results.filter((r) => r.kind === kind);
+              if (items.length === 0) return null;
+              const Icon = KIND_ICONS[kind];
+              return (
+                <CommandGroup key={kind} heading={t(...KIND_LABELS[kind])}>
+                  {items.map((result) => (
+                    <CommandItem
+                      key={`${kind}-${result.id}`}
+                      value={`${kind}-${result.id}`}
+                      onSelect={() => handleSelect(result)}
+                      className="gap-2"
+                    >
+                      <Icon className="w-4 h-4 shrink-0 opacity-60" />
+                      <span className={kind === "history" ? "truncate font-mono text-xs" : "truncate"}>{result.title}</span>
+                      {result.subtitle && (
+                        <span className="ml-auto truncate max-w-[45%] text-xs text-muted-foreground font-mono">
+                          {result.subtitle}
+                        </span>
+                      )}
+                    </CommandItem>
+                  ))}
+                </CommandGroup>
+              );
+            })}
+          </CommandList>
+        </Command>
+      </DialogContent>
+    </Dialog>
+  );
+};
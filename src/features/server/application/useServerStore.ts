@@ -134,6 +134,10 @@ export const useServerStore = create<ServerState>()(
             provider: serverData.provider ?? existingServer?.provider ?? 'Custom',
             sort: serverData.sort ?? existingServer?.sort ?? 0,
             tags: serverData.tags ?? existingServer?.tags ?? [],
+            // null 表示移到根目录，undefined 表示保持原文件夹
+            folderId: serverData.folderId !== undefined ? serverData.folderId : existingServer?.folderId ?? null,
+            templateId: serverData.templateId !== undefined ? serverData.templateId : existingServer?.templateId ?? null,
+            templateOverrides: serverData.templateOverrides ?? existingServer?.templateOverrides ?? [],
 
             passwordId: serverData.passwordId ?? existingServer?.passwordId,
             keyId: serverData.keyId ?? existingServer?.keyId,
@@ -149,6 +153,8 @@ export const useServerStore = create<ServerState>()(
             connectionType: serverData.connectionType ?? existingServer?.connectionType ?? 'direct',
             
             authType: finalAuthType,
+            protocol: serverData.protocol ?? existingServer?.protocol ?? 'ssh',
+            loginScript: serverData.loginScript ?? existingServer?.loginScript ?? [],
             
             password: serverData.password ?? existingServer?.password,
             privateKey: serverData.privateKey ?? existingServer?.privateKey,
@@ -163,6 +169,12 @@ export const useServerStore = create<ServerState>()(
             keepAliveInterval: serverData.keepAliveInterval ?? existingServer?.keepAliveInterval,
             autoReconnect: serverData.autoReconnect ?? existingServer?.autoReconnect,
             maxReconnects: serverData.maxReconnects ?? existingServer?.maxReconnects,
+            scrollbackSize: serverData.scrollbackSize !== undefined ? serverData.scrollbackSize : existingServer?.scrollbackSize,
+            tmuxSession: serverData.tmuxSession !== undefined ? serverData.tmuxSession : existingServer?.tmuxSession,
+            envVars: serverData.envVars ?? existingServer?.envVars ?? [],
+            initialCwd: serverData.initialCwd !== undefined ? serverData.initialCwd : existingServer?.initialCwd,
+            startupCommands: serverData.startupCommands ?? existingServer?.startupCommands ?? [],
+            algorithmPrefs: serverData.algorithmPrefs !== undefined ? serverData.algorithmPrefs : existingServer?.algorithmPrefs,
         };
 
         if (!existingServer && (!newServer.name || !newServer.ip)) {
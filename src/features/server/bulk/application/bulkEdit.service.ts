@@ -0,0 +1,8 @@
+import { invoke } from '@tauri-apps/api/core';
+import { BulkServerChange, BulkTarget, ServerPatch } from '../domain/types';
+
+export const BulkEditService = {
+  // dryRun 为 true 时只返回变更预览
+  update: (target: BulkTarget, patch: ServerPatch, dryRun = false) =>
+    invoke<BulkServerChange[]>('bulk_update_servers', { target, patch, dryRun }),
+};
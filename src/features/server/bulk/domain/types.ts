@@ -0,0 +1,50 @@
+// 批量编辑服务器 (对应 src-tauri/src/commands/bulk_edit.rs)
+
+// server_ids 与带有 tag 标签的服务器取并集
+export interface BulkTarget {
+  serverIds?: string[];
+  tag?: string | null;
+}
+
+// 未设置的字段保持不变
+export interface ServerPatch {
+  username?: string;
+  // 认证方式与凭据整体修改
+  authType?: 'password' | 'privateKey' | 'agent';
+  passwordId?: string;
+  keyId?: string;
+  // 空字符串表示改为直连
+  proxyId?: string;
+  port?: number;
+  // 整体替换标签，之后再应用 addTags / removeTags
+  tags?: string[];
+  addTags?: string[];
+  removeTags?: string[];
+  connectTimeout?: number;
+  keepAliveInterval?: number;
+  // 空字符串表示取消单独分配的高亮规则集
+  highlightSetId?: string;
+}
+
+export type BulkField =
+  | 'username'
+  | 'credential'
+  | 'proxy'
+  | 'port'
+  | 'tags'
+  | 'connectTimeout'
+  | 'keepAliveInterval'
+  | 'highlightSet';
+
+export interface FieldChange {
+  field: BulkField;
+  before?: string | null;
+  after?: string | null;
+}
+
+export interface BulkServerChange {
+  serverId: string;
+  name: string;
+  // 为空表示已经是目标值
+  changes: FieldChange[];
+}
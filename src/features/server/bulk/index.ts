@@ -0,0 +1,5 @@
+// src/features/server/bulk/index.ts
+
+export { BulkEditService } from './application/bulkEdit.service';
+export { BulkEditModal } from './presentation/BulkEditModal';
+export * from './domain/types';
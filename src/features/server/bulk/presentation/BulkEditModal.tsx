@@ -0,0 +1,266 @@
+import { ReactNode, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { ArrowRight, Eye, ListChecks, Save } from "lucide-react";
+
+import { BaseModal } from "@/components/common/BaseModal";
+import { Button } from "@/components/ui/button";
+import { Checkbox } from "@/components/ui/checkbox";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select";
+import { useSettingsStore } from "@/features/settings/application/useSettingsStore";
+import { useKeyStore } from "@/store/useKeyStore";
+import { getErrorMessage } from "@/utils/error";
+import { BulkEditService } from "../application/bulkEdit.service";
+import { BulkField, BulkServerChange, ServerPatch } from "../domain/types";
+
+interface Props {
+  isOpen: boolean;
+  onClose: () => void;
+  // 当前列表中可见的服务器
+  serverIds: string[];
+  onApplied?: () => void;
+}
+
+// Radix Select 的 "直连" / "不单独分配" 与 "SSH Agent" 选项
+const NONE_VALUE = "__none__";
+const AGENT_VALUE = "__agent__";
+
+const splitTags = (text: string) => text.split(",").map((t) => t.trim()).filter(Boolean);
+
+interface Draft {
+  username: string;
+  credential: string;
+  proxy: string;
+  port: string;
+  addTags: string;
+  removeTags: string;
+  connectTimeout: string;
+  keepAliveInterval: string;
+  highlightSet: string;
+}
+
+const EMPTY_DRAFT: Draft = {
+  username: "",
+  credential: "",
+  proxy: NONE_VALUE,
+  port: "22",
+  addTags: "",
+  removeTags: "",
+  connectTimeout: "10",
+  keepAliveInterval: "60",
+  highlightSet: NONE_VALUE,
+};
+
+export const BulkEditModal = ({ isOpen, onClose, serverIds, onApplied }: Props) => {
+  const { t } = useTranslation();
+  const { proxies, loadProxies, highlightSets, loadHighlightSets } = useSettingsStore();
+  const { keys, status: vaultStatus } = useKeyStore();
+
+  const [enabled, setEnabled] = useState<Partial<Record<BulkField, boolean>>>({});
+  const [draft, setDraft] = useState<Draft>(EMPTY_DRAFT);
+  const [preview, setPreview] = useState<BulkServerChange[] | null>(null);
+  const [isBusy, setIsBusy] = useState(false);
+
+  useEffect(() => {
+    if (!isOpen) return;
+    setEnabled({});
+    setDraft(EMPTY_DRAFT);
+    setPreview(null);
+    if (proxies.length === 0) loadProxies();
+    if (highlightSets.length === 0) loadHighlightSets();
+  }, [isOpen]);
+
+  const patchDraft = (values: Partial<Draft>) => {
+    setDraft((prev) => ({ ...prev, ...values }));
+    setPreview(null);
+  };
+  const toggle = (field: BulkField, on: boolean) => {
+    setEnabled((prev) => ({ ...prev, [field]: on }));
+    setPreview(null);
+  };
+
+  const buildPatch = (): ServerPatch => {
+    const patch: ServerPatch = {};
+    if (enabled.username) patch.username = draft.username;
+    if (enabled.credential) {
+      const key = keys.find((k) => k.id === draft.credential);
+      if (draft.credential === AGENT_VALUE) patch.authType = "agent";
+      else if (key?.type === "private_key") Object.assign(patch, { authType: "privateKey", keyId: key.id });
+      else if (key) Object.assign(patch, { authType: "password", passwordId: key.id });
+    }
+    if (enabled.proxy) patch.proxyId = draft.proxy === NONE_VALUE ? "" : draft.proxy;
+    if (enabled.port) patch.port = Number(draft.port);
+    if (enabled.tags) {
+      patch.addTags = splitTags(draft.addTags);
+      patch.removeTags = splitTags(draft.removeTags);
+    }
+    if (enabled.connectTimeout) patch.connectTimeout = Number(draft.connectTimeout);
+    if (enabled.keepAliveInterval) patch.keepAliveInterval = Number(draft.keepAliveInterval);
+    if (enabled.highlightSet) patch.highlightSetId = draft.highlightSet === NONE_VALUE ? "" : draft.highlightSet;
+    return patch;
+  };
+
+  const run = async (dryRun: boolean) => {
+    setIsBusy(true);
+    try {
+      const result = await BulkEditService.update({ serverIds }, buildPatch(), dryRun);
+      if (dryRun) {
+        setPreview(result);
+        return;
+      }
+      const count = result.filter((s) => s.changes.length > 0).length;
+      toast.success(t("server.bulk.applied", "Updated {{count}} server(s)", { count }));
+      onApplied?.();
+      onClose();
+    } catch (err) {
+      toast.error(getErrorMessage(err));
+    } finally {
+      setIsBusy(false);
+    }
+  };
+
+  const hasSelection = Object.values(enabled).some(Boolean);
+  const changedCount = preview?.filter((s) => s.changes.length > 0).length ?? 0;
+
+  // 勾选后才会修改该字段
+  const row = (field: BulkField, control: ReactNode) => (
+    <div className="flex items-center gap-3">
+      <Checkbox checked={!!enabled[field]} onCheckedChange={(v) => toggle(field, v === true)} />
+      <Label className="w-32 shrink-0 text-xs text-slate-500">{t(`server.bulk.fields.${field}`)}</Label>
+      <div className={enabled[field] ? "flex-1 min-w-0" : "flex-1 min-w-0 opacity-50 pointer-events-none"}>{control}</div>
+    </div>
+  );
+
+  return (
+    <BaseModal
+      isOpen={isOpen}
+      onClose={onClose}
+      title={t("server.bulk.title", "Bulk Edit Servers")}
+      icon={<ListChecks className="w-5 h-5" />}
+      className="max-w-2xl"
+    >
+      <div className="space-y-4">
+        <div className="text-xs text-slate-500">
+          {t("server.bulk.hint", "Changes apply to the {{count}} server(s) in the current list. Only checked fields are changed.", {
+            count: serverIds.length,
+          })}
+        </div>
+
+        <div className="space-y-2.5">
+          {row("username", <Input value={draft.username} onChange={(e) => patchDraft({ username: e.target.value })} />)}
+          {row(
+            "credential",
+            <Select value={draft.credential} onValueChange={(v) => patchDraft({ credential: v })} disabled={vaultStatus !== "unlocked"}>
+              <SelectTrigger>
+                <SelectValue placeholder={t("server.template.vaultLocked", "Unlock the vault to choose")} />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value={AGENT_VALUE}>SSH Agent</SelectItem>
+                {keys.map((k) => (
+                  <SelectItem key={k.id} value={k.id}>
+                    {k.name} · {k.type === "private_key" ? t("keys.type.key", "Key") : t("keys.type.password", "Password")}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          )}
+          {row(
+            "proxy",
+            <Select value={draft.proxy} onValueChange={(v) => patchDraft({ proxy: v })}>
+              <SelectTrigger><SelectValue /></SelectTrigger>
+              <SelectContent>
+                <SelectItem value={NONE_VALUE}>{t("server.bulk.direct", "Direct connection")}</SelectItem>
+                {proxies.map((p) => (
+                  <SelectItem key={p.id} value={p.id}>{p.name}</SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          )}
+          {row(
+            "port",
+            <Input type="number" min={1} max={65535} value={draft.port} onChange={(e) => patchDraft({ port: e.target.value })} />
+          )}
+          {row(
+            "tags",
+            <div className="grid grid-cols-2 gap-2">
+              <Input
+                value={draft.addTags}
+                onChange={(e) => patchDraft({ addTags: e.target.value })}
+                placeholder={t("server.bulk.addTags", "Add tags (comma separated)")}
+              />
+              <Input
+                value={draft.removeTags}
+                onChange={(e) => patchDraft({ removeTags: e.target.value })}
+                placeholder={t("server.bulk.removeTags", "Remove tags (comma separated)")}
+              />
+            </div>
+          )}
+          {row(
+            "connectTimeout",
+            <Input type="number" min={1} value={draft.connectTimeout} onChange={(e) => patchDraft({ connectTimeout: e.target.value })} />
+          )}
+          {row(
+            "keepAliveInterval",
+            <Input type="number" min={0} value={draft.keepAliveInterval} onChange={(e) => patchDraft({ keepAliveInterval: e.target.value })} />
+          )}
+          {row(
+            "highlightSet",
+            <Select value={draft.highlightSet} onValueChange={(v) => patchDraft({ highlightSet: v })}>
+              <SelectTrigger><SelectValue /></SelectTrigger>
+              <SelectContent>
+                <SelectItem value={NONE_VALUE}>{t("server.bulk.noHighlight", "Not assigned")}</SelectItem>
+                {highlightSets.map((s) => (
+                  <SelectItem key={s.id} value={s.id}>{s.name}</SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          )}
+        </div>
+
+        {/* 变更预览 */}
+        {preview && (
+          <div className="rounded-lg border border-slate-200 dark:border-slate-800 max-h-60 overflow-y-auto custom-scrollbar">
+            <div className="px-3 py-2 text-xs font-medium text-slate-500 border-b border-slate-100 dark:border-slate-800">
+              {t("server.bulk.previewSummary", "{{changed}} of {{total}} server(s) will change", {
+                changed: changedCount,
+                total: preview.length,
+              })}
+            </div>
+            <div className="divide-y divide-slate-100 dark:divide-slate-800">
+              {preview.map((server) => (
+                <div key={server.serverId} className="px-3 py-2">
+                  <div className="text-sm font-medium text-slate-700 dark:text-slate-200 truncate">{server.name}</div>
+                  {server.changes.length === 0 ? (
+                    <div className="text-xs text-slate-400">{t("server.bulk.unchanged", "Already up to date")}</div>
+                  ) : (
+                    server.changes.map((change) => (
+                      <div key={change.field} className="flex items-center gap-1.5 text-xs text-slate-500 min-w-0">
+                        <span className="w-28 shrink-0">{t(`server.bulk.fields.${change.field}`)}</span>
+                        <span className="truncate font-mono">{change.before || "—"}</span>
+                        <ArrowRight className="w-3 h-3 shrink-0" />
+                        <span className="truncate font-mono text-slate-700 dark:text-slate-200">{change.after || "—"}</span>
+                      </div>
+                    ))
+                  )}
+                </div>
+              ))}
+            </div>
+          </div>
+        )}
+
+        <div className="flex justify-end gap-2 pt-2">
+          <Button variant="outline" className="gap-1.5" onClick={() => run(true)} disabled={isBusy || !hasSelection || serverIds.length === 0}>
+            <Eye className="w-4 h-4" />
+            {t("server.bulk.preview", "Preview")}
+          </Button>
+          <Button className="gap-1.5" onClick={() => run(false)} disabled={isBusy || !preview || changedCount === 0}>
+            <Save className="w-4 h-4" />
+            {t("server.bulk.apply", "Apply")}
+          </Button>
+        </div>
+      </div>
+    </BaseModal>
+  );
+};
@@ -0,0 +1,28 @@
+import { v4 as uuidv4 } from 'uuid';
+import { Server } from './types';
+
+/**
+ * 构建快速连接用的临时服务器 (不落库)
+ * 仪表盘快速连接与 ssh:// 链接共用
+ */
+export const createQuickServer = (host: string, port: number, username: string): Server => ({
+  id: `quick-${uuidv4()}`,
+  name: `${username}@${host}:${port}`,
+  ip: host,
+  port: port,
+  username: username,
+  authType: 'password',
+  connectionType: 'direct',
+  os: 'linux',
+  icon: 'zap',
+  provider: 'QuickConnect',
+  tags: ['Quick'],
+  sort: 0,
+  isPinned: false,
+  enableExpiration: false,
+  createdAt: Date.now(),
+  updatedAt: Date.now(),
+  passwordSource: 'manual',
+  keySource: 'manual',
+  password: ""
+});
@@ -1,5 +1,33 @@
+import type { TemplateField, TemplateStartupCommand } from '../template/domain/types';
+
 export type AuthType = 'password' | 'key';
 export type ConnectionType = 'direct' | 'http' | 'socks5';
+export type Protocol = 'ssh' | 'telnet';
+
+// Telnet 登录脚本的一步：等待 expect 出现后发送 send
+export interface TelnetLoginStep {
+  expect: string;
+  send: string;
+}
+// SSH 环境变量 (通过 env 请求发送)
+export interface EnvVar {
+  name: string;
+  value: string;
+}
+
+// Shell 启动后依次执行的命令：直接给出命令，或引用片段库中的 snippet
+export type StartupCommand = TemplateStartupCommand;
+
+// SSH 算法偏好：各类列表为空时使用 profile 预设，二者都为空时使用默认值
+export type AlgorithmProfile = 'modern' | 'compatible' | 'legacy';
+export interface AlgorithmPreferences {
+  profile?: AlgorithmProfile | null;
+  kex?: string[];
+  hostKey?: string[];
+  cipher?: string[];
+  mac?: string[];
+}
+
 export type ServerStatus = 'connected' | 'disconnected' | 'connecting';
 
 export interface Server {
@@ -14,10 +42,13 @@ export interface Server {
   username: string;
   
   authType: AuthType;
+  protocol?: Protocol;
+  // Telnet 登录脚本，为空时按 login:/Password: 自动填充
+  loginScript?: TelnetLoginStep[];
 
   connectionType: ConnectionType;
   proxyId?: string;
-  // 修正拼写
+  // 仅在保存时提交，后端移入 Vault 后不再返回
   privateKey?: string; 
   passphrase?: string;
   password?: string; 
@@ -29,6 +60,11 @@ export interface Server {
   keyId?: string;
   
   tags: string[];
+  // 所属文件夹，为空时位于根目录
+  folderId?: string | null;
+  // 引用的服务器模板，templateOverrides 中的字段使用服务器自己的值
+  templateId?: string | null;
+  templateOverrides?: TemplateField[];
   sort: number;
   
   // [修改] 统一改为 CamelCase 以匹配后端 JSON 和前端习惯
@@ -45,6 +81,15 @@ export interface Server {
   keepAliveInterval?: number;
   autoReconnect?: boolean;
   maxReconnects?: number;
+  // 终端回滚缓冲大小 (字节)，为空时使用默认值
+  scrollbackSize?: number | null;
+  // 连接时自动进入 (或恢复) 的 tmux 会话
+  tmuxSession?: string | null;
+  envVars?: EnvVar[];
+  // 登录后自动进入的目录
+  initialCwd?: string | null;
+  startupCommands?: StartupCommand[];
+  algorithmPrefs?: AlgorithmPreferences | null;
 }
 
 export interface ProxyItem {
@@ -0,0 +1,9 @@
+import { invoke } from '@tauri-apps/api/core';
+import { ExpiringServer } from '../domain/types';
+
+export const ExpirationService = {
+  // withinDays 为空时使用设置中的提醒窗口
+  listUpcoming: (withinDays?: number) =>
+    invoke<ExpiringServer[]>('list_upcoming_expirations', { withinDays: withinDays ?? null }),
+  recheck: () => invoke<void>('recheck_expirations'),
+};
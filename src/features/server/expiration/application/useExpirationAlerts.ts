@@ -0,0 +1,69 @@
+import { useEffect } from 'react';
+import { listen } from '@tauri-apps/api/event';
+import { useTranslation } from 'react-i18next';
+import { toast } from 'sonner';
+import { useServerStore } from '@/features/server/application/useServerStore';
+import { EXPIRATION_EVENT, ExpiringServer } from '../domain/types';
+import { ExpirationService } from './expiration.service';
+import { useExpirationStore } from './useExpirationStore';
+
+// 同一天内同一台服务器只提醒一次
+const NOTIFIED_KEY = 'expiration-notified';
+
+const takeUnnotified = (servers: ExpiringServer[]): ExpiringServer[] => {
+  const today = new Date().toDateString();
+  let notified: { date: string; ids: string[] } = { date: today, ids: [] };
+  try {
+    const saved = JSON.parse(localStorage.getItem(NOTIFIED_KEY) || 'null');
+    if (saved?.date === today) notified = saved;
+  } catch {
+    // 忽略损坏的记录
+  }
+  const fresh = servers.filter((s) => !notified.ids.includes(s.id));
+  localStorage.setItem(NOTIFIED_KEY, JSON.stringify({ date: today, ids: [...notified.ids, ...fresh.map((s) => s.id)] }));
+  return fresh;
+};
+
+/**
+ * 到期提醒：启动时查询一次，之后跟随后台每日检查 (server-expiring 事件)
+ * 窗口在后台时额外发送系统通知；服务器列表变化后让后台重新检查以更新角标
+ */
+export const useExpirationAlerts = () => {
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    const notify = (servers: ExpiringServer[]) => {
+      useExpirationStore.getState().refresh().catch(console.error);
+      const fresh = takeUnnotified(servers);
+      if (fresh.length === 0) return;
+
+      const names = fresh.map((s) => s.name).join(', ');
+      const title = t('server.expiration.alertTitle', '{{count}} server(s) expiring soon', { count: fresh.length });
+      toast.warning(title, { description: names, duration: 10000 });
+
+      if (document.hidden && 'Notification' in window) {
+        const show = () => new Notification(title, { body: names });
+        if (Notification.permission === 'granted') show();
+        else if (Notification.permission !== 'denied') {
+          Notification.requestPermission().then((p) => p === 'granted' && show());
+        }
+      }
+    };
+
+    ExpirationService.listUpcoming().then(notify).catch(console.error);
+    const unlisten = listen<ExpiringServer[]>(EXPIRATION_EVENT, (event) => notify(event.payload));
+
+    // 到期日由服务器表单修改，列表变化后重新检查
+    const unsubscribe = useServerStore.subscribe((state, prev) => {
+      if (state.servers !== prev.servers) {
+        ExpirationService.recheck().catch(console.error);
+        useExpirationStore.getState().refresh().catch(console.error);
+      }
+    });
+
+    return () => {
+      unlisten.then((f) => f());
+      unsubscribe();
+    };
+  }, [t]);
+};
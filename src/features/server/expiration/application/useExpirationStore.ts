@@ -0,0 +1,17 @@
+import { create } from 'zustand';
+import { ExpiringServer } from '../domain/types';
+import { ExpirationService } from './expiration.service';
+
+interface ExpirationState {
+  upcoming: ExpiringServer[];
+  refresh: () => Promise<void>;
+}
+
+export const useExpirationStore = create<ExpirationState>((set) => ({
+  upcoming: [],
+
+  refresh: async () => {
+    const upcoming = await ExpirationService.listUpcoming();
+    set({ upcoming });
+  },
+}));
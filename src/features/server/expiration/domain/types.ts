@@ -0,0 +1,14 @@
+// 服务器到期提醒 (对应 src-tauri/src/commands/expiration.rs)
+
+export interface ExpiringServer {
+  id: string;
+  name: string;
+  ip: string;
+  // 本地日期 YYYY-MM-DD
+  expireDate: string;
+  // 距今天数，已过期为负数
+  daysLeft: number;
+}
+
+// 后台检查发出的提醒事件
+export const EXPIRATION_EVENT = 'server-expiring';
@@ -0,0 +1,7 @@
+// src/features/server/expiration/index.ts
+
+export { useExpirationStore } from './application/useExpirationStore';
+export { useExpirationAlerts } from './application/useExpirationAlerts';
+export { ExpirationService } from './application/expiration.service';
+export { UpcomingExpirationsModal } from './presentation/UpcomingExpirationsModal';
+export * from './domain/types';
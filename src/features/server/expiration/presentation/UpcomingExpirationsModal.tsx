@@ -0,0 +1,70 @@
+import { useEffect } from "react";
+import { useTranslation } from "react-i18next";
+import { CalendarClock } from "lucide-react";
+import { clsx } from "clsx";
+
+import { BaseModal } from "@/components/common/BaseModal";
+import { useExpirationStore } from "../application/useExpirationStore";
+
+interface Props {
+  isOpen: boolean;
+  onClose: () => void;
+  onEdit?: (serverId: string) => void;
+}
+
+export const UpcomingExpirationsModal = ({ isOpen, onClose, onEdit }: Props) => {
+  const { t } = useTranslation();
+  const { upcoming, refresh } = useExpirationStore();
+
+  useEffect(() => {
+    if (isOpen) refresh().catch(console.error);
+  }, [isOpen]);
+
+  const describe = (daysLeft: number) => {
+    if (daysLeft < 0) return t("server.expiration.expiredAgo", "Expired {{count}} day(s) ago", { count: -daysLeft });
+    if (daysLeft === 0) return t("server.expiration.today", "Expires today");
+    return t("server.expiration.inDays", "In {{count}} day(s)", { count: daysLeft });
+  };
+
+  return (
+    <BaseModal
+      isOpen={isOpen}
+      onClose={onClose}
+      title={t("server.expiration.title", "Upcoming Expirations")}
+      icon={<CalendarClock className="w-5 h-5" />}
+      className="max-w-lg"
+    >
+      {upcoming.length === 0 ? (
+        <div className="text-sm text-slate-400 text-center py-8">
+          {t("server.expiration.empty", "No servers expire within the reminder window.")}
+        </div>
+      ) : (
+        <div className="divide-y divide-slate-100 dark:divide-slate-800">
+          {upcoming.map((server) => (
+            <button
+              key={server.id}
+              onClick={() => onEdit?.(server.id)}
+              className="w-full flex items-center justify-between gap-3 px-2 py-2.5 text-left hover:bg-slate-50 dark:hover:bg-slate-800/50 rounded-md"
+            >
+              <div className="min-w-0">
+                <div className="text-sm font-medium text-slate-700 dark:text-slate-200 truncate">{server.name}</div>
+                <div className="text-xs text-slate-400 font-mono truncate">{server.ip}</div>
+              </div>
+              <div className="text-right shrink-0">
+                <div className="text-xs font-mono text-slate-500">{server.expireDate}</div>
+                <div
+                  className={clsx(
+                    "text-xs font-medium",
+                    server.daysLeft < 0 ? "text-red-500" : server.daysLeft <= 3 ? "text-amber-500" : "text-slate-500"
+                  )}
+                >
+                  {describe(server.daysLeft)}
+                </div>
+              </div>
+            </button>
+          ))}
+        </div>
+      )}
+    </BaseModal>
+  );
+};
@@ -0,0 +1,7 @@
+import { invoke } from '@tauri-apps/api/core';
+import { ExportOptions, ExportSummary } from '../domain/types';
+
+export const ServerExportService = {
+  export: (options: ExportOptions, path: string) =>
+    invoke<ExportSummary>('export_servers', { options, path }),
+};
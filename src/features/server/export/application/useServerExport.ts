@@ -0,0 +1,79 @@
+import { useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { save } from '@tauri-apps/plugin-dialog';
+import { toast } from 'sonner';
+import { getErrorCode, getErrorMessage } from '@/utils/error';
+import { useKeyStore } from '@/store/useKeyStore';
+import { ExportFormat, MIN_PASSPHRASE_LENGTH } from '../domain/types';
+import { ServerExportService } from './export.service';
+
+const DEFAULT_FILE_NAMES: Record<ExportFormat, string> = {
+  sshConfig: 'ssh_config',
+  csv: 'servers.csv',
+  json: 'servers.json',
+};
+
+/**
+ * 导出流程：选择格式/标签/是否包含凭据 -> 选择保存位置 -> 写入
+ */
+export const useServerExport = (onDone: () => void) => {
+  const { t } = useTranslation();
+  const openGlobalUnlockModal = useKeyStore((s) => s.openGlobalUnlockModal);
+
+  const [format, setFormatState] = useState<ExportFormat>('json');
+  const [tags, setTags] = useState<string[]>([]);
+  const [includeSecrets, setIncludeSecrets] = useState(false);
+  const [passphrase, setPassphrase] = useState('');
+  const [isExporting, setIsExporting] = useState(false);
+
+  // ssh_config 无法携带凭据
+  const setFormat = (next: ExportFormat) => {
+    setFormatState(next);
+    if (next === 'sshConfig') setIncludeSecrets(false);
+  };
+
+  const toggleTag = (tag: string) => {
+    setTags((prev) => (prev.includes(tag) ? prev.filter((t) => t !== tag) : [...prev, tag]));
+  };
+
+  const canExport = !includeSecrets || passphrase.length >= MIN_PASSPHRASE_LENGTH;
+
+  const reset = () => {
+    setTags([]);
+    setIncludeSecrets(false);
+    setPassphrase('');
+  };
+
+  const exportServers = async () => {
+    if (!canExport) return;
+    const path = await save({ defaultPath: DEFAULT_FILE_NAMES[format] });
+    if (!path) return;
+
+    setIsExporting(true);
+    try {
+      const summary = await ServerExportService.export(
+        { format, tags, includeSecrets, passphrase: includeSecrets ? passphrase : null },
+        path
+      );
+      toast.success(t('server.export.done', 'Exported {{count}} servers', { count: summary.count }), {
+        description: summary.path,
+      });
+      reset();
+      onDone();
+    } catch (err) {
+      if (getErrorCode(err) === 'VAULT_LOCKED') {
+        toast.info(t('server.export.vaultLocked', 'Unlock the vault to export secrets.'));
+        openGlobalUnlockModal();
+        return;
+      }
+      toast.error(t('server.export.failed', 'Export failed'), { description: getErrorMessage(err) });
+    } finally {
+      setIsExporting(false);
+    }
+  };
+
+  return {
+    state: { format, tags, includeSecrets, passphrase, isExporting, canExport },
+    actions: { setFormat, toggleTag, setIncludeSecrets, setPassphrase, exportServers, reset },
+  };
+};
@@ -0,0 +1,20 @@
+// 服务器导出 (对应 src-tauri/src/commands/export.rs)
+
+export type ExportFormat = 'sshConfig' | 'csv' | 'json';
+
+export const EXPORT_FORMATS: ExportFormat[] = ['sshConfig', 'csv', 'json'];
+
+export interface ExportOptions {
+  format: ExportFormat;
+  tags: string[];
+  includeSecrets: boolean;
+  passphrase?: string | null;
+}
+
+export interface ExportSummary {
+  path: string;
+  count: number;
+}
+
+// 加密导出的口令最短长度 (与后端一致)
+export const MIN_PASSPHRASE_LENGTH = 8;
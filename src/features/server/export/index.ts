@@ -0,0 +1,3 @@
+// src/features/server/export/index.ts
+
+export { ServerExportModal } from './presentation/ServerExportModal';
@@ -0,0 +1,128 @@
+import { useTranslation } from "react-i18next";
+import { FileOutput, Loader2, ShieldAlert } from "lucide-react";
+import { clsx } from "clsx";
+
+import { BaseModal } from "@/components/common/BaseModal";
+import { Button } from "@/components/ui/button";
+import { Checkbox } from "@/components/ui/checkbox";
+import { PasswordInput } from "@/components/ui/password-input";
+import { Switch } from "@/components/ui/switch";
+import { useServerExport } from "../application/useServerExport";
+import { EXPORT_FORMATS, ExportFormat, MIN_PASSPHRASE_LENGTH } from "../domain/types";
+
+interface Props {
+  isOpen: boolean;
+  onClose: () => void;
+  allTags: string[];
+}
+
+const FORMAT_LABELS: Record<ExportFormat, string> = {
+  sshConfig: "ssh_config",
+  csv: "CSV",
+  json: "JSON",
+};
+
+export const ServerExportModal = ({ isOpen, onClose, allTags }: Props) => {
+  const { t } = useTranslation();
+  const { state, actions } = useServerExport(onClose);
+
+  const handleClose = () => {
+    actions.reset();
+    onClose();
+  };
+
+  return (
+    <BaseModal
+      isOpen={isOpen}
+      onClose={handleClose}
+      title={t("server.export.title", "Export Servers")}
+      icon={<FileOutput className="w-5 h-5" />}
+      className="max-w-lg"
+      footer={
+        <>
+          <Button variant="ghost" onClick={handleClose}>
+            {t("common.cancel", "Cancel")}
+          </Button>
+          <Button onClick={actions.exportServers} disabled={!state.canExport || state.isExporting}>
+            {state.isExporting && <Loader2 className="w-4 h-4 animate-spin" />}
+            {t("server.export.confirm", "Export")}
+          </Button>
+        </>
+      }
+    >
+      <div className="space-y-5">
+        <div className="space-y-2">
+          <div className="text-xs font-semibold text-slate-500">{t("server.export.format", "Format")}</div>
+          <div className="grid grid-cols-3 gap-2">
+            {EXPORT_FORMATS.map((format) => (
+              <button
+                key={format}
+                onClick={() => actions.setFormat(format)}
+                className={clsx(
+                  "py-2 rounded-lg border text-sm font-mono transition-colors",
+                  state.format === format
+                    ? "border-blue-500 bg-blue-50 text-blue-600 dark:bg-blue-900/20 dark:text-blue-400"
+                    : "border-slate-200 dark:border-slate-700 text-slate-600 dark:text-slate-300 hover:bg-slate-50 dark:hover:bg-slate-800"
+                )}
+              >
+                {FORMAT_LABELS[format]}
+              </button>
+            ))}
+          </div>
+        </div>
+
+        {allTags.length > 0 && (
+          <div className="space-y-2">
+            <div className="text-xs font-semibold text-slate-500">
+              {t("server.export.tags", "Only servers with tags (empty = all)")}
+            </div>
+            <div className="flex flex-wrap gap-3">
+              {allTags.map((tag) => (
+                <label key={tag} className="flex items-center gap-1.5 text-xs text-slate-600 dark:text-slate-300 cursor-pointer">
+                  <Checkbox checked={state.tags.includes(tag)} onCheckedChange={() => actions.toggleTag(tag)} />
+                  {tag}
+                </label>
+              ))}
+            </div>
+          </div>
+        )}
+
+        <div className="space-y-3">
+          <label className="flex items-center justify-between gap-3">
+            <span className="text-sm text-slate-700 dark:text-slate-200">
+              {t("server.export.includeSecrets", "Include passwords and keys (encrypted)")}
+            </span>
+            <Switch
+              checked={state.includeSecrets}
+              onCheckedChange={actions.setIncludeSecrets}
+              disabled={state.format === "sshConfig"}
+            />
+          </label>
+          {state.format === "sshConfig" && (
+            <div className="text-xs text-slate-400">
+              {t("server.export.sshConfigNoSecrets", "ssh_config cannot contain secrets.")}
+            </div>
+          )}
+          {state.includeSecrets && (
+            <div className="space-y-2">
+              <PasswordInput
+                value={state.passphrase}
+                onChange={(e) => actions.setPassphrase(e.target.value)}
+                placeholder={t("server.export.passphrase", "Export passphrase (min. {{min}} characters)", {
+                  min: MIN_PASSPHRASE_LENGTH,
+                })}
+              />
+              <div className="flex items-start gap-1.5 text-xs text-amber-600 dark:text-amber-400">
+                <ShieldAlert className="w-3.5 h-3.5 mt-0.5 shrink-0" />
+                {t(
+                  "server.export.passphraseHint",
+                  "Secrets are encrypted with AES-256-GCM using a key derived from this passphrase. It cannot be recovered."
+                )}
+              </div>
+            </div>
+          )}
+        </div>
+      </div>
+    </BaseModal>
+  );
+};
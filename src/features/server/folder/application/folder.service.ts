@@ -0,0 +1,16 @@
+import { invoke } from '@tauri-apps/api/core';
+import { FolderDefaults, ServerFolder } from '../domain/types';
+
+export const FolderService = {
+  list: () => invoke<ServerFolder[]>('list_folders'),
+  create: (name: string, parentId: string | null) => invoke<ServerFolder>('create_folder', { name, parentId }),
+  update: (id: string, name: string, defaults: FolderDefaults) =>
+    invoke<ServerFolder>('update_folder', { id, name, defaults }),
+  move: (id: string, parentId: string | null) => invoke<void>('move_folder', { id, parentId }),
+  remove: (id: string) => invoke<void>('delete_folder', { id }),
+  moveServers: (serverIds: string[], folderId: string | null) =>
+    invoke<void>('move_servers_to_folder', { serverIds, folderId }),
+  reorderServers: (folderId: string | null, serverIds: string[]) =>
+    invoke<void>('reorder_folder_servers', { folderId, serverIds }),
+  getServerDefaults: (serverId: string) => invoke<FolderDefaults>('get_server_defaults', { serverId }),
+};
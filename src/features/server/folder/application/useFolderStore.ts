@@ -0,0 +1,61 @@
+import { create } from 'zustand';
+import { useServerStore } from '@/features/server/application/useServerStore';
+import { FolderDefaults, ServerFolder } from '../domain/types';
+import { FolderService } from './folder.service';
+
+interface FolderState {
+  folders: ServerFolder[];
+  isLoaded: boolean;
+
+  fetchFolders: () => Promise<void>;
+  createFolder: (name: string, parentId: string | null) => Promise<ServerFolder>;
+  updateFolder: (id: string, name: string, defaults: FolderDefaults) => Promise<void>;
+  moveFolder: (id: string, parentId: string | null) => Promise<void>;
+  deleteFolder: (id: string) => Promise<void>;
+  moveServers: (serverIds: string[], folderId: string | null) => Promise<void>;
+  reorderServers: (folderId: string | null, serverIds: string[]) => Promise<void>;
+}
+
+// 服务器的 folderId / sort 由后端修改，操作后刷新服务器列表
+const refreshServers = () => useServerStore.getState().fetchServers(true);
+
+export const useFolderStore = create<FolderState>((set, get) => ({
+  folders: [],
+  isLoaded: false,
+
+  fetchFolders: async () => {
+    const folders = await FolderService.list();
+    set({ folders, isLoaded: true });
+  },
+
+  createFolder: async (name, parentId) => {
+    const folder = await FolderService.create(name, parentId);
+    set((state) => ({ folders: [...state.folders, folder] }));
+    return folder;
+  },
+
+  updateFolder: async (id, name, defaults) => {
+    const folder = await FolderService.update(id, name, defaults);
+    set((state) => ({ folders: state.folders.map((f) => (f.id === id ? folder : f)) }));
+  },
+
+  moveFolder: async (id, parentId) => {
+    await FolderService.move(id, parentId);
+    await get().fetchFolders();
+  },
+
+  deleteFolder: async (id) => {
+    await FolderService.remove(id);
+    await Promise.all([get().fetchFolders(), refreshServers()]);
+  },
+
+  moveServers: async (serverIds, folderId) => {
+    await FolderService.moveServers(serverIds, folderId);
+    await refreshServers();
+  },
+
+  reorderServers: async (folderId, serverIds) => {
+    await FolderService.reorderServers(folderId, serverIds);
+    await refreshServers();
+  },
+}));
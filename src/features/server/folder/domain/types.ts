@@ -0,0 +1,72 @@
+// 服务器文件夹 (对应 src-tauri/src/models/folder.rs)
+
+export type FolderAuthType = 'password' | 'privateKey';
+
+// 文件夹级默认值，未设置的字段继续向上继承
+export interface FolderDefaults {
+  username?: string | null;
+  proxyId?: string | null;
+  authType?: FolderAuthType | null;
+  passwordId?: string | null;
+  keyId?: string | null;
+  highlightSetId?: string | null;
+}
+
+export interface ServerFolder {
+  id: string;
+  parentId?: string | null;
+  name: string;
+  sort: number;
+  defaults: FolderDefaults;
+  createdAt: number;
+  updatedAt: number;
+}
+
+export interface FolderNode extends ServerFolder {
+  depth: number;
+  // 从根开始的完整路径，例如 "Customer / Region / Role"
+  path: string;
+}
+
+/**
+ * 按树的先序展开文件夹 (同级按 sort 排序)，用于下拉框与管理列表
+ */
+export const flattenFolderTree = (folders: ServerFolder[]): FolderNode[] => {
+  const children = new Map<string | null, ServerFolder[]>();
+  const ids = new Set(folders.map((f) => f.id));
+  folders.forEach((f) => {
+    // 父文件夹不存在时当作根目录
+    const parent = f.parentId && ids.has(f.parentId) ? f.parentId : null;
+    children.set(parent, [...(children.get(parent) ?? []), f]);
+  });
+
+  const result: FolderNode[] = [];
+  const visit = (parentId: string | null, depth: number, prefix: string) => {
+    const list = [...(children.get(parentId) ?? [])].sort((a, b) => a.sort - b.sort || a.name.localeCompare(b.name));
+    list.forEach((f) => {
+      const path = prefix ? `${prefix} / ${f.name}` : f.name;
+      result.push({ ...f, depth, path });
+      visit(f.id, depth + 1, path);
+    });
+  };
+  visit(null, 0, '');
+  return result;
+};
+
+/**
+ * 文件夹自身及全部子孙文件夹的 ID
+ */
+export const getDescendantIds = (folders: ServerFolder[], rootId: string): Set<string> => {
+  const result = new Set<string>([rootId]);
+  let changed = true;
+  while (changed) {
+    changed = false;
+    folders.forEach((f) => {
+      if (f.parentId && result.has(f.parentId) && !result.has(f.id)) {
+        result.add(f.id);
+        changed = true;
+      }
+    });
+  }
+  return result;
+};
@@ -0,0 +1,7 @@
+// src/features/server/folder/index.ts
+
+export { useFolderStore } from './application/useFolderStore';
+export { FolderService } from './application/folder.service';
+export { FolderSelect } from './presentation/FolderSelect';
+export { FolderManagerModal } from './presentation/FolderManagerModal';
+export * from './domain/types';
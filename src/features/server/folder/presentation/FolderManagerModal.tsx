@@ -0,0 +1,284 @@
+import { useEffect, useMemo, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { FolderOpen, FolderPlus, FolderTree, Save, Trash2 } from "lucide-react";
+import { clsx } from "clsx";
+
+import { BaseModal } from "@/components/common/BaseModal";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select";
+import { useSettingsStore } from "@/features/settings/application/useSettingsStore";
+import { useKeyStore } from "@/store/useKeyStore";
+import { getErrorMessage } from "@/utils/error";
+import { useFolderStore } from "../application/useFolderStore";
+import { FolderDefaults, ServerFolder, flattenFolderTree, getDescendantIds } from "../domain/types";
+import { FolderSelect } from "./FolderSelect";
+
+interface Props {
+  isOpen: boolean;
+  onClose: () => void;
+}
+
+// Radix Select 的 "不设置" 选项
+const NONE_VALUE = "__none__";
+
+const toSelectValue = (v?: string | null) => v || NONE_VALUE;
+const fromSelectValue = (v: string) => (v === NONE_VALUE ? null : v);
+
+interface DraftState {
+  name: string;
+  parentId: string | null;
+  defaults: FolderDefaults;
+}
+
+const toDraft = (folder: ServerFolder): DraftState => ({
+  name: folder.name,
+  parentId: folder.parentId ?? null,
+  defaults: { ...folder.defaults },
+});
+
+export const FolderManagerModal = ({ isOpen, onClose }: Props) => {
+  const { t } = useTranslation();
+  const { folders, fetchFolders, createFolder, updateFolder, moveFolder, deleteFolder } = useFolderStore();
+  const { proxies, highlightSets, loadProxies, loadHighlightSets } = useSettingsStore();
+  const { keys, status: vaultStatus } = useKeyStore();
+
+  const [selectedId, setSelectedId] = useState<string | null>(null);
+  const [draft, setDraft] = useState<DraftState | null>(null);
+  const [isSaving, setIsSaving] = useState(false);
+
+  useEffect(() => {
+    if (!isOpen) return;
+    fetchFolders().catch(console.error);
+    if (proxies.length === 0) loadProxies();
+    if (highlightSets.length === 0) loadHighlightSets();
+  }, [isOpen]);
+
+  const nodes = useMemo(() => flattenFolderTree(folders), [folders]);
+  const selected = folders.find((f) => f.id === selectedId) ?? null;
+  const excludeIds = useMemo(
+    () => (selectedId ? getDescendantIds(folders, selectedId) : undefined),
+    [folders, selectedId]
+  );
+
+  const select = (folder: ServerFolder) => {
+    setSelectedId(folder.id);
+    setDraft(toDraft(folder));
+  };
+
+  const handleCreate = async (parentId: string | null) => {
+    try {
+      const folder = await createFolder(t("server.folder.newName", "New Folder"), parentId);
+      select(folder);
+    } catch (err) {
+      toast.error(getErrorMessage(err));
+    }
+  };
+
+  const handleSave = async () => {
+    if (!selected || !draft) return;
+    setIsSaving(true);
+    try {
+      await updateFolder(selected.id, draft.name, draft.defaults);
+      if ((selected.parentId ?? null) !== draft.parentId) {
+        await moveFolder(selected.id, draft.parentId);
+      }
+      toast.success(t("server.folder.saved", "Folder saved"));
+    } catch (err) {
+      toast.error(getErrorMessage(err));
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  const handleDelete = async () => {
+    if (!selected) return;
+    try {
+      await deleteFolder(selected.id);
+      setSelectedId(null);
+      setDraft(null);
+    } catch (err) {
+      toast.error(getErrorMessage(err));
+    }
+  };
+
+  const setDefaults = (patch: Partial<FolderDefaults>) =>
+    setDraft((prev) => (prev ? { ...prev, defaults: { ...prev.defaults, ...patch } } : prev));
+
+  // 凭据以 Vault 条目为单位选择，认证方式由条目类型决定
+  const credentialId = draft?.defaults.authType === "privateKey" ? draft.defaults.keyId : draft?.defaults.passwordId;
+  const handleCredentialChange = (v: string) => {
+    const key = keys.find((k) => k.id === v);
+    if (!key) {
+      setDefaults({ authType: null, passwordId: null, keyId: null });
+    } else if (key.type === "private_key") {
+      setDefaults({ authType: "privateKey", keyId: key.id, passwordId: null });
+    } else {
+      setDefaults({ authType: "password", passwordId: key.id, keyId: null });
+    }
+  };
+
+  return (
+    <BaseModal
+      isOpen={isOpen}
+      onClose={onClose}
+      title={t("server.folder.manage", "Manage Folders")}
+      icon={<FolderTree className="w-5 h-5" />}
+      className="max-w-3xl"
+    >
+      <div className="flex gap-4 min-h-[360px]">
+        {/* 左侧：文件夹树 */}
+        <div className="w-60 shrink-0 flex flex-col gap-2 border-r border-slate-200 dark:border-slate-800 pr-3">
+          <div className="flex gap-1">
+            <Button variant="outline" size="sm" className="flex-1 gap-1 text-xs" onClick={() => handleCreate(null)}>
+              <FolderPlus className="w-3.5 h-3.5" />
+              {t("server.folder.create", "New folder")}
+            </Button>
+            <Button
+              variant="outline"
+              size="sm"
+              className="text-xs"
+              disabled={!selectedId}
+              onClick={() => handleCreate(selectedId)}
+              title={t("server.folder.createChild", "New subfolder")}
+            >
+              <FolderPlus className="w-3.5 h-3.5" />+
+            </Button>
+          </div>
+          <div className="flex-1 overflow-y-auto custom-scrollbar space-y-0.5">
+            {nodes.length === 0 && (
+              <div className="text-xs text-slate-400 text-center py-6">{t("server.folder.empty", "No folders yet")}</div>
+            )}
+            {nodes.map((node) => (
+              <button
+                key={node.id}
+                onClick={() => select(node)}
+                className={clsx(
+                  "w-full flex items-center gap-1.5 px-2 py-1.5 rounded-md text-sm text-left truncate",
+                  node.id === selectedId
+                    ? "bg-blue-50 text-blue-600 dark:bg-blue-900/20 dark:text-blue-400"
+                    : "text-slate-600 dark:text-slate-300 hover:bg-slate-100 dark:hover:bg-slate-800"
+                )}
+                style={{ paddingLeft: 8 + node.depth * 14 }}
+              >
+                <FolderOpen className="w-3.5 h-3.5 shrink-0 opacity-70" />
+                <span className="truncate">{node.name}</span>
+              </button>
+            ))}
+          </div>
+        </div>
+
+        {/* 右侧：名称、位置与默认值 */}
+        <div className="flex-1 min-w-0">
+          {!selected || !draft ? (
+            <div className="h-full flex items-center justify-center text-sm text-slate-400">
+              {t("server.folder.selectHint", "Select a folder to edit its defaults")}
+            </div>
+          ) : (
+            <div className="space-y-4">
+              <div className="grid grid-cols-2 gap-3">
+                <div className="space-y-1.5">
+                  <Label className="text-xs text-slate-500">{t("server.folder.name", "Name")}</Label>
+                  <Input value={draft.name} onChange={(e) => setDraft({ ...draft, name: e.target.value })} />
+                </div>
+                <div className="space-y-1.5">
+                  <Label className="text-xs text-slate-500">{t("server.folder.parent", "Parent folder")}</Label>
+                  <FolderSelect
+                    value={draft.parentId}
+                    onChange={(v) => setDraft({ ...draft, parentId: v ?? null })}
+                    excludeIds={excludeIds}
+                  />
+                </div>
+              </div>
+
+              <div className="h-px bg-border/50" />
+              <div className="text-xs text-slate-500">
+                {t(
+                  "server.folder.defaultsHint",
+                  "Servers in this folder and its subfolders inherit these values when they leave them empty."
+                )}
+              </div>
+
+              <div className="grid grid-cols-2 gap-3">
+                <div className="space-y-1.5">
+                  <Label className="text-xs text-slate-500">{t("server.folder.username", "Default username")}</Label>
+                  <Input
+                    value={draft.defaults.username ?? ""}
+                    onChange={(e) => setDefaults({ username: e.target.value || null })}
+                    placeholder={t("server.folder.inherit", "Inherit")}
+                  />
+                </div>
+
+                <div className="space-y-1.5">
+                  <Label className="text-xs text-slate-500">{t("server.folder.proxy", "Default proxy")}</Label>
+                  <Select
+                    value={toSelectValue(draft.defaults.proxyId)}
+                    onValueChange={(v) => setDefaults({ proxyId: fromSelectValue(v) })}
+                  >
+                    <SelectTrigger><SelectValue /></SelectTrigger>
+                    <SelectContent>
+                      <SelectItem value={NONE_VALUE}>{t("server.folder.inherit", "Inherit")}</SelectItem>
+                      {proxies.map((p) => (
+                        <SelectItem key={p.id} value={p.id}>{p.name}</SelectItem>
+                      ))}
+                    </SelectContent>
+                  </Select>
+                </div>
+
+                <div className="space-y-1.5">
+                  <Label className="text-xs text-slate-500">{t("server.folder.credential", "Default credential")}</Label>
+                  <Select
+                    value={toSelectValue(credentialId)}
+                    onValueChange={handleCredentialChange}
+                    disabled={vaultStatus !== "unlocked"}
+                  >
+                    <SelectTrigger>
+                      <SelectValue placeholder={t("server.folder.vaultLocked", "Unlock the vault to choose")} />
+                    </SelectTrigger>
+                    <SelectContent>
+                      <SelectItem value={NONE_VALUE}>{t("server.folder.inherit", "Inherit")}</SelectItem>
+                      {keys.map((k) => (
+                        <SelectItem key={k.id} value={k.id}>
+                          {k.name} · {k.type === "private_key" ? t("keys.type.key", "Key") : t("keys.type.password", "Password")}
+                        </SelectItem>
+                      ))}
+                    </SelectContent>
+                  </Select>
+                </div>
+
+                <div className="space-y-1.5">
+                  <Label className="text-xs text-slate-500">{t("server.folder.highlight", "Highlight rules")}</Label>
+                  <Select
+                    value={toSelectValue(draft.defaults.highlightSetId)}
+                    onValueChange={(v) => setDefaults({ highlightSetId: fromSelectValue(v) })}
+                  >
+                    <SelectTrigger><SelectValue /></SelectTrigger>
+                    <SelectContent>
+                      <SelectItem value={NONE_VALUE}>{t("server.folder.inherit", "Inherit")}</SelectItem>
+                      {highlightSets.map((s) => (
+                        <SelectItem key={s.id} value={s.id}>{s.name}</SelectItem>
+                      ))}
+                    </SelectContent>
+                  </Select>
+                </div>
+              </div>
+
+              <div className="flex justify-between pt-2">
+                <Button variant="ghost" className="gap-1.5 text-red-500 hover:text-red-600" onClick={handleDelete}>
+                  <Trash2 className="w-4 h-4" />
+                  {t("server.folder.delete", "Delete folder")}
+                </Button>
+                <Button className="gap-1.5" onClick={handleSave} disabled={isSaving || !draft.name.trim()}>
+                  <Save className="w-4 h-4" />
+                  {t("common.save", "Save")}
+                </Button>
+              </div>
+            </div>
+          )}
+        </div>
+      </div>
+    </BaseModal>
+  );
+};
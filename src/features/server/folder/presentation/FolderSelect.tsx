@@ -0,0 +1,66 @@
+import { useEffect, useMemo } from "react";
+import { useTranslation } from "react-i18next";
+import { Folder, FolderOpen } from "lucide-react";
+
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select";
+import { useFolderStore } from "../application/useFolderStore";
+import { flattenFolderTree } from "../domain/types";
+
+// Radix Select 不接受空字符串作为值
+const ROOT_VALUE = "__root__";
+const ALL_VALUE = "__all__";
+
+interface Props {
+  /** null 表示根目录；allowAll 时 undefined 表示全部 */
+  value: string | null | undefined;
+  onChange: (value: string | null | undefined) => void;
+  allowAll?: boolean;
+  /** 不可选的文件夹 (移动文件夹时排除自身及子文件夹) */
+  excludeIds?: Set<string>;
+  className?: string;
+}
+
+export const FolderSelect = ({ value, onChange, allowAll = false, excludeIds, className }: Props) => {
+  const { t } = useTranslation();
+  const { folders, isLoaded, fetchFolders } = useFolderStore();
+
+  useEffect(() => {
+    if (!isLoaded) fetchFolders().catch(console.error);
+  }, [isLoaded]);
+
+  const nodes = useMemo(
+    () => flattenFolderTree(folders).filter((f) => !excludeIds?.has(f.id)),
+    [folders, excludeIds]
+  );
+
+  const selected = value === undefined ? (allowAll ? ALL_VALUE : ROOT_VALUE) : value ?? ROOT_VALUE;
+
+  const handleChange = (v: string) => {
+    if (v === ALL_VALUE) onChange(undefined);
+    else if (v === ROOT_VALUE) onChange(null);
+    else onChange(v);
+  };
+
+  return (
+    <Select value={selected} onValueChange={handleChange}>
+      <SelectTrigger className={className}>
+        <div className="flex items-center gap-2 truncate">
+          <Folder className="w-3.5 h-3.5 opacity-70 shrink-0" />
+          <SelectValue />
+        </div>
+      </SelectTrigger>
+      <SelectContent>
+        {allowAll && <SelectItem value={ALL_VALUE}>{t("server.folder.all", "All folders")}</SelectItem>}
+        <SelectItem value={ROOT_VALUE}>{t("server.folder.root", "No folder")}</SelectItem>
+        {nodes.map((node) => (
+          <SelectItem key={node.id} value={node.id}>
+            <span className="flex items-center gap-1.5" style={{ paddingLeft: node.depth * 12 }}>
+              <FolderOpen className="w-3 h-3 opacity-60" />
+              {node.name}
+            </span>
+          </SelectItem>
+        ))}
+      </SelectContent>
+    </Select>
+  );
+};
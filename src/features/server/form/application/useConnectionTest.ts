@@ -46,11 +46,16 @@ export const useConnectionTest = (
         connectionType: data.connectionType,
         proxyId: data.proxyId, 
         connectTimeout: data.connectTimeout,
+        algorithmPrefs: data.algorithmPrefs ?? null,
       };
 
       console.log("🔌 Testing Connection with:", payload);
 
-      await invoke("test_connection", { payload });
+      // 后端返回分阶段的诊断报告，失败时 error 中带有原因
+      const report = await invoke<{ success: boolean; error?: string | null }>("test_connection", { payload });
+      if (!report.success) {
+        throw report.error || "Unknown error";
+      }
       
       setStatus('success');
       // 🟢 [修改] 本地化成功提示
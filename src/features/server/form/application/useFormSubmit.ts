@@ -3,6 +3,7 @@ import { ServerFormValues } from "../domain/schema";
 import { v4 as uuidv4 } from "uuid";
 import { toast } from "sonner";
 import { useTranslation } from "react-i18next"; // 🟢 1. 引入 hook
+import { getErrorCode, getErrorMessage } from "@/utils/error";
 
 // 🟢 [移除] 不再需要在前端手动操作 KeyStore
 // import { useKeyStore } from "@/store/useKeyStore";
@@ -28,10 +29,15 @@ export const useFormSubmit = (onSuccess?: () => void) => {
         provider: data.provider || "Custom",
         
         authType: data.authType, 
+        protocol: data.protocol,
+        loginScript: data.loginScript,
         os: data.os,
         icon: data.icon,
         sort: data.sort,
         tags: data.tags,
+        folderId: data.folderId ?? null,
+        templateId: data.templateId ?? null,
+        templateOverrides: data.templateOverrides ?? [],
         enableExpiration: data.enableExpiration,
         expireDate: data.expireDate ? data.expireDate.toISOString() : undefined,
         
@@ -60,6 +66,12 @@ export const useFormSubmit = (onSuccess?: () => void) => {
         keepAliveInterval: data.keepAliveInterval,
         autoReconnect: data.autoReconnect,
         maxReconnects: data.maxReconnects,
+        scrollbackSize: data.scrollbackSizeMb ? data.scrollbackSizeMb * 1024 * 1024 : null,
+        tmuxSession: data.tmuxSession?.trim() || null,
+        envVars: data.envVars,
+        initialCwd: data.initialCwd?.trim() || null,
+        startupCommands: data.startupCommands,
+        algorithmPrefs: data.algorithmPrefs ?? null,
       };
 
 
@@ -74,7 +86,7 @@ export const useFormSubmit = (onSuccess?: () => void) => {
       console.error("Submit failed", error);
       
       // 依然需要捕获 Locked 错误 (虽然现在应该很少见了)
-      if (error?.toString().includes("VAULT_LOCKED")) {
+      if (getErrorCode(error) === "VAULT_LOCKED") {
         // 🟢 4. 本地化锁定提示
         toast.warning(t('server.form.vault.locked_save', 'Please unlock the Vault to save secure credentials.'));
         return;
@@ -82,7 +94,7 @@ export const useFormSubmit = (onSuccess?: () => void) => {
 
       // 🟢 5. 本地化错误提示 (使用插值)
       toast.error(t('server.form.saveError', 'Failed to save server: {{message}}', { 
-        message: error.message || error 
+        message: getErrorMessage(error)
       }));
     }
   };
@@ -3,6 +3,7 @@ import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
 import { Server } from "@/features/server/domain/types";
 import { toast } from "sonner";
+import { getErrorMessage } from "@/utils/error";
 import { useNavigate } from "react-router-dom";
 import { useTerminalStore } from "@/store/useTerminalStore";
 import { useKeyStore } from "@/store/useKeyStore";
@@ -111,7 +112,7 @@ export const useServerConnect = () => {
     } catch (error: any) {
         setIsLogError(true);
         // 🟢 本地化错误日志
-        setLogs(prev => [...prev, `[ERROR] ${t('server.logs.preflightError', 'Pre-flight failed')}: ${error}`]);
+        setLogs(prev => [...prev, `[ERROR] ${t('server.logs.preflightError', 'Pre-flight failed')}: ${getErrorMessage(error)}`]);
         console.error("Host check failed", error);
     }
   };
@@ -134,7 +135,7 @@ export const useServerConnect = () => {
     } catch (error: any) {
         setIsLogError(true);
         // 🟢 本地化错误日志
-        setLogs(prev => [...prev, `[ERROR] ${t('server.logs.saveError', 'Failed to save host key')}: ${error}`]);
+        setLogs(prev => [...prev, `[ERROR] ${t('server.logs.saveError', 'Failed to save host key')}: ${getErrorMessage(error)}`]);
         
         // 🟢 本地化 Toast
         toast.error(t('server.errorTrust', 'Failed to trust host key'));
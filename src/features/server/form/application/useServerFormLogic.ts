@@ -55,7 +55,12 @@ export const useServerFormLogic = ({ initialData, onClose }: UseServerFormLogicP
       // 🟢 [关键赋值]
       proxyId: rawProxyId, 
       
+      protocol: d.protocol || 'ssh',
+      loginScript: d.loginScript ?? [],
+      envVars: d.envVars ?? [],
+      startupCommands: d.startupCommands ?? [],
       connectionType: d.connectionType || 'direct', 
+      templateOverrides: d.templateOverrides ?? [],
 
       // 🟢 [新增] 手动处理 is_pinned 默认值
       is_pinned: d.is_pinned ?? d.isPinned ?? 0,
@@ -4,11 +4,18 @@ export const DEFAULT_SERVER_FORM_VALUES: Partial<ServerFormValues> = {
   port: 22,
   os: "linux",
   icon: "server",
+  protocol: "ssh",
+  loginScript: [],
+  envVars: [],
+  startupCommands: [],
   connectionType: "direct",
   authType: "password",
   passwordSource: "manual",
   keySource: "manual",
   tags: [],
+  folderId: null,
+  templateId: null,
+  templateOverrides: [],
   sort: 0,
   enableExpiration: false,
   is_pinned: false, // [新增] 默认不收藏
@@ -3,7 +3,10 @@ import { z } from "zod";
 // 枚举定义
 export const AuthTypeEnum = z.enum(["password", "key"]);
 export const ConnectionTypeEnum = z.enum(["direct", "http", "socks5"]);
+export const ProtocolEnum = z.enum(["ssh", "telnet"]);
+export const AlgorithmProfileEnum = z.enum(["modern", "compatible", "legacy"]);
 export const KeySourceEnum = z.enum(["manual", "store"]);
+export const TemplateFieldEnum = z.enum(["auth", "port", "proxy", "connectTimeout", "keepAliveInterval", "startupCommands"]);
 
 // 核心 Schema
 export const serverFormSchema = z.object({
@@ -21,12 +24,20 @@ export const serverFormSchema = z.object({
   is_pinned: z.union([z.number(), z.boolean()]).optional(),
 
   tags: z.array(z.string()).max(2),
+  folderId: z.string().optional().nullable(),
+  // 引用的服务器模板，templateOverrides 中的字段使用服务器自己的值
+  templateId: z.string().optional().nullable(),
+  templateOverrides: z.array(TemplateFieldEnum),
   
   // 连接信息
   host: z.string().min(1),
   // [修改] 移除 .default()
   port: z.number().min(1).max(65535),
-  username: z.string().min(1),
+  // 留空时继承所在文件夹的默认用户名
+  username: z.string(),
+  protocol: ProtocolEnum,
+  // Telnet 登录脚本 (等待 expect 后发送 send)
+  loginScript: z.array(z.object({ expect: z.string().min(1), send: z.string() })),
   connectionType: ConnectionTypeEnum,
   proxyId: z.string().optional().nullable(),
   
@@ -52,6 +63,29 @@ export const serverFormSchema = z.object({
   keepAliveInterval: z.number().min(0).max(3600).default(60),   // 默认 60秒 (0表示关闭)
   autoReconnect: z.boolean().default(false),                    // 默认 关闭
   maxReconnects: z.number().min(0).max(20).default(3),          // 默认 3次
+  scrollbackSizeMb: z.number().int().min(1).max(64).optional(), // 回滚缓冲 (MB)，为空时使用默认值
+  // tmux 会话名，规则与后端 validate_session_name 一致
+  tmuxSession: z.string().regex(/^[A-Za-z0-9_.-]{0,64}$/).optional(),
+  // 环境变量名规则与后端 validate_env_name 一致
+  envVars: z.array(z.object({ name: z.string().regex(/^[A-Za-z_][A-Za-z0-9_]*$/), value: z.string() })),
+  initialCwd: z.string().optional(),
+  startupCommands: z.array(
+    z.discriminatedUnion("type", [
+      z.object({ type: z.literal("command"), command: z.string() }),
+      z.object({ type: z.literal("snippet"), snippetId: z.string() }),
+    ])
+  ),
+  // SSH 算法偏好 (表单只编辑预设，自定义列表原样保留)
+  algorithmPrefs: z
+    .object({
+      profile: AlgorithmProfileEnum.nullable().optional(),
+      kex: z.array(z.string()).optional(),
+      hostKey: z.array(z.string()).optional(),
+      cipher: z.array(z.string()).optional(),
+      mac: z.array(z.string()).optional(),
+    })
+    .nullable()
+    .optional(),
 });
 
 export type ServerFormValues = z.infer<typeof serverFormSchema>;
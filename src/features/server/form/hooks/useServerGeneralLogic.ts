@@ -11,6 +11,7 @@ export const useServerGeneralLogic = () => {
   const currentIcon = watch("icon");
   const currentProvider = watch("provider");
   const tags = watch("tags") || [];
+  const folderId = watch("folderId");
   const enableExpiration = watch("enableExpiration");
   const expireDate = watch("expireDate");
 
@@ -73,6 +74,7 @@ export const useServerGeneralLogic = () => {
       icon: currentIcon,
       provider: currentProvider,
       tags,
+      folderId,
       enableExpiration,
       expireDate
     },
@@ -8,7 +8,11 @@ import { AuthCredentials } from "./components/AuthCredentials";
 import { ProxyConfiguration } from "./components/ProxyConfiguration"; 
 // 🟢 [新增] 引入高级设置组件
 import { AdvancedSettings } from "./components/AdvancedSettings";
+import { ProtocolSettings } from "./components/ProtocolSettings";
+import { TelnetLoginScript } from "./components/TelnetLoginScript";
+import { SessionStartupSettings } from "./components/SessionStartupSettings";
 import { useConnectionViewModel } from "./hooks/useConnectionViewModel";
+import { TemplateBinding } from "@/features/server/template";
 
 export const ServerConnectionPanel = () => {
   const { t } = useTranslation();
@@ -33,6 +37,10 @@ export const ServerConnectionPanel = () => {
 
         {/* Tab Content: Basic */}
         <TabsContent value="basic" className="flex-1 space-y-6 mt-0 outline-none">
+          <TemplateBinding />
+
+          <ProtocolSettings />
+
           <NetworkSettings 
             register={form.register}
             errors={form.errors}
@@ -78,6 +86,7 @@ export const ServerConnectionPanel = () => {
               watch={form.watch} 
               setValue={form.setValue}
            />
+           {logic.protocol === "telnet" ? <TelnetLoginScript /> : <SessionStartupSettings />}
         </TabsContent>
       </Tabs>
 
@@ -1,6 +1,6 @@
 import { useTranslation } from "react-i18next";
 import { cn } from "@/lib/utils";
-import { Tag, CalendarIcon, ArrowUpNarrowWide } from "lucide-react";
+import { Tag, CalendarIcon, ArrowUpNarrowWide, Folder } from "lucide-react";
 
 // Components & Logic
 import { Label } from "@/components/ui/label";
@@ -11,6 +11,7 @@ import {
   ExpirationPicker 
 } from "../components/ServerGeneralInputs"; 
 import { CommonTagSelector } from "@/components/common/CommonTagSelector"; 
+import { FolderSelect } from "@/features/server/folder";
 // 🟢 [新增] 引入 CustomInput
 import { CustomInput } from "@/components/common/CustomInput";
 
@@ -83,6 +84,18 @@ export const ServerGeneralInfo = () => {
       {/* ================= SECTION 2: META INFO (元数据) ================= */}
       <div className="space-y-5">
         
+        {/* Folder */}
+        <div className="space-y-2">
+          <Label className="text-xs font-semibold text-slate-500 uppercase tracking-wider flex items-center gap-2">
+            <Folder className="w-3.5 h-3.5" />
+            {t('server.form.folder', 'Folder')}
+          </Label>
+          <FolderSelect
+            value={values.folderId ?? null}
+            onChange={(v) => setValue("folderId", v ?? null, { shouldDirty: true })}
+          />
+        </div>
+
         {/* Tags */}
         <div className="space-y-2">
           <div className="flex justify-between items-center">
@@ -2,9 +2,10 @@ import { UseFormRegister, FieldErrors, UseFormWatch, UseFormSetValue } from "rea
 import { Label } from "@/components/ui/label";
 import { Input } from "@/components/ui/input";
 import { Switch } from "@/components/ui/switch";
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select";
 // ⚠️ 请根据你的实际目录结构确认引用路径
 import { ServerFormValues } from "../../domain/schema";
-import { Zap, Activity, Timer, RefreshCw } from "lucide-react";
+import { Zap, Activity, Timer, RefreshCw, History, Layers, ShieldHalf } from "lucide-react";
 
 interface AdvancedSettingsProps {
   t: any; // 这里的类型取决于你使用的 i18n 库，通常是 TFunction
@@ -14,9 +15,24 @@ interface AdvancedSettingsProps {
   setValue: UseFormSetValue<ServerFormValues>;
 }
 
+// Radix Select 不接受空字符串作为值
+const DEFAULT_PROFILE = "__default__";
+
 export const AdvancedSettings = ({ t, register, errors, watch, setValue }: AdvancedSettingsProps) => {
   // 监听自动重连开关
   const autoReconnect = watch("autoReconnect");
+  const isTelnet = watch("protocol") === "telnet";
+  const algorithmPrefs = watch("algorithmPrefs");
+  const hasCustomAlgorithms = ["kex", "hostKey", "cipher", "mac"].some(
+    (k) => ((algorithmPrefs as any)?.[k]?.length ?? 0) > 0
+  );
+
+  // 只修改预设；没有预设也没有自定义列表时清空，使用默认算法
+  const handleProfileChange = (value: string) => {
+    const profile = value === DEFAULT_PROFILE ? null : (value as "modern" | "compatible" | "legacy");
+    const next = { ...(algorithmPrefs ?? {}), profile };
+    setValue("algorithmPrefs", profile || hasCustomAlgorithms ? next : null, { shouldDirty: true });
+  };
 
   // 安全翻译辅助函数
   const translate = (key: string, fallback: string) => t ? t(key, fallback) : fallback;
@@ -154,6 +170,99 @@ export const AdvancedSettings = ({ t, register, errors, watch, setValue }: Advan
           </div>
         </div>
       )}
+
+      {/* 5. 回滚缓冲 (Scrollback) */}
+      <div className="flex items-center gap-4 p-3 rounded-lg border border-slate-200 dark:border-slate-800 bg-slate-50/50 dark:bg-slate-900/50">
+        <div className="flex items-center justify-center w-8 h-8 rounded-full bg-purple-100 dark:bg-purple-900/30">
+            <History className="w-4 h-4 text-purple-600 dark:text-purple-400" />
+        </div>
+        <div className="flex-1">
+          <Label className="text-xs font-semibold text-slate-700 dark:text-slate-300">
+            {translate('server.form.scrollback', 'Scrollback Buffer (MB)')}
+          </Label>
+          <p className="text-[10px] text-slate-500">
+            {translate('server.form.scrollbackDesc', 'Output kept for reattach and search (1-64, empty for default).')}
+          </p>
+          {errors.scrollbackSizeMb && (
+            <p className="text-[10px] text-red-500 mt-1">{errors.scrollbackSizeMb.message}</p>
+          )}
+        </div>
+        <div className="w-20">
+          <Input 
+            type="number" 
+            min={1}
+            max={64}
+            step={1}
+            onKeyDown={preventInvalidInput}
+            {...register("scrollbackSizeMb", { 
+              setValueAs: (v) => (v === "" || v == null ? undefined : Number(v)),
+            })}
+            className="h-8 text-right text-xs bg-white dark:bg-slate-800"
+            placeholder="1"
+          />
+        </div>
+      </div>
+
+      {/* 6. 自动进入 tmux 会话 (仅 SSH) */}
+      {!isTelnet && (
+        <div className="flex items-center gap-4 p-3 rounded-lg border border-slate-200 dark:border-slate-800 bg-slate-50/50 dark:bg-slate-900/50">
+          <div className="flex items-center justify-center w-8 h-8 rounded-full bg-teal-100 dark:bg-teal-900/30">
+              <Layers className="w-4 h-4 text-teal-600 dark:text-teal-400" />
+          </div>
+          <div className="flex-1">
+            <Label className="text-xs font-semibold text-slate-700 dark:text-slate-300">
+              {translate('server.form.tmuxSession', 'tmux Session')}
+            </Label>
+            <p className="text-[10px] text-slate-500">
+              {translate('server.form.tmuxSessionDesc', 'Attach to (or create) this session on connect. Letters, digits, _ . - only.')}
+            </p>
+            {errors.tmuxSession && (
+              <p className="text-[10px] text-red-500 mt-1">
+                {translate('server.form.tmuxSessionInvalid', 'Invalid session name')}
+              </p>
+            )}
+          </div>
+          <div className="w-32">
+            <Input 
+              {...register("tmuxSession")}
+              className="h-8 text-xs font-mono bg-white dark:bg-slate-800"
+              placeholder="main"
+            />
+          </div>
+        </div>
+      )}
+
+      {/* 7. SSH 算法预设 (仅 SSH) */}
+      {!isTelnet && (
+        <div className="flex items-center gap-4 p-3 rounded-lg border border-slate-200 dark:border-slate-800 bg-slate-50/50 dark:bg-slate-900/50">
+          <div className="flex items-center justify-center w-8 h-8 rounded-full bg-slate-200 dark:bg-slate-800">
+              <ShieldHalf className="w-4 h-4 text-slate-600 dark:text-slate-400" />
+          </div>
+          <div className="flex-1">
+            <Label className="text-xs font-semibold text-slate-700 dark:text-slate-300">
+              {translate('server.form.algorithms', 'SSH Algorithms')}
+            </Label>
+            <p className="text-[10px] text-slate-500">
+              {hasCustomAlgorithms
+                ? translate('server.form.algorithmsCustom', 'Custom algorithm lists are set and take precedence over the preset.')
+                : translate('server.form.algorithmsDesc', 'Use Legacy for old devices that only offer outdated algorithms.')}
+            </p>
+          </div>
+          <div className="w-36">
+            <Select value={algorithmPrefs?.profile ?? DEFAULT_PROFILE} onValueChange={handleProfileChange}>
+              <SelectTrigger className="h-8 text-xs bg-white dark:bg-slate-800">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value={DEFAULT_PROFILE}>{translate('server.form.algorithmProfiles.default', 'Default')}</SelectItem>
+                <SelectItem value="modern">{translate('server.form.algorithmProfiles.modern', 'Modern')}</SelectItem>
+                <SelectItem value="compatible">{translate('server.form.algorithmProfiles.compatible', 'Compatible')}</SelectItem>
+                <SelectItem value="legacy">{translate('server.form.algorithmProfiles.legacy', 'Legacy')}</SelectItem>
+              </SelectContent>
+            </Select>
+          </div>
+        </div>
+      )}
     </div>
   );
 };
\ No newline at end of file
@@ -0,0 +1,67 @@
+import { useTranslation } from "react-i18next";
+import { useFormContext } from "react-hook-form";
+import { motion } from "framer-motion";
+import { Terminal, Cable } from "lucide-react";
+
+import { Label } from "@/components/ui/label";
+import { cn } from "@/lib/utils";
+import { ServerFormValues } from "../../domain/schema";
+
+const DEFAULT_PORTS = { ssh: 22, telnet: 23 } as const;
+
+/**
+ * 终端协议选择：SSH / Telnet
+ * 端口仍是另一协议的默认值时，随协议一起切换
+ */
+export const ProtocolSettings = () => {
+  const { t } = useTranslation();
+  const { watch, setValue, getValues } = useFormContext<ServerFormValues>();
+  const protocol = watch("protocol") ?? "ssh";
+
+  const options = [
+    { value: "ssh" as const, label: "SSH", icon: Terminal },
+    { value: "telnet" as const, label: "Telnet", icon: Cable },
+  ];
+
+  const handleChange = (next: "ssh" | "telnet") => {
+    if (next === protocol) return;
+    const previous = next === "ssh" ? "telnet" : "ssh";
+    if (getValues("port") === DEFAULT_PORTS[previous]) {
+      setValue("port", DEFAULT_PORTS[next], { shouldDirty: true, shouldValidate: true });
+    }
+    setValue("protocol", next, { shouldDirty: true });
+  };
+
+  return (
+    <div className="space-y-1">
+      <Label className="text-xs font-semibold text-slate-500 uppercase tracking-wider">
+        {t("server.form.protocol", "Protocol")}
+      </Label>
+      <div className="flex p-1 bg-slate-100 dark:bg-slate-800 rounded-lg w-full">
+        {options.map((opt) => (
+          <button
+            key={opt.value}
+            type="button"
+            onClick={() => handleChange(opt.value)}
+            className={cn(
+              "flex-1 relative px-3 py-1.5 text-xs font-medium rounded-md transition-all duration-200 flex items-center justify-center gap-2 z-10",
+              protocol === opt.value
+                ? "text-blue-600 dark:text-blue-400"
+                : "text-slate-500 hover:text-slate-700 dark:text-slate-400"
+            )}
+          >
+            {protocol === opt.value && (
+              <motion.div
+                layoutId="protocol-segment"
+                className="absolute inset-0 bg-white dark:bg-slate-700 shadow-sm rounded-md -z-10"
+                transition={{ type: "spring", bounce: 0.2, duration: 0.3 }}
+              />
+            )}
+            <opt.icon className="w-3.5 h-3.5" />
+            {opt.label}
+          </button>
+        ))}
+      </div>
+    </div>
+  );
+};
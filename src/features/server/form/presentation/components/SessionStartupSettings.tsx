@@ -0,0 +1,137 @@
+import { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { useFieldArray, useFormContext } from "react-hook-form";
+import { FolderOpen, Plus, SquareTerminal, Trash2, Variable } from "lucide-react";
+
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import { Textarea } from "@/components/ui/textarea";
+import { StartupCommand } from "@/features/server/domain/types";
+import { ServerFormValues } from "../../domain/schema";
+
+const commandsToText = (commands: StartupCommand[]) =>
+  commands
+    .filter((c): c is Extract<StartupCommand, { type: "command" }> => c.type === "command")
+    .map((c) => c.command)
+    .join("\n");
+
+// 文本框只编辑直接给出的命令，引用片段的条目原样保留
+const textToCommands = (text: string, previous: StartupCommand[]): StartupCommand[] => [
+  ...text
+    .split("\n")
+    .map((line) => line.trim())
+    .filter(Boolean)
+    .map((command) => ({ type: "command" as const, command })),
+  ...previous.filter((c) => c.type === "snippet"),
+];
+
+/**
+ * SSH 会话启动设置：初始目录、环境变量与启动命令
+ */
+export const SessionStartupSettings = () => {
+  const { t } = useTranslation();
+  const { control, register, watch, setValue, formState: { errors } } = useFormContext<ServerFormValues>();
+  const { fields, append, remove } = useFieldArray({ control, name: "envVars" });
+
+  const serverId = watch("id");
+  const startupCommands = watch("startupCommands") ?? [];
+  const [commandsText, setCommandsText] = useState(() => commandsToText(startupCommands));
+
+  // 表单重置 (切换编辑的服务器) 时同步文本框
+  useEffect(() => {
+    setCommandsText(commandsToText(startupCommands));
+  }, [serverId]);
+
+  const handleCommandsChange = (text: string) => {
+    setCommandsText(text);
+    setValue("startupCommands", textToCommands(text, startupCommands), { shouldDirty: true });
+  };
+
+  const snippetCount = startupCommands.filter((c) => c.type === "snippet").length;
+
+  return (
+    <div className="space-y-4 p-3 rounded-lg border border-slate-200 dark:border-slate-800 bg-slate-50/50 dark:bg-slate-900/50">
+      {/* 1. 初始目录 */}
+      <div className="space-y-1.5">
+        <Label className="text-xs font-semibold text-slate-700 dark:text-slate-300 flex items-center gap-2">
+          <FolderOpen className="w-4 h-4 text-slate-400" />
+          {t("server.form.initialCwd", "Initial Directory")}
+        </Label>
+        <Input
+          {...register("initialCwd")}
+          placeholder="/var/www"
+          className="h-8 text-xs font-mono bg-white dark:bg-slate-800"
+        />
+      </div>
+
+      {/* 2. 环境变量 */}
+      <div className="space-y-2">
+        <div className="flex items-center justify-between">
+          <Label className="text-xs font-semibold text-slate-700 dark:text-slate-300 flex items-center gap-2">
+            <Variable className="w-4 h-4 text-slate-400" />
+            {t("server.form.envVars", "Environment Variables")}
+          </Label>
+          <Button
+            type="button"
+            variant="ghost"
+            size="sm"
+            className="h-7 gap-1 text-xs"
+            onClick={() => append({ name: "", value: "" })}
+          >
+            <Plus className="w-3.5 h-3.5" />
+            {t("server.form.addEnvVar", "Add variable")}
+          </Button>
+        </div>
+        <p className="text-[10px] text-slate-500">
+          {t("server.form.envVarsDesc", "Sent before the shell starts; the server must allow them via AcceptEnv.")}
+        </p>
+        {fields.map((field, index) => (
+          <div key={field.id} className="flex items-center gap-2">
+            <Input
+              {...register(`envVars.${index}.name` as const)}
+              placeholder="NAME"
+              className={
+                errors.envVars?.[index]?.name
+                  ? "h-8 text-xs font-mono border-red-500"
+                  : "h-8 text-xs font-mono"
+              }
+            />
+            <Input
+              {...register(`envVars.${index}.value` as const)}
+              placeholder={t("server.form.envValue", "Value")}
+              className="h-8 text-xs font-mono"
+            />
+            <Button type="button" variant="ghost" size="icon" className="h-8 w-8 shrink-0" onClick={() => remove(index)}>
+              <Trash2 className="w-3.5 h-3.5 text-slate-400" />
+            </Button>
+          </div>
+        ))}
+        {errors.envVars && (
+          <p className="text-[10px] text-red-500">
+            {t("server.form.envVarInvalid", "Names may only contain letters, digits and _, and must not start with a digit.")}
+          </p>
+        )}
+      </div>
+
+      {/* 3. 启动命令 */}
+      <div className="space-y-1.5">
+        <Label className="text-xs font-semibold text-slate-700 dark:text-slate-300 flex items-center gap-2">
+          <SquareTerminal className="w-4 h-4 text-slate-400" />
+          {t("server.form.startupCommands", "Startup Commands (one per line)")}
+        </Label>
+        <Textarea
+          rows={3}
+          className="font-mono text-xs bg-white dark:bg-slate-800"
+          value={commandsText}
+          onChange={(e) => handleCommandsChange(e.target.value)}
+        />
+        {snippetCount > 0 && (
+          <p className="text-[10px] text-slate-500">
+            {t("server.form.startupSnippets", "Also runs {{count}} snippet(s) referenced by this server.", { count: snippetCount })}
+          </p>
+        )}
+      </div>
+    </div>
+  );
+};
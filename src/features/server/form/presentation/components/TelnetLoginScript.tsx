@@ -0,0 +1,67 @@
+import { useTranslation } from "react-i18next";
+import { useFieldArray, useFormContext } from "react-hook-form";
+import { ListOrdered, Plus, Trash2 } from "lucide-react";
+
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import { ServerFormValues } from "../../domain/schema";
+
+/**
+ * Telnet 登录脚本：依次等待提示出现后发送内容
+ * 为空时后端按 login: / Password: 自动填充用户名和密码
+ */
+export const TelnetLoginScript = () => {
+  const { t } = useTranslation();
+  const { control, register, formState: { errors } } = useFormContext<ServerFormValues>();
+  const { fields, append, remove } = useFieldArray({ control, name: "loginScript" });
+
+  return (
+    <div className="space-y-3 p-3 rounded-lg border border-slate-200 dark:border-slate-800 bg-slate-50/50 dark:bg-slate-900/50">
+      <div className="flex items-center justify-between">
+        <Label className="text-xs font-semibold text-slate-700 dark:text-slate-300 flex items-center gap-2">
+          <ListOrdered className="w-4 h-4 text-slate-400" />
+          {t("server.form.loginScript", "Login Script")}
+        </Label>
+        <Button
+          type="button"
+          variant="ghost"
+          size="sm"
+          className="h-7 gap-1 text-xs"
+          onClick={() => append({ expect: "", send: "" })}
+        >
+          <Plus className="w-3.5 h-3.5" />
+          {t("server.form.addStep", "Add step")}
+        </Button>
+      </div>
+      <p className="text-[10px] text-slate-500">
+        {t(
+          "server.form.loginScriptDesc",
+          "Wait for each prompt, then send the text. {username} and {password} are replaced. Leave empty to answer login: / Password: automatically."
+        )}
+      </p>
+
+      {fields.map((field, index) => (
+        <div key={field.id} className="flex items-center gap-2">
+          <Input
+            {...register(`loginScript.${index}.expect` as const)}
+            placeholder={t("server.form.expect", "Wait for (e.g. login:)")}
+            className={
+              errors.loginScript?.[index]?.expect
+                ? "h-8 text-xs font-mono border-red-500"
+                : "h-8 text-xs font-mono"
+            }
+          />
+          <Input
+            {...register(`loginScript.${index}.send` as const)}
+            placeholder={t("server.form.send", "Send (e.g. {username})")}
+            className="h-8 text-xs font-mono"
+          />
+          <Button type="button" variant="ghost" size="icon" className="h-8 w-8 shrink-0" onClick={() => remove(index)}>
+            <Trash2 className="w-3.5 h-3.5 text-slate-400" />
+          </Button>
+        </div>
+      ))}
+    </div>
+  );
+};
@@ -16,6 +16,7 @@ export const useConnectionViewModel = () => {
   const connectionType = watch("connectionType") as ConnectionType | undefined;
   const currentMode: ConnectionType = connectionType || "direct";
 
+  const protocol = watch("protocol") ?? "ssh";
   const currentKeyId = watch("keyId");
   const currentProxyId = watch("proxyId");
 
@@ -128,6 +129,7 @@ export const useConnectionViewModel = () => {
       ...formLogic,
       connectionType: currentMode,
       proxyId: currentProxyId,
+      protocol,
     },
     ui: {
       isKeyModalOpen,
@@ -0,0 +1,9 @@
+import { invoke } from '@tauri-apps/api/core';
+import { ScanOptions, ScanSummary, ServerHealth } from '../domain/types';
+
+export const HealthService = {
+  list: () => invoke<ServerHealth[]>('list_server_health'),
+  // 进度通过 server-scan-progress 事件推送，完成后返回汇总
+  scan: (options: ScanOptions) => invoke<ScanSummary>('scan_servers', { options }),
+  cancel: () => invoke<void>('cancel_server_scan'),
+};
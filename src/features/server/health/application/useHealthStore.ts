@@ -0,0 +1,47 @@
+import { create } from 'zustand';
+import { listen } from '@tauri-apps/api/event';
+import { SCAN_PROGRESS_EVENT, ScanOptions, ScanProgress, ScanSummary, ServerHealth } from '../domain/types';
+import { HealthService } from './health.service';
+
+interface HealthState {
+  // serverId -> 最近一次扫描结果
+  health: Record<string, ServerHealth>;
+  isLoaded: boolean;
+  isScanning: boolean;
+  progress: { completed: number; total: number };
+
+  fetchHealth: () => Promise<void>;
+  scan: (options: ScanOptions) => Promise<ScanSummary>;
+  cancel: () => Promise<void>;
+}
+
+export const useHealthStore = create<HealthState>((set) => ({
+  health: {},
+  isLoaded: false,
+  isScanning: false,
+  progress: { completed: 0, total: 0 },
+
+  fetchHealth: async () => {
+    const rows = await HealthService.list();
+    set({ health: Object.fromEntries(rows.map((h) => [h.serverId, h])), isLoaded: true });
+  },
+
+  scan: async (options) => {
+    set({ isScanning: true, progress: { completed: 0, total: options.serverIds?.length ?? 0 } });
+    // 结果逐台写入，列表中的状态点随扫描实时更新
+    const unlisten = await listen<ScanProgress>(SCAN_PROGRESS_EVENT, ({ payload }) => {
+      set((state) => ({
+        health: { ...state.health, [payload.result.serverId]: payload.result },
+        progress: { completed: payload.completed, total: payload.total },
+      }));
+    });
+    try {
+      return await HealthService.scan(options);
+    } finally {
+      unlisten();
+      set({ isScanning: false });
+    }
+  },
+
+  cancel: () => HealthService.cancel(),
+}));
@@ -0,0 +1,41 @@
+// 服务器可达性扫描 (对应 src-tauri/src/commands/health.rs)
+
+// 没有扫描记录的服务器视为 unknown
+export type HealthStatus = 'up' | 'down' | 'unknown';
+
+export interface ServerHealth {
+  serverId: string;
+  status: Exclude<HealthStatus, 'unknown'>;
+  // TCP 连接耗时
+  latencyMs?: number | null;
+  resolvedIp?: string | null;
+  // SSH 版本串
+  banner?: string | null;
+  error?: string | null;
+  checkedAt: number;
+}
+
+export interface ScanOptions {
+  // 指定服务器；为空时按 tag / folderId 筛选，都为空时扫描全部
+  serverIds?: string[];
+  tag?: string | null;
+  folderId?: string | null;
+  concurrency?: number;
+  timeoutMs?: number;
+}
+
+export interface ScanProgress {
+  completed: number;
+  total: number;
+  result: ServerHealth;
+}
+
+export interface ScanSummary {
+  total: number;
+  up: number;
+  down: number;
+  cancelled: boolean;
+}
+
+// 每完成一台服务器发出的进度事件
+export const SCAN_PROGRESS_EVENT = 'server-scan-progress';
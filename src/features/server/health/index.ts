@@ -0,0 +1,7 @@
+// src/features/server/health/index.ts
+
+export { useHealthStore } from './application/useHealthStore';
+export { HealthService } from './application/health.service';
+export { HealthIndicator } from './presentation/HealthIndicator';
+export { ScanAction } from './presentation/ScanAction';
+export * from './domain/types';
@@ -0,0 +1,44 @@
+import { useTranslation } from "react-i18next";
+import { formatDistanceToNow } from "date-fns";
+import { cn } from "@/lib/utils";
+import { GlassTooltip } from "@/components/common/GlassTooltip";
+import { useHealthStore } from "../application/useHealthStore";
+
+interface Props {
+  serverId: string;
+  className?: string;
+}
+
+const DOT_CLASS = {
+  up: "bg-emerald-500",
+  down: "bg-red-500",
+  unknown: "bg-slate-300 dark:bg-slate-600",
+};
+
+/**
+ * 服务器最近一次扫描结果的状态点，悬停显示延迟、版本串或错误
+ */
+export const HealthIndicator = ({ serverId, className }: Props) => {
+  const { t } = useTranslation();
+  const health = useHealthStore((s) => s.health[serverId]);
+  const status = health?.status ?? "unknown";
+
+  const describe = () => {
+    if (!health) return t("server.health.unknown", "Not scanned yet");
+    const checked = t("server.health.checked", "Checked {{time}}", {
+      time: formatDistanceToNow(new Date(health.checkedAt), { addSuffix: true }),
+    });
+    if (health.status === "up") {
+      const parts = [t("server.health.up", "Reachable"), `${health.latencyMs ?? "-"} ms`];
+      if (health.banner) parts.push(health.banner);
+      return `${parts.join(" · ")}\n${checked}`;
+    }
+    return `${t("server.health.down", "Unreachable")} · ${health.error ?? ""}\n${checked}`;
+  };
+
+  return (
+    <GlassTooltip content={describe()} side="top" className="whitespace-pre-line">
+      <span className={cn("inline-block w-2 h-2 rounded-full shrink-0", DOT_CLASS[status], className)} />
+    </GlassTooltip>
+  );
+};
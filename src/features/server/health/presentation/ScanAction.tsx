@@ -0,0 +1,64 @@
+import { useEffect } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { Activity, Square } from "lucide-react";
+import { Button } from "@/components/ui/button";
+import { getErrorMessage } from "@/utils/error";
+import { useHealthStore } from "../application/useHealthStore";
+
+interface Props {
+  // 当前列表中可见的服务器 (随搜索、标签、文件夹筛选变化)
+  serverIds: string[];
+}
+
+/**
+ * 列表头部的扫描按钮：扫描当前筛选结果，扫描中显示进度，再次点击取消
+ */
+export const ScanAction = ({ serverIds }: Props) => {
+  const { t } = useTranslation();
+  const { isLoaded, isScanning, progress, fetchHealth, scan, cancel } = useHealthStore();
+
+  useEffect(() => {
+    if (!isLoaded) fetchHealth().catch(console.error);
+  }, [isLoaded]);
+
+  const handleClick = async () => {
+    if (isScanning) {
+      cancel().catch(console.error);
+      return;
+    }
+    if (serverIds.length === 0) return;
+    try {
+      const summary = await scan({ serverIds });
+      const message = t("server.health.summary", "{{up}} reachable, {{down}} unreachable", summary);
+      if (summary.cancelled) toast.info(t("server.health.cancelled", "Scan cancelled"), { description: message });
+      else if (summary.down > 0) toast.warning(message);
+      else toast.success(message);
+    } catch (err) {
+      toast.error(getErrorMessage(err));
+    }
+  };
+
+  return (
+    <Button
+      variant="outline"
+      size="sm"
+      onClick={handleClick}
+      disabled={!isScanning && serverIds.length === 0}
+      title={isScanning ? t("server.health.cancel", "Cancel scan") : t("server.health.scanHint", "Check reachability of the listed servers")}
+      className="h-9 gap-1.5 text-xs bg-slate-100 dark:bg-slate-800 border-slate-200 dark:border-slate-700"
+    >
+      {isScanning ? (
+        <>
+          <Square className="w-3.5 h-3.5" />
+          {progress.completed}/{progress.total}
+        </>
+      ) : (
+        <>
+          <Activity className="w-3.5 h-3.5" />
+          {t("server.health.scan", "Scan")}
+        </>
+      )}
+    </Button>
+  );
+};
@@ -0,0 +1,16 @@
+import { invoke } from '@tauri-apps/api/core';
+import { ImportApplyItem, ImportPreview, ImportResult, ImportSource } from '../domain/types';
+
+const PREVIEW_COMMANDS: Record<ImportSource, string> = {
+  sshConfig: 'preview_ssh_config_import',
+  putty: 'preview_putty_import',
+  xshell: 'preview_xshell_import',
+  mobaxterm: 'preview_mobaxterm_import',
+  csv: 'preview_csv_import',
+};
+
+export const ServerImportService = {
+  preview: (source: ImportSource, path?: string) =>
+    invoke<ImportPreview>(PREVIEW_COMMANDS[source], { path: path || null }),
+  apply: (items: ImportApplyItem[]) => invoke<ImportResult>('apply_server_import', { items }),
+};
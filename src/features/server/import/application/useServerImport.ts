@@ -0,0 +1,104 @@
+import { useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { toast } from 'sonner';
+import { getErrorCode, getErrorMessage } from '@/utils/error';
+import { useKeyStore } from '@/store/useKeyStore';
+import { ImportPreview, ImportResult, ImportSource } from '../domain/types';
+import { ServerImportService } from './import.service';
+
+/**
+ * 导入流程：选择来源 -> 预览与比对 -> 勾选后写入
+ */
+export const useServerImport = (onImported: () => void) => {
+  const { t } = useTranslation();
+  const openGlobalUnlockModal = useKeyStore((s) => s.openGlobalUnlockModal);
+
+  const [source, setSource] = useState<ImportSource>('sshConfig');
+  const [path, setPath] = useState('');
+  const [preview, setPreview] = useState<ImportPreview | null>(null);
+  // 勾选的条目 (按预览中的下标)
+  const [selected, setSelected] = useState<Set<number>>(new Set());
+  const [isLoading, setIsLoading] = useState(false);
+  const [result, setResult] = useState<ImportResult | null>(null);
+
+  const reset = () => {
+    setPreview(null);
+    setSelected(new Set());
+    setResult(null);
+  };
+
+  // 切换来源时清空路径与预览
+  const changeSource = (next: ImportSource) => {
+    setSource(next);
+    setPath('');
+    reset();
+  };
+
+  const loadPreview = async () => {
+    setIsLoading(true);
+    setResult(null);
+    try {
+      const data = await ServerImportService.preview(source, path.trim());
+      setPreview(data);
+      // 默认勾选新增与有变化的条目
+      setSelected(new Set(
+        data.entries.flatMap((e, i) => (e.status === 'unchanged' ? [] : [i]))
+      ));
+    } catch (err) {
+      toast.error(t('server.import.previewFailed', 'Failed to read import source'), {
+        description: getErrorMessage(err),
+      });
+    } finally {
+      setIsLoading(false);
+    }
+  };
+
+  const toggle = (index: number) => {
+    setSelected((prev) => {
+      const next = new Set(prev);
+      next.has(index) ? next.delete(index) : next.add(index);
+      return next;
+    });
+  };
+
+  const toggleAll = () => {
+    if (!preview) return;
+    setSelected((prev) =>
+      prev.size === preview.entries.length ? new Set() : new Set(preview.entries.map((_, i) => i))
+    );
+  };
+
+  const apply = async () => {
+    if (!preview || selected.size === 0) return;
+    setIsLoading(true);
+    try {
+      const items = preview.entries
+        .filter((_, i) => selected.has(i))
+        .map((e) => ({ host: e.host, existingId: e.existingId }));
+      const res = await ServerImportService.apply(items);
+      setResult(res);
+      onImported();
+
+      if (res.failed.some((f) => f.error.code === 'VAULT_LOCKED')) {
+        toast.info(t('server.import.vaultLocked', 'Unlock the vault to import private keys.'));
+        openGlobalUnlockModal();
+      }
+      toast.success(t('server.import.done', 'Imported {{created}} new, updated {{updated}}', {
+        created: res.created,
+        updated: res.updated,
+      }));
+      // 成功的条目不再重复导入
+      if (res.failed.length === 0) reset();
+    } catch (err) {
+      if (getErrorCode(err) === 'VAULT_LOCKED') openGlobalUnlockModal();
+      toast.error(getErrorMessage(err));
+    } finally {
+      setIsLoading(false);
+    }
+  };
+
+  return {
+    state: { source, path, preview, selected, isLoading, result },
+    actions: { setSource: changeSource, setPath, loadPreview, toggle, toggleAll, apply, reset },
+  };
+};
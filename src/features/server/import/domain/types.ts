@@ -0,0 +1,69 @@
+// 服务器导入 (对应 src-tauri/src/commands/import.rs)
+import { AppError } from '@/utils/error';
+
+export type ImportSource = 'sshConfig' | 'putty' | 'xshell' | 'mobaxterm' | 'csv';
+
+export const IMPORT_SOURCES: ImportSource[] = ['sshConfig', 'putty', 'xshell', 'mobaxterm', 'csv'];
+
+export interface ImportedProxy {
+  proxyType: 'http' | 'socks4' | 'socks5';
+  host: string;
+  port: number;
+  username?: string | null;
+  password?: string | null;
+}
+
+export interface ImportedHost {
+  name: string;
+  host: string;
+  port: number;
+  username?: string | null;
+  identityFile?: string | null;
+  connectTimeout?: number | null;
+  keepAliveInterval?: number | null;
+  protocol: 'ssh' | 'telnet';
+  proxy?: ImportedProxy | null;
+  tags: string[];
+  warnings: string[];
+}
+
+export type ImportStatus = 'new' | 'update' | 'unchanged';
+
+export interface FieldChange {
+  field: string;
+  oldValue?: string | null;
+  newValue?: string | null;
+}
+
+export interface ImportPreviewEntry {
+  host: ImportedHost;
+  status: ImportStatus;
+  existingId?: string | null;
+  existingName?: string | null;
+  changes: FieldChange[];
+}
+
+export interface ImportPreview {
+  source: string;
+  entries: ImportPreviewEntry[];
+  warnings: string[];
+  skipped: SkippedEntry[];
+}
+
+export interface SkippedEntry {
+  name: string;
+  reason: string;
+}
+
+export interface ImportApplyItem {
+  host: ImportedHost;
+  existingId?: string | null;
+}
+
+export interface ImportResult {
+  created: number;
+  updated: number;
+  keysImported: number;
+  proxiesCreated: number;
+  failed: { name: string; error: AppError }[];
+}
@@ -0,0 +1,3 @@
+// src/features/server/import/index.ts
+
+export { ServerImportModal } from './presentation/ServerImportModal';
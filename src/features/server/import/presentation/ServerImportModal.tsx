@@ -0,0 +1,245 @@
+import { useTranslation } from "react-i18next";
+import { open } from "@tauri-apps/plugin-dialog";
+import { AlertTriangle, Ban, FileInput, FolderOpen, FolderTree, KeyRound, Loader2, Network, Search } from "lucide-react";
+import { clsx } from "clsx";
+
+import { BaseModal } from "@/components/common/BaseModal";
+import { Button } from "@/components/ui/button";
+import { Checkbox } from "@/components/ui/checkbox";
+import { Input } from "@/components/ui/input";
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select";
+import { useServerImport } from "../application/useServerImport";
+import { IMPORT_SOURCES, ImportPreviewEntry, ImportSource, ImportStatus } from "../domain/types";
+
+interface Props {
+  isOpen: boolean;
+  onClose: () => void;
+  onImported: () => void;
+}
+
+const STATUS_STYLES: Record<ImportStatus, string> = {
+  new: "bg-emerald-50 text-emerald-600 dark:bg-emerald-900/20 dark:text-emerald-400",
+  update: "bg-amber-50 text-amber-600 dark:bg-amber-900/20 dark:text-amber-400",
+  unchanged: "bg-slate-100 text-slate-500 dark:bg-slate-800 dark:text-slate-400",
+};
+
+const SOURCE_LABELS: Record<ImportSource, string> = {
+  sshConfig: "OpenSSH config",
+  putty: "PuTTY (.reg)",
+  xshell: "Xshell (.xsh)",
+  mobaxterm: "MobaXterm (.mxtsessions)",
+  csv: "Termius / CSV",
+};
+
+// 文件选择框的扩展名过滤 (ssh config 没有扩展名)
+const SOURCE_EXTENSIONS: Partial<Record<ImportSource, string[]>> = {
+  putty: ["reg"],
+  xshell: ["xsh"],
+  mobaxterm: ["mxtsessions"],
+  csv: ["csv"],
+};
+
+const EntryRow = ({ entry, checked, onToggle }: { entry: ImportPreviewEntry; checked: boolean; onToggle: () => void }) => {
+  const { t } = useTranslation();
+  const { host } = entry;
+
+  return (
+    <div className="flex gap-3 p-3 rounded-lg border border-slate-200 dark:border-slate-800 bg-white dark:bg-slate-900">
+      <Checkbox checked={checked} onCheckedChange={onToggle} className="mt-1" />
+      <div className="flex-1 min-w-0 space-y-1">
+        <div className="flex items-center gap-2">
+          <span className="font-semibold text-sm text-slate-800 dark:text-slate-200 truncate">{host.name}</span>
+          <span className={clsx("px-1.5 py-0.5 rounded text-[10px] font-bold uppercase", STATUS_STYLES[entry.status])}>
+            {t(`server.import.status.${entry.status}`, entry.status)}
+          </span>
+          {entry.existingName && entry.existingName !== host.name && (
+            <span className="text-xs text-slate-400 truncate">→ {entry.existingName}</span>
+          )}
+        </div>
+        <div className="text-xs font-mono text-slate-500">
+          {host.protocol === "telnet" ? "telnet://" : ""}
+          {host.username ? `${host.username}@` : ""}{host.host}:{host.port}
+        </div>
+        {host.proxy && (
+          <div className="flex items-center gap-1 text-xs text-slate-500 truncate">
+            <Network className="w-3 h-3 shrink-0" /> {host.proxy.proxyType}://{host.proxy.host}:{host.proxy.port}
+          </div>
+        )}
+        {host.tags.length > 0 && (
+          <div className="flex items-center gap-1 text-xs text-slate-500 truncate">
+            <FolderTree className="w-3 h-3 shrink-0" /> {host.tags.join(", ")}
+          </div>
+        )}
+        {host.identityFile && (
+          <div className="flex items-center gap-1 text-xs text-slate-500 truncate">
+            <KeyRound className="w-3 h-3 shrink-0" /> {host.identityFile}
+          </div>
+        )}
+        {entry.changes.length > 0 && (
+          <ul className="text-xs text-slate-500 space-y-0.5">
+            {entry.changes.map((c) => (
+              <li key={c.field} className="font-mono truncate">
+                {c.field}: <span className="line-through opacity-60">{c.oldValue ?? "—"}</span> → {c.newValue ?? "—"}
+              </li>
+            ))}
+          </ul>
+        )}
+        {host.warnings.map((w) => (
+          <div key={w} className="flex items-start gap-1 text-xs text-amber-600 dark:text-amber-400">
+            <AlertTriangle className="w-3 h-3 mt-0.5 shrink-0" /> {w}
+          </div>
+        ))}
+      </div>
+    </div>
+  );
+};
+
+export const ServerImportModal = ({ isOpen, onClose, onImported }: Props) => {
+  const { t } = useTranslation();
+  const { state, actions } = useServerImport(onImported);
+
+  const handleClose = () => {
+    actions.reset();
+    onClose();
+  };
+
+  const handleBrowse = async (directory = false) => {
+    const extensions = SOURCE_EXTENSIONS[state.source];
+    const selected = await open({
+      multiple: false,
+      directory,
+      filters: !directory && extensions ? [{ name: SOURCE_LABELS[state.source], extensions }] : undefined,
+    });
+    if (typeof selected === "string") actions.setPath(selected);
+  };
+
+  const entries = state.preview?.entries ?? [];
+
+  return (
+    <BaseModal
+      isOpen={isOpen}
+      onClose={handleClose}
+      title={t("server.import.title", "Import Servers")}
+      icon={<FileInput className="w-5 h-5" />}
+      className="max-w-2xl"
+      footer={
+        <>
+          {state.preview && (
+            <span className="mr-auto text-xs text-slate-500">
+              {t("server.import.selected", "{{count}} of {{total}} selected", {
+                count: state.selected.size,
+                total: entries.length,
+              })}
+            </span>
+          )}
+          <Button variant="ghost" onClick={handleClose}>
+            {t("common.cancel", "Cancel")}
+          </Button>
+          <Button onClick={actions.apply} disabled={!state.preview || state.selected.size === 0 || state.isLoading}>
+            {state.isLoading && <Loader2 className="w-4 h-4 animate-spin" />}
+            {t("server.import.confirm", "Import")}
+          </Button>
+        </>
+      }
+    >
+      <div className="space-y-4">
+        <div className="flex gap-2">
+          <Select value={state.source} onValueChange={(v) => actions.setSource(v as ImportSource)}>
+            <SelectTrigger className="w-[200px] shrink-0 text-xs">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              {IMPORT_SOURCES.map((source) => (
+                <SelectItem key={source} value={source}>
+                  {SOURCE_LABELS[source]}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+          <Input
+            value={state.path}
+            onChange={(e) => actions.setPath(e.target.value)}
+            placeholder={
+              state.source === "sshConfig"
+                ? t("server.import.sshConfigPlaceholder", "~/.ssh/config")
+                : t("server.import.filePlaceholder", "Select an exported file")
+            }
+            className="font-mono text-xs"
+          />
+          <Button variant="outline" size="icon" onClick={() => handleBrowse()} title={t("server.import.browse", "Browse")}>
+            <FolderOpen className="w-4 h-4" />
+          </Button>
+          {state.source === "xshell" && (
+            <Button
+              variant="outline"
+              size="icon"
+              onClick={() => handleBrowse(true)}
+              title={t("server.import.browseFolder", "Choose sessions folder")}
+            >
+              <FolderTree className="w-4 h-4" />
+            </Button>
+          )}
+          <Button variant="secondary" onClick={actions.loadPreview} disabled={state.isLoading} className="gap-2">
+            <Search className="w-4 h-4" />
+            {t("server.import.preview", "Preview")}
+          </Button>
+        </div>
+
+        {state.preview?.warnings.map((w) => (
+          <div key={w} className="flex items-start gap-2 text-xs text-amber-600 dark:text-amber-400">
+            <AlertTriangle className="w-3.5 h-3.5 mt-0.5 shrink-0" /> {w}
+          </div>
+        ))}
+
+        {state.preview && state.preview.skipped.length > 0 && (
+          <details className="text-xs text-slate-500">
+            <summary className="cursor-pointer select-none">
+              {t("server.import.skipped", "{{count}} sessions skipped", { count: state.preview.skipped.length })}
+            </summary>
+            <ul className="mt-1 space-y-0.5 pl-4">
+              {state.preview.skipped.map((s, i) => (
+                <li key={`${s.name}-${i}`} className="flex items-start gap-1">
+                  <Ban className="w-3 h-3 mt-0.5 shrink-0" />
+                  <span className="font-semibold">{s.name}</span>: {s.reason}
+                </li>
+              ))}
+            </ul>
+          </details>
+        )}
+
+        {state.result && state.result.failed.length > 0 && (
+          <div className="p-3 rounded-lg bg-red-50 dark:bg-red-900/20 text-xs text-red-600 dark:text-red-400 space-y-1">
+            {state.result.failed.map((f) => (
+              <div key={f.name}>
+                <span className="font-semibold">{f.name}</span>: {f.error.details || f.error.message}
+              </div>
+            ))}
+          </div>
+        )}
+
+        {state.preview && (
+          entries.length === 0 ? (
+            <div className="text-center text-sm text-slate-400 py-8">
+              {t("server.import.empty", "No hosts found")}
+            </div>
+          ) : (
+            <div className="space-y-2">
+              <label className="flex items-center gap-2 text-xs text-slate-500 cursor-pointer">
+                <Checkbox checked={state.selected.size === entries.length} onCheckedChange={actions.toggleAll} />
+                {t("server.import.selectAll", "Select all")}
+              </label>
+              {entries.map((entry, i) => (
+                <EntryRow
+                  key={`${entry.host.name}-${i}`}
+                  entry={entry}
+                  checked={state.selected.has(i)}
+                  onToggle={() => actions.toggle(i)}
+                />
+              ))}
+            </div>
+          )
+        )}
+      </div>
+    </BaseModal>
+  );
+};
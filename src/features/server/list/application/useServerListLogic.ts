@@ -6,6 +6,8 @@ import { CardSize, ServerListState, SortOption, ViewMode } from '../domain/types
 import { toast } from 'sonner';
 import { useKeyStore } from "@/store/useKeyStore";
 import { useTranslation } from "react-i18next";
+import { getDescendantIds, useFolderStore } from '@/features/server/folder';
+import { showUndoToast, TrashKind } from '@/features/trash';
 
 export const useServerListLogic = () => {
   const { t } = useTranslation();
@@ -18,6 +20,7 @@ export const useServerListLogic = () => {
     markListVisited
   } = useServerStore();
   const { connect } = useServerConnect();
+  const folders = useFolderStore(s => s.folders);
   
   const keyStoreStatus = useKeyStore(s => s.status);
   const openGlobalUnlockModal = useKeyStore(s => s.openGlobalUnlockModal);
@@ -87,6 +90,14 @@ export const useServerListLogic = () => {
       );
     }
 
+    // 选中文件夹时包含其子文件夹中的服务器
+    if (state.activeFolderId === null) {
+      result = result.filter(s => !s.folderId);
+    } else if (state.activeFolderId !== undefined) {
+      const ids = getDescendantIds(folders, state.activeFolderId);
+      result = result.filter(s => !!s.folderId && ids.has(s.folderId));
+    }
+
     if (state.activeTags.length > 0) {
       result = result.filter(s => 
         state.activeTags.every(tag => s.tags.includes(tag))
@@ -105,7 +116,7 @@ export const useServerListLogic = () => {
     });
 
     return result;
-  }, [servers, state.searchQuery, state.activeTags, state.sortBy]);
+  }, [servers, folders, state.searchQuery, state.activeFolderId, state.activeTags, state.sortBy]);
 
   const allAvailableTags = useMemo(() => {
     const tags = new Set<string>();
@@ -132,6 +143,7 @@ export const useServerListLogic = () => {
     setViewMode: (mode: ViewMode) => updateState({ viewMode: mode }),
     setSortBy: (sort: SortOption) => updateState({ sortBy: sort }),
     setTags: (tags: string[]) => updateState({ activeTags: tags }),
+    setActiveFolder: (folderId: string | null | undefined) => updateState({ activeFolderId: folderId }),
     
     handleConnect: (server: Server) => {
       if (!isUnlocked) {
@@ -201,16 +213,22 @@ export const useServerListLogic = () => {
 
       try {
         await removeServer(server.id);
+        const trashed: { kind: TrashKind; id: string }[] = [{ kind: 'server', id: server.id }];
 
         if (shouldDeleteKey && relatedKeyId) {
            if (deleteKey) {
              await deleteKey(relatedKeyId);
+             trashed.push({ kind: 'key', id: relatedKeyId });
            } else {
              console.warn("deleteKey method not found in useKeyStore");
            }
         }
         
-        toast.success(t('server.delete.success', 'Server deleted successfully'));
+        // 撤销时服务器与随之删除的凭据一起恢复
+        showUndoToast(t, t('trash.serverDeleted', 'Server moved to trash'), trashed, async () => {
+          await fetchServers(true);
+          if (trashed.length > 1) await useKeyStore.getState().loadKeys();
+        });
       } catch (e) {
         console.error(e);
         toast.error(t('server.delete.error', 'Failed to delete server'));
@@ -55,7 +55,7 @@ export const DeleteServerModal = ({
       open={isOpen}
       onOpenChange={onOpenChange}
       title={t('server.delete.title', 'Delete Server?')}
-      description={t('server.delete.confirm', 'Are you sure you want to delete server "{{name}}"? This action cannot be undone.', { name: server.name })}
+      description={t('server.delete.confirm', 'Move server "{{name}}" to the trash? You can restore it from Settings > Trash.', { name: server.name })}
       confirmText={t('common.delete', 'Delete')}
       cancelText={t('common.cancel', 'Cancel')}
       variant="destructive" // 使用红色警告风格
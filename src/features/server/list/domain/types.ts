@@ -13,6 +13,8 @@ export type SortOption = 'created_desc' | 'created_asc' | 'id_desc' | 'id_asc';
 export interface ServerListState {
   searchQuery: string;
   activeTags: string[];
+  // undefined 为全部，null 为根目录 (未分组)
+  activeFolderId?: string | null;
   viewMode: ViewMode;
   cardSize: CardSize;
   sortBy: SortOption;
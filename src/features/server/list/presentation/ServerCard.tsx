@@ -8,6 +8,7 @@ import { DropdownMenu, DropdownMenuContent, DropdownMenuItem, DropdownMenuTrigge
 import { format, differenceInDays } from "date-fns";
 import { GlassTooltip } from "@/components/common/GlassTooltip";
 import { useTranslation } from "react-i18next";
+import { HealthIndicator } from "@/features/server/health";
 
 import {
   InteractiveCard,
@@ -132,6 +133,7 @@ export const ServerCard = ({ data, size, onConnect, onCopyIP, onPin, onDelete, o
                className="font-semibold text-foreground truncate flex items-center gap-1.5 leading-tight transition-all"
                style={{ fontSize: currentSize.title }}
              >
+                <HealthIndicator serverId={data.id} />
                 <span className="truncate">{data.name}</span>
                 {data.isPinned && <Pin className="w-3.5 h-3.5 text-blue-500 fill-blue-500/10" />}
              </h3>
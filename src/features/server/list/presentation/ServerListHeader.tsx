@@ -2,22 +2,43 @@ import {
   ArrowDownWideNarrow, 
   Calendar, 
   Hash,
+  FileInput,
+  FileOutput,
+  FolderTree,
+  LayoutTemplate,
+  CalendarClock,
+  ListChecks,
+  BarChart3,
 } from "lucide-react";
 import { ActionToolbar } from "@/components/common/ActionToolbar";
+import { Button } from "@/components/ui/button";
 import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select";
 import { ServerListState, SortOption, CardSize } from "../domain/types"; // 引入 CardSize
 import { useTranslation } from "react-i18next";
 import { clsx } from "clsx"; 
+import { FolderSelect } from "@/features/server/folder";
+import { useExpirationStore } from "@/features/server/expiration";
+import { ScanAction } from "@/features/server/health";
 
 interface Props {
   state: ServerListState;
   allTags: string[];
   actions: any; 
   onAddClick: () => void;
+  onImportClick: () => void;
+  onExportClick: () => void;
+  onManageFoldersClick: () => void;
+  onManageTemplatesClick: () => void;
+  onExpirationsClick: () => void;
+  onBulkEditClick: () => void;
+  onStatsClick: () => void;
+  // 当前筛选结果，供可达性扫描与批量编辑使用
+  visibleServerIds: string[];
 }
 
-export const ServerListHeader = ({ state, allTags, actions, onAddClick }: Props) => {
+export const ServerListHeader = ({ state, allTags, actions, onAddClick, onImportClick, onExportClick, onManageFoldersClick, onManageTemplatesClick, onExpirationsClick, onBulkEditClick, onStatsClick, visibleServerIds }: Props) => {
   const { t } = useTranslation();
+  const expiringCount = useExpirationStore((s) => s.upcoming.length);
 
   // 1. 排序组件
   const SortAction = (
@@ -39,6 +60,108 @@ export const ServerListHeader = ({ state, allTags, actions, onAddClick }: Props)
     </Select>
   );
 
+  // 文件夹筛选与管理
+  const FolderAction = (
+    <div className="flex items-center gap-1">
+      <FolderSelect
+        value={state.activeFolderId}
+        onChange={actions.setActiveFolder}
+        allowAll
+        className="h-9 w-[150px] text-xs bg-slate-100 dark:bg-slate-800 border-slate-200 dark:border-slate-700 focus:ring-0"
+      />
+      <Button
+        variant="outline"
+        size="icon"
+        onClick={onManageFoldersClick}
+        title={t('server.folder.manage', 'Manage Folders')}
+        className="h-9 w-9 bg-slate-100 dark:bg-slate-800 border-slate-200 dark:border-slate-700"
+      >
+        <FolderTree className="w-3.5 h-3.5" />
+      </Button>
+    </div>
+  );
+
+  // 模板管理
+  const TemplateAction = (
+    <Button
+      variant="outline"
+      size="sm"
+      onClick={onManageTemplatesClick}
+      className="h-9 gap-1.5 text-xs bg-slate-100 dark:bg-slate-800 border-slate-200 dark:border-slate-700"
+    >
+      <LayoutTemplate className="w-3.5 h-3.5" />
+      {t('server.template.button', 'Templates')}
+    </Button>
+  );
+
+  // 批量编辑当前筛选结果
+  const BulkEditAction = (
+    <Button
+      variant="outline"
+      size="sm"
+      onClick={onBulkEditClick}
+      disabled={visibleServerIds.length === 0}
+      title={t('server.bulk.title', 'Bulk Edit Servers')}
+      className="h-9 gap-1.5 text-xs bg-slate-100 dark:bg-slate-800 border-slate-200 dark:border-slate-700"
+    >
+      <ListChecks className="w-3.5 h-3.5" />
+      {t('server.bulk.button', 'Bulk edit')}
+    </Button>
+  );
+
+  // 连接统计
+  const StatsAction = (
+    <Button
+      variant="outline"
+      size="icon"
+      onClick={onStatsClick}
+      title={t('server.stats.title', 'Connection Statistics')}
+      className="h-9 w-9 bg-slate-100 dark:bg-slate-800 border-slate-200 dark:border-slate-700"
+    >
+      <BarChart3 className="w-3.5 h-3.5" />
+    </Button>
+  );
+
+  // 即将到期 (只在有服务器进入提醒窗口时显示)
+  const ExpirationAction = expiringCount > 0 && (
+    <Button
+      variant="outline"
+      size="sm"
+      onClick={onExpirationsClick}
+      title={t('server.expiration.title', 'Upcoming Expirations')}
+      className="h-9 gap-1.5 text-xs bg-amber-50 dark:bg-amber-900/20 border-amber-200 dark:border-amber-800 text-amber-600 dark:text-amber-400"
+    >
+      <CalendarClock className="w-3.5 h-3.5" />
+      {expiringCount}
+    </Button>
+  );
+
+  // 2. 导入按钮
+  const ImportAction = (
+    <Button
+      variant="outline"
+      size="sm"
+      onClick={onImportClick}
+      className="h-9 gap-1.5 text-xs bg-slate-100 dark:bg-slate-800 border-slate-200 dark:border-slate-700"
+    >
+      <FileInput className="w-3.5 h-3.5" />
+      {t('server.import.button', 'Import')}
+    </Button>
+  );
+
+  // 3. 导出按钮
+  const ExportAction = (
+    <Button
+      variant="outline"
+      size="sm"
+      onClick={onExportClick}
+      className="h-9 gap-1.5 text-xs bg-slate-100 dark:bg-slate-800 border-slate-200 dark:border-slate-700"
+    >
+      <FileOutput className="w-3.5 h-3.5" />
+      {t('server.export.button', 'Export')}
+    </Button>
+  );
+
   // 判断是否为网格视图
   const isGridView = state.viewMode === 'grid';
 
@@ -75,7 +198,7 @@ export const ServerListHeader = ({ state, allTags, actions, onAddClick }: Props)
         addLabel={t('server.list.add', 'Server')}
 
         // --- 额外动作 (排序) ---
-        extraActions={SortAction}
+        extraActions={<div className="flex items-center gap-2">{ExpirationAction}{FolderAction}{SortAction}<ScanAction serverIds={visibleServerIds} />{BulkEditAction}{StatsAction}{TemplateAction}{ImportAction}{ExportAction}</div>}
       />
     </div>
   );
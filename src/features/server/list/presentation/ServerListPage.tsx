@@ -11,6 +11,14 @@ import { DeleteServerModal } from "../components/DeleteServerModal";
 import { HostKeyVerificationModal } from "@/features/server/components/HostKeyVerificationModal";
 import { useServerConnect } from "@/features/server/form/application/useServerConnect";
 import { ConnectionStatusModal } from "@/features/server/components/ConnectionStatusModal";
+import { ServerImportModal } from "@/features/server/import";
+import { ServerExportModal } from "@/features/server/export";
+import { FolderManagerModal } from "@/features/server/folder";
+import { TemplateManagerModal } from "@/features/server/template";
+import { UpcomingExpirationsModal } from "@/features/server/expiration";
+import { BulkEditModal } from "@/features/server/bulk";
+import { ConnectionStatsModal } from "@/features/server/stats";
+import { useServerStore } from "@/features/server/application/useServerStore";
 import { Server } from "@/features/server/domain/types";
 import { ViewMode, CardSize } from "../domain/types";
 import { useKeyStore } from "@/store/useKeyStore";
@@ -52,6 +60,15 @@ export const ServerListPage = () => {
 
   const [isModalOpen, setIsModalOpen] = useState(false);
   const [editingServer, setEditingServer] = useState<Server | null>(null);
+  const [isImportOpen, setIsImportOpen] = useState(false);
+  const [isExportOpen, setIsExportOpen] = useState(false);
+  const [isFoldersOpen, setIsFoldersOpen] = useState(false);
+  const [isTemplatesOpen, setIsTemplatesOpen] = useState(false);
+  const [isExpirationsOpen, setIsExpirationsOpen] = useState(false);
+  const [isBulkEditOpen, setIsBulkEditOpen] = useState(false);
+  const [isStatsOpen, setIsStatsOpen] = useState(false);
+  const fetchServers = useServerStore((s) => s.fetchServers);
+  const allServers = useServerStore((s) => s.servers);
 
   const isUnlocked = useKeyStore((state) => state.status === 'unlocked');
   const openGlobalUnlockModal = useKeyStore((state) => state.openGlobalUnlockModal);
@@ -102,6 +119,14 @@ export const ServerListPage = () => {
         allTags={allTags} 
         actions={displayActions}
         onAddClick={handleAdd}
+        onImportClick={() => setIsImportOpen(true)}
+        onExportClick={() => setIsExportOpen(true)}
+        onManageFoldersClick={() => setIsFoldersOpen(true)}
+        onManageTemplatesClick={() => setIsTemplatesOpen(true)}
+        onExpirationsClick={() => setIsExpirationsOpen(true)}
+        onBulkEditClick={() => setIsBulkEditOpen(true)}
+        onStatsClick={() => setIsStatsOpen(true)}
+        visibleServerIds={servers.map((s) => s.id)}
       />
 
       <div className="flex-1 overflow-y-auto custom-scrollbar">
@@ -132,6 +157,42 @@ export const ServerListPage = () => {
         onClose={() => setIsModalOpen(false)}
       />
 
+      <ServerImportModal
+        isOpen={isImportOpen}
+        onClose={() => setIsImportOpen(false)}
+        onImported={() => fetchServers(true)}
+      />
+
+      <ServerExportModal
+        isOpen={isExportOpen}
+        onClose={() => setIsExportOpen(false)}
+        allTags={allTags}
+      />
+
+      <FolderManagerModal isOpen={isFoldersOpen} onClose={() => setIsFoldersOpen(false)} />
+
+      <TemplateManagerModal isOpen={isTemplatesOpen} onClose={() => setIsTemplatesOpen(false)} />
+
+      <BulkEditModal
+        isOpen={isBulkEditOpen}
+        onClose={() => setIsBulkEditOpen(false)}
+        serverIds={servers.map((s) => s.id)}
+        onApplied={() => fetchServers(true)}
+      />
+
+      <ConnectionStatsModal isOpen={isStatsOpen} onClose={() => setIsStatsOpen(false)} />
+
+      <UpcomingExpirationsModal
+        isOpen={isExpirationsOpen}
+        onClose={() => setIsExpirationsOpen(false)}
+        onEdit={(id) => {
+          const server = allServers.find((s) => s.id === id);
+          if (!server) return;
+          setIsExpirationsOpen(false);
+          handleEdit(server);
+        }}
+      />
+
       <DeleteServerModal 
         isOpen={deleteModalState.isOpen}
         onOpenChange={(open) => setDeleteModalState(prev => ({ ...prev, isOpen: open }))}
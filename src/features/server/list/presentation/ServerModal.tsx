@@ -43,6 +43,8 @@ export const ServerModal = ({ open, onOpenChange, initialData, onClose }: Server
       icon: initialData.icon,
       os: initialData.os,
       
+      protocol: initialData.protocol ?? "ssh",
+      loginScript: initialData.loginScript ?? [],
       connectionType: initialData.connectionType ?? "direct",
       proxyId: initialData.proxyId,
 
@@ -73,6 +75,14 @@ export const ServerModal = ({ open, onOpenChange, initialData, onClose }: Server
       keepAliveInterval: initialData.keepAliveInterval ?? 60,
       autoReconnect: initialData.autoReconnect ?? false,
       maxReconnects: initialData.maxReconnects ?? 3,
+      scrollbackSizeMb: initialData.scrollbackSize
+        ? Math.max(1, Math.round(initialData.scrollbackSize / (1024 * 1024)))
+        : undefined,
+      tmuxSession: initialData.tmuxSession ?? "",
+      envVars: initialData.envVars ?? [],
+      initialCwd: initialData.initialCwd ?? "",
+      startupCommands: initialData.startupCommands ?? [],
+      algorithmPrefs: initialData.algorithmPrefs ?? null,
     };
   }, [initialData]);
 
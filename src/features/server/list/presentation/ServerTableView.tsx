@@ -30,6 +30,7 @@ import { format, differenceInDays } from "date-fns";
 import { motion, Variants } from "framer-motion";
 
 import { GlassTooltip } from "@/components/common/GlassTooltip";
+import { HealthIndicator } from "@/features/server/health";
 import "@/styles/components/server/server-table.css";
 
 interface Props {
@@ -219,6 +220,7 @@ export const ServerTableView = ({ servers, actions, onEdit, onTagClick, isLoadin
                     </div>
                     <div className="flex flex-col min-w-0">
                       <div className="server-table__name">
+                          <HealthIndicator serverId={server.id} />
                           {server.name}
                           {server.isPinned && <Pin className="w-3 h-3 text-blue-500 rotate-45 fill-blue-500/20 shrink-0" />}
                       </div>
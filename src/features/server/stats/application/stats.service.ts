@@ -0,0 +1,10 @@
+import { invoke } from '@tauri-apps/api/core';
+import { ConnectionEvent, ServerConnectionStats } from '../domain/types';
+
+export const ConnectionStatsService = {
+  // 按成功连接次数排序；since 为毫秒时间戳
+  stats: (options: { serverId?: string; since?: number; limit?: number } = {}) =>
+    invoke<ServerConnectionStats[]>('get_connection_stats', options),
+  events: (serverId: string, limit?: number) =>
+    invoke<ConnectionEvent[]>('list_connection_events', { serverId, limit }),
+};
@@ -0,0 +1,40 @@
+// 连接统计与会话历史 (对应 src-tauri/src/commands/connection_stats.rs)
+
+export type ConnectionStatus = 'connecting' | 'connected' | 'failed';
+
+export type DisconnectReason = 'user' | 'closed' | 'error' | 'reconnect' | 'interrupted';
+
+export interface ConnectionEvent {
+  id: number;
+  serverId: string;
+  sessionId: string;
+  protocol?: string | null;
+  authMethod?: string | null;
+  status: ConnectionStatus;
+  error?: string | null;
+  // 同一标签页之前已连接的次数，0 表示首次连接
+  reconnectCount: number;
+  connectedAt: number;
+  // 仍在连接中时为空
+  disconnectedAt?: number | null;
+  durationMs?: number | null;
+  disconnectReason?: DisconnectReason | null;
+  bytesIn: number;
+  bytesOut: number;
+}
+
+export interface ServerConnectionStats {
+  serverId: string;
+  name: string;
+  ip: string;
+  attempts: number;
+  failures: number;
+  // 0 ~ 1
+  failureRate: number;
+  avgDurationMs?: number | null;
+  totalDurationMs: number;
+  bytesIn: number;
+  bytesOut: number;
+  reconnects: number;
+  lastConnectedAt?: number | null;
+}
@@ -0,0 +1,5 @@
+// src/features/server/stats/index.ts
+
+export { ConnectionStatsService } from './application/stats.service';
+export { ConnectionStatsModal } from './presentation/ConnectionStatsModal';
+export * from './domain/types';
@@ -0,0 +1,196 @@
+import { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { format } from "date-fns";
+import { ArrowLeft, BarChart3 } from "lucide-react";
+import { clsx } from "clsx";
+
+import { BaseModal } from "@/components/common/BaseModal";
+import { Button } from "@/components/ui/button";
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select";
+import { formatBytes, formatUptime } from "@/utils/format";
+import { getErrorMessage } from "@/utils/error";
+import { ConnectionStatsService } from "../application/stats.service";
+import { ConnectionEvent, ServerConnectionStats } from "../domain/types";
+
+interface Props {
+  isOpen: boolean;
+  onClose: () => void;
+}
+
+const DAY_MS = 24 * 60 * 60 * 1000;
+// 统计范围 (天)，0 表示全部
+const RANGES = [7, 30, 90, 0];
+
+const formatDuration = (ms?: number | null) => (ms == null ? "-" : formatUptime(Math.round(ms / 1000)));
+const formatPercent = (rate: number) => `${Math.round(rate * 100)}%`;
+
+export const ConnectionStatsModal = ({ isOpen, onClose }: Props) => {
+  const { t } = useTranslation();
+  const [rangeDays, setRangeDays] = useState(30);
+  const [stats, setStats] = useState<ServerConnectionStats[]>([]);
+  const [selected, setSelected] = useState<ServerConnectionStats | null>(null);
+  const [events, setEvents] = useState<ConnectionEvent[]>([]);
+
+  useEffect(() => {
+    if (!isOpen) return;
+    const since = rangeDays > 0 ? Date.now() - rangeDays * DAY_MS : undefined;
+    ConnectionStatsService.stats({ since })
+      .then(setStats)
+      .catch((err) => toast.error(getErrorMessage(err)));
+  }, [isOpen, rangeDays]);
+
+  useEffect(() => {
+    if (!isOpen) setSelected(null);
+  }, [isOpen]);
+
+  const openHistory = async (server: ServerConnectionStats) => {
+    try {
+      setEvents(await ConnectionStatsService.events(server.serverId, 100));
+      setSelected(server);
+    } catch (err) {
+      toast.error(getErrorMessage(err));
+    }
+  };
+
+  const describeEvent = (event: ConnectionEvent) => {
+    if (event.status === "failed") return event.error || t("server.stats.failed", "Failed");
+    if (!event.disconnectedAt) {
+      return event.disconnectReason === "interrupted"
+        ? t("server.stats.reasons.interrupted", "Interrupted")
+        : t("server.stats.active", "Connected");
+    }
+    return t(`server.stats.reasons.${event.disconnectReason ?? "closed"}`);
+  };
+
+  const rangeSelect = (
+    <Select value={String(rangeDays)} onValueChange={(v) => setRangeDays(Number(v))}>
+      <SelectTrigger className="h-8 w-[140px] text-xs">
+        <SelectValue />
+      </SelectTrigger>
+      <SelectContent>
+        {RANGES.map((days) => (
+          <SelectItem key={days} value={String(days)}>
+            {days === 0
+              ? t("server.stats.allTime", "All time")
+              : t("server.stats.lastDays", "Last {{count}} days", { count: days })}
+          </SelectItem>
+        ))}
+      </SelectContent>
+    </Select>
+  );
+
+  return (
+    <BaseModal
+      isOpen={isOpen}
+      onClose={onClose}
+      title={selected ? selected.name : t("server.stats.title", "Connection Statistics")}
+      icon={<BarChart3 className="w-5 h-5" />}
+      className="max-w-3xl"
+    >
+      {selected ? (
+        <div className="space-y-3">
+          <div className="flex items-center justify-between">
+            <Button variant="ghost" size="sm" className="gap-1.5 text-xs" onClick={() => setSelected(null)}>
+              <ArrowLeft className="w-3.5 h-3.5" />
+              {t("server.stats.back", "All servers")}
+            </Button>
+            <div className="text-xs text-slate-500">
+              {t("server.stats.sessionSummary", "{{count}} connection(s), average {{avg}}", {
+                count: selected.attempts,
+                avg: formatDuration(selected.avgDurationMs),
+              })}
+            </div>
+          </div>
+          {events.length === 0 ? (
+            <div className="text-sm text-slate-400 text-center py-8">{t("server.stats.noHistory", "No sessions recorded yet")}</div>
+          ) : (
+            <div className="max-h-[420px] overflow-y-auto custom-scrollbar">
+              <table className="w-full text-xs">
+                <thead className="text-slate-400 text-left">
+                  <tr>
+                    <th className="py-1.5 font-normal">{t("server.stats.connectedAt", "Connected")}</th>
+                    <th className="py-1.5 font-normal">{t("server.stats.duration", "Duration")}</th>
+                    <th className="py-1.5 font-normal">{t("server.stats.auth", "Auth")}</th>
+                    <th className="py-1.5 font-normal">{t("server.stats.traffic", "In / Out")}</th>
+                    <th className="py-1.5 font-normal">{t("server.stats.result", "Result")}</th>
+                  </tr>
+                </thead>
+                <tbody className="divide-y divide-slate-100 dark:divide-slate-800">
+                  {events.map((event) => (
+                    <tr key={event.id} className="text-slate-600 dark:text-slate-300">
+                      <td className="py-1.5 font-mono whitespace-nowrap">
+                        {format(new Date(event.connectedAt), "yyyy-MM-dd HH:mm")}
+                        {event.reconnectCount > 0 && (
+                          <span className="ml-1.5 text-amber-500">
+                            {t("server.stats.reconnect", "reconnect #{{count}}", { count: event.reconnectCount })}
+                          </span>
+                        )}
+                      </td>
+                      <td className="py-1.5">{formatDuration(event.durationMs)}</td>
+                      <td className="py-1.5">{event.authMethod ?? "-"}</td>
+                      <td className="py-1.5 whitespace-nowrap">
+                        {formatBytes(event.bytesIn)} / {formatBytes(event.bytesOut)}
+                      </td>
+                      <td
+                        className={clsx("py-1.5 max-w-[200px] truncate", event.status === "failed" && "text-red-500")}
+                        title={event.error ?? undefined}
+                      >
+                        {describeEvent(event)}
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+          )}
+        </div>
+      ) : (
+        <div className="space-y-3">
+          <div className="flex justify-end">{rangeSelect}</div>
+          {stats.length === 0 ? (
+            <div className="text-sm text-slate-400 text-center py-8">{t("server.stats.empty", "No connections in this period")}</div>
+          ) : (
+            <div className="max-h-[420px] overflow-y-auto custom-scrollbar">
+              <table className="w-full text-xs">
+                <thead className="text-slate-400 text-left">
+                  <tr>
+                    <th className="py-1.5 font-normal">{t("server.stats.server", "Server")}</th>
+                    <th className="py-1.5 font-normal text-right">{t("server.stats.connections", "Connections")}</th>
+                    <th className="py-1.5 font-normal text-right">{t("server.stats.avgSession", "Avg. session")}</th>
+                    <th className="py-1.5 font-normal text-right">{t("server.stats.failureRate", "Failure rate")}</th>
+                    <th className="py-1.5 font-normal text-right">{t("server.stats.reconnects", "Reconnects")}</th>
+                    <th className="py-1.5 font-normal text-right">{t("server.stats.traffic", "In / Out")}</th>
+                  </tr>
+                </thead>
+                <tbody className="divide-y divide-slate-100 dark:divide-slate-800">
+                  {stats.map((s) => (
+                    <tr
+                      key={s.serverId}
+                      onClick={() => openHistory(s)}
+                      className="cursor-pointer text-slate-600 dark:text-slate-300 hover:bg-slate-50 dark:hover:bg-slate-800/50"
+                    >
+                      <td className="py-1.5 pr-2">
+                        <div className="font-medium text-slate-700 dark:text-slate-200 truncate">{s.name}</div>
+                        <div className="text-slate-400 font-mono truncate">{s.ip}</div>
+                      </td>
+                      <td className="py-1.5 text-right">{s.attempts}</td>
+                      <td className="py-1.5 text-right">{formatDuration(s.avgDurationMs)}</td>
+                      <td className={clsx("py-1.5 text-right", s.failureRate >= 0.2 && "text-red-500")}>
+                        {formatPercent(s.failureRate)}
+                      </td>
+                      <td className="py-1.5 text-right">{s.reconnects}</td>
+                      <td className="py-1.5 text-right whitespace-nowrap">
+                        {formatBytes(s.bytesIn)} / {formatBytes(s.bytesOut)}
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+          )}
+        </div>
+      )}
+    </BaseModal>
+  );
+};
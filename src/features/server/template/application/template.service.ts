@@ -0,0 +1,8 @@
+import { invoke } from '@tauri-apps/api/core';
+import { ServerTemplate } from '../domain/types';
+
+export const TemplateService = {
+  list: () => invoke<ServerTemplate[]>('list_templates'),
+  save: (template: ServerTemplate) => invoke<ServerTemplate>('save_template', { template }),
+  remove: (id: string) => invoke<void>('delete_template', { id }),
+};
@@ -0,0 +1,35 @@
+import { create } from 'zustand';
+import { useServerStore } from '@/features/server/application/useServerStore';
+import { ServerTemplate } from '../domain/types';
+import { TemplateService } from './template.service';
+
+interface TemplateState {
+  templates: ServerTemplate[];
+  isLoaded: boolean;
+
+  fetchTemplates: () => Promise<void>;
+  saveTemplate: (template: ServerTemplate) => Promise<ServerTemplate>;
+  deleteTemplate: (id: string) => Promise<void>;
+}
+
+export const useTemplateStore = create<TemplateState>((set, get) => ({
+  templates: [],
+  isLoaded: false,
+
+  fetchTemplates: async () => {
+    const templates = await TemplateService.list();
+    set({ templates, isLoaded: true });
+  },
+
+  saveTemplate: async (template) => {
+    const saved = await TemplateService.save(template);
+    await get().fetchTemplates();
+    return saved;
+  },
+
+  // 删除时后端会把模板值写回引用它的服务器，需要刷新服务器列表
+  deleteTemplate: async (id) => {
+    await TemplateService.remove(id);
+    await Promise.all([get().fetchTemplates(), useServerStore.getState().fetchServers(true)]);
+  },
+}));
@@ -0,0 +1,76 @@
+// 服务器模板 (对应 src-tauri/src/models/template.rs)
+
+// 可被服务器单独覆盖的模板字段
+export type TemplateField =
+  | 'auth'
+  | 'port'
+  | 'proxy'
+  | 'connectTimeout'
+  | 'keepAliveInterval'
+  | 'startupCommands';
+
+export const TEMPLATE_FIELDS: TemplateField[] = [
+  'auth',
+  'port',
+  'proxy',
+  'connectTimeout',
+  'keepAliveInterval',
+  'startupCommands',
+];
+
+export type TemplateAuthType = 'password' | 'privateKey' | 'agent';
+
+export type TemplateStartupCommand =
+  | { type: 'command'; command: string }
+  | { type: 'snippet'; snippetId: string };
+
+// 为 null 的字段表示模板不设置该项
+export interface ServerTemplate {
+  id: string;
+  name: string;
+  description?: string | null;
+  authType?: TemplateAuthType | null;
+  passwordId?: string | null;
+  keyId?: string | null;
+  port?: number | null;
+  proxyId?: string | null;
+  connectTimeout?: number | null;
+  keepAliveInterval?: number | null;
+  startupCommands?: TemplateStartupCommand[] | null;
+  createdAt: number;
+  updatedAt: number;
+}
+
+export const createEmptyTemplate = (name: string): ServerTemplate => ({
+  id: '',
+  name,
+  description: null,
+  authType: null,
+  passwordId: null,
+  keyId: null,
+  port: null,
+  proxyId: null,
+  connectTimeout: null,
+  keepAliveInterval: null,
+  startupCommands: null,
+  createdAt: 0,
+  updatedAt: 0,
+});
+
+// 模板是否设置了某个字段 (未设置的字段无所谓覆盖)
+export const templateDefines = (template: ServerTemplate, field: TemplateField): boolean => {
+  switch (field) {
+    case 'auth':
+      return !!template.authType;
+    case 'port':
+      return template.port != null;
+    case 'proxy':
+      return !!template.proxyId;
+    case 'connectTimeout':
+      return template.connectTimeout != null;
+    case 'keepAliveInterval':
+      return template.keepAliveInterval != null;
+    case 'startupCommands':
+      return !!template.startupCommands?.length;
+  }
+};
@@ -0,0 +1,7 @@
+// src/features/server/template/index.ts
+
+export { useTemplateStore } from './application/useTemplateStore';
+export { TemplateService } from './application/template.service';
+export { TemplateBinding } from './presentation/TemplateBinding';
+export { TemplateManagerModal } from './presentation/TemplateManagerModal';
+export * from './domain/types';
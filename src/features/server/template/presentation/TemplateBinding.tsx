@@ -0,0 +1,85 @@
+import { useEffect } from "react";
+import { useTranslation } from "react-i18next";
+import { useFormContext } from "react-hook-form";
+import { LayoutTemplate } from "lucide-react";
+
+import { Label } from "@/components/ui/label";
+import { Switch } from "@/components/ui/switch";
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select";
+import { ServerFormValues } from "@/features/server/form/domain/schema";
+import { useTemplateStore } from "../application/useTemplateStore";
+import { TEMPLATE_FIELDS, TemplateField, templateDefines } from "../domain/types";
+
+// Radix Select 不接受空字符串作为值
+const NONE_VALUE = "__none__";
+
+const FIELD_LABELS: Record<TemplateField, [string, string]> = {
+  auth: ["server.template.fields.auth", "Credential"],
+  port: ["server.template.fields.port", "Port"],
+  proxy: ["server.template.fields.proxy", "Proxy"],
+  connectTimeout: ["server.template.fields.connectTimeout", "Connection timeout"],
+  keepAliveInterval: ["server.template.fields.keepAliveInterval", "Keep-alive"],
+  startupCommands: ["server.template.fields.startupCommands", "Startup commands"],
+};
+
+/**
+ * 服务器表单中的模板绑定：选择模板，并逐项决定使用模板值还是服务器自己的值
+ */
+export const TemplateBinding = () => {
+  const { t } = useTranslation();
+  const { watch, setValue } = useFormContext<ServerFormValues>();
+  const { templates, isLoaded, fetchTemplates } = useTemplateStore();
+
+  useEffect(() => {
+    if (!isLoaded) fetchTemplates().catch(console.error);
+  }, [isLoaded]);
+
+  const templateId = watch("templateId");
+  const overrides = watch("templateOverrides") ?? [];
+  const template = templates.find((tpl) => tpl.id === templateId);
+
+  const toggleOverride = (field: TemplateField, useTemplate: boolean) => {
+    const next = useTemplate ? overrides.filter((f) => f !== field) : [...overrides, field];
+    setValue("templateOverrides", next, { shouldDirty: true });
+  };
+
+  if (isLoaded && templates.length === 0 && !templateId) return null;
+
+  return (
+    <div className="space-y-3 p-3 rounded-lg border border-slate-200 dark:border-slate-800 bg-slate-50/50 dark:bg-slate-900/50">
+      <Label className="text-xs font-semibold text-slate-500 uppercase tracking-wider flex items-center gap-2">
+        <LayoutTemplate className="w-3.5 h-3.5" />
+        {t("server.template.label", "Template")}
+      </Label>
+      <Select
+        value={templateId || NONE_VALUE}
+        onValueChange={(v) => setValue("templateId", v === NONE_VALUE ? null : v, { shouldDirty: true })}
+      >
+        <SelectTrigger><SelectValue /></SelectTrigger>
+        <SelectContent>
+          <SelectItem value={NONE_VALUE}>{t("server.template.none", "No template")}</SelectItem>
+          {templates.map((tpl) => (
+            <SelectItem key={tpl.id} value={tpl.id}>{tpl.name}</SelectItem>
+          ))}
+        </SelectContent>
+      </Select>
+
+      {template && (
+        <div className="space-y-2">
+          <div className="text-[11px] text-slate-500">
+            {t("server.template.bindingHint", "Turn a field off to use this server's own value instead.")}
+          </div>
+          {TEMPLATE_FIELDS.filter((field) => templateDefines(template, field)).map((field) => (
+            <label key={field} className="flex items-center justify-between gap-3 text-xs text-slate-600 dark:text-slate-300">
+              {t(...FIELD_LABELS[field])}
+              <Switch
+                checked={!overrides.includes(field)}
+                onCheckedChange={(checked) => toggleOverride(field, checked)}
+              />
+            </label>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+};
@@ -0,0 +1,289 @@
+import { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { LayoutTemplate, Plus, Save, Trash2 } from "lucide-react";
+import { clsx } from "clsx";
+
+import { BaseModal } from "@/components/common/BaseModal";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import { Textarea } from "@/components/ui/textarea";
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select";
+import { useSettingsStore } from "@/features/settings/application/useSettingsStore";
+import { useKeyStore } from "@/store/useKeyStore";
+import { getErrorMessage } from "@/utils/error";
+import { useTemplateStore } from "../application/useTemplateStore";
+import { ServerTemplate, TemplateStartupCommand, createEmptyTemplate } from "../domain/types";
+
+interface Props {
+  isOpen: boolean;
+  onClose: () => void;
+}
+
+// Radix Select 的 "不设置" 与 "SSH Agent" 选项
+const NONE_VALUE = "__none__";
+const AGENT_VALUE = "__agent__";
+
+const toSelectValue = (v?: string | null) => v || NONE_VALUE;
+const fromSelectValue = (v: string) => (v === NONE_VALUE ? null : v);
+
+// 数字输入：留空表示模板不设置
+const parseOptionalNumber = (v: string) => (v.trim() === "" ? null : Number(v));
+
+// 启动命令按行编辑，片段引用保留在末尾
+const commandsToText = (commands?: TemplateStartupCommand[] | null) =>
+  (commands ?? [])
+    .filter((c): c is Extract<TemplateStartupCommand, { type: "command" }> => c.type === "command")
+    .map((c) => c.command)
+    .join("\n");
+
+const textToCommands = (text: string, previous?: TemplateStartupCommand[] | null): TemplateStartupCommand[] | null => {
+  const commands: TemplateStartupCommand[] = text
+    .split("\n")
+    .map((line) => line.trim())
+    .filter(Boolean)
+    .map((command) => ({ type: "command", command }));
+  const snippets = (previous ?? []).filter((c) => c.type === "snippet");
+  const merged = [...commands, ...snippets];
+  return merged.length ? merged : null;
+};
+
+export const TemplateManagerModal = ({ isOpen, onClose }: Props) => {
+  const { t } = useTranslation();
+  const { templates, fetchTemplates, saveTemplate, deleteTemplate } = useTemplateStore();
+  const { proxies, loadProxies } = useSettingsStore();
+  const { keys, status: vaultStatus } = useKeyStore();
+
+  const [draft, setDraft] = useState<ServerTemplate | null>(null);
+  const [commandsText, setCommandsText] = useState("");
+  const [isSaving, setIsSaving] = useState(false);
+
+  useEffect(() => {
+    if (!isOpen) return;
+    fetchTemplates().catch(console.error);
+    if (proxies.length === 0) loadProxies();
+  }, [isOpen]);
+
+  const select = (template: ServerTemplate) => {
+    setDraft({ ...template });
+    setCommandsText(commandsToText(template.startupCommands));
+  };
+
+  const patch = (values: Partial<ServerTemplate>) => setDraft((prev) => (prev ? { ...prev, ...values } : prev));
+
+  const handleSave = async () => {
+    if (!draft) return;
+    setIsSaving(true);
+    try {
+      const saved = await saveTemplate({
+        ...draft,
+        startupCommands: textToCommands(commandsText, draft.startupCommands),
+      });
+      select(saved);
+      toast.success(t("server.template.saved", "Template saved"));
+    } catch (err) {
+      toast.error(getErrorMessage(err));
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  const handleDelete = async () => {
+    if (!draft) return;
+    if (!draft.id) {
+      setDraft(null);
+      return;
+    }
+    try {
+      await deleteTemplate(draft.id);
+      setDraft(null);
+    } catch (err) {
+      toast.error(getErrorMessage(err));
+    }
+  };
+
+  // 凭据以 Vault 条目为单位选择，认证方式由条目类型决定
+  const credentialValue =
+    draft?.authType === "agent"
+      ? AGENT_VALUE
+      : toSelectValue(draft?.authType === "privateKey" ? draft.keyId : draft?.passwordId);
+  const handleCredentialChange = (v: string) => {
+    const key = keys.find((k) => k.id === v);
+    if (v === AGENT_VALUE) {
+      patch({ authType: "agent", passwordId: null, keyId: null });
+    } else if (!key) {
+      patch({ authType: null, passwordId: null, keyId: null });
+    } else if (key.type === "private_key") {
+      patch({ authType: "privateKey", keyId: key.id, passwordId: null });
+    } else {
+      patch({ authType: "password", passwordId: key.id, keyId: null });
+    }
+  };
+
+  return (
+    <BaseModal
+      isOpen={isOpen}
+      onClose={onClose}
+      title={t("server.template.manage", "Server Templates")}
+      icon={<LayoutTemplate className="w-5 h-5" />}
+      className="max-w-3xl"
+    >
+      <div className="flex gap-4 min-h-[380px]">
+        {/* 左侧：模板列表 */}
+        <div className="w-56 shrink-0 flex flex-col gap-2 border-r border-slate-200 dark:border-slate-800 pr-3">
+          <Button
+            variant="outline"
+            size="sm"
+            className="gap-1 text-xs"
+            onClick={() => select(createEmptyTemplate(t("server.template.newName", "New Template")))}
+          >
+            <Plus className="w-3.5 h-3.5" />
+            {t("server.template.create", "New template")}
+          </Button>
+          <div className="flex-1 overflow-y-auto custom-scrollbar space-y-0.5">
+            {templates.length === 0 && (
+              <div className="text-xs text-slate-400 text-center py-6">
+                {t("server.template.empty", "No templates yet")}
+              </div>
+            )}
+            {templates.map((template) => (
+              <button
+                key={template.id}
+                onClick={() => select(template)}
+                className={clsx(
+                  "w-full flex items-center gap-1.5 px-2 py-1.5 rounded-md text-sm text-left truncate",
+                  template.id === draft?.id
+                    ? "bg-blue-50 text-blue-600 dark:bg-blue-900/20 dark:text-blue-400"
+                    : "text-slate-600 dark:text-slate-300 hover:bg-slate-100 dark:hover:bg-slate-800"
+                )}
+              >
+                <LayoutTemplate className="w-3.5 h-3.5 shrink-0 opacity-70" />
+                <span className="truncate">{template.name}</span>
+              </button>
+            ))}
+          </div>
+        </div>
+
+        {/* 右侧：模板字段，留空表示不设置 */}
+        <div className="flex-1 min-w-0">
+          {!draft ? (
+            <div className="h-full flex items-center justify-center text-sm text-slate-400">
+              {t("server.template.selectHint", "Select a template to edit")}
+            </div>
+          ) : (
+            <div className="space-y-4">
+              <div className="grid grid-cols-2 gap-3">
+                <div className="space-y-1.5">
+                  <Label className="text-xs text-slate-500">{t("server.template.name", "Name")}</Label>
+                  <Input value={draft.name} onChange={(e) => patch({ name: e.target.value })} />
+                </div>
+                <div className="space-y-1.5">
+                  <Label className="text-xs text-slate-500">{t("server.template.description", "Description")}</Label>
+                  <Input value={draft.description ?? ""} onChange={(e) => patch({ description: e.target.value || null })} />
+                </div>
+              </div>
+
+              <div className="h-px bg-border/50" />
+              <div className="text-xs text-slate-500">
+                {t(
+                  "server.template.hint",
+                  "Servers using this template pick up these values when they connect, unless they override them. Empty fields are left to each server."
+                )}
+              </div>
+
+              <div className="grid grid-cols-2 gap-3">
+                <div className="space-y-1.5">
+                  <Label className="text-xs text-slate-500">{t("server.template.credential", "Credential")}</Label>
+                  <Select value={credentialValue} onValueChange={handleCredentialChange} disabled={vaultStatus !== "unlocked"}>
+                    <SelectTrigger>
+                      <SelectValue placeholder={t("server.template.vaultLocked", "Unlock the vault to choose")} />
+                    </SelectTrigger>
+                    <SelectContent>
+                      <SelectItem value={NONE_VALUE}>{t("server.template.notSet", "Not set")}</SelectItem>
+                      <SelectItem value={AGENT_VALUE}>SSH Agent</SelectItem>
+                      {keys.map((k) => (
+                        <SelectItem key={k.id} value={k.id}>
+                          {k.name} · {k.type === "private_key" ? t("keys.type.key", "Key") : t("keys.type.password", "Password")}
+                        </SelectItem>
+                      ))}
+                    </SelectContent>
+                  </Select>
+                </div>
+
+                <div className="space-y-1.5">
+                  <Label className="text-xs text-slate-500">{t("server.template.proxy", "Proxy")}</Label>
+                  <Select value={toSelectValue(draft.proxyId)} onValueChange={(v) => patch({ proxyId: fromSelectValue(v) })}>
+                    <SelectTrigger><SelectValue /></SelectTrigger>
+                    <SelectContent>
+                      <SelectItem value={NONE_VALUE}>{t("server.template.notSet", "Not set")}</SelectItem>
+                      {proxies.map((p) => (
+                        <SelectItem key={p.id} value={p.id}>{p.name}</SelectItem>
+                      ))}
+                    </SelectContent>
+                  </Select>
+                </div>
+
+                <div className="space-y-1.5">
+                  <Label className="text-xs text-slate-500">{t("server.template.port", "Port")}</Label>
+                  <Input
+                    type="number"
+                    min={1}
+                    max={65535}
+                    value={draft.port ?? ""}
+                    onChange={(e) => patch({ port: parseOptionalNumber(e.target.value) })}
+                    placeholder={t("server.template.notSet", "Not set")}
+                  />
+                </div>
+
+                <div className="grid grid-cols-2 gap-2">
+                  <div className="space-y-1.5">
+                    <Label className="text-xs text-slate-500">{t("server.template.timeout", "Timeout (s)")}</Label>
+                    <Input
+                      type="number"
+                      min={1}
+                      value={draft.connectTimeout ?? ""}
+                      onChange={(e) => patch({ connectTimeout: parseOptionalNumber(e.target.value) })}
+                    />
+                  </div>
+                  <div className="space-y-1.5">
+                    <Label className="text-xs text-slate-500">{t("server.template.keepAlive", "Keep-alive (s)")}</Label>
+                    <Input
+                      type="number"
+                      min={0}
+                      value={draft.keepAliveInterval ?? ""}
+                      onChange={(e) => patch({ keepAliveInterval: parseOptionalNumber(e.target.value) })}
+                    />
+                  </div>
+                </div>
+              </div>
+
+              <div className="space-y-1.5">
+                <Label className="text-xs text-slate-500">
+                  {t("server.template.startupCommands", "Startup commands (one per line)")}
+                </Label>
+                <Textarea
+                  rows={3}
+                  className="font-mono text-xs"
+                  value={commandsText}
+                  onChange={(e) => setCommandsText(e.target.value)}
+                />
+              </div>
+
+              <div className="flex justify-between pt-2">
+                <Button variant="ghost" className="gap-1.5 text-red-500 hover:text-red-600" onClick={handleDelete}>
+                  <Trash2 className="w-4 h-4" />
+                  {t("server.template.delete", "Delete template")}
+                </Button>
+                <Button className="gap-1.5" onClick={handleSave} disabled={isSaving || !draft.name.trim()}>
+                  <Save className="w-4 h-4" />
+                  {t("common.save", "Save")}
+                </Button>
+              </div>
+            </div>
+          )}
+        </div>
+      </div>
+    </BaseModal>
+  );
+};
@@ -0,0 +1,9 @@
+// src/features/settings/application/services/appSettings.service.ts
+import { invoke } from '@tauri-apps/api/core';
+import { AppSettings, AppSettingsPatch } from '../../domain/appSettings';
+
+export const AppSettingsService = {
+  get: () => invoke<AppSettings>('get_settings'),
+  update: (patch: AppSettingsPatch) => invoke<AppSettings>('update_settings', { patch }),
+  reset: () => invoke<AppSettings>('reset_settings'),
+};
@@ -4,6 +4,7 @@ import { useTranslation } from "react-i18next";
 import { invoke } from '@tauri-apps/api/core';
 import { listen } from '@tauri-apps/api/event';
 import { toast } from "sonner";
+import { getErrorMessage } from "@/utils/error";
 import { ask, save, open } from '@tauri-apps/plugin-dialog';
 import { useSettingsStore } from "./useSettingsStore";
 import { 
@@ -91,7 +92,7 @@ export const useBackupLogic = () => {
       updateState({ backupList: sortedList });
     } catch (e) {
       console.error(e);
-      toast.error(t('settings.backup.loadHistoryFailed', `Failed to load history: ${e}`));
+      toast.error(t('settings.backup.loadHistoryFailed', `Failed to load history: ${getErrorMessage(e)}`));
     } finally {
       updateState({ isLoadingList: false });
     }
@@ -107,7 +108,7 @@ export const useBackupLogic = () => {
       toast.success(t('common.deletedSuccess', "Deleted successfully"));
       await fetchBackupList(); 
     } catch (e) {
-      toast.error(t('common.deleteFailed', `Delete failed: ${e}`));
+      toast.error(t('common.deleteFailed', `Delete failed: ${getErrorMessage(e)}`));
     } finally {
       updateState({ isDeleting: false });
     }
@@ -132,7 +133,7 @@ export const useBackupLogic = () => {
       toast.success(t('settings.backup.connected', 'Connection successful'));
     } catch (e) {
       console.error(e);
-      toast.error(t('settings.backup.connectionFailed', `Connection failed: ${e}`));
+      toast.error(t('settings.backup.connectionFailed', `Connection failed: ${getErrorMessage(e)}`));
     } finally {
       updateState({ isTesting: false });
     }
@@ -162,7 +163,7 @@ export const useBackupLogic = () => {
       toast.success(t('settings.backup.backupSuccess', "Backup uploaded successfully"));
       fetchBackupList();
     } catch (e) {
-      toast.error(t('settings.backup.backupFailed', `Backup failed: ${e}`));
+      toast.error(t('settings.backup.backupFailed', `Backup failed: ${getErrorMessage(e)}`));
     } finally {
       setTimeout(() => {
         updateState({ isBackingUp: false, isProgressVisible: false });
@@ -225,7 +226,7 @@ export const useBackupLogic = () => {
 
       } catch (e) {
         console.error("===> [DEBUG] 'prepare_cloud_restore' Error:", e);
-        toast.error(t('settings.backup.downloadFailed', `Download failed: ${e}`));
+        toast.error(t('settings.backup.downloadFailed', `Download failed: ${getErrorMessage(e)}`));
         updateState({ isProgressVisible: false });
       }
   };
@@ -271,7 +272,7 @@ export const useBackupLogic = () => {
           toast.success(t('settings.backup.restoreSuccess', "Restore successful. Please restart app."));
       } catch(e) {
           console.error("===> [DEBUG] 'apply_restore_file' Error:", e);
-          toast.error(t('settings.backup.restoreFailed', `Restore failed: ${e}`));
+          toast.error(t('settings.backup.restoreFailed', `Restore failed: ${getErrorMessage(e)}`));
       } finally {
           setTimeout(() => {
              updateState({ 
@@ -299,7 +300,7 @@ export const useBackupLogic = () => {
       await invoke('export_local_backup', { targetPath: filePath });
       toast.success(t('settings.backup.exportSuccess', 'Backup exported successfully'));
     } catch (e) {
-      toast.error(t('settings.backup.exportFailed', `Export failed: ${e}`));
+      toast.error(t('settings.backup.exportFailed', `Export failed: ${getErrorMessage(e)}`));
     } finally {
       setTimeout(() => updateState({ isExporting: false, isProgressVisible: false }), 800);
     }
@@ -331,7 +332,7 @@ export const useBackupLogic = () => {
       await invoke('import_local_backup', { filePath });
       toast.success(t('settings.backup.restoreSuccess', 'Restored successfully. Please restart.'));
     } catch (e) {
-      toast.error(t('settings.backup.restoreFailed', `Restore failed: ${e}`));
+      toast.error(t('settings.backup.restoreFailed', `Restore failed: ${getErrorMessage(e)}`));
     } finally {
       setTimeout(() => updateState({ isImporting: false, isProgressVisible: false }), 800);
     }
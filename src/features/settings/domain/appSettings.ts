@@ -0,0 +1,88 @@
+// 后端持久化的应用设置 (对应 src-tauri/src/models/settings.rs)
+export type CloseBehavior = 'quit' | 'minimize';
+
+export interface WindowSettings {
+  minimizeToTray: boolean;
+  closeBehavior: CloseBehavior;
+}
+
+export interface TerminalDefaults {
+  fontFamily: string;
+  fontSize: number;
+  lineHeight: number;
+  scrollback: number;
+  cursorStyle: string;
+  cursorBlink: boolean;
+}
+
+export interface HistoryFilterConfig {
+  ignoreShort: boolean;
+  minLength: number;
+  sensitiveKeywords: string[];
+}
+
+export interface MonitorSettings {
+  metricsIntervalMs: number;
+  processIntervalMs: number;
+  dashboardIntervalMs: number;
+}
+
+export interface VaultSettings {
+  autoLockMinutes: number;
+}
+
+export interface ExpirationSettings {
+  // 提前多少天提醒，0 表示关闭
+  warnDays: number;
+}
+
+export interface TrashSettings {
+  // 删除的条目保留多少天，0 表示不自动清除
+  retentionDays: number;
+}
+
+export interface AppSettings {
+  window: WindowSettings;
+  terminal: TerminalDefaults;
+  history: HistoryFilterConfig;
+  monitor: MonitorSettings;
+  vault: VaultSettings;
+  expiration: ExpirationSettings;
+  trash: TrashSettings;
+}
+
+export type AppSettingsPatch = Partial<AppSettings>;
+
+export const DEFAULT_MONITOR_SETTINGS: MonitorSettings = {
+  metricsIntervalMs: 3000,
+  processIntervalMs: 5000,
+  dashboardIntervalMs: 10000,
+};
+
+/**
+ * 设置页 (扁平的 settings 键值) 中由后端使用的部分
+ * 历史过滤与监控间隔只存在于后端，不在这里映射
+ */
+export const toAppSettingsPatch = (settings: Record<string, any>): AppSettingsPatch => ({
+  window: {
+    minimizeToTray: settings['general.minimizeToTray'] ?? true,
+    closeBehavior: settings['general.closeBehavior'] === 'minimize' ? 'minimize' : 'quit',
+  },
+  terminal: {
+    fontFamily: settings['terminal.fontFamily'],
+    fontSize: Number(settings['terminal.fontSize']),
+    lineHeight: Number(settings['terminal.lineHeight']),
+    scrollback: Number(settings['terminal.scrollback']),
+    cursorStyle: settings['terminal.cursorStyle'],
+    cursorBlink: Boolean(settings['terminal.cursorBlink']),
+  },
+  vault: {
+    autoLockMinutes: Number(settings['security.idleTimeout'] ?? 0),
+  },
+  expiration: {
+    warnDays: Number(settings['general.expirationWarnDays'] ?? 7),
+  },
+  trash: {
+    retentionDays: Number(settings['trash.retentionDays'] ?? 30),
+  },
+});
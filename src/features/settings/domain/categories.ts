@@ -1,5 +1,5 @@
 import { 
-  Settings, Palette, TerminalSquare, Network, ShieldCheck, Info, CloudCog, Highlighter 
+  Settings, Palette, TerminalSquare, Network, ShieldCheck, Info, CloudCog, Highlighter, Trash2 
 } from "lucide-react";
 import { CategoryMeta } from "./types";
 
@@ -11,5 +11,6 @@ export const CATEGORIES: CategoryMeta[] = [
   { id: 'connection', labelKey: 'settings.nav.connection', icon: Network },
   { id: 'security', labelKey: 'settings.nav.security', icon: ShieldCheck },
   { id: 'backup', labelKey: 'settings.nav.backup', icon: CloudCog },
+  { id: 'trash', labelKey: 'settings.nav.trash', icon: Trash2 },
   { id: 'about', labelKey: 'settings.nav.about', icon: Info, isBottom: true },
 ];
\ No newline at end of file
@@ -7,6 +7,7 @@ import { connectionItems } from "./items/connection";
 import { aboutItems } from "./items/about";
 import { securityItems } from "./items/security";
 import { highlightItems } from './items/highlights';
+import { trashItems } from './items/trash';
 // 重新导出 CATEGORIES 供 Sidebar 使用
 export { CATEGORIES };
 
@@ -28,5 +29,6 @@ export const SETTING_ITEMS: SettingItem[] = [
   ...connectionItems,
   ...securityItems,
   ...backupItems,
+  ...trashItems,
   ...aboutItems,
 ];
\ No newline at end of file
@@ -45,6 +45,21 @@ export const generalItems: SettingItem[] = [
         { labelKey: 'settings.general.behavior.minimize', value: 'minimize' }
     ]
   },
+  {
+    id: 'general.expirationWarnDays',
+    categoryId: 'general',
+    type: 'select',
+    labelKey: 'settings.general.expirationWarnDays',
+    descKey: 'settings.general.expirationWarnDaysDesc',
+    defaultValue: 7,
+    options: [
+      { labelKey: 'settings.general.expirationOff', value: 0 },
+      { label: '3 Days', value: 3 },
+      { label: '7 Days', value: 7 },
+      { label: '14 Days', value: 14 },
+      { label: '30 Days', value: 30 },
+    ]
+  },
   {
     id: 'general.deviceName',
     categoryId: 'general',
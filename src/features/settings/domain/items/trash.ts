@@ -0,0 +1,24 @@
+import { SettingItem } from "../types";
+
+export const trashItems: SettingItem[] = [
+  {
+    id: 'trash.retentionDays',
+    categoryId: 'trash',
+    type: 'select',
+    labelKey: 'settings.trash.retentionDays',
+    descKey: 'settings.trash.retentionDaysDesc',
+    defaultValue: 30,
+    options: [
+      { labelKey: 'settings.trash.keepForever', value: 0 },
+      { label: '7 Days', value: 7 },
+      { label: '30 Days', value: 30 },
+      { label: '90 Days', value: 90 },
+    ]
+  },
+  {
+    id: 'trash.manager',
+    categoryId: 'trash',
+    type: 'trash-manager',
+    labelKey: 'settings.nav.trash',
+  },
+];
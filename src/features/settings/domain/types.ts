@@ -8,6 +8,7 @@ export type SettingCategory =
   | 'connection' 
   | 'security'
   | 'backup' 
+  | 'trash'
   | 'about';
 
 export type SettingItemType = 
@@ -21,6 +22,7 @@ export type SettingItemType =
   | 'image'
   | 'slider'
   | 'backup-manager'
+  | 'trash-manager'
   | 'shortcut';
 
 // [新增] 代理类型定义
@@ -2,6 +2,11 @@ import { useEffect, useRef, useCallback } from 'react';
 import { useSettingsStore } from '@/features/settings/application/useSettingsStore';
 import { useKeyStore } from '@/store/useKeyStore';
 import { toast } from 'sonner';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+
+// 用户操作的上报间隔 (后端按最近一次上报计算空闲时间)
+const ACTIVITY_REPORT_INTERVAL_MS = 30 * 1000;
 
 export const useSecurityEffects = () => {
   const settings = useSettingsStore((s) => s.settings);
@@ -14,8 +19,7 @@ export const useSecurityEffects = () => {
   // 快捷键配置，默认 Ctrl+Shift+L
   const lockShortcut = (settings['security.lockShortcut'] as string) || 'Ctrl+Shift+L';
 
-  const idleTimerRef = useRef<NodeJS.Timeout | null>(null);
-  const lastActivityRef = useRef<number>(Date.now());
+  const lastActivityRef = useRef<number>(0);
 
   // --- 核心锁定动作 ---
   const doLock = useCallback(() => {
@@ -28,36 +32,19 @@ export const useSecurityEffects = () => {
   }, [status, lockVault]);
 
   // --- A. 自动待机锁定逻辑 ---
+  // 计时与锁定由后端执行 (窗口隐藏时同样生效)，这里只上报用户操作并同步锁定状态
   useEffect(() => {
-    // 0 = 禁用功能
-    if (idleTimeoutMinutes <= 0) {
-      if (idleTimerRef.current) clearTimeout(idleTimerRef.current);
-      return;
-    }
-
-    const startTimer = () => {
-      if (idleTimerRef.current) clearTimeout(idleTimerRef.current);
-      
-      if (status === 'unlocked') {
-        idleTimerRef.current = setTimeout(() => {
-          console.log(`🔒 Idle timeout (${idleTimeoutMinutes}m) reached.`);
-          doLock();
-        }, idleTimeoutMinutes * 60 * 1000);
-      }
-    };
+    if (idleTimeoutMinutes <= 0 || status !== 'unlocked') return;
 
-    // 交互事件处理 (带1秒节流，避免鼠标移动频繁重置性能问题)
+    // 交互事件处理 (节流，避免鼠标移动频繁调用后端)
     const handleActivity = () => {
       const now = Date.now();
-      if (now - lastActivityRef.current > 1000) {
+      if (now - lastActivityRef.current > ACTIVITY_REPORT_INTERVAL_MS) {
         lastActivityRef.current = now;
-        startTimer();
+        invoke('touch_vault_activity').catch(console.error);
       }
     };
 
-    // 初始化计时
-    startTimer();
-
     // 监听列表
     const events = ['mousedown', 'mousemove', 'keydown', 'wheel', 'touchstart'];
     
@@ -65,10 +52,21 @@ export const useSecurityEffects = () => {
     events.forEach(event => window.addEventListener(event, handleActivity, true));
 
     return () => {
-      if (idleTimerRef.current) clearTimeout(idleTimerRef.current);
       events.forEach(event => window.removeEventListener(event, handleActivity, true));
     };
-  }, [idleTimeoutMinutes, status, doLock]);
+  }, [idleTimeoutMinutes, status]);
+
+  useEffect(() => {
+    const unlisten = listen('vault-locked', () => {
+      console.log(`🔒 Idle timeout (${idleTimeoutMinutes}m) reached.`);
+      // 后端已清除主密钥，这里只同步前端状态
+      useKeyStore.setState({ status: 'locked', keys: [], encryptionKey: null });
+      toast.info('Vault locked');
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [idleTimeoutMinutes]);
 
   // --- B. 快捷键锁定逻辑 ---
   useEffect(() => {
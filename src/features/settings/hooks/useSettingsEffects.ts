@@ -1,7 +1,9 @@
-import { useEffect } from 'react';
+import { useEffect, useState } from 'react';
 import { useTranslation } from 'react-i18next';
 import { useSettingsStore } from '../application/useSettingsStore';
-import { invoke } from '@tauri-apps/api/core';
+import { AppSettingsService } from '../application/services/appSettings.service';
+import { toAppSettingsPatch } from '../domain/appSettings';
+import { subscribeAppSettings } from '@/store/useAppSettingsStore';
 import { enable, disable } from '@tauri-apps/plugin-autostart'; 
 
 export const useSettingsEffects = () => {
@@ -36,12 +38,23 @@ export const useSettingsEffects = () => {
     syncAutostart();
   }, [settings['general.launchAtStartup']]);
 
-  // 3. 监听关闭行为 (保持不变)
+  // 3. 同步后端持久化设置 (关闭行为、终端默认值、自动锁定)
+  // 后端启动时已从数据库加载，这里只在设置页修改后写回；等本地设置读取完成再同步，避免用默认值覆盖
+  const [hydrated, setHydrated] = useState(useSettingsStore.persist.hasHydrated());
+  useEffect(() => useSettingsStore.persist.onFinishHydration(() => setHydrated(true)), []);
+
+  useEffect(() => {
+    const unlisten = subscribeAppSettings();
+    return () => { unlisten.then(fn => fn()); };
+  }, []);
+
+  const backendPatch = JSON.stringify(toAppSettingsPatch(settings));
   useEffect(() => {
-    const minimizeToTray = settings['general.minimizeToTray'];
-    const closeBehavior = settings['general.closeBehavior']; 
-    invoke('update_app_config', { minimizeToTray, closeBehavior }).catch(_err => {});
-  }, [settings['general.minimizeToTray'], settings['general.closeBehavior']]);
+    if (!hydrated) return;
+    AppSettingsService.update(JSON.parse(backendPatch)).catch(err => {
+      console.error('Failed to sync app settings:', err);
+    });
+  }, [hydrated, backendPatch]);
 
   // =========================================================
   // 4. 外观设置 (仅处理类名和终端主题，不再处理壁纸同步)
@@ -19,6 +19,7 @@ import { ImageItemRenderer } from "./ImageItemRenderer";
 import { ShortcutInput } from "./ShortcutInput";
 import { HighlightManager } from "./HighlightManager";
 import { HighlightAssigner } from "./highlight/HighlightAssigner";
+import { TrashManager } from "@/features/trash";
 interface Props {
   item: SettingItem;
   value: any;
@@ -51,6 +52,7 @@ export const SettingItemRenderer = ({ item, value, onChange }: Props) => {
   if (item.type === 'theme-manager') return <ThemeManager />;
   if (item.type === 'background-manager') return <BackgroundManager />;
   if (item.type === 'backup-manager') return <BackupManager />;
+  if (item.type === 'trash-manager') return <TrashManager />;
   if (item.type === 'highlight-manager') return <HighlightManager />;
   if (item.type === 'highlight-assigner') return <HighlightAssigner />;
   if (item.type === 'font-selector') {
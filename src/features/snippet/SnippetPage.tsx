@@ -9,6 +9,7 @@ import { Code2, Loader2 } from "lucide-react";
 import { motion, AnimatePresence } from "framer-motion";
 import { useTranslation } from "react-i18next";
 import { clsx } from "clsx"; 
+import { showUndoToast } from "@/features/trash";
 
 export const SnippetPage = () => {
   const { t } = useTranslation();
@@ -52,6 +53,7 @@ export const SnippetPage = () => {
   const handleConfirmDelete = async () => {
     if (deleteId) {
       await deleteSnippet(deleteId);
+      showUndoToast(t, t('trash.snippetDeleted', 'Snippet moved to trash'), [{ kind: 'snippet', id: deleteId }], init);
       setDeleteId(null); 
     }
   };
@@ -157,7 +159,7 @@ export const SnippetPage = () => {
         open={!!deleteId}
         onOpenChange={(open) => !open && setDeleteId(null)}
         title={t('snippet.delete_title', 'Delete Snippet?')}
-        description={t('snippet.delete_confirm', 'Are you sure you want to delete this snippet? This action cannot be undone.')}
+        description={t('snippet.delete_confirm', 'Move this snippet to the trash? You can restore it from Settings > Trash.')}
         confirmText={t('common.delete', 'Delete')}
         cancelText={t('common.cancel', 'Cancel')}
         variant="destructive"
@@ -6,6 +6,7 @@ import { useServerStore } from "@/features/server/application/useServerStore";
 import { useSessionCredentialStore } from "@/store/useSessionCredentialStore";
 import { TerminalService } from "../services/terminal.service";
 import { HistoryService } from "../services/history.service";
+import { getErrorCode, getErrorMessage } from "@/utils/error";
 
 // 🟢 1. 引入高亮处理 Hook
 import { useTerminalHighlight } from "./useTerminalHighlight";
@@ -70,10 +71,10 @@ export const useTerminalConnection = (
       }, 300);
 
     } catch (err: any) {
-      const msg = typeof err === 'string' ? err : (err.message || JSON.stringify(err));
+      const msg = getErrorMessage(err);
       term.write(`\r\n\x1b[31mConnection failed: ${msg}\x1b[0m\r\n`);
       updateSessionStatus(sessionId, 'error');
-      if ((msg.includes("Auth Failed") || msg.includes("denied")) && serverConfig.provider === 'QuickConnect') {
+      if (getErrorCode(err) === "AUTH_FAILED" && serverConfig.provider === 'QuickConnect') {
         setIsPasswordRequired(true);
       }
     }
@@ -3,6 +3,7 @@ import { useEffect, useState, useCallback, useMemo } from 'react';
 import { useSettingsStore } from '@/features/settings/application/useSettingsStore';
 import { HighlightService } from '@/features/settings/application/services/highlight.service';
 import { HighlightRule } from '@/features/settings/domain/types';
+import { FolderService } from '@/features/server/folder';
 
 // 匹配 ANSI 转义序列的正则，确保高亮不会破坏原有终端控制字符
 const ANSI_CONTROL_REGEX = /(\x1b\[[0-9;?]*[a-zA-Z])/g;
@@ -43,15 +44,23 @@ export const useTerminalHighlight = (serverId?: string) => {
         const loadRules = async () => {
             if (!highlightAssignments || highlightAssignments.length === 0) return;
 
-            // 查找分配：优先匹配当前服务器 ID，无匹配则使用全局分配
-            let assignment = highlightAssignments.find(a => a.targetId === serverId);
-            if (!assignment) {
-                assignment = highlightAssignments.find(a => a.targetId === 'global');
+            // 查找分配：优先匹配当前服务器 ID，其次是所在文件夹的默认规则集，最后使用全局分配
+            let setId = highlightAssignments.find(a => a.targetId === serverId)?.setId;
+            if (!setId && serverId) {
+                try {
+                    const defaults = await FolderService.getServerDefaults(serverId);
+                    setId = defaults.highlightSetId ?? undefined;
+                } catch (e) {
+                    // 临时连接等不在数据库中的服务器没有文件夹
+                }
+            }
+            if (!setId) {
+                setId = highlightAssignments.find(a => a.targetId === 'global')?.setId;
             }
 
-            if (assignment) {
+            if (setId) {
                 try {
-                    const rules = await HighlightService.getRulesBySet(assignment.setId);
+                    const rules = await HighlightService.getRulesBySet(setId);
                     setRawRules(rules);
                 } catch (e) {
                     setRawRules([]);
@@ -15,6 +15,7 @@ import { useServerStore } from "@/features/server/application/useServerStore";
 import { useMonitorStore, RemoteCpuInfo, RemoteMemInfo, RemoteDiskInfo, RemoteOsInfo, RemoteNetworkInfo } from "@/store/useMonitorStore";
 // 🟢 [新增] 引入设置 Store，用于获取当前字体/主题配置
 import { useSettingsStore } from "@/features/settings/application/useSettingsStore";
+import { useMonitorSettings } from "@/store/useAppSettingsStore";
 import { MonitorDescriptor, MonitorSyncPayload } from "./monitor/types"; 
 
 import { InfoCard } from "./monitor/card/InfoCard";
@@ -41,6 +42,7 @@ export const TerminalMonitor = ({ collapsed = false, onToggle }: Props) => {
   const serverConfig = useServerStore(state => state.servers.find(s => s.id === sessionObj?.serverId));
   
   const { sessions, setSessionData, updateHistory } = useMonitorStore();
+  const { metricsIntervalMs } = useMonitorSettings();
   const currentSessionData = sessionId ? sessions[sessionId] : undefined;
 
   // 🟢 获取当前全局设置
@@ -124,9 +126,9 @@ export const TerminalMonitor = ({ collapsed = false, onToggle }: Props) => {
       } catch (err) {}
     };
     fetchData();
-    const interval = setInterval(fetchData, 3000);
+    const interval = setInterval(fetchData, metricsIntervalMs);
     return () => clearInterval(interval);
-  }, [sessionId, setSessionData, updateHistory]);
+  }, [sessionId, setSessionData, updateHistory, metricsIntervalMs]);
 
   if (collapsed) {
       return (
@@ -5,6 +5,7 @@ import { ListTree, ArrowDownUp, ChevronDown, ChevronUp, Cpu, Zap } from "lucide-
 import { clsx } from "clsx";
 import { formatBytes } from "@/utils/format";
 import { MonitorCard } from "../MonitorCard";
+import { useMonitorSettings } from "@/store/useAppSettingsStore";
 
 export interface RemoteProcessInfo {
     pid: number;
@@ -53,6 +54,7 @@ export const ProcessCard = ({
     const [loading, setLoading] = useState(true);
     const [sortField, setSortField] = useState<SortField>('cpuUsage');
     const [sortDirection, setSortDirection] = useState<SortDirection>('desc');
+    const { processIntervalMs } = useMonitorSettings();
 
     useEffect(() => {
         if (!sessionId) return;
@@ -66,9 +68,9 @@ export const ProcessCard = ({
             }
         };
         fetchData();
-        const interval = setInterval(fetchData, 5000);
+        const interval = setInterval(fetchData, processIntervalMs);
         return () => clearInterval(interval);
-    }, [sessionId]);
+    }, [sessionId, processIntervalMs]);
 
     const handleSort = (field: SortField) => {
         if (field === sortField) {
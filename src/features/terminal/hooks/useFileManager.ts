@@ -4,6 +4,7 @@ import { useFileStore } from "@/store/useFileStore";
 import { useMonitorStore } from "@/store/useMonitorStore";
 import { FileEntry } from "@/features/fs/types";
 import { useTerminalStore } from "@/store/useTerminalStore";
+import { getErrorCode, getErrorMessage } from "@/utils/error";
 
 export const useFileManager = (sessionId?: string) => {
   const { 
@@ -85,14 +86,14 @@ export const useFileManager = (sessionId?: string) => {
       console.error("List files error:", err);
       if (isMounted.current) {
          setStoreLoading(sessionId, false);
-         const errorMsg = err.toString();
+         const code = getErrorCode(err);
 
-         if (errorMsg.includes("SFTP not enabled") || errorMsg.includes("channel request failed")) {
+         if (code === "SFTP_UNAVAILABLE") {
              setError("no_sftp");
-         } else if (errorMsg.includes("Timed Out")) {
+         } else if (code === "NETWORK") {
              setError("timeout");
-         } else if (!errorMsg.includes("SSH connection not active")) {
-             setError(errorMsg);
+         } else if (code !== "SESSION_NOT_ACTIVE") {
+             setError(getErrorMessage(err));
          }
       }
     }
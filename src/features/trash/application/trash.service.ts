@@ -0,0 +1,10 @@
+import { invoke } from '@tauri-apps/api/core';
+import { TrashItem, TrashKind } from '../domain/types';
+
+export const TrashService = {
+  list: () => invoke<TrashItem[]>('list_trash'),
+  restore: (kind: TrashKind, id: string) => invoke<void>('restore_from_trash', { kind, id }),
+  purge: (kind: TrashKind, id: string) => invoke<void>('purge_from_trash', { kind, id }),
+  // 返回清除数量
+  empty: () => invoke<number>('empty_trash'),
+};
@@ -0,0 +1,32 @@
+import { toast } from 'sonner';
+import { TFunction } from 'i18next';
+import { getErrorMessage } from '@/utils/error';
+import { TrashKind } from '../domain/types';
+import { TrashService } from './trash.service';
+
+/**
+ * 删除后的提示，附带撤销按钮：从回收站恢复后调用 onRestored 刷新对应列表
+ * items 可以包含多个条目 (例如服务器与随之删除的密码)
+ */
+export const showUndoToast = (
+  t: TFunction,
+  message: string,
+  items: { kind: TrashKind; id: string }[],
+  onRestored: () => void | Promise<void>
+) => {
+  toast.success(message, {
+    duration: 8000,
+    action: {
+      label: t('trash.undo', 'Undo'),
+      onClick: async () => {
+        try {
+          for (const item of items) await TrashService.restore(item.kind, item.id);
+          await onRestored();
+          toast.success(t('trash.restored', 'Restored'));
+        } catch (err) {
+          toast.error(getErrorMessage(err));
+        }
+      },
+    },
+  });
+};
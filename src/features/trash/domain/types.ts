@@ -0,0 +1,12 @@
+// 回收站 (对应 src-tauri/src/commands/trash.rs)
+
+export type TrashKind = 'server' | 'snippet' | 'key';
+
+export interface TrashItem {
+  kind: TrashKind;
+  id: string;
+  name: string;
+  // 服务器为 IP，片段为语言，保险库条目为类型
+  detail?: string | null;
+  deletedAt: number;
+}
@@ -0,0 +1,6 @@
+// src/features/trash/index.ts
+
+export { TrashService } from './application/trash.service';
+export { showUndoToast } from './application/undoToast';
+export { TrashManager } from './presentation/TrashManager';
+export * from './domain/types';
@@ -0,0 +1,151 @@
+import { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { formatDistanceToNow } from "date-fns";
+import { Server, Code2, KeyRound, RotateCcw, Trash2 } from "lucide-react";
+
+import { Button } from "@/components/ui/button";
+import { ConfirmDialog } from "@/components/common/ConfirmDialog";
+import { useServerStore } from "@/features/server/application/useServerStore";
+import { useSnippetStore } from "@/features/snippet/store/useSnippetStore";
+import { useKeyStore } from "@/store/useKeyStore";
+import { getErrorMessage } from "@/utils/error";
+import { TrashService } from "../application/trash.service";
+import { TrashItem, TrashKind } from "../domain/types";
+
+const KIND_ICONS: Record<TrashKind, typeof Server> = {
+  server: Server,
+  snippet: Code2,
+  key: KeyRound,
+};
+
+// 恢复后刷新对应的列表
+const refreshKind = (kind: TrashKind) => {
+  switch (kind) {
+    case "server":
+      return useServerStore.getState().fetchServers(true);
+    case "snippet":
+      return useSnippetStore.getState().init();
+    case "key":
+      return useKeyStore.getState().loadKeys();
+  }
+};
+
+/**
+ * 设置页中的回收站：恢复或彻底删除已删除的服务器、代码片段与保险库条目
+ */
+export const TrashManager = () => {
+  const { t } = useTranslation();
+  const [items, setItems] = useState<TrashItem[]>([]);
+  const [isEmptyConfirmOpen, setIsEmptyConfirmOpen] = useState(false);
+
+  const load = () => TrashService.list().then(setItems).catch(console.error);
+
+  useEffect(() => {
+    load();
+  }, []);
+
+  const handleRestore = async (item: TrashItem) => {
+    try {
+      await TrashService.restore(item.kind, item.id);
+      setItems((prev) => prev.filter((i) => !(i.kind === item.kind && i.id === item.id)));
+      await refreshKind(item.kind);
+      toast.success(t("trash.restored", "Restored"));
+    } catch (err) {
+      toast.error(getErrorMessage(err));
+    }
+  };
+
+  const handlePurge = async (item: TrashItem) => {
+    try {
+      await TrashService.purge(item.kind, item.id);
+      setItems((prev) => prev.filter((i) => !(i.kind === item.kind && i.id === item.id)));
+    } catch (err) {
+      toast.error(getErrorMessage(err));
+    }
+  };
+
+  const handleEmpty = async () => {
+    try {
+      await TrashService.empty();
+      setItems([]);
+    } catch (err) {
+      toast.error(getErrorMessage(err));
+    } finally {
+      setIsEmptyConfirmOpen(false);
+    }
+  };
+
+  return (
+    <div className="py-3 px-4 my-1 space-y-3">
+      <div className="flex items-center justify-between">
+        <div>
+          <div className="text-sm font-medium text-slate-900 dark:text-slate-100">{t("trash.title", "Trash")}</div>
+          <div className="text-xs text-slate-500 mt-0.5">
+            {t("trash.desc", "Deleted servers, snippets and vault entries can be restored until they are purged.")}
+          </div>
+        </div>
+        <Button
+          variant="outline"
+          size="sm"
+          className="gap-1.5 text-xs text-red-500 hover:text-red-600"
+          disabled={items.length === 0}
+          onClick={() => setIsEmptyConfirmOpen(true)}
+        >
+          <Trash2 className="w-3.5 h-3.5" />
+          {t("trash.empty", "Empty trash")}
+        </Button>
+      </div>
+
+      {items.length === 0 ? (
+        <div className="text-xs text-slate-400 text-center py-8 border border-dashed border-slate-200 dark:border-slate-800 rounded-lg">
+          {t("trash.emptyState", "Trash is empty")}
+        </div>
+      ) : (
+        <div className="divide-y divide-slate-100 dark:divide-slate-800 border border-slate-200 dark:border-slate-800 rounded-lg">
+          {items.map((item) => {
+            const Icon = KIND_ICONS[item.kind];
+            return (
+              <div key={`${item.kind}-${item.id}`} className="flex items-center gap-3 px-3 py-2">
+                <Icon className="w-4 h-4 shrink-0 text-slate-400" />
+                <div className="min-w-0 flex-1">
+                  <div className="text-sm text-slate-700 dark:text-slate-200 truncate">{item.name}</div>
+                  <div className="text-[11px] text-slate-400 truncate">
+                    {item.detail && <span className="font-mono">{item.detail} · </span>}
+                    {t("trash.deletedAgo", "Deleted {{time}}", {
+                      time: formatDistanceToNow(new Date(item.deletedAt), { addSuffix: true }),
+                    })}
+                  </div>
+                </div>
+                <Button variant="ghost" size="sm" className="h-7 gap-1 text-xs" onClick={() => handleRestore(item)}>
+                  <RotateCcw className="w-3.5 h-3.5" />
+                  {t("trash.restore", "Restore")}
+                </Button>
+                <Button
+                  variant="ghost"
+                  size="sm"
+                  className="h-7 text-xs text-red-500 hover:text-red-600"
+                  title={t("trash.purge", "Delete permanently")}
+                  onClick={() => handlePurge(item)}
+                >
+                  <Trash2 className="w-3.5 h-3.5" />
+                </Button>
+              </div>
+            );
+          })}
+        </div>
+      )}
+
+      <ConfirmDialog
+        open={isEmptyConfirmOpen}
+        onOpenChange={setIsEmptyConfirmOpen}
+        title={t("trash.emptyTitle", "Empty trash?")}
+        description={t("trash.emptyConfirm", "All items in the trash will be permanently deleted. This action cannot be undone.")}
+        variant="destructive"
+        confirmText={t("trash.empty", "Empty trash")}
+        cancelText={t("common.cancel", "Cancel")}
+        onConfirm={handleEmpty}
+      />
+    </div>
+  );
+};
@@ -0,0 +1,66 @@
+import { useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { useNavigate } from "react-router-dom";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { useTerminalStore } from "@/store/useTerminalStore";
+import { useServerStore } from "@/features/server/application/useServerStore";
+import { createQuickServer } from "@/features/server/domain/quickServer";
+import { AppError, getErrorMessage } from "@/utils/error";
+
+// 对应 src-tauri/src/cli.rs 的 CliRequest
+type CliRequest =
+  | { kind: 'connect'; serverId: string; name: string }
+  | { kind: 'quickConnect'; host: string; port: number; username?: string | null }
+  | { kind: 'error'; error: AppError };
+
+/**
+ * 处理命令行 / ssh:// 链接发来的连接请求
+ * 启动时先取一次 (首次启动的参数)，之后每次收到 cli-request 事件再取
+ */
+export const useCliRequests = () => {
+  const { t } = useTranslation();
+  const navigate = useNavigate();
+
+  useEffect(() => {
+    const handle = (request: CliRequest) => {
+      const { createTab } = useTerminalStore.getState();
+
+      switch (request.kind) {
+        case 'connect': {
+          const server = useServerStore.getState().servers.find(s => s.id === request.serverId);
+          createTab({
+            id: request.serverId,
+            title: server?.name ?? request.name,
+            type: 'ssh',
+            serverIp: server?.ip
+          });
+          navigate('/terminal');
+          break;
+        }
+        case 'quickConnect': {
+          const quickServer = createQuickServer(request.host, request.port, request.username || "root");
+          toast.info(t('dashboard.quickConnect.connecting', 'Connecting to {{host}}...', { host: request.host }));
+          useServerStore.getState().addTemporaryServer(quickServer);
+          createTab(quickServer);
+          navigate('/terminal');
+          break;
+        }
+        case 'error':
+          toast.error(getErrorMessage(request.error));
+          break;
+      }
+    };
+
+    const drain = () => {
+      invoke<CliRequest[]>('take_cli_requests')
+        .then(requests => requests.forEach(handle))
+        .catch(console.error);
+    };
+
+    drain();
+    const unlisten = listen('cli-request', drain);
+    return () => { unlisten.then(f => f()); };
+  }, [navigate, t]);
+};
@@ -151,12 +151,173 @@ export const en = {
       delete: {
         title: "Delete server?",
         confirm:
-          "Are you sure you want to delete server “{{name}}”? This action cannot be undone.",
+          "Move server “{{name}}” to the trash? You can restore it from Settings > Trash.",
         deletePassword:
           "Also delete associated password",
         deleteKey:
           "Also delete associated key"
       },
+      import: {
+        button: "Import",
+        title: "Import Servers",
+        sshConfigPlaceholder: "~/.ssh/config (leave empty for default)",
+        filePlaceholder: "Select an exported file",
+        browseFolder: "Choose sessions folder",
+        skipped: "{{count}} sessions skipped",
+        browse: "Browse",
+        preview: "Preview",
+        confirm: "Import",
+        selectAll: "Select all",
+        selected: "{{count}} of {{total}} selected",
+        empty: "No hosts found",
+        previewFailed: "Failed to read import source",
+        vaultLocked: "Unlock the vault to import private keys.",
+        done: "Imported {{created}} new, updated {{updated}}",
+        status: {
+          new: "New",
+          update: "Update",
+          unchanged: "Unchanged"
+        }
+      },
+      bulk: {
+        button: "Bulk edit",
+        title: "Bulk Edit Servers",
+        hint: "Changes apply to the {{count}} server(s) in the current list. Only checked fields are changed.",
+        direct: "Direct connection",
+        noHighlight: "Not assigned",
+        addTags: "Add tags (comma separated)",
+        removeTags: "Remove tags (comma separated)",
+        preview: "Preview",
+        apply: "Apply",
+        previewSummary: "{{changed}} of {{total}} server(s) will change",
+        unchanged: "Already up to date",
+        applied: "Updated {{count}} server(s)",
+        fields: {
+          username: "Username",
+          credential: "Credential",
+          proxy: "Proxy",
+          port: "Port",
+          tags: "Tags",
+          connectTimeout: "Timeout (s)",
+          keepAliveInterval: "Keep-alive (s)",
+          highlightSet: "Highlight rules"
+        }
+      },
+      stats: {
+        title: "Connection Statistics",
+        allTime: "All time",
+        lastDays: "Last {{count}} days",
+        empty: "No connections in this period",
+        server: "Server",
+        connections: "Connections",
+        avgSession: "Avg. session",
+        failureRate: "Failure rate",
+        reconnects: "Reconnects",
+        traffic: "In / Out",
+        back: "All servers",
+        sessionSummary: "{{count}} connection(s), average {{avg}}",
+        noHistory: "No sessions recorded yet",
+        connectedAt: "Connected",
+        duration: "Duration",
+        auth: "Auth",
+        result: "Result",
+        reconnect: "reconnect #{{count}}",
+        failed: "Failed",
+        active: "Connected",
+        reasons: {
+          user: "Disconnected",
+          closed: "Closed by server",
+          error: "Connection lost",
+          reconnect: "Reconnected",
+          interrupted: "Interrupted"
+        }
+      },
+      health: {
+        scan: "Scan",
+        scanHint: "Check reachability of the listed servers",
+        cancel: "Cancel scan",
+        cancelled: "Scan cancelled",
+        summary: "{{up}} reachable, {{down}} unreachable",
+        up: "Reachable",
+        down: "Unreachable",
+        unknown: "Not scanned yet",
+        checked: "Checked {{time}}"
+      },
+      expiration: {
+        title: "Upcoming Expirations",
+        empty: "No servers expire within the reminder window.",
+        alertTitle: "{{count}} server(s) expiring soon",
+        today: "Expires today",
+        inDays: "In {{count}} day(s)",
+        expiredAgo: "Expired {{count}} day(s) ago"
+      },
+      template: {
+        button: "Templates",
+        manage: "Server Templates",
+        label: "Template",
+        none: "No template",
+        create: "New template",
+        newName: "New Template",
+        empty: "No templates yet",
+        selectHint: "Select a template to edit",
+        name: "Name",
+        description: "Description",
+        hint: "Servers using this template pick up these values when they connect, unless they override them. Empty fields are left to each server.",
+        credential: "Credential",
+        proxy: "Proxy",
+        port: "Port",
+        timeout: "Timeout (s)",
+        keepAlive: "Keep-alive (s)",
+        startupCommands: "Startup commands (one per line)",
+        notSet: "Not set",
+        vaultLocked: "Unlock the vault to choose",
+        delete: "Delete template",
+        saved: "Template saved",
+        bindingHint: "Turn a field off to use this server's own value instead.",
+        fields: {
+          auth: "Credential",
+          port: "Port",
+          proxy: "Proxy",
+          connectTimeout: "Connection timeout",
+          keepAliveInterval: "Keep-alive",
+          startupCommands: "Startup commands"
+        }
+      },
+      folder: {
+        all: "All folders",
+        root: "No folder",
+        manage: "Manage Folders",
+        create: "New folder",
+        createChild: "New subfolder",
+        newName: "New Folder",
+        empty: "No folders yet",
+        selectHint: "Select a folder to edit its defaults",
+        name: "Name",
+        parent: "Parent folder",
+        defaultsHint: "Servers in this folder and its subfolders inherit these values when they leave them empty.",
+        username: "Default username",
+        proxy: "Default proxy",
+        credential: "Default credential",
+        highlight: "Highlight rules",
+        inherit: "Inherit",
+        vaultLocked: "Unlock the vault to choose",
+        delete: "Delete folder",
+        saved: "Folder saved"
+      },
+      export: {
+        button: "Export",
+        title: "Export Servers",
+        format: "Format",
+        tags: "Only servers with tags (empty = all)",
+        includeSecrets: "Include passwords and keys (encrypted)",
+        sshConfigNoSecrets: "ssh_config cannot contain secrets.",
+        passphrase: "Export passphrase (min. {{min}} characters)",
+        passphraseHint: "Secrets are encrypted with AES-256-GCM using a key derived from this passphrase. It cannot be recovered.",
+        confirm: "Export",
+        done: "Exported {{count}} servers",
+        failed: "Export failed",
+        vaultLocked: "Unlock the vault to export secrets."
+      },
       list: {
         searchPlaceholder:
           "Search server name, IP...",
@@ -185,6 +346,7 @@ export const en = {
       },
 
       form: {
+        folder: "Folder",
         titleNew: "New Server",
         titleEdit: "Edit Server",
         name: "Server Name",
@@ -247,7 +409,36 @@ export const en = {
         reconnectDesc:
           "Automatically retry if the connection is lost.",
         retries: "Retry Count",
-        retriesDesc: "Maximum retry attempts"
+        retriesDesc: "Maximum retry attempts",
+        scrollback: "Scrollback Buffer (MB)",
+        scrollbackDesc: "Output kept for reattach and search (1-64, empty for default).",
+        protocol: "Protocol",
+        loginScript: "Login Script",
+        loginScriptDesc:
+          "Wait for each prompt, then send the text. {username} and {password} are replaced. Leave empty to answer login: / Password: automatically.",
+        addStep: "Add step",
+        expect: "Wait for (e.g. login:)",
+        send: "Send (e.g. {username})",
+        tmuxSession: "tmux Session",
+        tmuxSessionDesc: "Attach to (or create) this session on connect. Letters, digits, _ . - only.",
+        tmuxSessionInvalid: "Invalid session name",
+        initialCwd: "Initial Directory",
+        envVars: "Environment Variables",
+        envVarsDesc: "Sent before the shell starts; the server must allow them via AcceptEnv.",
+        envValue: "Value",
+        addEnvVar: "Add variable",
+        envVarInvalid: "Names may only contain letters, digits and _, and must not start with a digit.",
+        startupCommands: "Startup Commands (one per line)",
+        startupSnippets: "Also runs {{count}} snippet(s) referenced by this server.",
+        algorithms: "SSH Algorithms",
+        algorithmsDesc: "Use Legacy for old devices that only offer outdated algorithms.",
+        algorithmsCustom: "Custom algorithm lists are set and take precedence over the preset.",
+        algorithmProfiles: {
+          default: "Default",
+          modern: "Modern",
+          compatible: "Compatible",
+          legacy: "Legacy"
+        }
       }
     },
 
@@ -381,7 +572,7 @@ export const en = {
       delete: {
         title: "Delete Credential",
         confirm:
-          "Are you sure you want to delete this credential? This action cannot be undone.",
+          "Move this credential to the trash? You can restore it from Settings > Trash.",
         warning: "Associated with {{count}} servers",
         impact:
           "Deleting this key will disconnect the following:"
@@ -498,6 +689,36 @@ export const en = {
       empty: "This folder is empty"
     },
 
+    trash: {
+      title: "Trash",
+      desc: "Deleted servers, snippets and vault entries can be restored until they are purged.",
+      empty: "Empty trash",
+      emptyState: "Trash is empty",
+      emptyTitle: "Empty trash?",
+      emptyConfirm: "All items in the trash will be permanently deleted. This action cannot be undone.",
+      restore: "Restore",
+      restored: "Restored",
+      purge: "Delete permanently",
+      deletedAgo: "Deleted {{time}}",
+      undo: "Undo",
+      serverDeleted: "Server moved to trash",
+      snippetDeleted: "Snippet moved to trash",
+      keyDeleted: "Credential moved to trash"
+    },
+
+    palette: {
+      title: "Search",
+      placeholder: "Search servers, snippets, vault and history...",
+      empty: "No results found.",
+      copied: "Command copied to clipboard",
+      kinds: {
+        server: "Servers",
+        snippet: "Snippets",
+        key: "Vault",
+        history: "Command History"
+      }
+    },
+
     snippet: {
       copy_hint: "Click to copy",
       search_placeholder: "Search code library",
@@ -516,7 +737,7 @@ export const en = {
       },
       delete_title: "Delete snippet?",
       delete_confirm:
-        "Are you sure you want to delete this snippet? This action cannot be undone."
+        "Move this snippet to the trash? You can restore it from Settings > Trash."
     },
 
     tools: {
@@ -562,9 +783,17 @@ export const en = {
         connection: "Connection",
         security: "Security",
         backup: "Data",
+        trash: "Trash",
         about: "About"
       },
 
+      trash: {
+        retentionDays: "Keep deleted items",
+        retentionDaysDesc:
+          "Items in the trash are permanently deleted after this period",
+        keepForever: "Forever"
+      },
+
       general: {
         deviceName: "Device Name",
         deviceNameDesc:
@@ -583,6 +812,10 @@ export const en = {
           "Minimize to system tray",
         minimizeToTrayDesc:
           "Hide taskbar icon when minimized",
+        expirationWarnDays: "Expiration reminders",
+        expirationWarnDaysDesc:
+          "Notify when servers with an expiration date are about to expire",
+        expirationOff: "Off",
         closeBehavior:
           "When closing the main window",
         closeBehaviorDesc:
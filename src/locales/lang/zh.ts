@@ -155,10 +155,171 @@ export const zh = {
       deleteKeyUsedWarning: "被其他服务器使用的凭据：",
       delete:{
         title: "删除服务器？",
-        confirm: "你确定要删除服务器 “{{name}}” 吗？此操作无法撤销。",
+        confirm: "将服务器 “{{name}}” 移至回收站？可以在 设置 > 回收站 中恢复。",
         deletePassword: "同时删除关联的密码",
         deleteKey: "同时删除关联的密钥",
       },
+      import: {
+        button: "导入",
+        title: "导入服务器",
+        sshConfigPlaceholder: "~/.ssh/config (留空使用默认路径)",
+        filePlaceholder: "选择导出的文件",
+        browseFolder: "选择会话目录",
+        skipped: "已跳过 {{count}} 个会话",
+        browse: "浏览",
+        preview: "预览",
+        confirm: "导入",
+        selectAll: "全选",
+        selected: "已选择 {{count}} / {{total}}",
+        empty: "未找到主机",
+        previewFailed: "读取导入源失败",
+        vaultLocked: "请解锁密钥库以导入私钥。",
+        done: "新增 {{created}} 台，更新 {{updated}} 台",
+        status: {
+          new: "新增",
+          update: "更新",
+          unchanged: "无变化"
+        }
+      },
+      bulk: {
+        button: "批量编辑",
+        title: "批量编辑服务器",
+        hint: "修改将应用到当前列表中的 {{count}} 台服务器，只修改勾选的字段。",
+        direct: "直连",
+        noHighlight: "不单独分配",
+        addTags: "添加标签 (逗号分隔)",
+        removeTags: "移除标签 (逗号分隔)",
+        preview: "预览",
+        apply: "应用",
+        previewSummary: "{{total}} 台服务器中有 {{changed}} 台将被修改",
+        unchanged: "已是目标值",
+        applied: "已更新 {{count}} 台服务器",
+        fields: {
+          username: "用户名",
+          credential: "凭据",
+          proxy: "代理",
+          port: "端口",
+          tags: "标签",
+          connectTimeout: "超时 (秒)",
+          keepAliveInterval: "心跳间隔 (秒)",
+          highlightSet: "高亮规则"
+        }
+      },
+      stats: {
+        title: "连接统计",
+        allTime: "全部",
+        lastDays: "最近 {{count}} 天",
+        empty: "该时间段内没有连接",
+        server: "服务器",
+        connections: "连接次数",
+        avgSession: "平均时长",
+        failureRate: "失败率",
+        reconnects: "重连",
+        traffic: "接收 / 发送",
+        back: "全部服务器",
+        sessionSummary: "共 {{count}} 次连接，平均 {{avg}}",
+        noHistory: "暂无会话记录",
+        connectedAt: "连接时间",
+        duration: "时长",
+        auth: "认证",
+        result: "结果",
+        reconnect: "第 {{count}} 次重连",
+        failed: "失败",
+        active: "连接中",
+        reasons: {
+          user: "已断开",
+          closed: "服务器关闭",
+          error: "连接中断",
+          reconnect: "已重连",
+          interrupted: "异常中断"
+        }
+      },
+      health: {
+        scan: "扫描",
+        scanHint: "检查列表中服务器的可达性",
+        cancel: "取消扫描",
+        cancelled: "扫描已取消",
+        summary: "{{up}} 台可达，{{down}} 台不可达",
+        up: "可达",
+        down: "不可达",
+        unknown: "尚未扫描",
+        checked: "检查于 {{time}}"
+      },
+      expiration: {
+        title: "即将到期",
+        empty: "提醒范围内没有即将到期的服务器。",
+        alertTitle: "{{count}} 台服务器即将到期",
+        today: "今天到期",
+        inDays: "{{count}} 天后到期",
+        expiredAgo: "已过期 {{count}} 天"
+      },
+      template: {
+        button: "模板",
+        manage: "服务器模板",
+        label: "模板",
+        none: "不使用模板",
+        create: "新建模板",
+        newName: "新模板",
+        empty: "还没有模板",
+        selectHint: "选择一个模板进行编辑",
+        name: "名称",
+        description: "描述",
+        hint: "使用此模板的服务器在连接时采用这些值，除非服务器自行覆盖。留空的字段由各服务器自己决定。",
+        credential: "凭据",
+        proxy: "代理",
+        port: "端口",
+        timeout: "超时 (秒)",
+        keepAlive: "心跳 (秒)",
+        startupCommands: "启动命令 (每行一条)",
+        notSet: "不设置",
+        vaultLocked: "解锁密钥库后选择",
+        delete: "删除模板",
+        saved: "模板已保存",
+        bindingHint: "关闭某项即改用此服务器自己的值。",
+        fields: {
+          auth: "凭据",
+          port: "端口",
+          proxy: "代理",
+          connectTimeout: "连接超时",
+          keepAliveInterval: "心跳间隔",
+          startupCommands: "启动命令"
+        }
+      },
+      folder: {
+        all: "全部文件夹",
+        root: "未分组",
+        manage: "管理文件夹",
+        create: "新建文件夹",
+        createChild: "新建子文件夹",
+        newName: "新文件夹",
+        empty: "还没有文件夹",
+        selectHint: "选择文件夹以编辑默认值",
+        name: "名称",
+        parent: "上级文件夹",
+        defaultsHint: "此文件夹及子文件夹中的服务器在对应字段留空时继承这些值。",
+        username: "默认用户名",
+        proxy: "默认代理",
+        credential: "默认凭据",
+        highlight: "高亮规则",
+        inherit: "继承",
+        vaultLocked: "解锁密钥库后选择",
+        delete: "删除文件夹",
+        saved: "文件夹已保存"
+      },
+      export: {
+        button: "导出",
+        title: "导出服务器",
+        format: "格式",
+        tags: "仅导出带有以下标签的服务器 (不选则全部)",
+        includeSecrets: "包含密码和私钥 (加密)",
+        sshConfigNoSecrets: "ssh_config 无法包含凭据。",
+        passphrase: "导出口令 (至少 {{min}} 个字符)",
+        passphraseHint: "凭据使用由此口令派生的密钥以 AES-256-GCM 加密，口令遗失后无法恢复。",
+        confirm: "导出",
+        done: "已导出 {{count}} 台服务器",
+        failed: "导出失败",
+        vaultLocked: "请解锁密钥库以导出凭据。"
+      },
       list: {
             searchPlaceholder: "搜索服务器名称、IP...",
             add: "添加服务器",
@@ -185,6 +346,7 @@ export const zh = {
             idAsc: "ID (正序)"
       },
       form: {
+        folder: "文件夹",
         titleNew: "新建服务器",
         titleEdit: "编辑服务器",
         name: "服务器名称",
@@ -240,6 +402,34 @@ export const zh = {
           reconnectDesc: "如果断开连接，则自动重试。",
           retries: "重试次数",
           retriesDesc: "最大尝试次数",
+          scrollback: "回滚缓冲 (MB)",
+          scrollbackDesc: "保留的终端输出，用于重新挂载与搜索 (1-64，留空使用默认值)。",
+          protocol: "协议",
+          loginScript: "登录脚本",
+          loginScriptDesc: "依次等待提示出现后发送内容，{username} 和 {password} 会被替换。留空时自动应答 login: / Password:。",
+          addStep: "添加步骤",
+          expect: "等待 (如 login:)",
+          send: "发送 (如 {username})",
+          tmuxSession: "tmux 会话",
+          tmuxSessionDesc: "连接时接入 (或创建) 该会话，仅限字母、数字与 _ . -。",
+          tmuxSessionInvalid: "会话名无效",
+          initialCwd: "初始目录",
+          envVars: "环境变量",
+          envVarsDesc: "在 Shell 启动前发送，需要服务端在 AcceptEnv 中放行。",
+          envValue: "值",
+          addEnvVar: "添加变量",
+          envVarInvalid: "变量名只能包含字母、数字和 _，且不能以数字开头。",
+          startupCommands: "启动命令 (每行一条)",
+          startupSnippets: "还会执行该服务器引用的 {{count}} 个片段。",
+          algorithms: "SSH 算法",
+          algorithmsDesc: "只支持过时算法的老旧设备请选择“旧版”。",
+          algorithmsCustom: "已设置自定义算法列表，优先于预设。",
+          algorithmProfiles: {
+            default: "默认",
+            modern: "现代",
+            compatible: "兼容",
+            legacy: "旧版",
+          },
       }
   },
 
@@ -388,7 +578,7 @@ keys: {
     },
     delete:{
       title: "删除凭据",
-      confirm: "您确定要删除此凭据吗？此操作无法撤销。",
+      confirm: "将此凭据移至回收站？可以在 设置 > 回收站 中恢复。",
       warning: "已经关联 {{count}} 台服务器",
       impact: "删除此密钥将断开与以下对象的连接：",
     },
@@ -506,6 +696,35 @@ keys: {
       },
       empty: "此文件夹为空"
     },
+    trash: {
+      title: "回收站",
+      desc: "删除的服务器、代码片段和密钥库条目在彻底清除前都可以恢复。",
+      empty: "清空回收站",
+      emptyState: "回收站为空",
+      emptyTitle: "清空回收站？",
+      emptyConfirm: "回收站中的所有条目将被彻底删除，此操作无法撤销。",
+      restore: "恢复",
+      restored: "已恢复",
+      purge: "彻底删除",
+      deletedAgo: "删除于 {{time}}",
+      undo: "撤销",
+      serverDeleted: "服务器已移至回收站",
+      snippetDeleted: "代码片段已移至回收站",
+      keyDeleted: "凭据已移至回收站"
+    },
+
+    palette: {
+      title: "搜索",
+      placeholder: "搜索服务器、代码片段、密钥库和命令历史...",
+      empty: "没有找到结果。",
+      copied: "命令已复制到剪贴板",
+      kinds: {
+        server: "服务器",
+        snippet: "代码片段",
+        key: "密钥库",
+        history: "命令历史"
+      }
+    },
     snippet:{
       copy_hint:"点击复制",
       search_placeholder: "搜索代码库",
@@ -523,7 +742,7 @@ keys: {
         tagsPlaceholder: "选择标签...",
       },
       delete_title: "删除代码片段？",
-      delete_confirm: "您确定要删除此代码片段吗？此操作无法撤销。"
+      delete_confirm: "将此代码片段移至回收站？可以在 设置 > 回收站 中恢复。"
     },
    tools: {
     placeholder: {
@@ -563,8 +782,14 @@ keys: {
       connection: "连接",
       security: "安全",
       backup: "数据",
+      trash: "回收站",
       about: "关于"
     },
+    trash: {
+      retentionDays: "已删除条目保留时间",
+      retentionDaysDesc: "回收站中的条目超过该时间后将被彻底删除",
+      keepForever: "永久保留"
+    },
     general: {
       deviceName: "设备名",
       deviceNameDesc: "用于识别此设备上的备份",
@@ -576,6 +801,9 @@ keys: {
       launchAtStartupDesc: "系统登录时自动启动应用",
       minimizeToTray: "最小化到系统托盘",
       minimizeToTrayDesc: "最小化时隐藏任务栏图标，仅显示在托盘",
+      expirationWarnDays: "到期提醒",
+      expirationWarnDaysDesc: "设置了到期日的服务器即将到期时发出通知",
+      expirationOff: "关闭",
       closeBehavior: "关闭主窗口时",
       closeBehaviorDesc: "点击窗口关闭按钮（X）时的行为",
         languageOptions: {
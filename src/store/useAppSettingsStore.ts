@@ -0,0 +1,55 @@
+import { useEffect } from 'react';
+import { create } from 'zustand';
+import { listen } from '@tauri-apps/api/event';
+import { AppSettings, AppSettingsPatch, DEFAULT_MONITOR_SETTINGS } from '@/features/settings/domain/appSettings';
+import { AppSettingsService } from '@/features/settings/application/services/appSettings.service';
+
+// 后端持久化设置的镜像 (监控间隔、历史过滤等)
+// 以后端为准：启动时拉取一次，之后跟随 settings-changed 事件更新
+interface AppSettingsState {
+  settings: AppSettings | null;
+  load: () => Promise<void>;
+  update: (patch: AppSettingsPatch) => Promise<void>;
+  reset: () => Promise<void>;
+}
+
+export const useAppSettingsStore = create<AppSettingsState>((set) => ({
+  settings: null,
+
+  load: async () => {
+    try {
+      set({ settings: await AppSettingsService.get() });
+    } catch (e) {
+      console.error('Failed to load app settings:', e);
+    }
+  },
+
+  update: async (patch) => {
+    set({ settings: await AppSettingsService.update(patch) });
+  },
+
+  reset: async () => {
+    set({ settings: await AppSettingsService.reset() });
+  },
+}));
+
+/**
+ * 订阅后端设置变更 (包括其他窗口发起的修改)，返回取消订阅函数
+ */
+export const subscribeAppSettings = () => {
+  useAppSettingsStore.getState().load();
+  return listen<AppSettings>('settings-changed', (event) => {
+    useAppSettingsStore.setState({ settings: event.payload });
+  });
+};
+
+/**
+ * 监控刷新间隔 (独立监控窗口没有订阅事件，首次使用时拉取一次)
+ */
+export const useMonitorSettings = () => {
+  const monitor = useAppSettingsStore((s) => s.settings?.monitor);
+  useEffect(() => {
+    if (!useAppSettingsStore.getState().settings) useAppSettingsStore.getState().load();
+  }, []);
+  return monitor ?? DEFAULT_MONITOR_SETTINGS;
+};
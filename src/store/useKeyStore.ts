@@ -51,7 +51,8 @@ interface KeyState {
     }) => Promise<KeyEntry>;
 
     updateKey: (id: string, data: Partial<KeyEntry> & { content?: string, passphrase?: string }) => Promise<void>;
-    deleteKey: (id: string) => Promise<void>;
+    // 仍被服务器引用时需要 force，否则后端拒绝删除
+    deleteKey: (id: string, force?: boolean) => Promise<void>;
     
     // 🟢 [新增] 检查密钥关联 (删除前调用)
     checkAssociations: (id: string) => Promise<KeyUsageStats>;
@@ -185,8 +186,8 @@ export const useKeyStore = create<KeyState>((set, get) => ({
         await get().loadKeys();
     },
 
-    deleteKey: async (id) => {
-        await invoke('delete_key', { id });
+    deleteKey: async (id, force) => {
+        await invoke('delete_key', { id, force: force ?? false });
         set(state => ({ keys: state.keys.filter(k => k.id !== id) }));
     },
 
@@ -0,0 +1,34 @@
+/**
+ * 后端命令的结构化错误 (对应 src-tauri/src/error.rs 的 AppError)
+ */
+export interface AppError {
+  code: string;
+  message: string;
+  details?: string | null;
+}
+
+export const isAppError = (err: unknown): err is AppError =>
+  typeof err === 'object' && err !== null && typeof (err as AppError).code === 'string';
+
+/**
+ * 取错误码
+ * 尚未迁移的命令仍返回 "CODE: message" 形式的字符串，这里一并兼容
+ */
+export const getErrorCode = (err: unknown): string | undefined => {
+  if (isAppError(err)) return err.code;
+  if (typeof err === 'string') {
+    const match = /^([A-Z_]+):/.exec(err);
+    return match?.[1];
+  }
+  return undefined;
+};
+
+/**
+ * 转为可展示的文本
+ */
+export const getErrorMessage = (err: unknown): string => {
+  if (isAppError(err)) return err.details ? `${err.message}: ${err.details}` : err.message;
+  if (typeof err === 'string') return err;
+  if (err instanceof Error) return err.message;
+  return JSON.stringify(err);
+};